@@ -1,25 +1,142 @@
-use std::ffi::CString;
-use rcdecomp_core::{buat_konteks_decompiler, muat_file_biner, hapus_konteks_decompiler};
+use std::ffi::{CString, c_char};
+use rcdecomp_core::{buat_konteks_decompiler, muat_file_biner, hapus_konteks_decompiler, atur_base_rebase, atur_sembunyikan_crt, atur_sintaks_listing, tambah_spec_semantik, ambil_listing_asm, ambil_xref_ke, ambil_xref_dari, ContextDecompiler};
+use rcdecomp_core::analysis::recovery::xref::JenisXref;
+use rcdecomp_core::codegen::asm_listing::SintaksAsm;
 use env_logger;
 use log::{info, error};
 
+fn parse_alamat(teks: &str) -> Option<u64> {
+    let bersih = teks.trim();
+    if let Some(hex) = bersih.strip_prefix("0x").or_else(|| bersih.strip_prefix("0X")) {
+        u64::from_str_radix(hex, 16).ok()
+    } else {
+        bersih.parse::<u64>().ok()
+    }
+}
+
+fn tampilkan_xref(ctx_ptr: *mut ContextDecompiler, alamat: u64) {
+    let query: [(&str, unsafe extern "C" fn(*const ContextDecompiler, u64, *mut u64, *mut i32, usize) -> i32); 2] = [
+        ("ke", ambil_xref_ke),
+        ("dari", ambil_xref_dari),
+    ];
+    for (arah, fungsi_query) in query {
+        let jumlah = unsafe { fungsi_query(ctx_ptr, alamat, std::ptr::null_mut(), std::ptr::null_mut(), 0) };
+        if jumlah <= 0 {
+            println!("Tidak ada xref {} 0x{:x}", arah, alamat);
+            continue;
+        }
+        let mut list_alamat = vec![0u64; jumlah as usize];
+        let mut list_jenis = vec![0i32; jumlah as usize];
+        unsafe { fungsi_query(ctx_ptr, alamat, list_alamat.as_mut_ptr(), list_jenis.as_mut_ptr(), jumlah as usize) };
+        println!("Xref {} 0x{:x} ({}):", arah, alamat, jumlah);
+        for (lawan, kode) in list_alamat.iter().zip(list_jenis.iter()) {
+            let jenis = JenisXref::dari_kode(*kode).map(|j| j.sebagai_str()).unwrap_or("unknown");
+            println!("  0x{:x} [{}]", lawan, jenis);
+        }
+    }
+}
+
+fn tampilkan_listing_asm(ctx_ptr: *mut ContextDecompiler) {
+    let panjang = unsafe { ambil_listing_asm(ctx_ptr, std::ptr::null_mut(), 0) };
+    if panjang <= 0 {
+        println!("Listing assembly kosong");
+        return;
+    }
+    let mut buffer = vec![0u8; panjang as usize + 1];
+    unsafe { ambil_listing_asm(ctx_ptr, buffer.as_mut_ptr() as *mut c_char, buffer.len()) };
+    buffer.truncate(panjang as usize);
+    println!("{}", String::from_utf8_lossy(&buffer));
+}
+
 fn main() {
     env_logger::init();
     info!("RCDecomp CLI - Memulai...");
+    let mut path_target = "test_binary.elf".to_string();
+    let mut base_rebase: Option<u64> = None;
+    let mut query_xref: Vec<u64> = Vec::new();
+    let mut sembunyikan_crt = false;
+    let mut tampilkan_asm = false;
+    let mut sintaks = SintaksAsm::Intel;
+    let mut daftar_spec: Vec<String> = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base" => {
+                match args.next().as_deref().and_then(parse_alamat) {
+                    Some(base) => base_rebase = Some(base),
+                    None => {
+                        error!("Argumen --base butuh alamat valid (contoh: --base 0x555555554000)");
+                        return;
+                    }
+                }
+            },
+            "--xref" => {
+                match args.next().as_deref().and_then(parse_alamat) {
+                    Some(alamat) => query_xref.push(alamat),
+                    None => {
+                        error!("Argumen --xref butuh alamat valid (contoh: --xref 0x401000)");
+                        return;
+                    }
+                }
+            },
+            "--semantics" => {
+                match args.next() {
+                    Some(path) => daftar_spec.push(path),
+                    None => {
+                        error!("Argumen --semantics butuh path file spec (contoh: --semantics x86_extra.json)");
+                        return;
+                    }
+                }
+            },
+            "--hide-crt" => sembunyikan_crt = true,
+            "--asm" => tampilkan_asm = true,
+            "--syntax" => {
+                match args.next().as_deref().and_then(SintaksAsm::dari_nama) {
+                    Some(s) => sintaks = s,
+                    None => {
+                        error!("Argumen --syntax butuh intel atau att");
+                        return;
+                    }
+                }
+            },
+            _ => path_target = arg,
+        }
+    }
     let ctx_ptr = buat_konteks_decompiler();
     if ctx_ptr.is_null() {
         error!("Gagal buat_konteks_decompiler!");
         return;
     }
     info!("Context berhasil dibuat.");
-    let path_target = "test_binary.elf"; 
-    let c_path = CString::new(path_target).expect("CString conversion failed");
+    if let Some(base) = base_rebase {
+        unsafe { atur_base_rebase(ctx_ptr, base) };
+        info!("Base image diatur ke 0x{:x}", base);
+    }
+    if sembunyikan_crt {
+        unsafe { atur_sembunyikan_crt(ctx_ptr, 1) };
+    }
+    unsafe { atur_sintaks_listing(ctx_ptr, sintaks.kode()) };
+    for path in &daftar_spec {
+        let c_spec = CString::new(path.as_str()).expect("CString conversion failed");
+        if unsafe { tambah_spec_semantik(ctx_ptr, c_spec.as_ptr()) } != 0 {
+            error!("Spec semantik {} ditolak, lihat log untuk detail", path);
+            hapus_konteks_decompiler(ctx_ptr);
+            return;
+        }
+    }
+    let c_path = CString::new(path_target.as_str()).expect("CString conversion failed");
     let status_code = muat_file_biner(ctx_ptr, c_path.as_ptr());
     if status_code == 0 {
         info!("Sukses memanggil muat_file_biner untuk: {}", path_target);
+        for alamat in &query_xref {
+            tampilkan_xref(ctx_ptr, *alamat);
+        }
+        if tampilkan_asm {
+            tampilkan_listing_asm(ctx_ptr);
+        }
     } else {
         error!("Gagal muat_file_biner dengan kode: {}", status_code);
     }
     hapus_konteks_decompiler(ctx_ptr);
     info!("Selesai cleanup_resources.");
-}
\ No newline at end of file
+}
@@ -43,11 +43,14 @@ impl ControlFlowGraph {
         cfg.entry_point = statements[0].address_asal;
         let leaders = cfg.identifikasi_leaders(&statements, jump_table_targets);
         let mut current_block = BasicBlock::new(statements[0].address_asal);
+        let mut alamat_sebelumnya = None;
         for stmt in statements {
-            if leaders.contains(&stmt.address_asal) && !current_block.instruksi_list.is_empty() {
+            let is_alamat_baru = alamat_sebelumnya != Some(stmt.address_asal);
+            if is_alamat_baru && leaders.contains(&stmt.address_asal) && !current_block.instruksi_list.is_empty() {
                 cfg.blocks.insert(current_block.id_block, current_block);
                 current_block = BasicBlock::new(stmt.address_asal);
             }
+            alamat_sebelumnya = Some(stmt.address_asal);
             current_block.instruksi_list.push(stmt);
         }
         if !current_block.instruksi_list.is_empty() {
@@ -80,8 +83,7 @@ impl ControlFlowGraph {
                         leaders.insert(stmts[i + 1].address_asal);
                     }
                 },
-                OperasiIr::Je | OperasiIr::Jne | OperasiIr::Call | OperasiIr::Jg | 
-                OperasiIr::Jge | OperasiIr::Jl | OperasiIr::Jle => {
+                ref op if *op == OperasiIr::Call || op.is_jump_kondisi() => {
                     if let crate::ir::types::TipeOperand::Immediate(target) = stmt.operand_satu {
                         leaders.insert(target as u64);
                     }
@@ -117,7 +119,7 @@ impl ControlFlowGraph {
                             targets.extend(indirect_list);
                         }
                     },
-                    OperasiIr::Je | OperasiIr::Jne | OperasiIr::Jg | OperasiIr::Jge | OperasiIr::Jl | OperasiIr::Jle => {
+                    ref op if op.is_jump_kondisi() => {
                         if let crate::ir::types::TipeOperand::Immediate(val) = stmt.operand_satu {
                             targets.push(val as u64); 
                         }
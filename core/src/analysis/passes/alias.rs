@@ -1,6 +1,8 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
 use crate::ir::types::{TipeOperand, OperasiIr, StatementIr};
+use crate::arch::Architecture;
+use crate::arch::x86::X86Arsitektur64;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub enum NodeId {
@@ -139,14 +141,21 @@ pub struct AliasAnalyzer {
     graph: PointsToGraph,
     tracker_offset_pointer: HashMap<String, ValueInterval>,
     set_variabel_escaped: HashSet<String>,
+    register_ip: String,
+    register_return: String,
 }
 
 impl AliasAnalyzer {
     pub fn new() -> Self {
+        Self::untuk_arsitektur(&X86Arsitektur64)
+    }
+    pub fn untuk_arsitektur(arch: &dyn Architecture) -> Self {
         Self {
             graph: PointsToGraph::new(),
             tracker_offset_pointer: HashMap::new(),
             set_variabel_escaped: HashSet::new(),
+            register_ip: arch.dapatkan_instruction_pointer(),
+            register_return: arch.dapatkan_register_return(),
         }
     }
     pub fn analisis_pointer_lanjutan(&mut self, stmts: &[StatementIr], frame_pointer: &str) {
@@ -214,7 +223,7 @@ impl AliasAnalyzer {
             let dest_id = self.dapatkan_node_id(&dest_name);
             match &stmt.operand_dua {
                 TipeOperand::MemoryRef { base, offset } => {
-                    if base == "rip" {
+                    if *base == self.register_ip {
                         let global_addr = stmt.address_asal.wrapping_add(*offset as u64);
                         let global_obj = NodeId::Global(global_addr);
                         self.graph.constraints.push_back(ConstraintAlias::AddrOf { dest: dest_id, target: global_obj });
@@ -254,7 +263,7 @@ impl AliasAnalyzer {
         let is_allocator = matches!(func_name.as_str(), "malloc" | "calloc" | "operator new");
         if is_allocator {
             let heap_obj = NodeId::AbstractHeap(stmt.address_asal);
-            let ret_reg = "rax".to_string(); 
+            let ret_reg = self.register_return.clone();
             let ret_id = self.dapatkan_node_id(&ret_reg);
             self.graph.constraints.push_back(ConstraintAlias::AddrOf { dest: ret_id, target: heap_obj });
             self.tracker_offset_pointer.insert(ret_reg, ValueInterval::single(0));
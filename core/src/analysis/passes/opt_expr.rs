@@ -201,6 +201,11 @@ impl ExpressionOptimizer {
                             *op = TipeOperand::Immediate(0); return;
                         }
                     },
+                    OperasiIr::ZeroExtend | OperasiIr::Truncate | OperasiIr::SignExtend => {
+                        if let Some(sederhana) = sederhanakan_lebar(operasi, operand_kiri, operand_kanan) {
+                            *op = sederhana; return;
+                        }
+                    },
                     OperasiIr::Div => {
                         if let TipeOperand::Immediate(1) = **operand_kanan {
                             *op = *operand_kiri.clone(); return;
@@ -220,9 +225,10 @@ impl ExpressionOptimizer {
                         OperasiIr::And => Some(v1 & v2),
                         OperasiIr::Or => Some(v1 | v2),
                         OperasiIr::Xor => Some(v1 ^ v2),
-                        OperasiIr::Shl => Some(v1 << (*v2 as u32)),
-                        OperasiIr::Shr => Some(v1 >> (*v2 as u32)),
-                        _ => None
+                        OperasiIr::Shl => Some(v1.wrapping_shl(*v2 as u32)),
+                        OperasiIr::Shr => Some((*v1 as u64).wrapping_shr(*v2 as u32) as i64),
+                        OperasiIr::Sar => Some(v1.wrapping_shr(*v2 as u32)),
+                        _ => operasi.evaluasi_lebar(*v1, *v2),
                     }
                 } else {
                     None
@@ -261,4 +267,29 @@ impl ExpressionOptimizer {
             _ => {}
         }
     }
-}
\ No newline at end of file
+}
+
+fn sederhanakan_lebar(operasi: &OperasiIr, kiri: &TipeOperand, kanan: &TipeOperand) -> Option<TipeOperand> {
+    let TipeOperand::Immediate(lebar) = *kanan else { return None };
+    if lebar >= 64 {
+        return Some(kiri.clone());
+    }
+    let TipeOperand::Expression { operasi: operasi_dalam, operand_kiri: nilai, operand_kanan: lebar_dalam } = kiri else { return None };
+    let TipeOperand::Immediate(lebar_dalam) = **lebar_dalam else { return None };
+    let bertanda = *operasi == OperasiIr::SignExtend;
+    let dalam_bertanda = *operasi_dalam == OperasiIr::SignExtend;
+    if !operasi_dalam.is_operasi_lebar() || matches!(operasi_dalam, OperasiIr::BitInsert { .. }) {
+        return None;
+    }
+    if bertanda == dalam_bertanda && lebar_dalam <= lebar {
+        return Some(kiri.clone());
+    }
+    if !bertanda && lebar <= lebar_dalam {
+        return Some(TipeOperand::Expression {
+            operasi: operasi.clone(),
+            operand_kiri: nilai.clone(),
+            operand_kanan: Box::new(TipeOperand::Immediate(lebar)),
+        });
+    }
+    None
+}
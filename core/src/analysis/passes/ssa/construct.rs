@@ -5,6 +5,8 @@ use crate::ir::types::{StatementIr, OperasiIr, TipeOperand};
 use crate::analysis::passes::alias::{AliasAnalyzer, MemoryRegion};
 use super::opt_sccp::SccpSolver;
 use super::opt_adce::AdceSolver;
+use crate::arch::Architecture;
+use crate::arch::x86::X86Arsitektur64;
 use log::info;
 
 pub struct SsaTransformer {
@@ -12,43 +14,21 @@ pub struct SsaTransformer {
     counter_versi: HashMap<String, usize>,
     peta_alias_register: HashMap<String, String>,
     alias_analyzer: AliasAnalyzer,
+    frame_pointer: String,
 }
 
 impl SsaTransformer {
     pub fn new() -> Self {
+        Self::untuk_arsitektur(&X86Arsitektur64)
+    }
+    pub fn untuk_arsitektur(arch: &dyn Architecture) -> Self {
         Self {
             stack_versi: HashMap::new(),
             counter_versi: HashMap::new(),
-            peta_alias_register: Self::inisialisasi_alias_map(),
-            alias_analyzer: AliasAnalyzer::new(),
-        }
-    }
-    fn inisialisasi_alias_map() -> HashMap<String, String> {
-        let mut m = HashMap::new();
-        let gprs = vec![
-            ("rax", vec!["eax", "ax", "al", "ah"]),
-            ("rbx", vec!["ebx", "bx", "bl", "bh"]),
-            ("rcx", vec!["ecx", "cx", "cl", "ch"]),
-            ("rdx", vec!["edx", "dx", "dl", "dh"]),
-            ("rsi", vec!["esi", "si", "sil"]),
-            ("rdi", vec!["edi", "di", "dil"]),
-            ("rbp", vec!["ebp", "bp", "bpl"]),
-            ("rsp", vec!["esp", "sp", "spl"]),
-            ("r8", vec!["r8d", "r8w", "r8b"]),
-            ("r9", vec!["r9d", "r9w", "r9b"]),
-            ("r10", vec!["r10d", "r10w", "r10b"]),
-            ("r11", vec!["r11d", "r11w", "r11b"]),
-            ("r12", vec!["r12d", "r12w", "r12b"]),
-            ("r13", vec!["r13d", "r13w", "r13b"]),
-            ("r14", vec!["r14d", "r14w", "r14b"]),
-            ("r15", vec!["r15d", "r15w", "r15b"]),
-        ];
-        for (parent, children) in gprs {
-            for child in children {
-                m.insert(child.to_string(), parent.to_string());
-            }
+            peta_alias_register: arch.dapatkan_peta_alias(),
+            alias_analyzer: AliasAnalyzer::untuk_arsitektur(arch),
+            frame_pointer: arch.dapatkan_frame_pointer(),
         }
-        m
     }
     fn dapatkan_nama_kanonik(&self, reg: &str) -> String {
         self.peta_alias_register.get(reg).cloned().unwrap_or_else(|| reg.to_string())
@@ -58,7 +38,8 @@ impl SsaTransformer {
         for block in cfg.blocks.values() {
             all_stmts.extend(block.instruksi_list.clone());
         }
-        self.alias_analyzer.analisis_pointer_lanjutan(&all_stmts, "rbp");
+        let fp = self.frame_pointer.clone();
+        self.alias_analyzer.analisis_pointer_lanjutan(&all_stmts, &fp);
         self.sisipkan_phi_nodes(cfg, dom_tree);
         self.inisialisasi_stack_variabel(cfg);
         self.rename_variabel(cfg, dom_tree, cfg.entry_point);
@@ -163,7 +144,8 @@ impl SsaTransformer {
                     match stmt.operation_code {
                          OperasiIr::Add | OperasiIr::Sub | OperasiIr::FAdd | OperasiIr::VecAdd | 
                          OperasiIr::And | OperasiIr::Or | OperasiIr::Xor | OperasiIr::Imul => {
-                             if matches!(stmt.operand_satu, TipeOperand::Register(_)) { read_src = true; }
+                             let nilai_penuh = matches!(stmt.operand_dua, TipeOperand::Expression { .. });
+                             if matches!(stmt.operand_satu, TipeOperand::Register(_)) && !nilai_penuh { read_src = true; }
                         }
                         _ => {}
                     }
@@ -280,7 +262,7 @@ impl SsaTransformer {
         self.stack_versi.get(name).and_then(|s| s.last()).cloned().unwrap_or(0)
     }
     fn generate_memory_key(&self, op: &TipeOperand) -> Option<String> {
-        let state = self.alias_analyzer.infer_region_state(op, "rbp")?;
+        let state = self.alias_analyzer.infer_region_state(op, &self.frame_pointer)?;
         match state.base_region {
             MemoryRegion::Stack(offset) => Some(format!("mem_stack_{}", offset)),
             MemoryRegion::Global(addr) => Some(format!("mem_global_{:x}", addr)),
@@ -34,7 +34,8 @@ impl AdceSolver {
             let mut new_instruksi = Vec::new();
             for (idx, stmt) in block.instruksi_list.iter().enumerate() {
                 if self.live_stmts_map.contains(&(*block_id, idx)) || 
-                   matches!(stmt.operation_code, OperasiIr::Jmp | OperasiIr::Je | OperasiIr::Jne | OperasiIr::Ret | OperasiIr::Call) {
+                   matches!(stmt.operation_code, OperasiIr::Jmp | OperasiIr::Ret | OperasiIr::Call) ||
+                   stmt.operation_code.is_jump_kondisi() {
                     new_instruksi.push(stmt.clone());
                 }
             }
@@ -43,9 +44,9 @@ impl AdceSolver {
     }
     fn cek_apakah_critical(&self, stmt: &StatementIr) -> bool {
         match stmt.operation_code {
-            OperasiIr::Call | OperasiIr::Ret | 
-            OperasiIr::Jmp | OperasiIr::Je | OperasiIr::Jne | 
-            OperasiIr::Jg | OperasiIr::Jge | OperasiIr::Jl | OperasiIr::Jle => true,
+            OperasiIr::Call | OperasiIr::Ret | OperasiIr::Jmp => true,
+            ref op if op.is_jump_kondisi() => true,
+            OperasiIr::Intrinsic(_) => matches!(stmt.operand_satu, TipeOperand::None),
             OperasiIr::Mov | OperasiIr::Add => {
                 if let TipeOperand::Memory(_) | TipeOperand::MemoryRef { .. } = stmt.operand_satu {
                     true
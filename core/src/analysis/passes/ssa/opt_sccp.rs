@@ -110,6 +110,8 @@ impl SccpSolver {
             TipeOperand::SsaVariable(n, v) => format!("{}_{}", n, v) == var,
             TipeOperand::Expression { operand_kiri, operand_kanan, .. } => 
                 self.operand_uses_var(operand_kiri, var) || self.operand_uses_var(operand_kanan, var),
+            TipeOperand::Conditional { condition, true_val, false_val } =>
+                self.operand_uses_var(condition, var) || self.operand_uses_var(true_val, var) || self.operand_uses_var(false_val, var),
             _ => false
         }
     }
@@ -117,10 +119,15 @@ impl SccpSolver {
         if let TipeOperand::SsaVariable(dest, ver) = &stmt.operand_satu {
              let dest_key = format!("{}_{}", dest, ver);
              let result = match stmt.operation_code {
-                 OperasiIr::Mov => self.evaluasi_operand(&stmt.operand_dua),
+                 OperasiIr::Mov | OperasiIr::Neg => self.evaluasi_operand(&stmt.operand_dua),
                  OperasiIr::Add | OperasiIr::Sub | OperasiIr::Imul | 
                  OperasiIr::And | OperasiIr::Or | OperasiIr::Xor | 
-                 OperasiIr::Shl | OperasiIr::Shr => self.evaluasi_operand(&stmt.operand_dua),
+                 OperasiIr::Shl | OperasiIr::Shr | OperasiIr::Sar |
+                 OperasiIr::Cmp | OperasiIr::Test => self.evaluasi_operand(&stmt.operand_dua),
+                 OperasiIr::Popcnt => match self.evaluasi_operand(&stmt.operand_dua) {
+                     StatusLattice::Constant(c) => StatusLattice::Constant(c.count_ones() as i64),
+                     lain => lain,
+                 },
                  _ => StatusLattice::Bottom,
              };
              self.update_lattice(&dest_key, result);
@@ -131,7 +138,7 @@ impl SccpSolver {
                     self.flow_worklist.push_back((block_id, target as u64));
                 }
             },
-            OperasiIr::Je | OperasiIr::Jne | OperasiIr::Jg | OperasiIr::Jle => {
+            ref op if op.is_jump_kondisi() => {
                 let cond_val = self.evaluasi_operand(&stmt.operand_dua);
                 let successors = &cfg.blocks.get(&block_id).unwrap().successors; 
                 if successors.len() == 2 {
@@ -189,20 +196,32 @@ impl SccpSolver {
                             OperasiIr::And => StatusLattice::Constant(c1 & c2),
                             OperasiIr::Or => StatusLattice::Constant(c1 | c2),
                             OperasiIr::Xor => StatusLattice::Constant(c1 ^ c2),
-                            OperasiIr::Shl => StatusLattice::Constant(c1 << (c2 as u32)),
-                            OperasiIr::Shr => StatusLattice::Constant(c1 >> (c2 as u32)),
+                            OperasiIr::Shl => StatusLattice::Constant(c1.wrapping_shl(c2 as u32)),
+                            OperasiIr::Shr => StatusLattice::Constant((c1 as u64).wrapping_shr(c2 as u32) as i64),
+                            OperasiIr::Sar => StatusLattice::Constant(c1.wrapping_shr(c2 as u32)),
                             OperasiIr::Je => StatusLattice::Constant(if c1 == c2 { 1 } else { 0 }),
                             OperasiIr::Jne => StatusLattice::Constant(if c1 != c2 { 1 } else { 0 }),
                             OperasiIr::Jg => StatusLattice::Constant(if c1 > c2 { 1 } else { 0 }),
                             OperasiIr::Jl => StatusLattice::Constant(if c1 < c2 { 1 } else { 0 }),
                             OperasiIr::Jge => StatusLattice::Constant(if c1 >= c2 { 1 } else { 0 }),
                             OperasiIr::Jle => StatusLattice::Constant(if c1 <= c2 { 1 } else { 0 }),
+                            OperasiIr::Ja => StatusLattice::Constant(if (c1 as u64) > (c2 as u64) { 1 } else { 0 }),
+                            OperasiIr::Jae => StatusLattice::Constant(if (c1 as u64) >= (c2 as u64) { 1 } else { 0 }),
+                            OperasiIr::Jb => StatusLattice::Constant(if (c1 as u64) < (c2 as u64) { 1 } else { 0 }),
+                            OperasiIr::Jbe => StatusLattice::Constant(if (c1 as u64) <= (c2 as u64) { 1 } else { 0 }),
+                            op if op.is_operasi_lebar() => op.evaluasi_lebar(c1, c2).map_or(StatusLattice::Bottom, StatusLattice::Constant),
                             _ => StatusLattice::Bottom,
                         }
                     },
                     (StatusLattice::Bottom, _) | (_, StatusLattice::Bottom) => StatusLattice::Bottom,
                     _ => StatusLattice::Top,
                 }
+            },
+            TipeOperand::Conditional { condition, true_val, false_val } => {
+                match self.evaluasi_operand(condition) {
+                    StatusLattice::Constant(c) => self.evaluasi_operand(if c != 0 { true_val } else { false_val }),
+                    lain => lain,
+                }
             },
              TipeOperand::FloatImmediate(_) => {
                 StatusLattice::Bottom 
@@ -241,6 +260,14 @@ impl SccpSolver {
                     *op = TipeOperand::Immediate(c);
                 }
             },
+            TipeOperand::Conditional { condition, true_val, false_val } => {
+                self.ganti_konstanta_di_operand(condition);
+                self.ganti_konstanta_di_operand(true_val);
+                self.ganti_konstanta_di_operand(false_val);
+                if let StatusLattice::Constant(c) = self.evaluasi_operand(op) {
+                    *op = TipeOperand::Immediate(c);
+                }
+            },
             _ => {}
         }
     }
@@ -2,6 +2,7 @@ use std::collections::{HashMap, HashSet};
 use crate::analysis::graph::cfg::ControlFlowGraph;
 use crate::ir::types::{StatementIr, OperasiIr, TipeOperand};
 use crate::arch::Architecture;
+use crate::analysis::recovery::types::KonvensiPanggilan;
 use log::info;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -9,6 +10,10 @@ pub enum TipeAbi {
     SystemV,
     MicrosoftX64,
     Arm64Aapcs,
+    Arm32Aapcs,
+    RiscVPsabi,
+    MipsO32,
+    X86Cdecl,
     Unknown
 }
 
@@ -38,12 +43,7 @@ impl ProfilAbi {
                         "xmm2".to_string(), "xmm3".to_string()
                     ],
                     shadow_space: 32,
-                    volatile_registers: HashSet::from_iter(vec![
-                        "rax".to_string(), "rcx".to_string(), "rdx".to_string(), 
-                        "r8".to_string(), "r9".to_string(), "r10".to_string(), "r11".to_string(),
-                        "xmm0".to_string(), "xmm1".to_string(), "xmm2".to_string(), "xmm3".to_string(), 
-                        "xmm4".to_string(), "xmm5".to_string()
-                    ]),
+                    volatile_registers: Self::kumpulkan_volatile_ms_x64(arch),
                 }
             } else {
                 info!("Mengaktifkan Profil ABI: System V AMD64 (Unix-like)");
@@ -59,13 +59,50 @@ impl ProfilAbi {
                         "xmm6".to_string(), "xmm7".to_string()
                     ],
                     shadow_space: 0,
-                    volatile_registers: HashSet::from_iter(vec![
-                        "rax".to_string(), "rdi".to_string(), "rsi".to_string(), 
-                        "rdx".to_string(), "rcx".to_string(), "r8".to_string(), 
-                        "r9".to_string(), "r10".to_string(), "r11".to_string()
-                    ]),
+                    volatile_registers: HashSet::from_iter(arch.dapatkan_register_volatile()),
                 }
             }
+        } else if arch_stack_ptr == "esp" {
+            info!("Mengaktifkan Profil ABI: x86 32-bit (cdecl/stdcall/fastcall/thiscall)");
+            Self {
+                tipe: TipeAbi::X86Cdecl,
+                register_integer: Vec::new(),
+                register_float: Vec::new(),
+                shadow_space: 0,
+                volatile_registers: HashSet::from_iter(arch.dapatkan_register_volatile()),
+            }
+        } else if arch_stack_ptr == "sp" && arch.dapatkan_register_return() == "a0" {
+            info!("Mengaktifkan Profil ABI: RISC-V psABI");
+            let daftar = |awalan: &str, jumlah: usize| -> Vec<String> {
+                (0..jumlah).map(|i| format!("{}{}", awalan, i)).collect()
+            };
+            Self {
+                tipe: TipeAbi::RiscVPsabi,
+                register_integer: arch.dapatkan_register_argumen(),
+                register_float: daftar("fa", 8),
+                shadow_space: 0,
+                volatile_registers: HashSet::from_iter(arch.dapatkan_register_volatile()),
+            }
+        } else if arch_stack_ptr == "sp" && arch.dapatkan_register_return() == "v0" {
+            info!("Mengaktifkan Profil ABI: MIPS o32");
+            Self {
+                tipe: TipeAbi::MipsO32,
+                register_integer: arch.dapatkan_register_argumen(),
+                register_float: vec!["f12".to_string(), "f14".to_string()],
+                shadow_space: 16,
+                volatile_registers: HashSet::from_iter(arch.dapatkan_register_volatile()),
+            }
+        } else if arch_stack_ptr == "sp" && arch.dapatkan_register_return() == "r0" {
+            info!("Mengaktifkan Profil ABI: AAPCS (ARM32)");
+            Self {
+                tipe: TipeAbi::Arm32Aapcs,
+                register_integer: arch.dapatkan_register_argumen(),
+                register_float: vec![
+                    "s0".to_string(), "s1".to_string(), "s2".to_string(), "s3".to_string()
+                ],
+                shadow_space: 0,
+                volatile_registers: HashSet::from_iter(arch.dapatkan_register_volatile()),
+            }
         } else if arch_stack_ptr == "sp" {
             info!("Mengaktifkan Profil ABI: AAPCS64 (ARM64)");
             Self {
@@ -81,13 +118,7 @@ impl ProfilAbi {
                     "v6".to_string(), "v7".to_string()
                 ],
                 shadow_space: 0,
-                volatile_registers: HashSet::from_iter(vec![
-                    "x0".to_string(), "x1".to_string(), "x2".to_string(), "x3".to_string(),
-                    "x4".to_string(), "x5".to_string(), "x6".to_string(), "x7".to_string(),
-                    "x8".to_string(), "x9".to_string(), "x10".to_string(), "x11".to_string(),
-                    "x12".to_string(), "x13".to_string(), "x14".to_string(), "x15".to_string(),
-                    "x16".to_string(), "x17".to_string(), "x18".to_string()
-                ]),
+                volatile_registers: HashSet::from_iter(arch.dapatkan_register_volatile()),
             }
         } else {
             info!("Arsitektur tidak dikenal, menggunakan fallback ABI.");
@@ -100,6 +131,28 @@ impl ProfilAbi {
             }
         }
     }
+    fn kumpulkan_volatile_ms_x64(arch: &dyn Architecture) -> HashSet<String> {
+        let file = arch.dapatkan_register_file();
+        let mut callee_saved_tambahan = vec!["rdi".to_string(), "rsi".to_string()];
+        callee_saved_tambahan.extend((6..16).map(|i| format!("ymm{}", i)));
+        arch.dapatkan_register_volatile().into_iter()
+            .filter(|r| !callee_saved_tambahan.contains(&file.dapatkan_register_akar(r)))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfoKonvensiX86 {
+    pub konvensi: KonvensiPanggilan,
+    pub register_argumen: Vec<String>,
+    pub argumen_stack: Vec<String>,
+    pub pembersihan_stack: i64,
+}
+
+impl InfoKonvensiX86 {
+    pub fn daftar_parameter(&self) -> Vec<String> {
+        self.register_argumen.iter().chain(self.argumen_stack.iter()).cloned().collect()
+    }
 }
 
 pub struct CallingConventionAnalyzer {
@@ -207,6 +260,120 @@ impl CallingConventionAnalyzer {
         }
         params
     }
+    pub fn deteksi_konvensi_x86(&self, stmts: &[StatementIr], is_method: bool) -> InfoKonvensiX86 {
+        let mut ditulis = HashSet::new();
+        let mut dibaca = HashSet::new();
+        let mut ecx_sebagai_basis = false;
+        for stmt in stmts {
+            if stmt.operation_code == OperasiIr::Call {
+                break;
+            }
+            let is_nolkan = matches!(stmt.operation_code, OperasiIr::Xor | OperasiIr::Sub)
+                && matches!(&stmt.operand_dua, TipeOperand::Expression { operand_kiri, operand_kanan, .. }
+                    if operand_kiri == operand_kanan && **operand_kiri == stmt.operand_satu);
+            if !is_nolkan {
+                Self::kumpulkan_baca_register_x86(&stmt.operand_dua, &ditulis, &mut dibaca, &mut ecx_sebagai_basis);
+                if let TipeOperand::MemoryRef { .. } = &stmt.operand_satu {
+                    Self::kumpulkan_baca_register_x86(&stmt.operand_satu, &ditulis, &mut dibaca, &mut ecx_sebagai_basis);
+                }
+                if matches!(stmt.operation_code, OperasiIr::Cmp | OperasiIr::Test) {
+                    Self::kumpulkan_baca_register_x86(&stmt.operand_satu, &ditulis, &mut dibaca, &mut ecx_sebagai_basis);
+                }
+            }
+            if !matches!(stmt.operation_code, OperasiIr::Cmp | OperasiIr::Test)
+                && let TipeOperand::Register(r) = &stmt.operand_satu
+            {
+                ditulis.insert(Self::normalisasi_register_x86(r).to_string());
+            }
+        }
+        let is_frame_ebp = stmts.iter().any(|s| s.operation_code == OperasiIr::Mov
+            && s.operand_satu == TipeOperand::Register("ebp".to_string())
+            && s.operand_dua == TipeOperand::Register("esp".to_string()));
+        let mut offset_argumen = HashSet::new();
+        if is_frame_ebp {
+            for stmt in stmts {
+                for op in [&stmt.operand_satu, &stmt.operand_dua] {
+                    Self::kumpulkan_offset_argumen(op, &mut offset_argumen);
+                }
+            }
+        }
+        let pembersihan_stack = stmts.iter().enumerate()
+            .filter(|(_, s)| s.operation_code == OperasiIr::Ret)
+            .filter_map(|(i, ret)| {
+                stmts[..i].iter().rev()
+                    .take_while(|s| s.address_asal == ret.address_asal)
+                    .find_map(|s| match (&s.operation_code, &s.operand_satu, &s.operand_dua) {
+                        (OperasiIr::Add, TipeOperand::Register(r), TipeOperand::Immediate(n)) if r == "esp" => Some(n - 4),
+                        _ => None,
+                    })
+            })
+            .max()
+            .unwrap_or(0);
+        let jumlah_dari_akses = offset_argumen.iter().max().map(|m| (m - 8) / 4 + 1).unwrap_or(0);
+        let jumlah_stack = jumlah_dari_akses.max(pembersihan_stack / 4);
+        let argumen_stack: Vec<String> = (0..jumlah_stack).map(|i| format!("arg_{}", 8 + i * 4)).collect();
+        let baca_ecx = dibaca.contains("ecx");
+        let baca_edx = dibaca.contains("edx");
+        let (konvensi, register_argumen) = if baca_edx {
+            (KonvensiPanggilan::Fastcall, vec!["ecx_0".to_string(), "edx_0".to_string()])
+        } else if baca_ecx && (is_method || ecx_sebagai_basis) {
+            (KonvensiPanggilan::Thiscall, vec!["ecx_0".to_string()])
+        } else if baca_ecx {
+            (KonvensiPanggilan::Fastcall, vec!["ecx_0".to_string()])
+        } else if pembersihan_stack > 0 {
+            (KonvensiPanggilan::Stdcall, Vec::new())
+        } else {
+            (KonvensiPanggilan::Cdecl, Vec::new())
+        };
+        info!("Konvensi x86 terdeteksi: {:?} ({} argumen stack, cleanup {})", konvensi, argumen_stack.len(), pembersihan_stack);
+        InfoKonvensiX86 {
+            konvensi,
+            register_argumen,
+            argumen_stack,
+            pembersihan_stack,
+        }
+    }
+    fn normalisasi_register_x86(reg: &str) -> &str {
+        match reg {
+            "cx" | "cl" | "ch" => "ecx",
+            "dx" | "dl" | "dh" => "edx",
+            _ => reg,
+        }
+    }
+    fn kumpulkan_baca_register_x86(op: &TipeOperand, ditulis: &HashSet<String>, dibaca: &mut HashSet<String>, ecx_sebagai_basis: &mut bool) {
+        match op {
+            TipeOperand::Register(r) => {
+                let reg = Self::normalisasi_register_x86(r);
+                if matches!(reg, "ecx" | "edx") && !ditulis.contains(reg) {
+                    dibaca.insert(reg.to_string());
+                }
+            },
+            TipeOperand::MemoryRef { base, .. } => {
+                let reg = Self::normalisasi_register_x86(base);
+                if matches!(reg, "ecx" | "edx") && !ditulis.contains(reg) {
+                    *ecx_sebagai_basis |= reg == "ecx";
+                    dibaca.insert(reg.to_string());
+                }
+            },
+            TipeOperand::Expression { operand_kiri, operand_kanan, .. } => {
+                Self::kumpulkan_baca_register_x86(operand_kiri, ditulis, dibaca, ecx_sebagai_basis);
+                Self::kumpulkan_baca_register_x86(operand_kanan, ditulis, dibaca, ecx_sebagai_basis);
+            },
+            _ => {}
+        }
+    }
+    fn kumpulkan_offset_argumen(op: &TipeOperand, hasil: &mut HashSet<i64>) {
+        match op {
+            TipeOperand::MemoryRef { base, offset } if base == "ebp" && *offset >= 8 && offset % 4 == 0 => {
+                hasil.insert(*offset);
+            },
+            TipeOperand::Expression { operand_kiri, operand_kanan, .. } => {
+                Self::kumpulkan_offset_argumen(operand_kiri, hasil);
+                Self::kumpulkan_offset_argumen(operand_kanan, hasil);
+            },
+            _ => {}
+        }
+    }
     fn cek_usage_sebagai_param(&self, op: &TipeOperand, written: &HashSet<String>, read_out: &mut HashSet<String>) {
         match op {
             TipeOperand::SsaVariable(name, _) | TipeOperand::Register(name) => {
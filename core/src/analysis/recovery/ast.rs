@@ -2,6 +2,8 @@ use std::collections::{HashSet, HashMap};
 use crate::analysis::graph::cfg::ControlFlowGraph;
 use crate::analysis::graph::dom::DominatorTree;
 use crate::ir::types::{StatementIr, OperasiIr, TipeOperand};
+use crate::ir::lift::x87::{self, HasilBandingFloat};
+use crate::arch::x86::FPU_C3;
 
 #[derive(Debug, Clone)]
 pub enum NodeAst {
@@ -505,19 +507,24 @@ impl ControlFlowStructurer {
     fn rekonstruksi_kondisi_branch(&self, stmts: &[StatementIr]) -> String {
         if stmts.is_empty() { return "true".to_string(); }
         let last_stmt = stmts.last().unwrap();
-        let mut stmt_cmp_opt = None;
-        for i in (0..stmts.len()-1).rev() {
-            match stmts[i].operation_code {
-                OperasiIr::Cmp | OperasiIr::Test | OperasiIr::FCmp => {
-                    stmt_cmp_opt = Some(&stmts[i]);
-                    break;
-                },
-                _ => {}
-            }
+        let idx_cmp = (0..stmts.len()-1).rev()
+            .find(|&i| matches!(stmts[i].operation_code, OperasiIr::Cmp | OperasiIr::Test | OperasiIr::FCmp));
+        if let Some(kondisi) = idx_cmp.and_then(|idx| self.rekonstruksi_kondisi_float(stmts, idx, &last_stmt.operation_code)) {
+            return kondisi;
         }
+        let stmt_cmp_opt = idx_cmp.map(|idx| &stmts[idx]);
         if let Some(stmt_cmp) = stmt_cmp_opt {
-            let op1 = self.format_operand_readable(&stmt_cmp.operand_satu);
-            let op2 = self.format_operand_readable(&stmt_cmp.operand_dua);
+            let (kiri, kanan) = match &stmt_cmp.operand_dua {
+                TipeOperand::Expression { operand_kiri, operand_kanan, .. } => (&**operand_kiri, &**operand_kanan),
+                _ => (&stmt_cmp.operand_satu, &stmt_cmp.operand_dua),
+            };
+            let op1 = self.format_operand_readable(kiri);
+            let op2 = self.format_operand_readable(kanan);
+            let hasil = if stmt_cmp.operation_code == OperasiIr::Test {
+                format!("({} & {})", op1, op2)
+            } else {
+                format!("({} - {})", op1, op2)
+            };
             match last_stmt.operation_code {
                 OperasiIr::Je => format!("{} == {}", op1, op2),
                 OperasiIr::Jne => format!("{} != {}", op1, op2),
@@ -525,6 +532,19 @@ impl ControlFlowStructurer {
                 OperasiIr::Jge => format!("{} >= {}", op1, op2),
                 OperasiIr::Jl => format!("{} < {}", op1, op2),
                 OperasiIr::Jle => format!("{} <= {}", op1, op2),
+                OperasiIr::Ja => format!("(unsigned long){} > (unsigned long){}", op1, op2),
+                OperasiIr::Jae => format!("(unsigned long){} >= (unsigned long){}", op1, op2),
+                OperasiIr::Jb => format!("(unsigned long){} < (unsigned long){}", op1, op2),
+                OperasiIr::Jbe => format!("(unsigned long){} <= (unsigned long){}", op1, op2),
+                OperasiIr::Js => format!("(long){} < 0", hasil),
+                OperasiIr::Jns => format!("(long){} >= 0", hasil),
+                OperasiIr::Jo | OperasiIr::Jno if stmt_cmp.operation_code == OperasiIr::Test => {
+                    if last_stmt.operation_code == OperasiIr::Jo { "false" } else { "true" }.to_string()
+                },
+                OperasiIr::Jo => format!("__builtin_sub_overflow_p({}, {}, (long)0)", op1, op2),
+                OperasiIr::Jno => format!("!__builtin_sub_overflow_p({}, {}, (long)0)", op1, op2),
+                OperasiIr::Jp => format!("__builtin_parity({} & 0xff) == 0", hasil),
+                OperasiIr::Jnp => format!("__builtin_parity({} & 0xff) != 0", hasil),
                 _ => "cond_unknown".to_string() 
             }
         } else {
@@ -534,6 +554,55 @@ impl ControlFlowStructurer {
              }
         }
     }
+    fn rekonstruksi_kondisi_float(&self, stmts: &[StatementIr], idx_cmp: usize, op_branch: &OperasiIr) -> Option<String> {
+        let stmt_cmp = &stmts[idx_cmp];
+        let (idx_fcmp, mask) = match stmt_cmp.operation_code {
+            OperasiIr::FCmp => (idx_cmp, None),
+            OperasiIr::Test => {
+                let TipeOperand::Expression { operand_kanan, .. } = &stmt_cmp.operand_dua else { return None };
+                let TipeOperand::Immediate(mask) = **operand_kanan else { return None };
+                let idx_status = (0..idx_cmp).rev().find(|&i| Self::is_baca_status_fpu(&stmts[i].operand_dua))?;
+                let idx_fcmp = (0..idx_status).rev().find(|&i| stmts[i].operation_code == OperasiIr::FCmp)?;
+                (idx_fcmp, Some(mask))
+            },
+            _ => return None,
+        };
+        let TipeOperand::Expression { operand_kiri, operand_kanan, .. } = &stmts[idx_fcmp].operand_dua else { return None };
+        let hasil = x87::evaluasi_branch_float(op_branch, mask)?;
+        Some(self.format_relasi_float(hasil, &self.format_operand_readable(operand_kiri), &self.format_operand_readable(operand_kanan)))
+    }
+    fn is_baca_status_fpu(op: &TipeOperand) -> bool {
+        match op {
+            TipeOperand::Register(r) | TipeOperand::SsaVariable(r, _) => r == FPU_C3,
+            TipeOperand::Expression { operand_kiri, operand_kanan, .. } => {
+                Self::is_baca_status_fpu(operand_kiri) || Self::is_baca_status_fpu(operand_kanan)
+            },
+            _ => false,
+        }
+    }
+    fn format_relasi_float(&self, hasil: HasilBandingFloat, op1: &str, op2: &str) -> String {
+        let relasi = |besar: bool, kecil: bool, sama: bool| match (besar, kecil, sama) {
+            (true, false, false) => Some(">"),
+            (false, true, false) => Some("<"),
+            (false, false, true) => Some("=="),
+            (true, false, true) => Some(">="),
+            (false, true, true) => Some("<="),
+            (true, true, false) => Some("!="),
+            _ => None,
+        };
+        let tak_berurut = format!("__builtin_isunordered({}, {})", op1, op2);
+        match relasi(hasil.lebih_besar, hasil.lebih_kecil, hasil.sama) {
+            Some(op) if (op == "!=") == hasil.tak_berurut => format!("{} {} {}", op1, op, op2),
+            Some("!=") => format!("__builtin_islessgreater({}, {})", op1, op2),
+            Some("==") => format!("{} == {} || {}", op1, op2, tak_berurut),
+            Some(_) => {
+                let kebalikan = relasi(!hasil.lebih_besar, !hasil.lebih_kecil, !hasil.sama).unwrap_or("==");
+                format!("!({} {} {})", op1, kebalikan, op2)
+            },
+            None if hasil.lebih_besar => if hasil.tak_berurut { "true".to_string() } else { format!("!{}", tak_berurut) },
+            None => if hasil.tak_berurut { tak_berurut } else { "false".to_string() },
+        }
+    }
     fn ambil_assignment_terakhir(&self, stmts: &[StatementIr]) -> Option<(String, String)> {
         for stmt in stmts.iter().rev() {
             if let OperasiIr::Mov = stmt.operation_code {
@@ -0,0 +1,390 @@
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use log::{info, debug};
+use crate::analysis::recovery::explorer::FunctionContext;
+use crate::analysis::recovery::types::{TypeSystem, SignatureFungsi, TipePrimitif, KonvensiPanggilan};
+use crate::ir::types::{StatementIr, OperasiIr, TipeOperand};
+use crate::loader::vmem::VirtualMemory;
+
+const KEDALAMAN_MAKS_STARTUP: usize = 3;
+const PENANDA_MUSL: [&str; 5] = ["libc_start_main_stage2", "__libc_start_main_stage2", "__init_libc", "_start_c", "__dls2"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarianCrt {
+    Glibc,
+    Musl,
+    Msvc,
+    Simbol,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JenisMain {
+    Main,
+    WMain,
+    WinMain,
+    WWinMain,
+}
+
+impl JenisMain {
+    pub fn nama(&self) -> &'static str {
+        match self {
+            JenisMain::Main => "main",
+            JenisMain::WMain => "wmain",
+            JenisMain::WinMain => "WinMain",
+            JenisMain::WWinMain => "wWinMain",
+        }
+    }
+    pub fn dari_nama(nama: &str) -> Option<Self> {
+        match nama {
+            "main" | "_main" => Some(JenisMain::Main),
+            "wmain" | "_wmain" => Some(JenisMain::WMain),
+            "WinMain" | "_WinMain@16" => Some(JenisMain::WinMain),
+            "wWinMain" | "_wWinMain@16" => Some(JenisMain::WWinMain),
+            _ => None,
+        }
+    }
+    pub fn signature(&self) -> SignatureFungsi {
+        let ptr = |t: TipePrimitif| TipePrimitif::Pointer(Box::new(t));
+        let arg_types = match self {
+            JenisMain::Main => vec![TipePrimitif::Integer(4), ptr(ptr(TipePrimitif::Integer(1))), ptr(ptr(TipePrimitif::Integer(1)))],
+            JenisMain::WMain => vec![TipePrimitif::Integer(4), ptr(ptr(TipePrimitif::Integer(2))), ptr(ptr(TipePrimitif::Integer(2)))],
+            JenisMain::WinMain => vec![ptr(TipePrimitif::Void), ptr(TipePrimitif::Void), ptr(TipePrimitif::Integer(1)), TipePrimitif::Integer(4)],
+            JenisMain::WWinMain => vec![ptr(TipePrimitif::Void), ptr(TipePrimitif::Void), ptr(TipePrimitif::Integer(2)), TipePrimitif::Integer(4)],
+        };
+        SignatureFungsi {
+            return_type: TipePrimitif::Integer(4),
+            arg_types,
+            konvensi: KonvensiPanggilan::Bawaan,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HasilDeteksiCrt {
+    pub alamat_main: u64,
+    pub main_thumb: bool,
+    pub jenis_main: JenisMain,
+    pub varian: VarianCrt,
+    pub fungsi_crt: BTreeSet<u64>,
+}
+
+pub struct CrtAnalyzer {
+    reg_argumen: Vec<[&'static str; 2]>,
+    pakai_stack: bool,
+    ptr_size: u8,
+    is_arm32: bool,
+}
+
+impl CrtAnalyzer {
+    pub fn new(arch: &str, format_biner: &str) -> Self {
+        let is_arm32 = crate::arch::is_arm32(arch);
+        let reg_argumen = if is_arm32 {
+            vec![["r0", "r0"], ["r1", "r1"], ["r2", "r2"], ["r3", "r3"]]
+        } else if crate::arch::is_riscv(arch) {
+            vec![["a0", "a0"], ["a1", "a1"], ["a2", "a2"], ["a3", "a3"], ["a4", "a4"]]
+        } else if crate::arch::is_mips(arch) {
+            vec![["a0", "a0"], ["a1", "a1"], ["a2", "a2"], ["a3", "a3"]]
+        } else if arch.contains("arm") || arch.contains("aarch64") {
+            vec![["x0", "w0"], ["x1", "w1"], ["x2", "w2"], ["x3", "w3"], ["x4", "w4"]]
+        } else if format_biner == "pe" {
+            vec![["rcx", "ecx"], ["rdx", "edx"], ["r8", "r8d"], ["r9", "r9d"]]
+        } else {
+            vec![["rdi", "edi"], ["rsi", "esi"], ["rdx", "edx"], ["rcx", "ecx"], ["r8", "r8d"]]
+        };
+        Self {
+            reg_argumen,
+            pakai_stack: arch == "x86",
+            ptr_size: if arch.contains("64") { 8 } else { 4 },
+            is_arm32,
+        }
+    }
+    pub fn deteksi_main(&self, vmem: &VirtualMemory, fungsi: &HashMap<u64, FunctionContext>) -> Option<HasilDeteksiCrt> {
+        if let Some(hasil) = self.deteksi_dari_simbol(vmem, fungsi) {
+            return Some(hasil);
+        }
+        let entry = vmem.entry_point;
+        if !fungsi.contains_key(&entry) {
+            return None;
+        }
+        let varian_libc = Self::tentukan_varian_libc(vmem);
+        let mut parent: HashMap<u64, u64> = HashMap::new();
+        let mut dikunjungi = HashSet::new();
+        let mut antrian = VecDeque::new();
+        antrian.push_back((entry, 0usize));
+        dikunjungi.insert(entry);
+        while let Some((addr, kedalaman)) = antrian.pop_front() {
+            let ctx = match fungsi.get(&addr) {
+                Some(c) => c,
+                None => continue,
+            };
+            let temuan = if vmem.format_biner == "pe" {
+                self.cari_main_pola_msvc(vmem, &ctx.ir_code).map(|m| (m, VarianCrt::Msvc))
+            } else {
+                self.cari_main_pola_libc(vmem, addr, &ctx.ir_code).map(|m| (m, varian_libc))
+            };
+            if let Some(((alamat_main, helper_tambahan), varian)) = temuan {
+                let main_thumb = self.is_arm32 && (alamat_main & 1 == 1 || vmem.is_thumb(alamat_main));
+                let alamat_main = if self.is_arm32 { alamat_main & !1 } else { alamat_main };
+                let jenis_main = self.tentukan_jenis_main(vmem, fungsi, &parent, addr, alamat_main);
+                let mut fungsi_crt: BTreeSet<u64> = helper_tambahan.into_iter().collect();
+                let mut jalur = Some(addr);
+                while let Some(node) = jalur {
+                    fungsi_crt.insert(node);
+                    jalur = parent.get(&node).cloned();
+                }
+                fungsi_crt.extend(self.ambil_callee_langsung(&ctx.ir_code, fungsi));
+                fungsi_crt.extend(self.ambil_helper_dari_nama(vmem, fungsi));
+                fungsi_crt.remove(&alamat_main);
+                info!("CRT {:?} terdeteksi: {} di 0x{:x}", varian, jenis_main.nama(), alamat_main);
+                return Some(HasilDeteksiCrt { alamat_main, main_thumb, jenis_main, varian, fungsi_crt });
+            }
+            if kedalaman >= KEDALAMAN_MAKS_STARTUP {
+                continue;
+            }
+            for callee in self.ambil_callee_langsung(&ctx.ir_code, fungsi) {
+                if dikunjungi.insert(callee) {
+                    parent.insert(callee, addr);
+                    antrian.push_back((callee, kedalaman + 1));
+                }
+            }
+        }
+        debug!("Pola startup CRT tidak ditemukan dari entry 0x{:x}", entry);
+        None
+    }
+    fn deteksi_dari_simbol(&self, vmem: &VirtualMemory, fungsi: &HashMap<u64, FunctionContext>) -> Option<HasilDeteksiCrt> {
+        let (alamat_main, jenis_main) = vmem.simbol_global.iter()
+            .filter(|(addr, _)| fungsi.contains_key(addr))
+            .find_map(|(addr, nama)| JenisMain::dari_nama(nama).map(|j| (*addr, j)))?;
+        let mut fungsi_crt = self.ambil_helper_dari_nama(vmem, fungsi);
+        fungsi_crt.remove(&alamat_main);
+        let main_thumb = vmem.is_thumb(alamat_main);
+        Some(HasilDeteksiCrt { alamat_main, main_thumb, jenis_main, varian: VarianCrt::Simbol, fungsi_crt })
+    }
+    fn tentukan_varian_libc(vmem: &VirtualMemory) -> VarianCrt {
+        let ada_simbol_musl = vmem.simbol_global.values()
+            .any(|nama| PENANDA_MUSL.contains(&nama.rsplit(':').next().unwrap_or(nama)));
+        let interp_musl = vmem.segments.iter()
+            .filter(|seg| seg.nama_section == ".interp")
+            .any(|seg| seg.data.windows(7).any(|w| w == b"ld-musl"));
+        if ada_simbol_musl || interp_musl { VarianCrt::Musl } else { VarianCrt::Glibc }
+    }
+    fn cari_main_pola_libc(&self, vmem: &VirtualMemory, fungsi_addr: u64, stmts: &[StatementIr]) -> Option<(u64, Vec<u64>)> {
+        let posisi_transfer: Vec<usize> = stmts.iter().enumerate()
+            .filter(|(_, s)| match s.operation_code {
+                OperasiIr::Call => true,
+                OperasiIr::Jmp => !self.is_target_lokal(&s.operand_satu, stmts),
+                _ => false,
+            })
+            .map(|(i, _)| i)
+            .collect();
+        let start_main_dikenal = vmem.simbol_global.values().any(|n| n.contains("libc_start_main"));
+        let terakhir = posisi_transfer.last().cloned();
+        for idx in posisi_transfer {
+            let nama_target = self.ambil_nama_target(vmem, &stmts[idx].operand_satu);
+            let is_start_main = match nama_target {
+                Some(nama) => nama.contains("libc_start_main"),
+                None => !start_main_dikenal && Some(idx) == terakhir,
+            };
+            if !is_start_main {
+                continue;
+            }
+            let kandidat = match self.nilai_argumen(vmem, stmts, idx, 0) {
+                Some(addr) if addr != fungsi_addr && vmem.cek_alamat_executable(addr) => addr,
+                _ => continue,
+            };
+            let helper: Vec<u64> = [3, 4].iter()
+                .filter_map(|&pos| self.nilai_argumen(vmem, stmts, idx, pos))
+                .filter(|&addr| vmem.cek_alamat_executable(addr))
+                .collect();
+            return Some((kandidat, helper));
+        }
+        None
+    }
+    fn cari_main_pola_msvc(&self, vmem: &VirtualMemory, stmts: &[StatementIr]) -> Option<(u64, Vec<u64>)> {
+        for (idx, stmt) in stmts.iter().enumerate() {
+            if stmt.operation_code != OperasiIr::Call {
+                continue;
+            }
+            let nama = match self.ambil_nama_target(vmem, &stmt.operand_satu) {
+                Some(n) => n,
+                None => continue,
+            };
+            if !matches!(nama, "exit" | "_exit" | "ExitProcess") {
+                continue;
+            }
+            let mut reg_sumber = match self.sumber_argumen(stmts, idx, 0) {
+                Some((_, TipeOperand::Register(r))) => r.clone(),
+                _ => continue,
+            };
+            let mut batas = idx;
+            while !Self::is_reg_return(&reg_sumber) {
+                let posisi_mov = stmts[..batas].iter().rposition(|s| {
+                    s.operation_code == OperasiIr::Mov
+                        && matches!(&s.operand_satu, TipeOperand::Register(r) if Self::sama_register(r, &reg_sumber))
+                })?;
+                reg_sumber = match stmts[posisi_mov].operand_dua.tanpa_lebar() {
+                    TipeOperand::Register(r) => r.clone(),
+                    _ => break,
+                };
+                batas = posisi_mov;
+            }
+            if !Self::is_reg_return(&reg_sumber) {
+                continue;
+            }
+            let posisi_call = stmts[..batas].iter().rposition(|s| s.operation_code == OperasiIr::Call)?;
+            if let TipeOperand::Immediate(target) = stmts[posisi_call].operand_satu
+                && vmem.cek_alamat_executable(target as u64)
+            {
+                return Some((target as u64, Vec::new()));
+            }
+        }
+        None
+    }
+    fn tentukan_jenis_main(
+        &self,
+        vmem: &VirtualMemory,
+        fungsi: &HashMap<u64, FunctionContext>,
+        parent: &HashMap<u64, u64>,
+        fungsi_startup: u64,
+        alamat_main: u64
+    ) -> JenisMain {
+        let mut is_gui = false;
+        let mut is_wide = false;
+        let mut jalur = Some(fungsi_startup);
+        while let Some(node) = jalur {
+            if let Some(ctx) = fungsi.get(&node) {
+                for (idx, stmt) in ctx.ir_code.iter().enumerate() {
+                    if stmt.operation_code != OperasiIr::Call {
+                        continue;
+                    }
+                    if let Some(nama) = self.ambil_nama_target(vmem, &stmt.operand_satu) {
+                        let nama_lower = nama.to_lowercase();
+                        if nama_lower.contains("wargv") || nama_lower.contains("wgetmainargs") || nama_lower.contains("wide") || nama_lower.contains("wcmdln") {
+                            is_wide = true;
+                        }
+                    }
+                    let is_call_main = matches!(stmt.operand_satu, TipeOperand::Immediate(t) if t as u64 == alamat_main);
+                    if is_call_main && vmem.format_biner == "pe" && self.nilai_argumen(vmem, &ctx.ir_code, idx, 0) == Some(vmem.image_base) {
+                        is_gui = true;
+                    }
+                }
+            }
+            jalur = parent.get(&node).cloned();
+        }
+        match (is_gui, is_wide) {
+            (true, true) => JenisMain::WWinMain,
+            (true, false) => JenisMain::WinMain,
+            (false, true) => JenisMain::WMain,
+            (false, false) => JenisMain::Main,
+        }
+    }
+    fn nilai_argumen(&self, vmem: &VirtualMemory, stmts: &[StatementIr], idx_call: usize, posisi: usize) -> Option<u64> {
+        let (operasi, sumber) = self.sumber_argumen(stmts, idx_call, posisi)?;
+        match (operasi, sumber) {
+            (_, TipeOperand::Immediate(val)) => Some(*val as u64),
+            (OperasiIr::Lea, TipeOperand::Memory(addr)) => Some(*addr),
+            (_, TipeOperand::Memory(addr)) => vmem.baca_pointer(*addr, self.ptr_size),
+            _ => None,
+        }
+    }
+    fn sumber_argumen<'a>(&self, stmts: &'a [StatementIr], idx_call: usize, posisi: usize) -> Option<(&'a OperasiIr, &'a TipeOperand)> {
+        let mut sisa_push = posisi;
+        for stmt in stmts[..idx_call].iter().rev() {
+            if stmt.operation_code == OperasiIr::Call {
+                return None;
+            }
+            if self.pakai_stack {
+                if stmt.operation_code == OperasiIr::Mov
+                    && matches!(&stmt.operand_satu, TipeOperand::MemoryRef { base, offset: 0 } if base == "rsp" || base == "esp")
+                {
+                    if sisa_push == 0 {
+                        return Some((&stmt.operation_code, &stmt.operand_dua));
+                    }
+                    sisa_push -= 1;
+                }
+                continue;
+            }
+            let regs = self.reg_argumen.get(posisi)?;
+            let is_target = matches!(&stmt.operand_satu, TipeOperand::Register(r) if regs.contains(&r.as_str()));
+            if !is_target {
+                continue;
+            }
+            return match stmt.operation_code {
+                OperasiIr::Mov | OperasiIr::Lea => Some((&stmt.operation_code, stmt.operand_dua.tanpa_lebar())),
+                _ => None,
+            };
+        }
+        None
+    }
+    fn ambil_nama_target<'a>(&self, vmem: &'a VirtualMemory, target: &TipeOperand) -> Option<&'a str> {
+        let addr = match target {
+            TipeOperand::Immediate(val) => *val as u64,
+            TipeOperand::Memory(addr) => *addr,
+            _ => return None,
+        };
+        let nama = vmem.simbol_global.get(&addr)?;
+        Some(nama.rsplit(':').next().unwrap_or(nama).trim_start_matches("__imp_"))
+    }
+    fn is_target_lokal(&self, target: &TipeOperand, stmts: &[StatementIr]) -> bool {
+        match target {
+            TipeOperand::Immediate(val) => stmts.iter().any(|s| s.address_asal == *val as u64),
+            _ => false,
+        }
+    }
+    fn ambil_callee_langsung(&self, stmts: &[StatementIr], fungsi: &HashMap<u64, FunctionContext>) -> Vec<u64> {
+        let mut hasil = Vec::new();
+        for stmt in stmts {
+            if !matches!(stmt.operation_code, OperasiIr::Call | OperasiIr::Jmp) {
+                continue;
+            }
+            if let TipeOperand::Immediate(val) = stmt.operand_satu
+                && fungsi.contains_key(&(val as u64))
+                && !self.is_target_lokal(&stmt.operand_satu, stmts)
+                && !hasil.contains(&(val as u64))
+            {
+                hasil.push(val as u64);
+            }
+        }
+        hasil
+    }
+    fn ambil_helper_dari_nama(&self, vmem: &VirtualMemory, fungsi: &HashMap<u64, FunctionContext>) -> BTreeSet<u64> {
+        vmem.simbol_global.iter()
+            .filter(|(addr, nama)| fungsi.contains_key(addr) && is_nama_helper_crt(nama))
+            .map(|(addr, _)| *addr)
+            .collect()
+    }
+    fn is_reg_return(reg: &str) -> bool {
+        matches!(reg, "rax" | "eax" | "x0" | "w0" | "a0" | "v0")
+    }
+    fn sama_register(a: &str, b: &str) -> bool {
+        let normalisasi = |r: &str| -> String {
+            let r = r.trim_end_matches('d');
+            match r.strip_prefix('e') {
+                Some(sisa) if sisa.len() == 2 => format!("r{}", sisa),
+                _ => r.to_string(),
+            }
+        };
+        normalisasi(a) == normalisasi(b)
+    }
+}
+
+pub fn is_nama_helper_crt(nama: &str) -> bool {
+    const NAMA_HELPER: [&str; 28] = [
+        "_start", "_start_c", "__libc_csu_init", "__libc_csu_fini", "_init", "_fini",
+        "deregister_tm_clones", "register_tm_clones", "__do_global_dtors_aux", "__do_global_ctors_aux",
+        "frame_dummy", "_dl_relocate_static_pie", "__gmon_start__",
+        "mainCRTStartup", "wmainCRTStartup", "WinMainCRTStartup", "wWinMainCRTStartup", "_DllMainCRTStartup",
+        "__tmainCRTStartup", "__security_init_cookie", "__security_check_cookie", "_initterm", "_initterm_e",
+        "pre_c_initialization", "post_pgo_initialization", "pre_cpp_initialization", "__GSHandlerCheck",
+        "_guard_dispatch_icall_nop",
+    ];
+    NAMA_HELPER.contains(&nama) || nama.starts_with("__scrt_")
+}
+
+pub fn terapkan_hasil_crt(hasil: &HasilDeteksiCrt, vmem: &mut VirtualMemory, type_sys: &mut TypeSystem) {
+    let nama_main = hasil.jenis_main.nama().to_string();
+    let nama_sudah_ada = vmem.simbol_global.iter().any(|(addr, n)| *n == nama_main && *addr != hasil.alamat_main);
+    if !nama_sudah_ada {
+        vmem.simbol_global.insert(hasil.alamat_main, nama_main);
+    }
+    type_sys.global_signatures.insert(hasil.alamat_main, hasil.jenis_main.signature());
+}
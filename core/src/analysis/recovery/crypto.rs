@@ -0,0 +1,264 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use log::info;
+use crate::loader::vmem::VirtualMemory;
+use crate::ir::types::{StatementIr, TipeOperand};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AlgoritmaKripto {
+    Aes,
+    Sha256,
+    Md5,
+    Crc32,
+    ChaCha20,
+}
+
+impl AlgoritmaKripto {
+    pub fn nama(&self) -> &'static str {
+        match self {
+            AlgoritmaKripto::Aes => "AES",
+            AlgoritmaKripto::Sha256 => "SHA-256",
+            AlgoritmaKripto::Md5 => "MD5",
+            AlgoritmaKripto::Crc32 => "CRC32",
+            AlgoritmaKripto::ChaCha20 => "ChaCha20",
+        }
+    }
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlgoritmaKripto::Aes => "aes",
+            AlgoritmaKripto::Sha256 => "sha256",
+            AlgoritmaKripto::Md5 => "md5",
+            AlgoritmaKripto::Crc32 => "crc32",
+            AlgoritmaKripto::ChaCha20 => "chacha20",
+        }
+    }
+}
+
+enum PolaKonstanta {
+    Byte(&'static [u8]),
+    Kata32(&'static [u32]),
+}
+
+struct SignatureData {
+    algoritma: AlgoritmaKripto,
+    deskripsi: &'static str,
+    pola: PolaKonstanta,
+    panjang_byte: usize,
+}
+
+const SIGNATURE_DATA: &[SignatureData] = &[
+    SignatureData {
+        algoritma: AlgoritmaKripto::Aes,
+        deskripsi: "AES S-box",
+        pola: PolaKonstanta::Byte(&[0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76]),
+        panjang_byte: 256,
+    },
+    SignatureData {
+        algoritma: AlgoritmaKripto::Aes,
+        deskripsi: "AES inverse S-box",
+        pola: PolaKonstanta::Byte(&[0x52, 0x09, 0x6a, 0xd5, 0x30, 0x36, 0xa5, 0x38, 0xbf, 0x40, 0xa3, 0x9e, 0x81, 0xf3, 0xd7, 0xfb]),
+        panjang_byte: 256,
+    },
+    SignatureData {
+        algoritma: AlgoritmaKripto::Aes,
+        deskripsi: "AES Te0",
+        pola: PolaKonstanta::Kata32(&[0xc66363a5, 0xf87c7c84, 0xee777799, 0xf67b7b8d]),
+        panjang_byte: 1024,
+    },
+    SignatureData {
+        algoritma: AlgoritmaKripto::Sha256,
+        deskripsi: "SHA-256 K",
+        pola: PolaKonstanta::Kata32(&[0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5]),
+        panjang_byte: 256,
+    },
+    SignatureData {
+        algoritma: AlgoritmaKripto::Md5,
+        deskripsi: "MD5 T",
+        pola: PolaKonstanta::Kata32(&[0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501]),
+        panjang_byte: 256,
+    },
+    SignatureData {
+        algoritma: AlgoritmaKripto::Crc32,
+        deskripsi: "CRC32 tabel (reflected)",
+        pola: PolaKonstanta::Kata32(&[0x00000000, 0x77073096, 0xee0e612c, 0x990951ba, 0x076dc419, 0x706af48f, 0xe963a535, 0x9e6495a3]),
+        panjang_byte: 1024,
+    },
+    SignatureData {
+        algoritma: AlgoritmaKripto::Crc32,
+        deskripsi: "CRC32 tabel (normal)",
+        pola: PolaKonstanta::Kata32(&[0x00000000, 0x04c11db7, 0x09823b6e, 0x0d4326d9, 0x130476dc, 0x17c56b6b, 0x1a864db2, 0x1e475005]),
+        panjang_byte: 1024,
+    },
+    SignatureData {
+        algoritma: AlgoritmaKripto::ChaCha20,
+        deskripsi: "ChaCha sigma \"expand 32-byte k\"",
+        pola: PolaKonstanta::Byte(b"expand 32-byte k"),
+        panjang_byte: 16,
+    },
+    SignatureData {
+        algoritma: AlgoritmaKripto::ChaCha20,
+        deskripsi: "ChaCha tau \"expand 16-byte k\"",
+        pola: PolaKonstanta::Byte(b"expand 16-byte k"),
+        panjang_byte: 16,
+    },
+];
+
+const SIGNATURE_IMMEDIATE: &[(u32, AlgoritmaKripto)] = &[
+    (0xd76aa478, AlgoritmaKripto::Md5),
+    (0xe8c7b756, AlgoritmaKripto::Md5),
+    (0x242070db, AlgoritmaKripto::Md5),
+    (0x428a2f98, AlgoritmaKripto::Sha256),
+    (0x71374491, AlgoritmaKripto::Sha256),
+    (0x6a09e667, AlgoritmaKripto::Sha256),
+    (0xbb67ae85, AlgoritmaKripto::Sha256),
+    (0x61707865, AlgoritmaKripto::ChaCha20),
+    (0x3320646e, AlgoritmaKripto::ChaCha20),
+    (0x79622d32, AlgoritmaKripto::ChaCha20),
+    (0x6b206574, AlgoritmaKripto::ChaCha20),
+    (0xedb88320, AlgoritmaKripto::Crc32),
+    (0x82f63b78, AlgoritmaKripto::Crc32),
+    (0x04c11db7, AlgoritmaKripto::Crc32),
+];
+
+const MINIMUM_IMMEDIATE: usize = 2;
+
+#[derive(Debug, Clone)]
+pub struct KonstantaKripto {
+    pub alamat: u64,
+    pub algoritma: AlgoritmaKripto,
+    pub deskripsi: String,
+    pub panjang_byte: usize,
+    pub referensi: Vec<u64>,
+}
+
+#[derive(Default)]
+pub struct TabelKripto {
+    pub konstanta: BTreeMap<u64, KonstantaKripto>,
+    pub fungsi: BTreeMap<u64, BTreeSet<AlgoritmaKripto>>,
+}
+
+impl TabelKripto {
+    pub fn new() -> Self {
+        Self {
+            konstanta: BTreeMap::new(),
+            fungsi: BTreeMap::new(),
+        }
+    }
+    pub fn pindai_vmem(&mut self, vmem: &VirtualMemory) {
+        info!("Memindai konstanta kriptografi...");
+        for seg in &vmem.segments {
+            self.pindai_segment(seg.start_addr, &seg.data, vmem.big_endian);
+        }
+        info!("Ditemukan {} konstanta kriptografi.", self.konstanta.len());
+    }
+    pub fn pindai_segment(&mut self, base_addr: u64, data: &[u8], big_endian: bool) {
+        for sig in SIGNATURE_DATA {
+            let pola: Vec<u8> = match sig.pola {
+                PolaKonstanta::Byte(bytes) => bytes.to_vec(),
+                PolaKonstanta::Kata32(kata) => kata.iter().flat_map(|k| {
+                    if big_endian { k.to_be_bytes() } else { k.to_le_bytes() }
+                }).collect(),
+            };
+            let mut offset = 0;
+            while offset + pola.len() <= data.len() {
+                if data[offset..offset + pola.len()] == pola[..] {
+                    let alamat = base_addr + offset as u64;
+                    self.konstanta.insert(alamat, KonstantaKripto {
+                        alamat,
+                        algoritma: sig.algoritma,
+                        deskripsi: sig.deskripsi.to_string(),
+                        panjang_byte: sig.panjang_byte.min(data.len() - offset),
+                        referensi: Vec::new(),
+                    });
+                    offset += pola.len();
+                } else {
+                    offset += 1;
+                }
+            }
+        }
+    }
+    pub fn cari_konstanta(&self, alamat: u64) -> Option<&KonstantaKripto> {
+        let (_, entri) = self.konstanta.range(..=alamat).next_back()?;
+        (alamat < entri.alamat + entri.panjang_byte as u64).then_some(entri)
+    }
+    pub fn catat_referensi_dari_ir(&mut self, semua_fungsi: &HashMap<u64, Vec<StatementIr>>) {
+        for (&func_addr, stmts) in semua_fungsi {
+            let mut alamat_dirujuk = Vec::new();
+            let mut nilai_immediate = BTreeSet::new();
+            for stmt in stmts {
+                let mut operands = vec![&stmt.operand_satu, &stmt.operand_dua];
+                operands.extend(stmt.operand_tambahan.iter());
+                for op in operands {
+                    kumpulkan_nilai_operand(op, stmt.address_asal, &mut alamat_dirujuk, &mut nilai_immediate);
+                }
+            }
+            let mut algoritma = BTreeSet::new();
+            for (alamat, asal) in alamat_dirujuk {
+                let Some(awal) = self.cari_konstanta(alamat).map(|k| k.alamat) else {
+                    continue;
+                };
+                if let Some(entri) = self.konstanta.get_mut(&awal) {
+                    entri.referensi.push(asal);
+                    algoritma.insert(entri.algoritma);
+                }
+            }
+            let mut hitungan: HashMap<AlgoritmaKripto, usize> = HashMap::new();
+            for nilai in nilai_immediate {
+                if let Some((_, algo)) = SIGNATURE_IMMEDIATE.iter().find(|(k, _)| *k == nilai) {
+                    *hitungan.entry(*algo).or_insert(0) += 1;
+                }
+            }
+            for (algo, jumlah) in hitungan {
+                if jumlah >= MINIMUM_IMMEDIATE || algo == AlgoritmaKripto::Crc32 {
+                    algoritma.insert(algo);
+                }
+            }
+            if !algoritma.is_empty() {
+                self.fungsi.entry(func_addr).or_default().extend(algoritma);
+            }
+        }
+        for entri in self.konstanta.values_mut() {
+            entri.referensi.sort();
+            entri.referensi.dedup();
+        }
+    }
+    pub fn algoritma_fungsi(&self, func_addr: u64) -> Option<&BTreeSet<AlgoritmaKripto>> {
+        self.fungsi.get(&func_addr)
+    }
+    pub fn usulan_nama_fungsi(&self, func_addr: u64) -> Option<String> {
+        let algoritma = self.fungsi.get(&func_addr)?;
+        let label: Vec<&str> = algoritma.iter().map(|a| a.label()).collect();
+        Some(format!("crypto_{}_{:x}", label.join("_"), func_addr))
+    }
+    pub fn komentar_fungsi(&self, func_addr: u64) -> Option<String> {
+        let algoritma = self.fungsi.get(&func_addr)?;
+        let nama: Vec<&str> = algoritma.iter().map(|a| a.nama()).collect();
+        Some(format!("// crypto: {}\n", nama.join(", ")))
+    }
+    pub fn terapkan_nama(&self, vmem: &mut VirtualMemory) {
+        for &func_addr in self.fungsi.keys() {
+            if func_addr == vmem.entry_point || vmem.simbol_global.contains_key(&func_addr) {
+                continue;
+            }
+            if let Some(nama) = self.usulan_nama_fungsi(func_addr) {
+                vmem.simbol_global.insert(func_addr, nama);
+            }
+        }
+    }
+}
+
+fn kumpulkan_nilai_operand(op: &TipeOperand, asal: u64, alamat: &mut Vec<(u64, u64)>, immediate: &mut BTreeSet<u32>) {
+    match op {
+        TipeOperand::Immediate(val) => {
+            alamat.push((*val as u64, asal));
+            immediate.insert(*val as u32);
+        },
+        TipeOperand::Memory(addr) => alamat.push((*addr, asal)),
+        TipeOperand::MemoryRef { offset, .. } => alamat.push((*offset as u64, asal)),
+        TipeOperand::MemoryComplex { disp, .. } => alamat.push((*disp as u64, asal)),
+        TipeOperand::Expression { operand_kiri, operand_kanan, .. } => {
+            kumpulkan_nilai_operand(operand_kiri, asal, alamat, immediate);
+            kumpulkan_nilai_operand(operand_kanan, asal, alamat, immediate);
+        },
+        _ => {}
+    }
+}
@@ -1,18 +1,23 @@
 use std::collections::{HashSet, VecDeque, HashMap};
+use std::sync::Arc;
 use log::{info, debug};
 use rayon::prelude::*;
-use crate::loader::vmem::VirtualMemory;
+use crate::loader::vmem::{VirtualMemory, ModeKode};
 use crate::disasm::engine::DisasmEngine;
-use crate::ir::lift::IrLifter;
-use crate::ir::types::StatementIr;
-use crate::disasm::instruction::InstructionNormalized;
+use crate::disasm::cache::CacheInstruksi;
+use crate::ir::lift::{IrLifter, arm64, riscv, mips, string, x87};
+use crate::ir::lift::semantic::SemanticEngine;
+use crate::ir::types::{StatementIr, TipeOperand, OperasiIr};
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
 use crate::analysis::recovery::indirect::JumpTableAnalyzer;
+use crate::analysis::recovery::xref::{self, DatabaseXref, EntriXref, JenisXref};
 
 pub struct FunctionContext {
     pub entry_point: u64,
     pub ir_code: Vec<StatementIr>,
     pub instruction_count: usize,
     pub end_address: u64, 
+    pub instruksi: Vec<InstructionNormalized>,
 }
 
 pub struct RecursiveDescent {
@@ -21,18 +26,45 @@ pub struct RecursiveDescent {
     visited_global: HashSet<u64>, 
     detected_functions: HashMap<u64, FunctionContext>,
     pub global_jump_targets: HashMap<u64, Vec<u64>>, 
+    pub xref_db: DatabaseXref,
+    pub signal_handlers: Vec<u64>,
+    fungsi_thumb: HashSet<u64>,
+    cache_instruksi: Arc<CacheInstruksi>,
 }
 
 impl RecursiveDescent {
     pub fn new(arch: &str) -> Self {
         Self {
             arch_target: arch.to_string(),
-            lifter: IrLifter::new(),
+            lifter: IrLifter::untuk_arsitektur(arch),
             visited_global: HashSet::new(),
             detected_functions: HashMap::new(),
             global_jump_targets: HashMap::new(),
+            xref_db: DatabaseXref::new(),
+            signal_handlers: Vec::new(),
+            fungsi_thumb: HashSet::new(),
+            cache_instruksi: Arc::new(CacheInstruksi::new(arch)),
         }
     }
+    pub fn atur_semantic_engine(&mut self, engine: SemanticEngine) {
+        self.lifter.semantic_engine = Arc::new(engine);
+    }
+    pub fn ambil_cache_instruksi(&self) -> &CacheInstruksi {
+        &self.cache_instruksi
+    }
+    pub fn is_fungsi_thumb(&self, addr: u64) -> bool {
+        self.fungsi_thumb.contains(&addr)
+    }
+    fn normalisasi_target(&mut self, vmem: &VirtualMemory, target: u64) -> u64 {
+        if !crate::arch::is_arm32(&self.arch_target) {
+            return target;
+        }
+        let alamat = target & !1;
+        if target & 1 == 1 || vmem.is_thumb(alamat) {
+            self.fungsi_thumb.insert(alamat);
+        }
+        alamat
+    }
     pub fn lakukan_analisis_full(&mut self, vmem: &VirtualMemory) {
         info!("Memulai Analisis Recursive Descent Fase 1 (Enhanced Slicing)...");
         let mut frontier_functions: Vec<u64> = self.inisialisasi_queue_dari_simbol(vmem);
@@ -42,34 +74,81 @@ impl RecursiveDescent {
         let mut visited_addresses: HashSet<u64> = HashSet::new();
         self.jalankan_fase_recursive(vmem, frontier_functions, &mut visited_addresses);
         self.lakukan_analisis_gap_dan_sweep(vmem, &mut visited_addresses);
-        info!("Analisis selesai. Ditemukan {} fungsi.", self.detected_functions.len());
+        self.proses_signal_handler(vmem, &mut visited_addresses);
+        self.finalisasi_xref(vmem);
+        let (hit, miss) = self.cache_instruksi.statistik();
+        info!("Analisis selesai. Ditemukan {} fungsi (cache dekode: {} hit, {} miss).", self.detected_functions.len(), hit, miss);
+    }
+    pub fn analisis_fungsi_tambahan(&mut self, vmem: &VirtualMemory, alamat: &[u64]) {
+        let mut baru: Vec<u64> = alamat.iter()
+            .map(|&addr| self.normalisasi_target(vmem, addr))
+            .collect();
+        baru.retain(|addr| !self.visited_global.contains(addr));
+        if baru.is_empty() {
+            return;
+        }
+        info!("Menganalisis {} fungsi tambahan...", baru.len());
+        let mut visited_addresses = HashSet::new();
+        self.jalankan_fase_recursive(vmem, baru, &mut visited_addresses);
+        self.proses_signal_handler(vmem, &mut visited_addresses);
+        self.finalisasi_xref(vmem);
     }
-    fn jalankan_fase_recursive(&mut self, vmem: &VirtualMemory, mut frontier: Vec<u64>, visited_addresses: &mut HashSet<u64>) {
-        let arch_clone = self.arch_target.clone();
+    fn proses_signal_handler(&mut self, vmem: &VirtualMemory, visited_addresses: &mut HashSet<u64>) {
+        let mut handlers: Vec<u64> = self.deteksi_signal_handler(vmem).into_iter()
+            .map(|addr| self.normalisasi_target(vmem, addr))
+            .collect();
+        handlers.dedup();
+        self.signal_handlers = handlers;
+        let handler_baru: Vec<u64> = self.signal_handlers.iter()
+            .filter(|addr| !self.visited_global.contains(addr))
+            .cloned()
+            .collect();
+        if !handler_baru.is_empty() {
+            info!("Memproses {} signal handler yang ditemukan dari argumen pointer fungsi...", handler_baru.len());
+            self.jalankan_fase_recursive(vmem, handler_baru, visited_addresses);
+        }
+    }
+    fn finalisasi_xref(&mut self, vmem: &VirtualMemory) {
+        let mut entry_fungsi: Vec<u64> = self.detected_functions.keys().cloned().collect();
+        entry_fungsi.sort();
+        self.xref_db.klasifikasi_tail_call(&entry_fungsi);
+        let ptr_size = if self.arch_target.contains("64") { 8 } else { 4 };
+        self.xref_db.pindai_pointer_data(vmem, ptr_size);
+    }
+    fn jalankan_fase_recursive(&mut self, vmem: &VirtualMemory, frontier: Vec<u64>, visited_addresses: &mut HashSet<u64>) {
+        let cache = Arc::clone(&self.cache_instruksi);
         let lifter_template = self.lifter.clone();
+        let mut frontier: Vec<u64> = frontier.into_iter()
+            .map(|addr| self.normalisasi_target(vmem, addr))
+            .collect();
         for &addr in &frontier {
             self.visited_global.insert(addr);
         }
         while !frontier.is_empty() {
             info!("Memproses batch paralel: {} fungsi...", frontier.len());
-            let results: Vec<(u64, FunctionContext, Vec<u64>, HashMap<u64, Vec<u64>>, HashSet<u64>)> = frontier
+            let tugas: Vec<(u64, bool)> = frontier.iter()
+                .map(|&addr| (addr, self.fungsi_thumb.contains(&addr)))
+                .collect();
+            let results: Vec<(u64, FunctionContext, Vec<u64>, HashMap<u64, Vec<u64>>, HashSet<u64>, Vec<EntriXref>)> = tugas
                 .par_iter()
-                .map(|&func_addr| {
-                    let engine_local = DisasmEngine::buat_engine_baru(&arch_clone);   
+                .map(|&(func_addr, mode_thumb)| {
                     Self::analisa_fungsi_worker(
                         func_addr, 
                         vmem, 
-                        &engine_local, 
-                        lifter_template.clone()
+                        &cache, 
+                        lifter_template.clone(),
+                        mode_thumb
                     )
                 })
                 .collect();
             let mut next_frontier = HashSet::new();
-            for (addr, ctx, new_targets, jump_tables, covered_addrs) in results {
+            for (addr, ctx, new_targets, jump_tables, covered_addrs, xrefs) in results {
                 self.detected_functions.insert(addr, ctx);
+                self.xref_db.gabung(xrefs);
                 self.global_jump_targets.extend(jump_tables);
                 visited_addresses.extend(covered_addrs);
                 for target in new_targets {
+                    let target = self.normalisasi_target(vmem, target);
                     if !self.visited_global.contains(&target) {
                         self.visited_global.insert(target);
                         next_frontier.insert(target);
@@ -83,8 +162,16 @@ impl RecursiveDescent {
         info!("Memulai Gap Analysis untuk menemukan fungsi tersembunyi...");
         let exec_regions = vmem.ambil_executable_regions();
         let mut detected_gap_funcs = Vec::new();
-        let arch_clone = self.arch_target.clone();
-        let align = if self.arch_target.contains("64") { 16 } else { 4 };
+        let is_arm32 = crate::arch::is_arm32(&self.arch_target);
+        let align = if crate::arch::is_riscv(&self.arch_target) || is_arm32 {
+            2
+        } else if crate::arch::is_mips(&self.arch_target) {
+            4
+        } else if self.arch_target.contains("64") {
+            16
+        } else {
+            4
+        };
         for (start_seg, data) in exec_regions {
             let end_seg = start_seg + data.len() as u64;
             let mut curr = start_seg;
@@ -94,16 +181,28 @@ impl RecursiveDescent {
                     while curr % align != 0 { curr += 1; }
                     continue;
                 }
+                if vmem.ambil_mode_kode(curr) == Some(ModeKode::Data) {
+                    curr += align;
+                    continue;
+                }
                 if let Some(bytes) = vmem.baca_array(curr, 4) {
                     if bytes.iter().all(|&b| b == 0x00) || bytes.iter().all(|&b| b == 0xCC) {
                         curr += align;
                         continue;
                     }
-                    let engine = DisasmEngine::buat_engine_baru(&arch_clone);
-                    if Self::cek_heuristic_gap_entry(vmem, curr, &engine) {
+                    let kandidat_mode: Vec<bool> = match vmem.ambil_mode_kode(curr) {
+                        _ if !is_arm32 => vec![false],
+                        Some(ModeKode::Thumb) => vec![true],
+                        Some(ModeKode::Arm) => vec![false],
+                        _ => vec![false, true],
+                    };
+                    let mode_terdeteksi = kandidat_mode.into_iter()
+                        .filter(|&thumb| thumb || curr % 4 == 0)
+                        .find(|&thumb| Self::cek_heuristic_gap_entry(vmem, curr, &self.cache_instruksi, thumb));
+                    if let Some(mode_thumb) = mode_terdeteksi {
                         info!("Fungsi ditemukan via Gap Analysis di 0x{:x}", curr);
-                        detected_gap_funcs.push(curr);
-                        let (_, _, _, _, covered) = Self::analisa_fungsi_worker(curr, vmem, &engine, self.lifter.clone());
+                        detected_gap_funcs.push(if mode_thumb { curr | 1 } else { curr });
+                        let (_, _, _, _, covered, _) = Self::analisa_fungsi_worker(curr, vmem, &self.cache_instruksi, self.lifter.clone(), mode_thumb);
                         visited_addresses.extend(covered);
                         curr += 16; 
                     } else {
@@ -119,22 +218,19 @@ impl RecursiveDescent {
             self.jalankan_fase_recursive(vmem, detected_gap_funcs, visited_addresses);
         }
     }
-    fn cek_heuristic_gap_entry(vmem: &VirtualMemory, addr: u64, engine: &DisasmEngine) -> bool {
-        let bytes = match vmem.baca_array(addr, 16) {
-            Some(b) => b,
-            None => return false,
-        };
+    fn cek_heuristic_gap_entry(vmem: &VirtualMemory, addr: u64, cache: &CacheInstruksi, mode_thumb: bool) -> bool {
         let mut curr_offset = 0;
         for _ in 0..3 {
-            if let Some(instr) = engine.ambil_satu_instruksi(&bytes[curr_offset..], addr + curr_offset as u64) {
+            if let Some(instr) = cache.dekode(vmem, addr + curr_offset as u64, mode_thumb) {
                 if instr.mnemonic == "INVALID" { return false; }
                 let mnem = instr.mnemonic.as_str();
                 let op = instr.op_str.as_str();
-                if mnem == "push" && op == "rbp" { return true; }
-                if mnem == "sub" && op.starts_with("rsp") { return true; }
+                if mnem == "push" && (op == "rbp" || op == "ebp") { return true; }
+                if mnem == "sub" && (op.starts_with("rsp") || op.starts_with("esp")) { return true; }
                 if mnem == "endbr64" { return true; }
                 if mnem == "stp" && (op.contains("x29") || op.contains("fp")) { return true; }
                 if mnem == "pacibsp" { return true; }
+                if mnem == "push" && op.contains("lr") { return curr_offset == 0; }
                 curr_offset += instr.hitung_panjang_byte();
             } else {
                 break;
@@ -144,59 +240,252 @@ impl RecursiveDescent {
     }
     fn inisialisasi_queue_dari_simbol(&self, vmem: &VirtualMemory) -> Vec<u64> {
         let mut queue = Vec::new();
-        for &addr in vmem.simbol_global.keys() {
-            if self.is_executable_address(vmem, addr) {
+        let mut terdaftar = HashSet::new();
+        let kandidat = vmem.simbol_global.keys().cloned()
+            .chain(vmem.entry_points.iter().map(|ep| ep.alamat));
+        for addr in kandidat {
+            if self.is_executable_address(vmem, addr) && terdaftar.insert(addr) {
                 queue.push(addr);
             }
         }
         queue
     }
+    pub fn deteksi_thunk_impor(&self, vmem: &VirtualMemory) -> Vec<(u64, String)> {
+        let mut hasil = Vec::new();
+        for (addr, ctx) in &self.detected_functions {
+            if vmem.simbol_global.contains_key(addr) {
+                continue;
+            }
+            let instruksi_pertama = ctx.ir_code.iter()
+                .find(|s| !matches!(s.operation_code, OperasiIr::Unknown | OperasiIr::Nop));
+            if let Some(stmt) = instruksi_pertama
+                && stmt.operation_code == OperasiIr::Jmp
+                && let TipeOperand::Memory(slot) = stmt.operand_satu
+                && let Some(nama) = vmem.simbol_global.get(&slot)
+            {
+                hasil.push((*addr, nama.clone()));
+            }
+        }
+        hasil.sort();
+        hasil
+    }
+    fn deteksi_signal_handler(&self, vmem: &VirtualMemory) -> Vec<u64> {
+        let reg_argumen: [[&str; 2]; 2] = if crate::arch::is_arm32(&self.arch_target) {
+            [["r0", "r0"], ["r1", "r1"]]
+        } else if crate::arch::is_riscv(&self.arch_target) || crate::arch::is_mips(&self.arch_target) {
+            [["a0", "a0"], ["a1", "a1"]]
+        } else if self.arch_target.contains("arm") || self.arch_target.contains("aarch64") {
+            [["x0", "w0"], ["x1", "w1"]]
+        } else if vmem.format_biner == "pe" {
+            [["rcx", "ecx"], ["rdx", "edx"]]
+        } else {
+            [["rdi", "edi"], ["rsi", "esi"]]
+        };
+        let is_stack = crate::arch::is_x86_32(&self.arch_target);
+        let thunk: HashMap<u64, String> = self.deteksi_thunk_impor(vmem).into_iter().collect();
+        let mut handlers = Vec::new();
+        for ctx in self.detected_functions.values() {
+            for (idx, stmt) in ctx.ir_code.iter().enumerate() {
+                if stmt.operation_code != OperasiIr::Call {
+                    continue;
+                }
+                let target = match &stmt.operand_satu {
+                    TipeOperand::Immediate(val) => *val as u64,
+                    TipeOperand::Memory(addr) => *addr,
+                    _ => continue,
+                };
+                let nama_lengkap = match vmem.simbol_global.get(&target).or_else(|| thunk.get(&target)) {
+                    Some(n) => n.as_str(),
+                    None => continue,
+                };
+                let nama = nama_lengkap.rsplit(':').next().unwrap_or(nama_lengkap);
+                let posisi_arg = match nama {
+                    "signal" | "bsd_signal" | "sysv_signal" | "__sysv_signal" | "AddVectoredExceptionHandler" => 1,
+                    "SetConsoleCtrlHandler" | "SetUnhandledExceptionFilter" => 0,
+                    _ => continue,
+                };
+                let kandidat = if is_stack {
+                    Self::cari_argumen_stack(&ctx.ir_code[..idx], stmt.address_asal, posisi_arg)
+                } else {
+                    Self::cari_argumen_register(&ctx.ir_code[..idx], &reg_argumen[posisi_arg])
+                };
+                if let Some(handler) = kandidat
+                    && self.is_executable_address(vmem, handler)
+                    && !handlers.contains(&handler)
+                {
+                    debug!("Signal handler terdeteksi di 0x{:x} (argumen {})", handler, nama);
+                    handlers.push(handler);
+                }
+            }
+        }
+        handlers.sort();
+        handlers
+    }
+    fn geser_stack(op: &TipeOperand) -> Option<i64> {
+        match op {
+            TipeOperand::Immediate(n) => Some(*n),
+            TipeOperand::Expression { operand_kiri, operand_kanan, .. } => match (operand_kiri.as_ref(), operand_kanan.as_ref()) {
+                (TipeOperand::Register(r), TipeOperand::Immediate(n)) if r == "esp" => Some(*n),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+    fn nilai_alamat_argumen(stmt: &StatementIr) -> Option<u64> {
+        match (&stmt.operation_code, &stmt.operand_dua) {
+            (OperasiIr::Mov, TipeOperand::Immediate(val)) | (OperasiIr::Lea, TipeOperand::Immediate(val)) => Some(*val as u64),
+            (OperasiIr::Lea, TipeOperand::Memory(addr)) => Some(*addr),
+            _ => None,
+        }
+    }
+    fn cari_argumen_register(sebelum_call: &[StatementIr], reg_target: &[&str; 2]) -> Option<u64> {
+        for prev in sebelum_call.iter().rev() {
+            if prev.operation_code == OperasiIr::Call {
+                return None;
+            }
+            if matches!(&prev.operand_satu, TipeOperand::Register(r) if reg_target.contains(&r.as_str())) {
+                return Self::nilai_alamat_argumen(prev);
+            }
+        }
+        None
+    }
+    fn cari_argumen_stack(sebelum_call: &[StatementIr], alamat_call: u64, posisi: usize) -> Option<u64> {
+        let offset_target = posisi as i64 * 4;
+        let mut turun = 0i64;
+        for prev in sebelum_call.iter().rev().filter(|s| s.address_asal != alamat_call) {
+            if prev.operation_code == OperasiIr::Call {
+                return None;
+            }
+            match (&prev.operation_code, &prev.operand_satu, Self::geser_stack(&prev.operand_dua)) {
+                (OperasiIr::Sub, TipeOperand::Register(r), Some(n)) if r == "esp" => turun += n,
+                (OperasiIr::Add, TipeOperand::Register(r), Some(n)) if r == "esp" => turun -= n,
+                (_, TipeOperand::Register(r), _) if r == "esp" => return None,
+                (_, TipeOperand::MemoryRef { base, offset }, _) if base == "esp" && turun + offset == offset_target => {
+                    return Self::nilai_alamat_argumen(prev);
+                },
+                _ => {}
+            }
+        }
+        None
+    }
     fn analisa_fungsi_worker(
         start_addr: u64, 
         vmem: &VirtualMemory, 
-        engine: &DisasmEngine,
-        lifter: IrLifter
-    ) -> (u64, FunctionContext, Vec<u64>, HashMap<u64, Vec<u64>>, HashSet<u64>) {
+        cache: &CacheInstruksi,
+        lifter: IrLifter,
+        mode_thumb: bool
+    ) -> (u64, FunctionContext, Vec<u64>, HashMap<u64, Vec<u64>>, HashSet<u64>, Vec<EntriXref>) {
+        let is_arm32 = crate::arch::is_arm32(cache.arch());
+        let is_riscv = crate::arch::is_riscv(cache.arch());
+        let is_mips = crate::arch::is_mips(cache.arch());
+        let is_arm64 = crate::arch::is_arm64(cache.arch());
+        let is_x86 = crate::arch::is_x86(cache.arch());
         let mut instructions_ir = Vec::new();
         let mut worklist_block = VecDeque::new();
         let mut visited_local = HashSet::new();
         let mut found_call_targets = Vec::new();
         let mut local_jump_targets = HashMap::new();
+        let mut xrefs_lokal = Vec::new();
         let mut instruction_history: Vec<InstructionNormalized> = Vec::new(); 
+        let mut instruksi_fungsi: Vec<InstructionNormalized> = Vec::new();
+        let mut kondisi_it: HashMap<u64, String> = HashMap::new();
+        let mut status_register: HashMap<u64, HashMap<String, u64>> = HashMap::new();
         let mut max_addr = start_addr;
         worklist_block.push_back(start_addr);
         while let Some(curr_addr) = worklist_block.pop_front() {
             if visited_local.contains(&curr_addr) {
                 continue;
             }
-            let buffer_opt = vmem.baca_array(curr_addr, 16); 
-            if buffer_opt.is_none() { continue; }
-            let buffer = buffer_opt.unwrap();
-            if let Some(instr) = engine.ambil_satu_instruksi(&buffer, curr_addr) {
+            if is_arm32 && vmem.ambil_mode_kode(curr_addr) == Some(ModeKode::Data) && curr_addr != start_addr {
+                continue;
+            }
+            if let Some(mut instr) = cache.dekode(vmem, curr_addr, mode_thumb).map(|i| (*i).clone()) {
                 visited_local.insert(curr_addr);
+                if let Some(kondisi) = kondisi_it.remove(&curr_addr) {
+                    DisasmEngine::terapkan_kondisi_it(&mut instr, &kondisi);
+                }
+                let mut peta_register = status_register.remove(&curr_addr).unwrap_or_default();
+                if is_riscv {
+                    riscv::gabungkan_pasangan_auipc(&mut instr, &mut peta_register);
+                }
+                if is_arm64 {
+                    arm64::gabungkan_pasangan_adrp(&mut instr, &mut peta_register);
+                }
+                if is_x86 {
+                    x87::lacak_top_fpu(&mut instr, &mut peta_register);
+                    string::lacak_flag_arah(&mut instr, &mut peta_register);
+                }
+                let mut slot_delay = None;
+                if is_mips {
+                    mips::lipat_akses_gp(&mut instr, vmem.nilai_gp, &mut peta_register);
+                    if mips::is_punya_delay_slot(&instr.mnemonic) {
+                        slot_delay = Self::ambil_slot_delay(curr_addr + 4, vmem, cache, &mut peta_register);
+                    }
+                }
                 if curr_addr > max_addr { max_addr = curr_addr; }
-                let next_addr = curr_addr + instr.hitung_panjang_byte() as u64;
-                let (is_terminator, new_targets, jump_table_res) = Self::analisa_control_flow_lokal(
-                    &instr, 
-                    vmem,
-                    &instruction_history, // Pass history
-                    &mut found_call_targets
-                );
+                let mut next_addr = curr_addr + instr.hitung_panjang_byte() as u64;
+                if let Some(slot) = &slot_delay {
+                    visited_local.insert(slot.address);
+                    max_addr = max_addr.max(slot.address);
+                    next_addr += slot.hitung_panjang_byte() as u64;
+                }
+                if is_arm32 && mode_thumb {
+                    Self::petakan_blok_it(&instr, next_addr, vmem, &mut kondisi_it);
+                }
+                let (is_terminator, new_targets, jump_table_res) = if is_arm32 {
+                    Self::analisa_control_flow_arm32(&instr, mode_thumb, &mut found_call_targets)
+                } else if is_riscv {
+                    Self::analisa_control_flow_riscv(&instr, &mut found_call_targets)
+                } else if is_mips {
+                    Self::analisa_control_flow_mips(&instr, &mut found_call_targets)
+                } else {
+                    Self::analisa_control_flow_lokal(
+                        &instr, 
+                        vmem,
+                        &instruction_history, // Pass history
+                        &mut found_call_targets
+                    )
+                };
+                xrefs_lokal.extend(xref::kumpulkan_xref_instruksi(&instr, start_addr, vmem));
                 if let Some(targets) = jump_table_res {
                     local_jump_targets.insert(instr.address, targets.clone());
                     for t in targets {
+                        xrefs_lokal.push(EntriXref {
+                            dari: instr.address,
+                            ke: t,
+                            jenis: JenisXref::Jump,
+                            ukuran_akses: 0,
+                            fungsi_asal: Some(start_addr),
+                        });
                         worklist_block.push_back(t);
                     }
                 }
                 for t in new_targets {
                     worklist_block.push_back(t);
                 }
-                let micro_ops = lifter.konversi_instruksi_ke_microcode(&instr);
+                let mut micro_ops = lifter.konversi_instruksi_ke_microcode(&instr);
+                if let Some(slot) = &slot_delay {
+                    xrefs_lokal.extend(xref::kumpulkan_xref_instruksi(slot, start_addr, vmem));
+                    let ops_slot = lifter.konversi_instruksi_ke_microcode(slot);
+                    micro_ops = mips::gabungkan_delay_slot(curr_addr, micro_ops, ops_slot);
+                }
+                if is_arm32 {
+                    for pool in Self::lipat_literal_pool(&instr, vmem, &mut micro_ops) {
+                        visited_local.insert(pool);
+                    }
+                }
                 instructions_ir.extend(micro_ops);
+                instruksi_fungsi.push(instr.clone());
+                if let Some(slot) = slot_delay {
+                    instruksi_fungsi.push(slot);
+                }
                 instruction_history.push(instr);
                 if instruction_history.len() > 50 {
                     instruction_history.remove(0);
                 }
+                if !is_terminator && !peta_register.is_empty() {
+                    status_register.insert(next_addr, peta_register);
+                }
                 if !is_terminator {
                     if vmem.simbol_global.contains_key(&next_addr) {
                          debug!("Control flow stop: fallthrough ke simbol global di 0x{:x}", next_addr);
@@ -209,13 +498,15 @@ impl RecursiveDescent {
             }
         }
         instructions_ir.sort_by_key(|k| k.address_asal);
+        instruksi_fungsi.sort_by_key(|i| i.address);
         let context = FunctionContext {
             entry_point: start_addr,
             ir_code: instructions_ir.clone(),
             instruction_count: instructions_ir.len(),
             end_address: max_addr,
+            instruksi: instruksi_fungsi,
         };
-        (start_addr, context, found_call_targets, local_jump_targets, visited_local)
+        (start_addr, context, found_call_targets, local_jump_targets, visited_local, xrefs_lokal)
     }
     fn analisa_control_flow_lokal(
         instr: &InstructionNormalized, 
@@ -243,7 +534,7 @@ impl RecursiveDescent {
                 }
                 is_terminator = true;
             },
-            "ret" | "retn" => {
+            "ret" | "retn" | "hlt" | "ud2" => {
                 is_terminator = true;
             },
             _ => {
@@ -256,6 +547,166 @@ impl RecursiveDescent {
         }
         (is_terminator, local_targets, jump_table_targets)
     }
+    fn analisa_control_flow_arm32(
+        instr: &InstructionNormalized,
+        mode_thumb: bool,
+        global_targets_collector: &mut Vec<u64>
+    ) -> (bool, Vec<u64>, Option<Vec<u64>>) {
+        let is_kondisional = instr.kondisi.is_some();
+        let target_imm = instr.operands_detail.iter().find_map(|op| match op {
+            JenisOperandDisasm::Immediate(val) => Some(*val as u64),
+            _ => None,
+        });
+        let pc = JenisOperandDisasm::Register("pc".to_string());
+        let mut local_targets = Vec::new();
+        let mut is_terminator = false;
+        match instr.mnemonic.as_str() {
+            "bl" => {
+                if let Some(target) = target_imm {
+                    global_targets_collector.push(target | mode_thumb as u64);
+                }
+            },
+            "blx" => {
+                if let Some(target) = target_imm {
+                    global_targets_collector.push(target | !mode_thumb as u64);
+                }
+            },
+            "b" => {
+                if let Some(target) = target_imm {
+                    local_targets.push(target);
+                }
+                is_terminator = !is_kondisional;
+            },
+            "cbz" | "cbnz" => {
+                if let Some(target) = target_imm {
+                    local_targets.push(target);
+                }
+            },
+            "bx" => is_terminator = !is_kondisional,
+            "pop" | "ldm" => is_terminator = !is_kondisional && instr.operands_detail.contains(&pc),
+            "mov" | "ldr" => is_terminator = !is_kondisional && instr.operands_detail.first() == Some(&pc),
+            "udf" => is_terminator = true,
+            _ => {}
+        }
+        (is_terminator, local_targets, None)
+    }
+    fn analisa_control_flow_riscv(
+        instr: &InstructionNormalized,
+        global_targets_collector: &mut Vec<u64>
+    ) -> (bool, Vec<u64>, Option<Vec<u64>>) {
+        let mnem = instr.mnemonic.as_str();
+        let rd_nol = instr.operands_detail.first() == Some(&JenisOperandDisasm::Register("zero".to_string()));
+        let target_imm = match instr.operands_detail.last() {
+            Some(JenisOperandDisasm::Immediate(val)) => Some(*val as u64),
+            _ => None,
+        };
+        let mut local_targets = Vec::new();
+        let mut is_terminator = false;
+        match mnem {
+            "jal" => match (rd_nol, target_imm) {
+                (true, Some(target)) => {
+                    local_targets.push(target);
+                    is_terminator = true;
+                },
+                (false, Some(target)) => global_targets_collector.push(target),
+                _ => {},
+            },
+            "jalr" => match (rd_nol, instr.alamat_terlipat) {
+                (true, Some(target)) => {
+                    local_targets.push(target);
+                    is_terminator = true;
+                },
+                (false, Some(target)) => global_targets_collector.push(target),
+                (rd_nol, None) => is_terminator = rd_nol,
+            },
+            "unimp" => is_terminator = true,
+            _ if mnem.starts_with('b') => {
+                if let Some(target) = target_imm {
+                    local_targets.push(target);
+                }
+            },
+            _ => {}
+        }
+        (is_terminator, local_targets, None)
+    }
+    fn ambil_slot_delay(
+        alamat_slot: u64,
+        vmem: &VirtualMemory,
+        cache: &CacheInstruksi,
+        peta_register: &mut HashMap<String, u64>
+    ) -> Option<InstructionNormalized> {
+        let mut slot = (*cache.dekode(vmem, alamat_slot, false)?).clone();
+        if mips::is_punya_delay_slot(&slot.mnemonic) {
+            debug!("Branch di delay slot 0x{:x} diabaikan", alamat_slot);
+            return None;
+        }
+        mips::lipat_akses_gp(&mut slot, vmem.nilai_gp, peta_register);
+        Some(slot)
+    }
+    fn analisa_control_flow_mips(
+        instr: &InstructionNormalized,
+        global_targets_collector: &mut Vec<u64>
+    ) -> (bool, Vec<u64>, Option<Vec<u64>>) {
+        let mnem = instr.mnemonic.as_str();
+        let target_imm = match instr.operands_detail.last() {
+            Some(JenisOperandDisasm::Immediate(val)) => Some(*val as u64),
+            _ => None,
+        };
+        let mut local_targets = Vec::new();
+        let mut is_terminator = false;
+        match mnem {
+            "j" | "b" => {
+                if let Some(target) = target_imm {
+                    local_targets.push(target);
+                }
+                is_terminator = true;
+            },
+            "jal" | "bal" | "bltzal" | "bgezal" => {
+                if let Some(target) = target_imm {
+                    global_targets_collector.push(target);
+                }
+            },
+            "jr" => is_terminator = true,
+            _ if mips::is_branch_kondisional(mnem) => {
+                if let Some(target) = target_imm {
+                    local_targets.push(target);
+                }
+            },
+            _ => {}
+        }
+        (is_terminator, local_targets, None)
+    }
+    fn petakan_blok_it(instr: &InstructionNormalized, next_addr: u64, vmem: &VirtualMemory, kondisi_it: &mut HashMap<u64, String>) {
+        let mut alamat = next_addr;
+        for kondisi in DisasmEngine::ekspansi_blok_it(instr) {
+            kondisi_it.insert(alamat, kondisi);
+            let halfword = vmem.baca_array(alamat, 2).map(|b| u16::from_le_bytes([b[0], b[1]])).unwrap_or(0);
+            alamat += if halfword >> 11 >= 0b11101 { 4 } else { 2 };
+        }
+    }
+    fn lipat_literal_pool(instr: &InstructionNormalized, vmem: &VirtualMemory, micro_ops: &mut [StatementIr]) -> Vec<u64> {
+        if !instr.mnemonic.starts_with("ldr") {
+            return Vec::new();
+        }
+        let pool = match instr.operands_detail.get(1) {
+            Some(JenisOperandDisasm::Memory { base: None, index: None, disp, .. }) => *disp as u64,
+            _ => return Vec::new(),
+        };
+        if !vmem.cek_alamat_executable(pool) {
+            return Vec::new();
+        }
+        if instr.mnemonic == "ldr"
+            && let Some(nilai) = vmem.baca_pointer(pool, 4)
+        {
+            for stmt in micro_ops.iter_mut() {
+                if stmt.operation_code == OperasiIr::Mov && stmt.operand_dua == TipeOperand::Memory(pool) {
+                    stmt.operand_dua = TipeOperand::Immediate(nilai as i64);
+                }
+            }
+        }
+        let ukuran = if instr.mnemonic == "ldrd" { 8 } else { 4 };
+        (0..ukuran).step_by(2).map(|off| pool + off).collect()
+    }
     fn ekstrak_target_address(instr: &InstructionNormalized) -> Option<u64> {
         if let Some(op) = instr.operands_detail.first() {
             if let crate::disasm::instruction::JenisOperandDisasm::Immediate(val) = op {
@@ -280,4 +731,7 @@ impl RecursiveDescent {
     pub fn ambil_hasil_fungsi(&self) -> &HashMap<u64, FunctionContext> {
         &self.detected_functions
     }
+    pub fn lepas_hasil_fungsi(&mut self) -> HashMap<u64, FunctionContext> {
+        std::mem::take(&mut self.detected_functions)
+    }
 }
\ No newline at end of file
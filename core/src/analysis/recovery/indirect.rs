@@ -15,7 +15,7 @@ impl JumpTableAnalyzer {
         }
         let operand = instr.operands_detail.first()?;
         match operand {
-            JenisOperandDisasm::Memory { base, index, scale, disp } => {
+            JenisOperandDisasm::Memory { base, index, scale, disp, .. } => {
                 let mut table_base_addr = 0u64;
                 let index_reg_opt = index.clone();
                 if let Some(base_reg) = base {
@@ -102,8 +102,7 @@ impl JumpTableAnalyzer {
                             if r == target_reg {
                                 if let Some(src) = instr.operands_detail.get(1) {
                                     if let JenisOperandDisasm::Immediate(val) = src {
-                                        let _page_base = instr.address & !0xFFF;
-                                        return Some(instr.address.wrapping_add(*val as u64));
+                                        return Some(*val as u64);
                                     }
                                 }
                             }
@@ -155,16 +154,10 @@ impl JumpTableAnalyzer {
         let safe_limit = if limit > 1024 { 1024 } else { limit };
         for i in 0..safe_limit {
             let entry_addr = base_addr + (i as u64 * step);
-            let target_val = if step == 8 {
-                match vmem.baca_array(entry_addr, 8) {
-                    Some(b) => u64::from_le_bytes(b.try_into().unwrap()),
-                    None => break,
-                }
-            } else {
-                match vmem.baca_array(entry_addr, 4) {
-                    Some(b) => u32::from_le_bytes(b.try_into().unwrap()) as u64,
-                    None => break,
-                }
+            let lebar = if step == 8 { 8 } else { 4 };
+            let target_val = match vmem.baca_pointer(entry_addr, lebar) {
+                Some(v) => v,
+                None => break,
             };
             if Self::is_valid_code_ptr(vmem, target_val) {
                 if !targets.contains(&target_val) {
@@ -1,8 +1,12 @@
 pub mod abi;
 pub mod ast;
+pub mod crt;
+pub mod crypto;
 pub mod explorer;
 pub mod indirect;
 pub mod stack;
 pub mod std_lib;
+pub mod strings;
 pub mod types;
-pub mod vtable;
\ No newline at end of file
+pub mod vtable;
+pub mod xref;
\ No newline at end of file
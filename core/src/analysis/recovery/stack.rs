@@ -26,6 +26,7 @@ pub struct StackFrame {
     pub frame_size: i64,
     counter_var: usize,
     array_access_offsets: HashSet<i64>, 
+    ukuran_slot: usize,
 }
 
 impl StackFrame {
@@ -35,12 +36,14 @@ impl StackFrame {
             frame_size: 0,
             counter_var: 0,
             array_access_offsets: HashSet::new(),
+            ukuran_slot: 8,
         }
     }
     pub fn analisis_stack_frame(stmts: &[StatementIr], arch: &dyn Architecture) -> Self {
         let mut frame = Self::new();
         let mut akses_stack: HashMap<i64, Vec<(u64, JenisAkses)>> = HashMap::new();
-        let fp = arch.dapatkan_frame_pointer();
+        frame.ukuran_slot = arch.dapatkan_ukuran_pointer() as usize;
+        let fp = Self::kumpulkan_alias_frame_pointer(arch);
         for stmt in stmts {
             frame.deteksi_pola_array_stack(&stmt.operand_satu, &fp);
             frame.deteksi_pola_array_stack(&stmt.operand_dua, &fp);
@@ -48,7 +51,7 @@ impl StackFrame {
             frame.kumpulkan_akses(&stmt.operand_dua, stmt.address_asal, &mut akses_stack, &fp, false);
             if let OperasiIr::Lea = stmt.operation_code {
                 if let TipeOperand::MemoryRef { base, offset } = &stmt.operand_dua {
-                    if base == &fp {
+                    if fp.contains(base) {
                         akses_stack.entry(*offset).or_default().push((stmt.address_asal, JenisAkses::AddressTaken));
                     }
                 }
@@ -61,6 +64,7 @@ impl StackFrame {
                     var.is_array_buffer = true;
                     var.nama_var = format!("buf_{}", var.offset.abs());
                     var.tipe_data = "char".to_string(); 
+                    var.element_size = 1;
                     var.array_count = 64;
                 }
             }
@@ -68,7 +72,19 @@ impl StackFrame {
         }
         frame
     }
-    fn deteksi_pola_array_stack(&mut self, op: &TipeOperand, fp: &str) {
+    fn kumpulkan_alias_frame_pointer(arch: &dyn Architecture) -> HashSet<String> {
+        let file = arch.dapatkan_register_file();
+        let fp = arch.dapatkan_frame_pointer();
+        let akar_fp = file.dapatkan_register_akar(&fp);
+        let lebar_penuh = arch.dapatkan_ukuran_pointer() as u16 * 8;
+        let mut hasil: HashSet<String> = file.daftar_register.iter()
+            .filter(|r| r.lebar_bit == lebar_penuh && file.dapatkan_register_akar(&r.nama) == akar_fp)
+            .map(|r| r.nama.clone())
+            .collect();
+        hasil.insert(fp);
+        hasil
+    }
+    fn deteksi_pola_array_stack(&mut self, op: &TipeOperand, fp: &HashSet<String>) {
         if let TipeOperand::Expression { operasi: OperasiIr::Add, operand_kiri, operand_kanan } = op {
              self.deteksi_pola_array_stack(operand_kiri, fp);
              self.deteksi_pola_array_stack(operand_kanan, fp);
@@ -79,9 +95,9 @@ impl StackFrame {
              }
         }
     }
-    fn parse_stack_address_expression(&self, expr: &TipeOperand, fp: &str) -> Option<(i64, bool)> {
+    fn parse_stack_address_expression(&self, expr: &TipeOperand, fp: &HashSet<String>) -> Option<(i64, bool)> {
         match expr {
-            TipeOperand::Register(r) if r == fp => Some((0, false)),
+            TipeOperand::Register(r) if fp.contains(r) => Some((0, false)),
             TipeOperand::Immediate(val) => Some((*val, false)), // Offset murni
             TipeOperand::Expression { operasi: OperasiIr::Add, operand_kiri, operand_kanan } => {
                 let res1 = self.parse_stack_address_expression(operand_kiri, fp);
@@ -108,12 +124,12 @@ impl StackFrame {
         op: &TipeOperand, 
         addr: u64, 
         peta_akses: &mut HashMap<i64, Vec<(u64, JenisAkses)>>, 
-        fp: &str,
+        fp: &HashSet<String>,
         is_dest: bool
     ) {
         match op {
             TipeOperand::MemoryRef { base, offset } => {
-                if base == fp {
+                if fp.contains(base) {
                     let jenis = if is_dest { JenisAkses::Write } else { JenisAkses::Read };
                     peta_akses.entry(*offset).or_default().push((addr, jenis));
                 }
@@ -179,7 +195,7 @@ impl StackFrame {
             range_aktif: vec![IntervalLiveness { start_addr: start, end_addr: start }],
             is_address_taken: false,
             is_array_buffer: false,
-            element_size: 1,
+            element_size: self.ukuran_slot,
             array_count: 1,
         }
     }
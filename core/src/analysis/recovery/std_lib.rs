@@ -5,7 +5,9 @@ use std::collections::hash_map::DefaultHasher;
 use log::{info, debug};
 use serde::{Deserialize, Serialize};
 
-use crate::analysis::recovery::types::{TypeSystem, SignatureFungsi, TipePrimitif};
+use crate::analysis::recovery::types::{TypeSystem, SignatureFungsi, TipePrimitif, KonvensiPanggilan};
+use crate::analysis::recovery::explorer::FunctionContext;
+use crate::disasm::cache::dengan_engine_lokal;
 use crate::disasm::engine::DisasmEngine;
 use crate::disasm::instruction::JenisOperandDisasm;
 use crate::loader::vmem::VirtualMemory;
@@ -51,6 +53,7 @@ impl StdLibManager {
         let sig = SignatureFungsi {
             return_type: self.parse_tipe_string(ret),
             arg_types: args.iter().map(|s| self.parse_tipe_string(s)).collect(),
+            konvensi: KonvensiPanggilan::Bawaan,
         };
         self.signature_by_name.insert(nama.to_string(), sig);
     }
@@ -62,6 +65,7 @@ impl StdLibManager {
             let sig = SignatureFungsi {
                 return_type: self.parse_tipe_string(&entry.return_type),
                 arg_types: entry.arg_types.iter().map(|s| self.parse_tipe_string(s)).collect(),
+                konvensi: KonvensiPanggilan::Bawaan,
             };
             self.signature_by_name.insert(entry.name.clone(), sig.clone());
             if let Some(hash) = entry.hash_signature {
@@ -82,14 +86,13 @@ impl StdLibManager {
     pub fn identifikasi_fungsi_statis(
         &self, 
         vmem: &mut VirtualMemory, 
-        detected_functions: &HashMap<u64, crate::analysis::recovery::explorer::FunctionContext>,
+        detected_functions: &HashMap<u64, FunctionContext>,
         type_sys: &mut TypeSystem,
         arch: &str
     ) {
-        let engine = DisasmEngine::buat_engine_baru(arch);
         for (addr, ctx) in detected_functions {
             if !vmem.simbol_global.contains_key(addr) {
-                let hash = self.hitung_hash_fungsi(vmem, *addr, ctx.instruction_count, &engine);
+                let hash = dengan_engine_lokal(arch, |engine| self.hitung_hash_fungsi(vmem, *addr, ctx.instruction_count, engine));
                 if let Some(hash_str) = hash {
                     if let Some((nama, sig)) = self.signature_by_hash.get(&hash_str) {
                         info!("MATCH: Fungsi statis di 0x{:x} diidentifikasi sebagai '{}'", addr, nama);
@@ -118,7 +121,7 @@ impl StdLibManager {
                         JenisOperandDisasm::Immediate(_) => {
                             "IMM".hash(&mut hasher); 
                         },
-                        JenisOperandDisasm::Memory { base, index, scale, disp: _ } => {
+                        JenisOperandDisasm::Memory { base, index, scale, .. } => {
                             "MEM".hash(&mut hasher);
                             base.hash(&mut hasher);
                             index.hash(&mut hasher);
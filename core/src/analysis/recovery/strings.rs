@@ -0,0 +1,215 @@
+use std::collections::{BTreeMap, HashMap};
+use log::info;
+use crate::loader::vmem::{VirtualMemory, IzinAkses};
+use crate::ir::types::{StatementIr, TipeOperand};
+
+const BATAS_PANJANG_STRING: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EncodingString {
+    Ascii,
+    Utf8,
+    Utf16Le,
+}
+
+#[derive(Debug, Clone)]
+pub struct EntriString {
+    pub alamat: u64,
+    pub isi: String,
+    pub encoding: EncodingString,
+    pub panjang_byte: usize,
+    pub referensi: Vec<u64>,
+}
+
+pub struct TabelString {
+    pub entri: BTreeMap<u64, EntriString>,
+    pub panjang_minimum: usize,
+}
+
+impl Default for TabelString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TabelString {
+    pub fn new() -> Self {
+        Self {
+            entri: BTreeMap::new(),
+            panjang_minimum: 4,
+        }
+    }
+    pub fn pindai_vmem(&mut self, vmem: &VirtualMemory) {
+        info!("Memindai string literal di segment read-only...");
+        for seg in &vmem.segments {
+            let is_read_only = seg.permissions == IzinAkses::Read || seg.nama_section.contains("cstring");
+            if is_read_only {
+                self.pindai_segment(seg.start_addr, &seg.data);
+            }
+        }
+        info!("Ditemukan {} string literal.", self.entri.len());
+    }
+    pub fn pindai_segment(&mut self, base_addr: u64, data: &[u8]) {
+        let mut offset = 0;
+        while offset < data.len() {
+            if let Some((isi, panjang)) = self.coba_utf16le(&data[offset..]) {
+                self.tambah_entri(base_addr + offset as u64, isi, EncodingString::Utf16Le, panjang);
+                offset += panjang;
+            } else if let Some((isi, panjang)) = self.coba_utf8(&data[offset..]) {
+                let encoding = if isi.is_ascii() { EncodingString::Ascii } else { EncodingString::Utf8 };
+                self.tambah_entri(base_addr + offset as u64, isi, encoding, panjang);
+                offset += panjang;
+            } else {
+                offset += 1;
+            }
+        }
+    }
+    fn tambah_entri(&mut self, alamat: u64, isi: String, encoding: EncodingString, panjang_byte: usize) {
+        self.entri.insert(alamat, EntriString {
+            alamat,
+            isi,
+            encoding,
+            panjang_byte,
+            referensi: Vec::new(),
+        });
+    }
+    fn coba_utf8(&self, data: &[u8]) -> Option<(String, usize)> {
+        let akhir = data.iter().take(BATAS_PANJANG_STRING).position(|&b| b == 0)?;
+        let isi = std::str::from_utf8(&data[..akhir]).ok()?;
+        let valid = isi.chars().all(|c| !c.is_control() || c == '\n' || c == '\r' || c == '\t');
+        if !valid || isi.chars().count() < self.panjang_minimum {
+            return None;
+        }
+        Some((isi.to_string(), akhir + 1))
+    }
+    fn coba_utf16le(&self, data: &[u8]) -> Option<(String, usize)> {
+        let mut units = Vec::new();
+        let mut idx = 0;
+        while units.len() < BATAS_PANJANG_STRING {
+            let pair = data.get(idx..idx + 2)?;
+            let unit = u16::from_le_bytes([pair[0], pair[1]]);
+            idx += 2;
+            if unit == 0 {
+                break;
+            }
+            units.push(unit);
+        }
+        if units.len() >= BATAS_PANJANG_STRING {
+            return None;
+        }
+        if units.len() < self.panjang_minimum {
+            return None;
+        }
+        let isi = String::from_utf16(&units).ok()?;
+        let valid = isi.chars().all(|c| (!c.is_control() || c == '\n' || c == '\r' || c == '\t') && !matches!(c, '\u{FFFD}' | '\u{FFFE}' | '\u{FFFF}'));
+        let mayoritas_ascii = units.iter().filter(|&&u| u < 0x80).count() * 2 >= units.len();
+        if !valid || !mayoritas_ascii {
+            return None;
+        }
+        Some((isi, idx))
+    }
+    pub fn cari_string(&self, alamat: u64) -> Option<&EntriString> {
+        self.entri.range(..=alamat).next_back()
+            .map(|(_, entri)| entri)
+            .filter(|entri| alamat < entri.alamat + entri.panjang_byte as u64)
+    }
+    pub fn isi_pada(&self, alamat: u64) -> Option<(String, EncodingString)> {
+        let entri = self.cari_string(alamat)?;
+        let offset = (alamat - entri.alamat) as usize;
+        let isi = match entri.encoding {
+            EncodingString::Utf16Le if offset.is_multiple_of(2) => {
+                let units: Vec<u16> = entri.isi.encode_utf16().skip(offset / 2).collect();
+                String::from_utf16(&units).ok()?
+            },
+            EncodingString::Utf16Le => return None,
+            _ => entri.isi.get(offset..)?.to_string(),
+        };
+        if isi.is_empty() {
+            return None;
+        }
+        Some((isi, entri.encoding))
+    }
+    fn entri_referensi(&mut self, alamat: u64) -> Option<&mut EntriString> {
+        if !self.entri.contains_key(&alamat) {
+            let (isi, encoding) = self.isi_pada(alamat)?;
+            if isi.chars().count() < self.panjang_minimum {
+                return None;
+            }
+            let panjang_byte = match encoding {
+                EncodingString::Utf16Le => (isi.encode_utf16().count() + 1) * 2,
+                _ => isi.len() + 1,
+            };
+            self.tambah_entri(alamat, isi, encoding, panjang_byte);
+        }
+        self.entri.get_mut(&alamat)
+    }
+    pub fn catat_referensi_dari_ir(&mut self, semua_fungsi: &HashMap<u64, Vec<StatementIr>>) {
+        for stmts in semua_fungsi.values() {
+            for stmt in stmts {
+                let mut operands = vec![&stmt.operand_satu, &stmt.operand_dua];
+                operands.extend(stmt.operand_tambahan.iter());
+                for op in operands {
+                    self.kumpulkan_referensi_operand(op, stmt.address_asal);
+                }
+            }
+        }
+        for entri in self.entri.values_mut() {
+            entri.referensi.sort();
+            entri.referensi.dedup();
+        }
+    }
+    fn kumpulkan_referensi_operand(&mut self, op: &TipeOperand, asal: u64) {
+        match op {
+            TipeOperand::Immediate(val) => {
+                if let Some(entri) = self.entri_referensi(*val as u64) {
+                    entri.referensi.push(asal);
+                }
+            },
+            TipeOperand::Memory(addr) => {
+                if let Some(entri) = self.entri_referensi(*addr) {
+                    entri.referensi.push(asal);
+                }
+            },
+            TipeOperand::Expression { operand_kiri, operand_kanan, .. } => {
+                self.kumpulkan_referensi_operand(operand_kiri, asal);
+                self.kumpulkan_referensi_operand(operand_kanan, asal);
+            },
+            _ => {}
+        }
+    }
+    pub fn format_literal_c(&self, alamat: u64) -> Option<String> {
+        let (isi, encoding) = self.isi_pada(alamat)?;
+        let prefix = if encoding == EncodingString::Utf16Le { "L" } else { "" };
+        Some(format!("{}\"{}\"", prefix, escape_literal_c(&isi)))
+    }
+}
+
+pub fn escape_literal_c(isi: &str) -> String {
+    let mut hasil = String::with_capacity(isi.len());
+    let mut sebelumnya_hex = false;
+    for c in isi.chars() {
+        let escaped = match c {
+            '\\' => Some("\\\\".to_string()),
+            '"' => Some("\\\"".to_string()),
+            '\n' => Some("\\n".to_string()),
+            '\r' => Some("\\r".to_string()),
+            '\t' => Some("\\t".to_string()),
+            c if (c as u32) < 0x20 || c as u32 == 0x7F => Some(format!("\\x{:02x}", c as u32)),
+            _ => None,
+        };
+        match escaped {
+            Some(e) => {
+                sebelumnya_hex = e.starts_with("\\x");
+                hasil.push_str(&e);
+            },
+            None => {
+                if sebelumnya_hex && c.is_ascii_hexdigit() {
+                    hasil.push_str("\"\"");
+                }
+                sebelumnya_hex = false;
+                hasil.push(c);
+            }
+        }
+    }
+    hasil
+}
@@ -6,6 +6,7 @@ pub use sys::{
     StructLayout, 
     ClassLayout,
     SignatureFungsi, 
+    KonvensiPanggilan,
     ConstraintTipe, 
     CallGraph, 
     TypeSystem
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet, BTreeMap, VecDeque};
-use crate::ir::types::{StatementIr, OperasiIr, TipeOperand};
-use super::sys::{TypeSystem, ConstraintTipe, TipePrimitif, StructLayout, SignatureFungsi};
+use crate::ir::types::{StatementIr, OperasiIr, TipeOperand, TipeDataIr};
+use super::sys::{TypeSystem, ConstraintTipe, TipePrimitif, StructLayout, SignatureFungsi, KonvensiPanggilan};
 use log::{info, warn, debug};
 
 pub struct TypeSolver<'a> {
@@ -24,6 +24,7 @@ impl<'a> TypeSolver<'a> {
             self.sys.global_signatures.entry(*addr).or_insert(SignatureFungsi {
                 return_type: TipePrimitif::Unknown,
                 arg_types: vec![TipePrimitif::Unknown; 8],
+                konvensi: KonvensiPanggilan::Bawaan,
             });
             self.worklist_queue.push_back(*addr);
             self.processed_functions.insert(*addr);
@@ -88,6 +89,14 @@ impl<'a> TypeSolver<'a> {
         for stmt in stmts {
             self.inferensi_pola_memori(&stmt.operand_satu);
             self.inferensi_pola_memori(&stmt.operand_dua);
+            let ukuran_float = match stmt.tipe_hasil {
+                TipeDataIr::F32 => Some(4),
+                TipeDataIr::F64 => Some(8),
+                _ => None,
+            };
+            if let (Some(ukuran), Some(dest)) = (ukuran_float, self.dapatkan_nama_variabel(&stmt.operand_satu)) {
+                self.sys.constraints.insert(ConstraintTipe::IsType(dest, TipePrimitif::Float(ukuran)));
+            }
             match &stmt.operation_code {
                 OperasiIr::Mov | OperasiIr::VecMov | OperasiIr::Lea => {
                     self.analisa_data_movement(&stmt.operand_satu, &stmt.operand_dua);
@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet, BTreeMap};
 use crate::ir::types::StatementIr;
+use crate::loader::demangle::{self, SimbolDemangled};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TipePrimitif {
@@ -30,12 +31,35 @@ pub struct ClassLayout {
     pub vtable_address: Option<u64>,
     pub fields: BTreeMap<i64, TipePrimitif>,
     pub virtual_methods: Vec<u64>,
+    pub methods: BTreeMap<u64, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KonvensiPanggilan {
+    Bawaan,
+    Cdecl,
+    Stdcall,
+    Fastcall,
+    Thiscall,
+}
+
+impl KonvensiPanggilan {
+    pub fn sebagai_atribut(&self) -> Option<&'static str> {
+        match self {
+            KonvensiPanggilan::Bawaan => None,
+            KonvensiPanggilan::Cdecl => Some("__cdecl"),
+            KonvensiPanggilan::Stdcall => Some("__stdcall"),
+            KonvensiPanggilan::Fastcall => Some("__fastcall"),
+            KonvensiPanggilan::Thiscall => Some("__thiscall"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SignatureFungsi {
     pub return_type: TipePrimitif,
     pub arg_types: Vec<TipePrimitif>,
+    pub konvensi: KonvensiPanggilan,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -89,6 +113,31 @@ impl TypeSystem {
             call_graph: CallGraph::new(),
         }
     }
+    pub fn kelompokkan_method_kelas(&mut self, info_simbol: &BTreeMap<u64, SimbolDemangled>) {
+        let kelas_pasti: HashSet<String> = info_simbol.values()
+            .filter(|info| info.is_method || info.is_vtable)
+            .filter_map(|info| info.nama_kelas())
+            .collect();
+        for (addr, info) in info_simbol {
+            if info.is_vtable {
+                continue;
+            }
+            let kelas = match info.nama_kelas() {
+                Some(k) if kelas_pasti.contains(&k) => k,
+                _ => continue,
+            };
+            let nama_layout = demangle::sanitasi_identifier(&kelas);
+            let layout = self.class_definitions.entry(nama_layout.clone()).or_insert_with(|| ClassLayout {
+                name: nama_layout,
+                parent_name: None,
+                vtable_address: None,
+                fields: BTreeMap::new(),
+                virtual_methods: Vec::new(),
+                methods: BTreeMap::new(),
+            });
+            layout.methods.insert(*addr, info.nama_lengkap.clone());
+        }
+    }
     pub fn analisis_interprosedural(&mut self, all_functions_stmts: &HashMap<u64, Vec<StatementIr>>) {
         use super::solve::TypeSolver;
         let mut solver = TypeSolver::new(self);
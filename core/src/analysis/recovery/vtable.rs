@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use log::{info, debug};
 use crate::loader::vmem::{VirtualMemory, IzinAkses};
+use crate::loader::demangle;
 use crate::ir::types::{StatementIr, OperasiIr, TipeOperand};
 use crate::analysis::recovery::types::{TypeSystem, ClassLayout, TipePrimitif};
 
@@ -10,6 +11,7 @@ pub struct VirtualTableInfo {
     pub panjang_entry: usize,
     pub list_fungsi: Vec<u64>,
     pub nama_simbol: Option<String>,
+    pub nama_kelas: Option<String>,
 }
 
 pub struct VtableAnalyzer {
@@ -57,16 +59,10 @@ impl VtableAnalyzer {
         let mut curr = start_addr;
         let step = self.pointer_size as u64;
         for _ in 0..50 { 
-            let ptr_val = if self.pointer_size == 8 {
-                match vmem.baca_array(curr, 8) {
-                    Some(b) => u64::from_le_bytes(b.try_into().unwrap()),
-                    None => break,
-                }
-            } else {
-                match vmem.baca_array(curr, 4) {
-                    Some(b) => u32::from_le_bytes(b.try_into().unwrap()) as u64,
-                    None => break,
-                }
+            let lebar = if self.pointer_size == 8 { 8 } else { 4 };
+            let ptr_val = match vmem.baca_pointer(curr, lebar) {
+                Some(v) => v,
+                None => break,
             };
             if self.cek_pointer_ke_executable(vmem, ptr_val) {
                 list_fungsi.push(ptr_val);
@@ -83,9 +79,17 @@ impl VtableAnalyzer {
                 panjang_entry: list_fungsi.len(),
                 list_fungsi,
                 nama_simbol: vmem.simbol_global.get(&start_addr).cloned(),
+                nama_kelas: self.cari_kelas_dari_simbol(vmem, start_addr),
             })
         }
     }
+    fn cari_kelas_dari_simbol(&self, vmem: &VirtualMemory, start_addr: u64) -> Option<String> {
+        let header_itanium = 2 * self.pointer_size as u64;
+        [start_addr, start_addr.wrapping_sub(header_itanium)].iter()
+            .filter_map(|addr| vmem.info_simbol.get(addr))
+            .find(|info| info.is_vtable)
+            .and_then(|info| info.nama_kelas())
+    }
     fn cek_pointer_ke_executable(&self, vmem: &VirtualMemory, target: u64) -> bool {
         if target == 0 { return false; }
         for seg in &vmem.segments {
@@ -105,23 +109,31 @@ impl VtableAnalyzer {
                         if self.detected_vtables.contains_key(&vtable_addr) {
                             if let Some(base_reg) = self.extract_base_assignment_dest(&stmt.operand_satu) {
                                 debug!("Konstruktor potensial ditemukan di 0x{:x}, assign vtable 0x{:x} ke {}", func_addr, vtable_addr, base_reg);
-                                let class_name = if let Some(sym) = &self.detected_vtables[&vtable_addr].nama_simbol {
+                                let info_vtable = &self.detected_vtables[&vtable_addr];
+                                let class_name = if let Some(kelas) = &info_vtable.nama_kelas {
+                                    demangle::sanitasi_identifier(kelas)
+                                } else if let Some(sym) = &info_vtable.nama_simbol {
                                     format!("Class_{}", sym)
                                 } else {
                                     class_counter += 1;
                                     format!("Class_{}_{:x}", class_counter, vtable_addr)
                                 };
-                                if !type_sys.class_definitions.contains_key(&class_name) {
-                                    let layout = ClassLayout {
+                                let sudah_ada_vtable = type_sys.class_definitions.get(&class_name)
+                                    .map(|c| c.vtable_address.is_some())
+                                    .unwrap_or(false);
+                                if !sudah_ada_vtable {
+                                    let layout = type_sys.class_definitions.entry(class_name.clone()).or_insert_with(|| ClassLayout {
                                         name: class_name.clone(),
                                         parent_name: None,
-                                        vtable_address: Some(vtable_addr),
+                                        vtable_address: None,
                                         fields: std::collections::BTreeMap::new(),
-                                        virtual_methods: self.detected_vtables[&vtable_addr].list_fungsi.clone(),
-                                    };
-                                    type_sys.class_definitions.insert(class_name.clone(), layout);
-                                    if let Some(sig) = type_sys.global_signatures.get_mut(func_addr) {
-                                        sig.arg_types[0] = TipePrimitif::Pointer(Box::new(TipePrimitif::Class(class_name.clone())));
+                                        virtual_methods: Vec::new(),
+                                        methods: std::collections::BTreeMap::new(),
+                                    });
+                                    layout.vtable_address = Some(vtable_addr);
+                                    layout.virtual_methods = self.detected_vtables[&vtable_addr].list_fungsi.clone();
+                                    if let Some(arg_this) = type_sys.global_signatures.get_mut(func_addr).and_then(|sig| sig.arg_types.first_mut()) {
+                                        *arg_this = TipePrimitif::Pointer(Box::new(TipePrimitif::Class(class_name.clone())));
                                     }
                                 }
                             }
@@ -0,0 +1,317 @@
+use std::collections::BTreeMap;
+use log::info;
+use crate::loader::vmem::{VirtualMemory, IzinAkses};
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm, AksesOperand};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JenisXref {
+    Call,
+    Jump,
+    TailCall,
+    Baca,
+    Tulis,
+    AmbilAlamat,
+    PointerData,
+}
+
+impl JenisXref {
+    pub fn kode(&self) -> i32 {
+        match self {
+            JenisXref::Call => 0,
+            JenisXref::Jump => 1,
+            JenisXref::TailCall => 2,
+            JenisXref::Baca => 3,
+            JenisXref::Tulis => 4,
+            JenisXref::AmbilAlamat => 5,
+            JenisXref::PointerData => 6,
+        }
+    }
+    pub fn dari_kode(kode: i32) -> Option<Self> {
+        match kode {
+            0 => Some(JenisXref::Call),
+            1 => Some(JenisXref::Jump),
+            2 => Some(JenisXref::TailCall),
+            3 => Some(JenisXref::Baca),
+            4 => Some(JenisXref::Tulis),
+            5 => Some(JenisXref::AmbilAlamat),
+            6 => Some(JenisXref::PointerData),
+            _ => None,
+        }
+    }
+    pub fn sebagai_str(&self) -> &'static str {
+        match self {
+            JenisXref::Call => "call",
+            JenisXref::Jump => "jump",
+            JenisXref::TailCall => "tail_call",
+            JenisXref::Baca => "read",
+            JenisXref::Tulis => "write",
+            JenisXref::AmbilAlamat => "address",
+            JenisXref::PointerData => "data_pointer",
+        }
+    }
+    pub fn is_code_flow(&self) -> bool {
+        matches!(self, JenisXref::Call | JenisXref::Jump | JenisXref::TailCall)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntriXref {
+    pub dari: u64,
+    pub ke: u64,
+    pub jenis: JenisXref,
+    pub ukuran_akses: u8,
+    pub fungsi_asal: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DatabaseXref {
+    referensi_keluar: BTreeMap<u64, Vec<EntriXref>>,
+    referensi_masuk: BTreeMap<u64, Vec<EntriXref>>,
+}
+
+impl DatabaseXref {
+    pub fn new() -> Self {
+        Self {
+            referensi_keluar: BTreeMap::new(),
+            referensi_masuk: BTreeMap::new(),
+        }
+    }
+    pub fn tambah_xref(&mut self, entri: EntriXref) {
+        let keluar = self.referensi_keluar.entry(entri.dari).or_default();
+        if keluar.iter().any(|e| e.ke == entri.ke && e.jenis == entri.jenis) {
+            return;
+        }
+        keluar.push(entri.clone());
+        self.referensi_masuk.entry(entri.ke).or_default().push(entri);
+    }
+    pub fn gabung(&mut self, daftar: Vec<EntriXref>) {
+        for entri in daftar {
+            self.tambah_xref(entri);
+        }
+    }
+    pub fn ambil_xref_ke(&self, alamat: u64) -> &[EntriXref] {
+        self.referensi_masuk.get(&alamat).map(|v| v.as_slice()).unwrap_or(&[])
+    }
+    pub fn ambil_xref_dari(&self, alamat: u64) -> &[EntriXref] {
+        self.referensi_keluar.get(&alamat).map(|v| v.as_slice()).unwrap_or(&[])
+    }
+    pub fn ambil_xref_dari_fungsi(&self, fungsi: u64) -> Vec<&EntriXref> {
+        self.referensi_keluar.values()
+            .flatten()
+            .filter(|e| e.fungsi_asal == Some(fungsi))
+            .collect()
+    }
+    pub fn ambil_caller(&self, fungsi: u64) -> Vec<u64> {
+        let mut callers: Vec<u64> = self.ambil_xref_ke(fungsi).iter()
+            .filter(|e| matches!(e.jenis, JenisXref::Call | JenisXref::TailCall))
+            .filter_map(|e| e.fungsi_asal)
+            .collect();
+        callers.sort();
+        callers.dedup();
+        callers
+    }
+    pub fn semua_xref(&self) -> impl Iterator<Item = &EntriXref> {
+        self.referensi_keluar.values().flatten()
+    }
+    pub fn jumlah_xref(&self) -> usize {
+        self.referensi_keluar.values().map(|v| v.len()).sum()
+    }
+    pub fn klasifikasi_tail_call(&mut self, entry_fungsi: &[u64]) {
+        let semua: Vec<EntriXref> = self.semua_xref().cloned().collect();
+        self.referensi_keluar.clear();
+        self.referensi_masuk.clear();
+        for mut entri in semua {
+            let is_fungsi_lain = entry_fungsi.binary_search(&entri.ke).is_ok() && entri.fungsi_asal != Some(entri.ke);
+            if entri.jenis == JenisXref::Jump && is_fungsi_lain {
+                entri.jenis = JenisXref::TailCall;
+            }
+            self.tambah_xref(entri);
+        }
+    }
+    pub fn pindai_pointer_data(&mut self, vmem: &VirtualMemory, ptr_size: u8) {
+        info!("Memindai pointer di segment data untuk xref data...");
+        let mut ditemukan = Vec::new();
+        for seg in &vmem.segments {
+            let is_data = matches!(seg.permissions, IzinAkses::Read | IzinAkses::Write | IzinAkses::ReadWrite);
+            if !is_data {
+                continue;
+            }
+            let lebar = ptr_size as usize;
+            let mut offset = (lebar - (seg.start_addr as usize % lebar)) % lebar;
+            while offset + lebar <= seg.data.len() {
+                let alamat = seg.start_addr + offset as u64;
+                if let Some(target) = vmem.baca_pointer(alamat, ptr_size)
+                    && target != 0
+                    && vmem.cek_alamat_valid(target)
+                {
+                    ditemukan.push(EntriXref {
+                        dari: alamat,
+                        ke: target,
+                        jenis: JenisXref::PointerData,
+                        ukuran_akses: ptr_size,
+                        fungsi_asal: None,
+                    });
+                }
+                offset += lebar;
+            }
+        }
+        info!("Ditemukan {} pointer data.", ditemukan.len());
+        self.gabung(ditemukan);
+    }
+    pub fn geser_alamat<F: Fn(u64) -> u64>(&mut self, geser: F) {
+        let semua: Vec<EntriXref> = self.semua_xref().cloned().collect();
+        self.referensi_keluar.clear();
+        self.referensi_masuk.clear();
+        for mut entri in semua {
+            entri.dari = geser(entri.dari);
+            entri.ke = geser(entri.ke);
+            entri.fungsi_asal = entri.fungsi_asal.map(&geser);
+            self.tambah_xref(entri);
+        }
+    }
+}
+
+pub fn kumpulkan_xref_instruksi(instr: &InstructionNormalized, fungsi_asal: u64, vmem: &VirtualMemory) -> Vec<EntriXref> {
+    let mut hasil = Vec::new();
+    let mnemonic = instr.mnemonic.to_lowercase();
+    let mnem = mnemonic.as_str();
+    let buat = |ke: u64, jenis: JenisXref, ukuran: u8| EntriXref {
+        dari: instr.address,
+        ke,
+        jenis,
+        ukuran_akses: ukuran,
+        fungsi_asal: Some(fungsi_asal),
+    };
+    let is_call = is_instruksi_call(instr, mnem);
+    let is_jump = is_instruksi_jump(instr, mnem);
+    if let Some(target) = instr.alamat_terlipat.filter(|&alamat| vmem.cek_alamat_valid(alamat)) {
+        let jenis = match (is_call, is_jump) {
+            (true, _) => JenisXref::Call,
+            (false, true) => JenisXref::Jump,
+            _ => JenisXref::AmbilAlamat,
+        };
+        hasil.push(buat(target, jenis, 0));
+    }
+    if is_call || is_jump {
+        let jenis = if is_call { JenisXref::Call } else { JenisXref::Jump };
+        for op in &instr.operands_detail {
+            match op {
+                JenisOperandDisasm::Immediate(val) if vmem.cek_alamat_valid(*val as u64) => {
+                    hasil.push(buat(*val as u64, jenis, 0));
+                },
+                JenisOperandDisasm::Memory { base: None, index: None, disp, segment: None, .. } => {
+                    hasil.push(buat(*disp as u64, JenisXref::Baca, hitung_ukuran_akses(instr)));
+                },
+                _ => {}
+            }
+        }
+        return hasil;
+    }
+    let ukuran = hitung_ukuran_akses(instr);
+    for (idx, op) in instr.operands_detail.iter().enumerate() {
+        match op {
+            JenisOperandDisasm::Memory { base: None, index: None, disp, segment: None, .. } => {
+                let target = *disp as u64;
+                if !vmem.cek_alamat_valid(target) {
+                    continue;
+                }
+                for jenis in tentukan_jenis_akses(instr, mnem, idx) {
+                    hasil.push(buat(target, jenis, ukuran));
+                }
+            },
+            JenisOperandDisasm::Immediate(val) => {
+                let target = *val as u64;
+                if target != 0 && vmem.cek_alamat_valid(target) {
+                    hasil.push(buat(target, JenisXref::AmbilAlamat, 0));
+                }
+            },
+            _ => {}
+        }
+    }
+    hasil
+}
+
+fn is_instruksi_call(instr: &InstructionNormalized, mnem: &str) -> bool {
+    match mnem {
+        "call" | "bl" | "blr" | "blx" | "bal" | "bgezal" | "bltzal" | "jalx" => true,
+        "jal" | "jalr" => !matches!(instr.operands_detail.first(), Some(JenisOperandDisasm::Register(rd)) if rd == "zero"),
+        _ => instr.punya_grup("call"),
+    }
+}
+
+fn is_instruksi_jump(instr: &InstructionNormalized, mnem: &str) -> bool {
+    if instr.punya_grup("jump") || instr.punya_grup("branch_relative") {
+        return true;
+    }
+    mnem.starts_with('j') || mnem == "b" || mnem == "br" || mnem.starts_with("b.")
+        || mnem.starts_with("cbz") || mnem.starts_with("cbnz") || mnem.starts_with("tbz") || mnem.starts_with("tbnz")
+}
+
+fn tentukan_jenis_akses(instr: &InstructionNormalized, mnem: &str, idx_operand: usize) -> Vec<JenisXref> {
+    if mnem == "lea" || mnem == "adr" || mnem == "adrp" {
+        return vec![JenisXref::AmbilAlamat];
+    }
+    match instr.ambil_akses_operand(idx_operand) {
+        AksesOperand::Baca => return vec![JenisXref::Baca],
+        AksesOperand::Tulis => return vec![JenisXref::Tulis],
+        AksesOperand::BacaTulis => return vec![JenisXref::Baca, JenisXref::Tulis],
+        AksesOperand::TidakDiketahui => {},
+    }
+    if mnem.starts_with("st") && !mnem.starts_with("stos") {
+        return vec![JenisXref::Tulis];
+    }
+    if mnem.starts_with("ld") || idx_operand > 0 {
+        return vec![JenisXref::Baca];
+    }
+    let hanya_baca = ["cmp", "test", "push", "bt", "ucomiss", "ucomisd", "comiss", "comisd"];
+    let hanya_tulis = mnem.starts_with("mov") || mnem.starts_with("set") || mnem == "pop" || mnem.starts_with("stos");
+    if hanya_baca.contains(&mnem) {
+        vec![JenisXref::Baca]
+    } else if hanya_tulis {
+        vec![JenisXref::Tulis]
+    } else {
+        vec![JenisXref::Baca, JenisXref::Tulis]
+    }
+}
+
+pub fn hitung_ukuran_akses(instr: &InstructionNormalized) -> u8 {
+    let ukuran_memori = instr.operands_detail.iter().zip(instr.atribut_operand.iter())
+        .find(|(op, atribut)| matches!(op, JenisOperandDisasm::Memory { .. }) && atribut.ukuran > 0)
+        .map(|(_, atribut)| atribut.ukuran);
+    if let Some(ukuran) = ukuran_memori {
+        return ukuran;
+    }
+    let op_str = instr.op_str.to_lowercase();
+    let tabel_ptr = [
+        ("zmmword ptr", 64), ("ymmword ptr", 32), ("xmmword ptr", 16), ("tbyte ptr", 10),
+        ("qword ptr", 8), ("dword ptr", 4), ("word ptr", 2), ("byte ptr", 1),
+    ];
+    for (kata, ukuran) in tabel_ptr {
+        if op_str.contains(kata) {
+            return ukuran;
+        }
+    }
+    let mnem = instr.mnemonic.to_lowercase();
+    if mnem.starts_with("ld") || mnem.starts_with("st") {
+        if mnem.ends_with('b') {
+            return 1;
+        }
+        if mnem.ends_with('h') {
+            return 2;
+        }
+        if mnem.ends_with("sw") {
+            return 4;
+        }
+        if let Some(JenisOperandDisasm::Register(reg)) = instr.operands_detail.first() {
+            return match reg.chars().next() {
+                Some('w') | Some('s') => 4,
+                Some('x') | Some('d') => 8,
+                Some('q') => 16,
+                Some('h') => 2,
+                Some('b') => 1,
+                _ => 0,
+            };
+        }
+    }
+    0
+}
@@ -0,0 +1,67 @@
+use super::{Architecture, KelasRegister, RegisterFile};
+use super::arm64::{FLAG_C, FLAG_N, FLAG_V, FLAG_Z};
+
+pub struct Arm32Arsitektur {
+    pub mode_thumb: bool,
+}
+
+impl Arm32Arsitektur {
+    pub fn new(mode_thumb: bool) -> Self {
+        Self { mode_thumb }
+    }
+}
+
+impl Architecture for Arm32Arsitektur {
+    fn dapatkan_ukuran_pointer(&self) -> u8 {
+        4
+    }
+    fn dapatkan_register_file(&self) -> RegisterFile {
+        let mut file = RegisterFile::new();
+        for i in 0..13 {
+            file.tambah_register(&format!("r{}", i), 32, KelasRegister::Umum);
+        }
+        file.tambah_sub_register("fp", 32, "r11", 0);
+        file.tambah_sub_register("ip", 32, "r12", 0);
+        file.tambah_register("sp", 32, KelasRegister::Khusus);
+        file.tambah_register("lr", 32, KelasRegister::Umum);
+        file.tambah_register("pc", 32, KelasRegister::Khusus);
+        file.tambah_keluarga("cpsr", 32, KelasRegister::Flag, &[
+            (FLAG_V, 1, 28), (FLAG_C, 1, 29), (FLAG_Z, 1, 30), (FLAG_N, 1, 31),
+        ]);
+        for i in 0..16 {
+            let akar = format!("q{}", i);
+            file.tambah_register(&akar, 128, KelasRegister::Vektor);
+            for bagian in 0..2 {
+                let d = format!("d{}", i * 2 + bagian);
+                file.tambah_sub_register(&d, 64, &akar, (bagian * 64) as u16);
+                if i < 8 {
+                    file.tambah_sub_register(&format!("s{}", (i * 2 + bagian) * 2), 32, &d, 0);
+                    file.tambah_sub_register(&format!("s{}", (i * 2 + bagian) * 2 + 1), 32, &d, 32);
+                }
+            }
+        }
+        file
+    }
+    fn dapatkan_callee_saved(&self) -> Vec<String> {
+        let mut hasil: Vec<String> = (4..12).map(|i| format!("r{}", i)).collect();
+        hasil.extend((8..16).map(|i| format!("d{}", i)));
+        hasil
+    }
+    fn dapatkan_stack_pointer(&self) -> String {
+        "sp".to_string()
+    }
+    fn dapatkan_frame_pointer(&self) -> String {
+        if self.mode_thumb { "r7".to_string() } else { "r11".to_string() }
+    }
+    fn dapatkan_instruction_pointer(&self) -> String {
+        "pc".to_string()
+    }
+    fn dapatkan_register_argumen(&self) -> Vec<String> {
+        vec![
+            "r0".to_string(), "r1".to_string(), "r2".to_string(), "r3".to_string()
+        ]
+    }
+    fn dapatkan_register_return(&self) -> String {
+        "r0".to_string()
+    }
+}
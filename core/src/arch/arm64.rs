@@ -1,8 +1,47 @@
-use super::Architecture;
+use super::{Architecture, KelasRegister, RegisterFile};
+
+pub const FLAG_N: &str = "nzcv_n";
+pub const FLAG_Z: &str = "nzcv_z";
+pub const FLAG_C: &str = "nzcv_c";
+pub const FLAG_V: &str = "nzcv_v";
 
 pub struct Arm64Arsitektur;
 
 impl Architecture for Arm64Arsitektur {
+    fn dapatkan_ukuran_pointer(&self) -> u8 {
+        8
+    }
+    fn dapatkan_register_file(&self) -> RegisterFile {
+        let mut file = RegisterFile::new();
+        for i in 0..31 {
+            let akar = format!("x{}", i);
+            file.tambah_register(&akar, 64, KelasRegister::Umum);
+            file.tambah_sub_register(&format!("w{}", i), 32, &akar, 0);
+        }
+        file.tambah_sub_register("fp", 64, "x29", 0);
+        file.tambah_sub_register("lr", 64, "x30", 0);
+        file.tambah_keluarga("sp", 64, KelasRegister::Khusus, &[("wsp", 32, 0)]);
+        file.tambah_keluarga("xzr", 64, KelasRegister::Khusus, &[("wzr", 32, 0)]);
+        file.tambah_register("pc", 64, KelasRegister::Khusus);
+        file.tambah_keluarga("nzcv", 32, KelasRegister::Flag, &[
+            (FLAG_V, 1, 28), (FLAG_C, 1, 29), (FLAG_Z, 1, 30), (FLAG_N, 1, 31),
+        ]);
+        for i in 0..32 {
+            let akar = format!("v{}", i);
+            file.tambah_register(&akar, 128, KelasRegister::Vektor);
+            file.tambah_sub_register(&format!("q{}", i), 128, &akar, 0);
+            file.tambah_sub_register(&format!("d{}", i), 64, &akar, 0);
+            file.tambah_sub_register(&format!("s{}", i), 32, &akar, 0);
+            file.tambah_sub_register(&format!("h{}", i), 16, &akar, 0);
+            file.tambah_sub_register(&format!("b{}", i), 8, &akar, 0);
+        }
+        file
+    }
+    fn dapatkan_callee_saved(&self) -> Vec<String> {
+        let mut hasil: Vec<String> = (19..30).map(|i| format!("x{}", i)).collect();
+        hasil.extend((8..16).map(|i| format!("d{}", i)));
+        hasil
+    }
     fn dapatkan_stack_pointer(&self) -> String {
         "sp".to_string()
     }
@@ -0,0 +1,58 @@
+use super::{Architecture, KelasRegister, RegisterFile};
+
+pub struct MipsArsitektur;
+
+impl MipsArsitektur {
+    pub fn dapatkan_global_pointer(&self) -> String {
+        "gp".to_string()
+    }
+}
+
+impl Architecture for MipsArsitektur {
+    fn dapatkan_ukuran_pointer(&self) -> u8 {
+        4
+    }
+    fn dapatkan_register_file(&self) -> RegisterFile {
+        let mut file = RegisterFile::new();
+        for nama in ["zero", "k0", "k1", "gp", "pc"] {
+            file.tambah_register(nama, 32, KelasRegister::Khusus);
+        }
+        for nama in ["at", "v0", "v1", "sp", "fp", "ra", "hi", "lo"] {
+            file.tambah_register(nama, 32, KelasRegister::Umum);
+        }
+        file.tambah_sub_register("s8", 32, "fp", 0);
+        for (awalan, jumlah) in [("a", 4), ("t", 10), ("s", 8)] {
+            for i in 0..jumlah {
+                file.tambah_register(&format!("{}{}", awalan, i), 32, KelasRegister::Umum);
+            }
+        }
+        for i in 0..32 {
+            file.tambah_register(&format!("f{}", i), 32, KelasRegister::Float);
+        }
+        file.tambah_keluarga("fcsr", 32, KelasRegister::Flag, &[("fcc0", 1, 23)]);
+        file
+    }
+    fn dapatkan_callee_saved(&self) -> Vec<String> {
+        let mut hasil: Vec<String> = ["sp", "fp"].iter().map(|r| r.to_string()).collect();
+        hasil.extend((0..8).map(|i| format!("s{}", i)));
+        hasil.extend((20..32).map(|i| format!("f{}", i)));
+        hasil
+    }
+    fn dapatkan_stack_pointer(&self) -> String {
+        "sp".to_string()
+    }
+    fn dapatkan_frame_pointer(&self) -> String {
+        "fp".to_string()
+    }
+    fn dapatkan_instruction_pointer(&self) -> String {
+        "pc".to_string()
+    }
+    fn dapatkan_register_argumen(&self) -> Vec<String> {
+        vec![
+            "a0".to_string(), "a1".to_string(), "a2".to_string(), "a3".to_string()
+        ]
+    }
+    fn dapatkan_register_return(&self) -> String {
+        "v0".to_string()
+    }
+}
@@ -1,5 +1,144 @@
+use std::collections::HashMap;
+
 pub mod x86;
 pub mod arm64;
+pub mod arm;
+pub mod riscv;
+pub mod mips;
+
+pub fn is_arm32(nama_arch: &str) -> bool {
+    matches!(nama_arch, "arm" | "armv7" | "thumb")
+}
+
+pub fn is_x86_32(nama_arch: &str) -> bool {
+    matches!(nama_arch, "x86" | "i386")
+}
+
+pub fn is_x86(nama_arch: &str) -> bool {
+    nama_arch == "x86_64" || is_x86_32(nama_arch)
+}
+
+pub fn is_arm64(nama_arch: &str) -> bool {
+    nama_arch != "x86_64" && (nama_arch.contains("arm") || nama_arch == "aarch64") && !is_arm32(nama_arch)
+}
+
+pub fn is_riscv(nama_arch: &str) -> bool {
+    nama_arch.starts_with("riscv")
+}
+
+pub fn is_mips(nama_arch: &str) -> bool {
+    matches!(nama_arch, "mips" | "mipsel")
+}
+
+pub fn is_big_endian(nama_arch: &str) -> bool {
+    nama_arch == "mips"
+}
+
+pub fn buat_arsitektur(nama_arch: &str) -> Box<dyn Architecture> {
+    if is_x86_32(nama_arch) {
+        Box::new(x86::X86Arsitektur32)
+    } else if is_arm32(nama_arch) {
+        Box::new(arm::Arm32Arsitektur::new(nama_arch == "thumb"))
+    } else if is_riscv(nama_arch) {
+        Box::new(riscv::RiscVArsitektur::new(nama_arch != "riscv32"))
+    } else if is_mips(nama_arch) {
+        Box::new(mips::MipsArsitektur)
+    } else if is_arm64(nama_arch) {
+        Box::new(arm64::Arm64Arsitektur)
+    } else {
+        Box::new(x86::X86Arsitektur64)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KelasRegister {
+    Umum,
+    Float,
+    Vektor,
+    Flag,
+    Khusus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InfoRegister {
+    pub nama: String,
+    pub lebar_bit: u16,
+    pub induk: Option<String>,
+    pub offset_bit: u16,
+    pub kelas: KelasRegister,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RegisterFile {
+    pub daftar_register: Vec<InfoRegister>,
+    indeks_nama: HashMap<String, usize>,
+}
+
+impl RegisterFile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn tambah_register(&mut self, nama: &str, lebar_bit: u16, kelas: KelasRegister) {
+        self.sisipkan(InfoRegister { nama: nama.to_string(), lebar_bit, induk: None, offset_bit: 0, kelas });
+    }
+    pub fn tambah_sub_register(&mut self, nama: &str, lebar_bit: u16, induk: &str, offset_bit: u16) {
+        let kelas = self.cari_register(induk).map(|r| r.kelas).unwrap_or(KelasRegister::Umum);
+        self.sisipkan(InfoRegister { nama: nama.to_string(), lebar_bit, induk: Some(induk.to_string()), offset_bit, kelas });
+    }
+    pub fn tambah_keluarga(&mut self, induk: &str, lebar_bit: u16, kelas: KelasRegister, anak: &[(&str, u16, u16)]) {
+        self.tambah_register(induk, lebar_bit, kelas);
+        for (nama, lebar, offset) in anak {
+            self.tambah_sub_register(nama, *lebar, induk, *offset);
+        }
+    }
+    fn sisipkan(&mut self, info: InfoRegister) {
+        self.indeks_nama.insert(info.nama.clone(), self.daftar_register.len());
+        self.daftar_register.push(info);
+    }
+    pub fn cari_register(&self, nama: &str) -> Option<&InfoRegister> {
+        self.indeks_nama.get(nama).map(|&idx| &self.daftar_register[idx])
+    }
+    pub fn dapatkan_register_akar(&self, nama: &str) -> String {
+        self.daftar_leluhur(nama).pop().unwrap_or_else(|| nama.to_string())
+    }
+    pub fn daftar_leluhur(&self, nama: &str) -> Vec<String> {
+        let mut hasil = vec![nama.to_string()];
+        let mut sekarang = nama;
+        while let Some(induk) = self.cari_register(sekarang).and_then(|r| r.induk.as_deref()) {
+            if hasil.iter().any(|n| n == induk) { break; }
+            hasil.push(induk.to_string());
+            sekarang = induk;
+        }
+        hasil
+    }
+    pub fn hitung_offset_absolut(&self, nama: &str) -> Option<u16> {
+        let mut info = self.cari_register(nama)?;
+        let mut offset = info.offset_bit;
+        while let Some(induk) = info.induk.as_deref() {
+            info = self.cari_register(induk)?;
+            offset += info.offset_bit;
+        }
+        Some(offset)
+    }
+    pub fn buat_peta_alias(&self, kelas: KelasRegister) -> HashMap<String, String> {
+        self.daftar_register.iter()
+            .filter(|r| r.induk.is_some() && r.kelas == kelas)
+            .map(|r| (r.nama.clone(), self.dapatkan_register_akar(&r.nama)))
+            .collect()
+    }
+    pub fn daftar_akar_kelas(&self, kelas: KelasRegister) -> Vec<String> {
+        self.daftar_register.iter()
+            .filter(|r| r.induk.is_none() && r.kelas == kelas)
+            .map(|r| r.nama.clone())
+            .collect()
+    }
+    pub fn daftar_flag(&self) -> Vec<String> {
+        self.daftar_register.iter()
+            .filter(|r| r.kelas == KelasRegister::Flag && r.induk.is_some())
+            .map(|r| r.nama.clone())
+            .collect()
+    }
+}
 
 pub trait Architecture {
     fn dapatkan_stack_pointer(&self) -> String;
@@ -7,4 +146,26 @@ pub trait Architecture {
     fn dapatkan_instruction_pointer(&self) -> String;
     fn dapatkan_register_argumen(&self) -> Vec<String>;
     fn dapatkan_register_return(&self) -> String;
-}
\ No newline at end of file
+    fn dapatkan_ukuran_pointer(&self) -> u8;
+    fn dapatkan_register_file(&self) -> RegisterFile;
+    fn dapatkan_callee_saved(&self) -> Vec<String>;
+    fn dapatkan_register_flag(&self) -> Vec<String> {
+        self.dapatkan_register_file().daftar_flag()
+    }
+    fn dapatkan_peta_alias(&self) -> HashMap<String, String> {
+        self.dapatkan_register_file().buat_peta_alias(KelasRegister::Umum)
+    }
+    fn dapatkan_register_volatile(&self) -> Vec<String> {
+        let file = self.dapatkan_register_file();
+        let callee_saved = self.dapatkan_callee_saved();
+        let dipertahankan = [self.dapatkan_stack_pointer(), self.dapatkan_instruction_pointer()];
+        file.daftar_register.iter()
+            .filter(|r| matches!(r.kelas, KelasRegister::Umum | KelasRegister::Float | KelasRegister::Vektor))
+            .filter(|r| {
+                let leluhur = file.daftar_leluhur(&r.nama);
+                !leluhur.iter().any(|n| callee_saved.contains(n) || dipertahankan.contains(n))
+            })
+            .map(|r| r.nama.clone())
+            .collect()
+    }
+}
@@ -0,0 +1,62 @@
+use super::{Architecture, KelasRegister, RegisterFile};
+
+pub struct RiscVArsitektur {
+    pub is_64: bool,
+}
+
+impl RiscVArsitektur {
+    pub fn new(is_64: bool) -> Self {
+        Self { is_64 }
+    }
+}
+
+impl Architecture for RiscVArsitektur {
+    fn dapatkan_ukuran_pointer(&self) -> u8 {
+        if self.is_64 { 8 } else { 4 }
+    }
+    fn dapatkan_register_file(&self) -> RegisterFile {
+        let lebar = self.dapatkan_ukuran_pointer() as u16 * 8;
+        let mut file = RegisterFile::new();
+        for nama in ["zero", "gp", "tp", "pc"] {
+            file.tambah_register(nama, lebar, KelasRegister::Khusus);
+        }
+        file.tambah_register("ra", lebar, KelasRegister::Umum);
+        file.tambah_register("sp", lebar, KelasRegister::Umum);
+        for (awalan, jumlah) in [("t", 7), ("s", 12), ("a", 8)] {
+            for i in 0..jumlah {
+                file.tambah_register(&format!("{}{}", awalan, i), lebar, KelasRegister::Umum);
+            }
+        }
+        file.tambah_sub_register("fp", lebar, "s0", 0);
+        for (awalan, jumlah) in [("ft", 12), ("fs", 12), ("fa", 8)] {
+            for i in 0..jumlah {
+                file.tambah_register(&format!("{}{}", awalan, i), 64, KelasRegister::Float);
+            }
+        }
+        file
+    }
+    fn dapatkan_callee_saved(&self) -> Vec<String> {
+        let mut hasil = vec!["sp".to_string()];
+        hasil.extend((0..12).map(|i| format!("s{}", i)));
+        hasil.extend((0..12).map(|i| format!("fs{}", i)));
+        hasil
+    }
+    fn dapatkan_stack_pointer(&self) -> String {
+        "sp".to_string()
+    }
+    fn dapatkan_frame_pointer(&self) -> String {
+        "s0".to_string()
+    }
+    fn dapatkan_instruction_pointer(&self) -> String {
+        "pc".to_string()
+    }
+    fn dapatkan_register_argumen(&self) -> Vec<String> {
+        vec![
+            "a0".to_string(), "a1".to_string(), "a2".to_string(), "a3".to_string(),
+            "a4".to_string(), "a5".to_string(), "a6".to_string(), "a7".to_string()
+        ]
+    }
+    fn dapatkan_register_return(&self) -> String {
+        "a0".to_string()
+    }
+}
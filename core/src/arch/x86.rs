@@ -1,4 +1,4 @@
-use super::Architecture;
+use super::{Architecture, KelasRegister, RegisterFile};
 
 pub const EFLAGS_CF_MASK: u64 = 0x0001;
 pub const EFLAGS_PF_MASK: u64 = 0x0004;
@@ -10,6 +10,58 @@ pub const EFLAGS_IF_MASK: u64 = 0x0200;
 pub const EFLAGS_DF_MASK: u64 = 0x0400;
 pub const EFLAGS_OF_MASK: u64 = 0x0800;
 
+pub const FLAG_CF: &str = "eflags_cf";
+pub const FLAG_PF: &str = "eflags_pf";
+pub const FLAG_AF: &str = "eflags_af";
+pub const FLAG_ZF: &str = "eflags_zf";
+pub const FLAG_SF: &str = "eflags_sf";
+pub const FLAG_DF: &str = "eflags_df";
+pub const FLAG_OF: &str = "eflags_of";
+
+pub const FPU_C0: &str = "fpsw_c0";
+pub const FPU_C2: &str = "fpsw_c2";
+pub const FPU_C3: &str = "fpsw_c3";
+
+fn tambah_register_flag(file: &mut RegisterFile) {
+    file.tambah_register("eflags", 32, KelasRegister::Flag);
+    for (nama, mask) in [
+        (FLAG_CF, EFLAGS_CF_MASK), (FLAG_PF, EFLAGS_PF_MASK), (FLAG_AF, EFLAGS_AF_MASK),
+        (FLAG_ZF, EFLAGS_ZF_MASK), (FLAG_SF, EFLAGS_SF_MASK), (FLAG_DF, EFLAGS_DF_MASK),
+        (FLAG_OF, EFLAGS_OF_MASK),
+    ] {
+        file.tambah_sub_register(nama, 1, "eflags", mask.trailing_zeros() as u16);
+    }
+}
+
+fn tambah_register_fpu(file: &mut RegisterFile) {
+    for i in 0..8 {
+        file.tambah_register(&format!("fpr{}", i), 80, KelasRegister::Float);
+    }
+    file.tambah_register("fpsw", 16, KelasRegister::Flag);
+    for (nama, bit) in [(FPU_C0, 8), (FPU_C2, 10), (FPU_C3, 14)] {
+        file.tambah_sub_register(nama, 1, "fpsw", bit);
+    }
+    file.tambah_register("fpcw", 16, KelasRegister::Khusus);
+}
+
+fn tambah_keluarga_legacy(file: &mut RegisterFile, lebar_akar: u16, basis: &str, byte_rendah: Option<&str>, byte_tinggi: Option<&str>) {
+    let nama_32 = format!("e{}", basis);
+    if lebar_akar == 64 {
+        let akar = format!("r{}", basis);
+        file.tambah_register(&akar, 64, KelasRegister::Umum);
+        file.tambah_sub_register(&nama_32, 32, &akar, 0);
+    } else {
+        file.tambah_register(&nama_32, 32, KelasRegister::Umum);
+    }
+    file.tambah_sub_register(basis, 16, &nama_32, 0);
+    if let Some(rendah) = byte_rendah {
+        file.tambah_sub_register(rendah, 8, basis, 0);
+    }
+    if let Some(tinggi) = byte_tinggi {
+        file.tambah_sub_register(tinggi, 8, basis, 8);
+    }
+}
+
 pub struct X86Arsitektur64;
 
 impl X86Arsitektur64 {
@@ -19,6 +71,37 @@ impl X86Arsitektur64 {
 }
 
 impl Architecture for X86Arsitektur64 {
+    fn dapatkan_ukuran_pointer(&self) -> u8 {
+        8
+    }
+    fn dapatkan_register_file(&self) -> RegisterFile {
+        let mut file = RegisterFile::new();
+        for (basis, rendah, tinggi) in [
+            ("ax", "al", Some("ah")), ("bx", "bl", Some("bh")), ("cx", "cl", Some("ch")), ("dx", "dl", Some("dh")),
+            ("si", "sil", None), ("di", "dil", None), ("bp", "bpl", None), ("sp", "spl", None),
+        ] {
+            tambah_keluarga_legacy(&mut file, 64, basis, Some(rendah), tinggi);
+        }
+        for i in 8..16 {
+            let akar = format!("r{}", i);
+            file.tambah_register(&akar, 64, KelasRegister::Umum);
+            file.tambah_sub_register(&format!("{}d", akar), 32, &akar, 0);
+            file.tambah_sub_register(&format!("{}w", akar), 16, &format!("{}d", akar), 0);
+            file.tambah_sub_register(&format!("{}b", akar), 8, &format!("{}w", akar), 0);
+        }
+        file.tambah_register("rip", 64, KelasRegister::Khusus);
+        tambah_register_flag(&mut file);
+        tambah_register_fpu(&mut file);
+        for i in 0..16 {
+            let ymm = format!("ymm{}", i);
+            file.tambah_register(&ymm, 256, KelasRegister::Vektor);
+            file.tambah_sub_register(&format!("xmm{}", i), 128, &ymm, 0);
+        }
+        file
+    }
+    fn dapatkan_callee_saved(&self) -> Vec<String> {
+        ["rbx", "rbp", "r12", "r13", "r14", "r15"].iter().map(|r| r.to_string()).collect()
+    }
     fn dapatkan_stack_pointer(&self) -> String {
         "rsp".to_string()
     }
@@ -37,4 +120,47 @@ impl Architecture for X86Arsitektur64 {
     fn dapatkan_register_return(&self) -> String {
         "rax".to_string()
     }
-}
\ No newline at end of file
+}
+
+pub struct X86Arsitektur32;
+
+impl Architecture for X86Arsitektur32 {
+    fn dapatkan_ukuran_pointer(&self) -> u8 {
+        4
+    }
+    fn dapatkan_register_file(&self) -> RegisterFile {
+        let mut file = RegisterFile::new();
+        for (basis, rendah, tinggi) in [
+            ("ax", Some("al"), Some("ah")), ("bx", Some("bl"), Some("bh")),
+            ("cx", Some("cl"), Some("ch")), ("dx", Some("dl"), Some("dh")),
+            ("si", None, None), ("di", None, None), ("bp", None, None), ("sp", None, None),
+        ] {
+            tambah_keluarga_legacy(&mut file, 32, basis, rendah, tinggi);
+        }
+        file.tambah_register("eip", 32, KelasRegister::Khusus);
+        tambah_register_flag(&mut file);
+        tambah_register_fpu(&mut file);
+        for i in 0..8 {
+            file.tambah_register(&format!("xmm{}", i), 128, KelasRegister::Vektor);
+        }
+        file
+    }
+    fn dapatkan_callee_saved(&self) -> Vec<String> {
+        ["ebx", "esi", "edi", "ebp"].iter().map(|r| r.to_string()).collect()
+    }
+    fn dapatkan_stack_pointer(&self) -> String {
+        "esp".to_string()
+    }
+    fn dapatkan_frame_pointer(&self) -> String {
+        "ebp".to_string()
+    }
+    fn dapatkan_instruction_pointer(&self) -> String {
+        "eip".to_string()
+    }
+    fn dapatkan_register_argumen(&self) -> Vec<String> {
+        vec!["ecx".to_string(), "edx".to_string()]
+    }
+    fn dapatkan_register_return(&self) -> String {
+        "eax".to_string()
+    }
+}
@@ -0,0 +1,414 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use crate::analysis::recovery::explorer::FunctionContext;
+use crate::analysis::recovery::strings::TabelString;
+use crate::analysis::recovery::xref::{DatabaseXref, JenisXref};
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
+use crate::loader::vmem::{VirtualMemory, SegmentMemori, IzinAkses};
+use capstone::prelude::*;
+
+const MAKS_BYTE_DITAMPILKAN: usize = 8;
+const MAKS_XREF_DITAMPILKAN: usize = 4;
+const LEBAR_KOLOM_BYTE: usize = MAKS_BYTE_DITAMPILKAN * 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SintaksAsm {
+    #[default]
+    Intel,
+    Att,
+}
+
+impl SintaksAsm {
+    pub fn kode(&self) -> i32 {
+        match self {
+            SintaksAsm::Intel => 0,
+            SintaksAsm::Att => 1,
+        }
+    }
+    pub fn dari_kode(kode: i32) -> Option<Self> {
+        match kode {
+            0 => Some(SintaksAsm::Intel),
+            1 => Some(SintaksAsm::Att),
+            _ => None,
+        }
+    }
+    pub fn dari_nama(nama: &str) -> Option<Self> {
+        match nama.to_lowercase().as_str() {
+            "intel" => Some(SintaksAsm::Intel),
+            "att" | "at&t" | "gas" => Some(SintaksAsm::Att),
+            _ => None,
+        }
+    }
+    pub fn sebagai_str(&self) -> &'static str {
+        match self {
+            SintaksAsm::Intel => "intel",
+            SintaksAsm::Att => "att",
+        }
+    }
+}
+
+pub struct AsmListingEmitter {
+    sintaks: SintaksAsm,
+    literal_string: BTreeMap<u64, String>,
+    nama_alamat: HashMap<u64, String>,
+    asal_jump_table: HashMap<u64, Vec<u64>>,
+    cs: Option<Capstone>,
+    cs_thumb: Option<Capstone>,
+}
+
+impl AsmListingEmitter {
+    pub fn new(sintaks: SintaksAsm) -> Self {
+        Self {
+            sintaks,
+            literal_string: BTreeMap::new(),
+            nama_alamat: HashMap::new(),
+            asal_jump_table: HashMap::new(),
+            cs: None,
+            cs_thumb: None,
+        }
+    }
+    pub fn atur_tabel_string(&mut self, tabel: &TabelString) {
+        self.literal_string = tabel.entri.keys()
+            .filter_map(|&addr| tabel.format_literal_c(addr).map(|lit| (addr, lit)))
+            .collect();
+    }
+    pub fn hasilkan_listing(
+        &mut self,
+        vmem: &VirtualMemory,
+        fungsi: &HashMap<u64, FunctionContext>,
+        jump_targets: &HashMap<u64, Vec<u64>>,
+        xref_db: &DatabaseXref,
+    ) -> String {
+        self.kumpulkan_nama(vmem, fungsi, jump_targets, xref_db);
+        let is_x86 = crate::arch::is_x86(&vmem.arsitektur);
+        let sintaks_efektif = if is_x86 { self.sintaks } else { SintaksAsm::Intel };
+        self.cs = buat_capstone_listing(&vmem.arsitektur, sintaks_efektif, false);
+        self.cs_thumb = buat_capstone_listing(&vmem.arsitektur, sintaks_efektif, true);
+        let mut hasil = format!("; Listing RCDecomp | arsitektur {} | format {} | sintaks {}\n", vmem.arsitektur, vmem.format_biner, sintaks_efektif.sebagai_str());
+        hasil.push_str(&format!("; entry point: 0x{:x}\n", vmem.entry_point));
+        let mut fungsi_sorted: Vec<&FunctionContext> = fungsi.values().collect();
+        fungsi_sorted.sort_by_key(|ctx| ctx.entry_point);
+        let mut segments: Vec<&SegmentMemori> = vmem.segments.iter().collect();
+        segments.sort_by_key(|seg| seg.start_addr);
+        for seg in segments {
+            hasil.push_str(&Self::format_header_section(seg));
+            if Self::is_executable(seg.permissions) {
+                self.tulis_section_kode(&mut hasil, vmem, seg, &fungsi_sorted, xref_db);
+            } else {
+                self.tulis_section_data(&mut hasil, vmem, seg, xref_db);
+            }
+        }
+        hasil
+    }
+    fn kumpulkan_nama(
+        &mut self,
+        vmem: &VirtualMemory,
+        fungsi: &HashMap<u64, FunctionContext>,
+        jump_targets: &HashMap<u64, Vec<u64>>,
+        xref_db: &DatabaseXref,
+    ) {
+        self.nama_alamat.clear();
+        self.asal_jump_table.clear();
+        for (asal, targets) in jump_targets {
+            for &t in targets {
+                self.asal_jump_table.entry(t).or_default().push(*asal);
+            }
+        }
+        for asal in self.asal_jump_table.values_mut() {
+            asal.sort();
+            asal.dedup();
+        }
+        for ctx in fungsi.values() {
+            let alamat_fungsi: BTreeSet<u64> = ctx.instruksi.iter().map(|i| i.address).collect();
+            for instr in &ctx.instruksi {
+                for entri in xref_db.ambil_xref_dari(instr.address) {
+                    let is_lokal = matches!(entri.jenis, JenisXref::Jump | JenisXref::TailCall);
+                    if is_lokal && alamat_fungsi.contains(&entri.ke) && entri.ke != ctx.entry_point {
+                        self.nama_alamat.insert(entri.ke, format!("loc_{:x}", entri.ke));
+                    }
+                }
+                if let Some(targets) = jump_targets.get(&instr.address) {
+                    for &t in targets {
+                        self.nama_alamat.insert(t, format!("loc_{:x}", t));
+                    }
+                }
+            }
+        }
+        for &addr in self.literal_string.keys() {
+            self.nama_alamat.insert(addr, format!("str_{:x}", addr));
+        }
+        for entri in xref_db.semua_xref() {
+            if !entri.jenis.is_code_flow() && vmem.cek_alamat_valid(entri.ke) && !vmem.cek_alamat_executable(entri.ke) {
+                self.nama_alamat.entry(entri.ke).or_insert_with(|| format!("data_{:x}", entri.ke));
+            }
+        }
+        for (addr, nama) in &vmem.simbol_global {
+            self.nama_alamat.insert(*addr, nama.clone());
+        }
+        for addr in fungsi.keys() {
+            let nama = if let Some(sym) = vmem.simbol_global.get(addr) {
+                sym.clone()
+            } else if *addr == vmem.entry_point {
+                "entry_point".to_string()
+            } else {
+                format!("sub_{:x}", addr)
+            };
+            self.nama_alamat.insert(*addr, nama);
+        }
+    }
+    fn is_executable(izin: IzinAkses) -> bool {
+        (izin as u32) & (IzinAkses::Execute as u32) != 0
+    }
+    fn format_izin(izin: IzinAkses) -> String {
+        let bit = izin as u32;
+        let huruf = [(IzinAkses::Read, 'r'), (IzinAkses::Write, 'w'), (IzinAkses::Execute, 'x')];
+        huruf.iter()
+            .map(|(flag, c)| if bit & (*flag as u32) != 0 { *c } else { '-' })
+            .collect()
+    }
+    fn format_header_section(seg: &SegmentMemori) -> String {
+        let garis = format!("; {}\n", "-".repeat(60));
+        let nama = if seg.nama_section.is_empty() { "<tanpa nama>" } else { seg.nama_section.as_str() };
+        format!("\n{}; section {}  0x{:x}-0x{:x}  {}  ({} byte)\n{}", garis, nama, seg.start_addr, seg.end_addr, Self::format_izin(seg.permissions), seg.data.len(), garis)
+    }
+    fn tulis_section_kode(&self, hasil: &mut String, vmem: &VirtualMemory, seg: &SegmentMemori, fungsi_sorted: &[&FunctionContext], xref_db: &DatabaseXref) {
+        let mut tercakup: BTreeMap<u64, u64> = BTreeMap::new();
+        for ctx in fungsi_sorted.iter().filter(|ctx| ctx.entry_point >= seg.start_addr && ctx.entry_point < seg.end_addr) {
+            let mode_thumb = vmem.is_thumb(ctx.entry_point) || ctx.instruksi.iter().any(|i| i.bytes.len() == 2);
+            self.tulis_fungsi(hasil, ctx, mode_thumb, xref_db);
+            for instr in &ctx.instruksi {
+                tercakup.insert(instr.address, instr.address + instr.hitung_panjang_byte() as u64);
+            }
+        }
+        let mut posisi = seg.start_addr;
+        let mut celah = Vec::new();
+        for (&awal, &akhir) in &tercakup {
+            if awal > posisi && awal <= seg.end_addr {
+                celah.push((posisi, awal));
+            }
+            posisi = posisi.max(akhir);
+        }
+        if posisi < seg.end_addr {
+            celah.push((posisi, seg.end_addr));
+        }
+        for (awal, akhir) in celah {
+            hasil.push_str(&format!("; 0x{:x}-0x{:x}: {} byte tidak terjangkau dari analisis (data/padding)\n", awal, akhir, akhir - awal));
+        }
+    }
+    fn tulis_section_data(&self, hasil: &mut String, vmem: &VirtualMemory, seg: &SegmentMemori, xref_db: &DatabaseXref) {
+        let mut item: BTreeSet<u64> = self.literal_string.range(seg.start_addr..seg.end_addr).map(|(addr, _)| *addr).collect();
+        item.extend(vmem.simbol_global.range(seg.start_addr..seg.end_addr).map(|(addr, _)| *addr));
+        item.extend(xref_db.semua_xref()
+            .filter(|e| !e.jenis.is_code_flow() && e.ke >= seg.start_addr && e.ke < seg.end_addr)
+            .map(|e| e.ke));
+        for addr in item {
+            let mut baris = format!("{}:", self.nama_atau_hex(addr));
+            if let Some(komentar) = Self::format_xref_masuk(xref_db, addr) {
+                baris.push_str(&format!("  ; {}", komentar));
+            }
+            hasil.push_str(&baris);
+            hasil.push('\n');
+            if let Some(literal) = self.literal_string.get(&addr) {
+                let (direktif, isi) = match literal.strip_prefix('L') {
+                    Some(isi) => ("dw", isi),
+                    None => ("db", literal.as_str()),
+                };
+                hasil.push_str(&format!("  {:08x}  {} {}, 0\n", addr, direktif, isi));
+                continue;
+            }
+            let ukuran = xref_db.ambil_xref_ke(addr).iter().map(|e| e.ukuran_akses).max().unwrap_or(0);
+            let direktif = match ukuran {
+                1 => "db",
+                2 => "dw",
+                4 => "dd",
+                8 => "dq",
+                _ => continue,
+            };
+            if let Some(nilai) = vmem.baca_pointer(addr, ukuran) {
+                let isi = match self.nama_alamat.get(&nilai) {
+                    Some(nama) if ukuran >= 4 => nama.clone(),
+                    _ => format!("0x{:x}", nilai),
+                };
+                hasil.push_str(&format!("  {:08x}  {} {}\n", addr, direktif, isi));
+            }
+        }
+    }
+    fn tulis_fungsi(&self, hasil: &mut String, ctx: &FunctionContext, mode_thumb: bool, xref_db: &DatabaseXref) {
+        let nama = self.nama_alamat.get(&ctx.entry_point).cloned().unwrap_or_else(|| format!("sub_{:x}", ctx.entry_point));
+        let akhir = ctx.instruksi.last()
+            .map(|i| i.address + i.hitung_panjang_byte() as u64)
+            .unwrap_or(ctx.end_address);
+        hasil.push_str(&format!("\n; ======== fungsi {} (0x{:x}-0x{:x}) ========\n", nama, ctx.entry_point, akhir));
+        if let Some(komentar) = Self::format_xref_masuk(xref_db, ctx.entry_point) {
+            hasil.push_str(&format!("; {}\n", komentar));
+        }
+        hasil.push_str(&format!("{}:\n", nama));
+        for instr in &ctx.instruksi {
+            let label = self.nama_alamat.get(&instr.address).filter(|_| instr.address != ctx.entry_point);
+            if let Some(label) = label {
+                let mut baris = format!("{}:", label);
+                if let Some(asal) = self.asal_jump_table.get(&instr.address) {
+                    let daftar: Vec<String> = asal.iter().map(|a| format!("0x{:x}", a)).collect();
+                    baris.push_str(&format!("  ; target jump table dari {}", daftar.join(", ")));
+                }
+                hasil.push_str(&baris);
+                hasil.push('\n');
+            }
+            hasil.push_str(&self.format_baris_instruksi(instr, mode_thumb, xref_db));
+            hasil.push('\n');
+        }
+        hasil.push_str(&format!("; ======== akhir fungsi {} ========\n", nama));
+    }
+    fn format_xref_masuk(xref_db: &DatabaseXref, alamat: u64) -> Option<String> {
+        let daftar = xref_db.ambil_xref_ke(alamat);
+        if daftar.is_empty() {
+            return None;
+        }
+        let mut bagian: Vec<String> = daftar.iter()
+            .take(MAKS_XREF_DITAMPILKAN)
+            .map(|e| format!("0x{:x} [{}]", e.dari, e.jenis.sebagai_str()))
+            .collect();
+        if daftar.len() > MAKS_XREF_DITAMPILKAN {
+            bagian.push(format!("+{} lainnya", daftar.len() - MAKS_XREF_DITAMPILKAN));
+        }
+        Some(format!("xref: {}", bagian.join(", ")))
+    }
+    fn format_baris_instruksi(&self, instr: &InstructionNormalized, mode_thumb: bool, xref_db: &DatabaseXref) -> String {
+        let mut bytes: String = instr.bytes.iter()
+            .take(MAKS_BYTE_DITAMPILKAN)
+            .map(|b| format!("{:02x} ", b))
+            .collect();
+        if instr.bytes.len() > MAKS_BYTE_DITAMPILKAN {
+            bytes.replace_range(bytes.len() - 1.., "+");
+        }
+        let (mnemonic, operand, mut komentar) = self.format_instruksi(instr, mode_thumb);
+        if let Some(targets) = self.ambil_target_jump_table(instr, xref_db) {
+            let label: Vec<String> = targets.iter().map(|t| self.nama_atau_hex(*t)).collect();
+            komentar.push(format!("jump table {} target: {}", targets.len(), label.join(", ")));
+        }
+        let mut baris = format!("  {:08x}  {:<lebar$} {:<8} {}", instr.address, bytes, mnemonic, operand, lebar = LEBAR_KOLOM_BYTE);
+        let panjang_isi = baris.trim_end().len();
+        baris.truncate(panjang_isi);
+        if !komentar.is_empty() {
+            baris.push_str(&format!("  ; {}", komentar.join("; ")));
+        }
+        baris
+    }
+    fn ambil_target_jump_table(&self, instr: &InstructionNormalized, xref_db: &DatabaseXref) -> Option<Vec<u64>> {
+        let mut targets: Vec<u64> = xref_db.ambil_xref_dari(instr.address).iter()
+            .filter(|e| e.jenis == JenisXref::Jump && self.asal_jump_table.get(&e.ke).is_some_and(|asal| asal.contains(&instr.address)))
+            .map(|e| e.ke)
+            .collect();
+        if targets.is_empty() {
+            return None;
+        }
+        targets.sort();
+        targets.dedup();
+        Some(targets)
+    }
+    fn nama_atau_hex(&self, alamat: u64) -> String {
+        self.nama_alamat.get(&alamat).cloned().unwrap_or_else(|| format!("0x{:x}", alamat))
+    }
+    fn alamat_referensi(instr: &InstructionNormalized) -> Vec<u64> {
+        instr.operands_detail.iter()
+            .filter_map(|op| match op {
+                JenisOperandDisasm::Immediate(val) => Some(*val as u64),
+                JenisOperandDisasm::Memory { base: None, index: None, disp, segment: None, .. } => Some(*disp as u64),
+                _ => None,
+            })
+            .chain(instr.alamat_terlipat)
+            .collect()
+    }
+    fn komentar_referensi(&self, instr: &InstructionNormalized, sudah_diganti: &[u64]) -> Vec<String> {
+        let mut komentar = Vec::new();
+        for alamat in Self::alamat_referensi(instr) {
+            if let Some(nama) = self.nama_alamat.get(&alamat).filter(|_| !sudah_diganti.contains(&alamat)) {
+                komentar.push(nama.clone());
+            }
+            if let Some(literal) = self.literal_string.get(&alamat) {
+                komentar.push(literal.clone());
+            }
+        }
+        komentar
+    }
+    fn disasm_ulang(&self, instr: &InstructionNormalized, mode_thumb: bool) -> Option<(String, String)> {
+        let cs = self.cs_thumb.as_ref().filter(|_| mode_thumb).or(self.cs.as_ref())?;
+        let insns = cs.disasm_count(&instr.bytes, instr.address, 1).ok()?;
+        let insn = insns.iter().next()?;
+        Some((insn.mnemonic().unwrap_or("").to_string(), insn.op_str().unwrap_or("").to_string()))
+    }
+    fn format_instruksi(&self, instr: &InstructionNormalized, mode_thumb: bool) -> (String, String, Vec<String>) {
+        let (mnemonic, mut operand) = self.disasm_ulang(instr, mode_thumb)
+            .unwrap_or_else(|| (instr.mnemonic.clone(), instr.op_str.clone()));
+        let next_ip = instr.address + instr.hitung_panjang_byte() as u64;
+        let mut diganti = Vec::new();
+        let mut komentar = Vec::new();
+        for alamat in Self::alamat_referensi(instr) {
+            let nama = self.nama_alamat.get(&alamat);
+            if operand.contains("rip") {
+                let relatif = alamat.wrapping_sub(next_ip) as i64;
+                let token = if relatif < 0 { format!("-0x{:x}(%rip)", relatif.unsigned_abs()) } else { format!("0x{:x}(%rip)", relatif) };
+                match nama {
+                    Some(n) => {
+                        if let Some(baru) = ganti_token_hex(&operand, &token, &format!("{}(%rip)", n)) {
+                            operand = baru;
+                            diganti.push(alamat);
+                        }
+                    },
+                    None if !self.literal_string.contains_key(&alamat) => komentar.push(format!("0x{:x}", alamat)),
+                    None => {},
+                }
+                continue;
+            }
+            let Some(nama) = nama else { continue };
+            let hex = format!("0x{:x}", alamat);
+            let baru = ganti_token_hex(&operand, &format!("#{}", hex), nama)
+                .or_else(|| ganti_token_hex(&operand, &hex, nama));
+            if let Some(baru) = baru {
+                operand = baru;
+                diganti.push(alamat);
+            }
+        }
+        let mut hasil_komentar = self.komentar_referensi(instr, &diganti);
+        hasil_komentar.extend(komentar);
+        (mnemonic, operand, hasil_komentar)
+    }
+}
+
+fn buat_capstone_listing(arsitektur: &str, sintaks: SintaksAsm, mode_thumb: bool) -> Option<Capstone> {
+    use capstone::arch::{arm, arm64, mips, riscv, x86};
+    if crate::arch::is_arm32(arsitektur) {
+        let mode = if mode_thumb { arm::ArchMode::Thumb } else { arm::ArchMode::Arm };
+        return Capstone::new().arm().mode(mode).build().ok();
+    }
+    if mode_thumb {
+        return None;
+    }
+    if crate::arch::is_mips(arsitektur) {
+        let endian = if arsitektur == "mips" { capstone::Endian::Big } else { capstone::Endian::Little };
+        Capstone::new().mips().mode(mips::ArchMode::Mips32).endian(endian).build().ok()
+    } else if crate::arch::is_riscv(arsitektur) {
+        let mode = if arsitektur == "riscv32" { riscv::ArchMode::RiscV32 } else { riscv::ArchMode::RiscV64 };
+        Capstone::new().riscv().mode(mode).extra_mode([riscv::ArchExtraMode::RiscVC].iter().copied()).build().ok()
+    } else if arsitektur == "aarch64" || arsitektur == "arm64" {
+        Capstone::new().arm64().mode(arm64::ArchMode::Arm).build().ok()
+    } else {
+        let mode = if arsitektur == "x86_64" { x86::ArchMode::Mode64 } else { x86::ArchMode::Mode32 };
+        let syntax = if sintaks == SintaksAsm::Att { x86::ArchSyntax::Att } else { x86::ArchSyntax::Intel };
+        Capstone::new().x86().mode(mode).syntax(syntax).build().ok()
+    }
+}
+
+fn ganti_token_hex(teks: &str, token: &str, pengganti: &str) -> Option<String> {
+    let mut mulai = 0;
+    while let Some(pos) = teks[mulai..].find(token) {
+        let awal = mulai + pos;
+        let akhir = awal + token.len();
+        let sebelum_ok = awal == 0 || !teks.as_bytes()[awal - 1].is_ascii_alphanumeric();
+        let sesudah_ok = akhir == teks.len() || !teks.as_bytes()[akhir].is_ascii_hexdigit();
+        if sebelum_ok && sesudah_ok {
+            return Some(format!("{}{}{}", &teks[..awal], pengganti, &teks[akhir..]));
+        }
+        mulai = akhir;
+    }
+    None
+}
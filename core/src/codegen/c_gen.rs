@@ -1,8 +1,9 @@
 use std::collections::{BTreeMap, HashMap, HashSet};
 use crate::analysis::recovery::ast::NodeAst;
-use crate::analysis::recovery::types::{TypeSystem, TipePrimitif};
+use crate::analysis::recovery::types::{TypeSystem, TipePrimitif, KonvensiPanggilan};
 use crate::analysis::recovery::stack::StackFrame;
-use crate::ir::types::{StatementIr, OperasiIr, TipeOperand};
+use crate::analysis::recovery::strings::TabelString;
+use crate::ir::types::{StatementIr, OperasiIr, TipeOperand, TipeDataIr};
 use crate::arch::Architecture;
 
 #[derive(PartialEq, PartialOrd, Clone, Copy)]
@@ -30,6 +31,7 @@ pub struct CEmitter {
     indent_level: usize,
     var_rename_map: HashMap<String, String>,
     declared_vars: HashSet<String>,
+    literal_string: HashMap<u64, String>,
 }
 
 impl CEmitter {
@@ -38,6 +40,19 @@ impl CEmitter {
             indent_level: 0,
             var_rename_map: HashMap::new(),
             declared_vars: HashSet::new(),
+            literal_string: HashMap::new(),
+        }
+    }
+    pub fn atur_tabel_string(&mut self, tabel: &TabelString) {
+        self.literal_string = tabel.entri.keys()
+            .filter_map(|&addr| tabel.format_literal_c(addr).map(|lit| (addr, lit)))
+            .collect();
+    }
+    fn format_literal_string(&self, op: &TipeOperand) -> Option<String> {
+        match op {
+            TipeOperand::Immediate(val) => self.literal_string.get(&(*val as u64)).cloned(),
+            TipeOperand::Memory(addr) => self.literal_string.get(addr).cloned(),
+            _ => None,
         }
     }
     pub fn generate_header_includes(&self) -> String {
@@ -69,6 +84,11 @@ impl CEmitter {
                     source.push_str(&format!("    {} field_{:x};\n", tipe_str, offset));
                     last_offset = *offset + 8; 
                 }
+                if let Some(kelas) = types.class_definitions.get(nama) {
+                    for (addr, nama_method) in &kelas.methods {
+                        source.push_str(&format!("    // method 0x{:x}: {}\n", addr, nama_method));
+                    }
+                }
                 source.push_str("};\n\n");
             }
         }
@@ -82,6 +102,7 @@ impl CEmitter {
         stack_frame: &StackFrame,
         symbol_map: &BTreeMap<u64, String>,
         entry_params: &[String],
+        konvensi: KonvensiPanggilan,
         arch: &dyn Architecture
     ) -> String {
         let mut source = String::new();
@@ -93,11 +114,13 @@ impl CEmitter {
             let p: Vec<String> = entry_params.iter().map(|s| {
                 let clean_name = self.bersihkan_nama_variabel(s);
                 let tipe = types.dapatkan_tipe_c_string(s);
+                self.declared_vars.insert(clean_name.clone());
                 format!("{} {}", tipe, clean_name)
             }).collect();
             p.join(", ")
         };
-        source.push_str(&format!("long {}({}) {{\n", func_name, params_str));
+        let atribut = konvensi.sebagai_atribut().map(|a| format!("{} ", a)).unwrap_or_default();
+        source.push_str(&format!("long {}{}({}) {{\n", atribut, func_name, params_str));
         self.indent_level = 1;
         let indent = "    ".repeat(self.indent_level);
         if !stack_frame.map_offset_variabel.is_empty() {
@@ -240,45 +263,101 @@ impl CEmitter {
         match &stmt.operation_code {
              OperasiIr::Lea => {
                 let op1 = fmt(&stmt.operand_satu);
-                let op2 = self.format_address_only(&stmt.operand_dua, types, stack_frame, arch, stmt.address_asal);
+                let op2 = match self.format_literal_string(&stmt.operand_dua) {
+                    Some(lit) => lit,
+                    None => self.format_address_only(&stmt.operand_dua, types, stack_frame, arch, stmt.address_asal),
+                };
                 format!("{}{} = {};", prefix, op1, op2)
              },
-             OperasiIr::Add | OperasiIr::Sub | OperasiIr::Imul | 
+             OperasiIr::Add | OperasiIr::Sub | OperasiIr::Imul | OperasiIr::Mul |
              OperasiIr::And | OperasiIr::Or | OperasiIr::Xor | 
-             OperasiIr::Shl | OperasiIr::Shr => {
+             OperasiIr::Shl | OperasiIr::Shr | OperasiIr::Sar |
+             OperasiIr::Adc | OperasiIr::Sbb | OperasiIr::MulHi | OperasiIr::MulHiU | OperasiIr::MulHiSU |
+             OperasiIr::Div | OperasiIr::Idiv | OperasiIr::VecDiv => {
                 let op1 = fmt(&stmt.operand_satu);
-                let op2 = fmt(&stmt.operand_dua);
                 let symbol = match stmt.operation_code {
                     OperasiIr::Add => "+=", OperasiIr::Sub => "-=",
-                    OperasiIr::Imul => "*=", OperasiIr::And => "&=", 
+                    OperasiIr::Imul | OperasiIr::Mul => "*=", OperasiIr::And => "&=", 
                     OperasiIr::Or => "|=", OperasiIr::Xor => "^=",
                     OperasiIr::Shl => "<<=", OperasiIr::Shr => ">>=",
+                    OperasiIr::Div | OperasiIr::Idiv | OperasiIr::VecDiv => "/=",
                     _ => "="
                 };
-                format!("{}{} {} {};", prefix, op1, symbol, op2)
+                match &stmt.operand_dua {
+                    TipeOperand::Expression { operasi, operand_kiri, operand_kanan }
+                        if *operasi == stmt.operation_code && **operand_kiri == stmt.operand_satu && symbol != "=" => {
+                        format!("{}{} {} {};", prefix, op1, symbol, fmt(operand_kanan))
+                    },
+                    op2 @ (TipeOperand::Expression { .. } | TipeOperand::Conditional { .. }) => {
+                        format!("{}{} = {};", prefix, op1, fmt(op2))
+                    },
+                    op2 => format!("{}{} {} {};", prefix, op1, symbol, fmt(op2)),
+                }
             },
-            OperasiIr::Mov | OperasiIr::VecMov => {
+            OperasiIr::Mov | OperasiIr::VecMov | OperasiIr::Neg | OperasiIr::Not | OperasiIr::Cmov => {
                 let op1 = fmt(&stmt.operand_satu);
-                let op2 = fmt(&stmt.operand_dua);
+                let op2 = match &stmt.operand_dua {
+                    TipeOperand::Immediate(_) => self.format_literal_string(&stmt.operand_dua).unwrap_or_else(|| fmt(&stmt.operand_dua)),
+                    _ => fmt(&stmt.operand_dua),
+                };
                 format!("{}{} = {};", prefix, op1, op2)
             },
-            OperasiIr::Div | OperasiIr::FDiv | OperasiIr::VecDiv => {
-                let op1 = fmt(&stmt.operand_satu);
-                let op2 = fmt(&stmt.operand_dua);
-                format!("{}{} /= {};", prefix, op1, op2)
+            OperasiIr::FAdd | OperasiIr::FSub | OperasiIr::FMul | OperasiIr::FDiv |
+            OperasiIr::FMin | OperasiIr::FMax => {
+                format!("{}{} = {};", prefix, fmt(&stmt.operand_satu), fmt(&stmt.operand_dua))
+            },
+            OperasiIr::FSqrt => {
+                let fungsi = match stmt.tipe_hasil {
+                    TipeDataIr::F32 => "sqrtf",
+                    TipeDataIr::F80 => "sqrtl",
+                    _ => "sqrt",
+                };
+                format!("{}{} = {}({});", prefix, fmt(&stmt.operand_satu), fungsi, fmt(&stmt.operand_dua))
+            },
+            OperasiIr::FConv => {
+                let op2 = self.format_operand_safe(&stmt.operand_dua, types, stack_frame, arch, stmt.address_asal, Precedence::Prefix);
+                match self.nama_tipe_c(&stmt.tipe_hasil) {
+                    Some(tipe) => format!("{}{} = ({}){};", prefix, fmt(&stmt.operand_satu), tipe, op2),
+                    None => format!("{}{} = {};", prefix, fmt(&stmt.operand_satu), op2),
+                }
+            },
+            OperasiIr::Popcnt | OperasiIr::Lzcnt | OperasiIr::Tzcnt => {
+                let lebar64 = stmt.tipe_hasil == TipeDataIr::I64;
+                let fungsi = match (&stmt.operation_code, lebar64) {
+                    (OperasiIr::Popcnt, true) => "__builtin_popcountll",
+                    (OperasiIr::Popcnt, false) => "__builtin_popcount",
+                    (OperasiIr::Lzcnt, true) => "_lzcnt_u64",
+                    (OperasiIr::Lzcnt, false) => "_lzcnt_u32",
+                    (_, true) => "_tzcnt_u64",
+                    (_, false) => "_tzcnt_u32",
+                };
+                format!("{}{} = {}({});", prefix, fmt(&stmt.operand_satu), fungsi, fmt(&stmt.operand_dua))
+            },
+            OperasiIr::Intrinsic(nama) => {
+                let args: Vec<String> = std::iter::once(&stmt.operand_dua)
+                    .chain(stmt.operand_tambahan.iter())
+                    .filter(|op| !matches!(op, TipeOperand::None))
+                    .map(|op| self.format_operand_safe(op, types, stack_frame, arch, stmt.address_asal, Precedence::Comma))
+                    .collect();
+                match stmt.operand_satu {
+                    TipeOperand::None => format!("{}{}({});", prefix, nama, args.join(", ")),
+                    _ => format!("{}{} = {}({});", prefix, fmt(&stmt.operand_satu), nama, args.join(", ")),
+                }
             },
             OperasiIr::Call => {
-                let func_target = if let TipeOperand::Immediate(addr) = stmt.operand_satu {
-                    if let Some(sym) = symbol_map.get(&(addr as u64)) {
-                        sym.clone()
-                    } else {
-                        format!("sub_{:x}", addr)
-                    }
-                } else {
-                    fmt(&stmt.operand_satu)
+                let func_target = match stmt.operand_satu {
+                    TipeOperand::Immediate(addr) => match symbol_map.get(&(addr as u64)) {
+                        Some(sym) => sym.clone(),
+                        None => format!("sub_{:x}", addr),
+                    },
+                    TipeOperand::Memory(slot) if symbol_map.contains_key(&slot) => symbol_map[&slot].clone(),
+                    _ => fmt(&stmt.operand_satu),
                 };
                 let args: Vec<String> = stmt.operand_tambahan.iter()
-                    .map(|arg| self.format_operand_safe(arg, types, stack_frame, arch, stmt.address_asal, Precedence::Comma))
+                    .map(|arg| match arg {
+                        TipeOperand::Immediate(_) => self.format_literal_string(arg),
+                        _ => None,
+                    }.unwrap_or_else(|| self.format_operand_safe(arg, types, stack_frame, arch, stmt.address_asal, Precedence::Comma)))
                     .collect();
                 format!("{}{}({});", prefix, func_target, args.join(", "))
             },
@@ -333,12 +412,55 @@ impl CEmitter {
                 };
                 format!("*(long*)({})", expr)
             },
+            TipeOperand::Expression { operasi, operand_kiri, operand_kanan } if operasi.is_operasi_lebar() => {
+                let nilai = |o, prec| self.format_operand_safe(o, types, stack_frame, arch, addr, prec);
+                let (expr_str, prec) = match (operasi, &**operand_kanan) {
+                    (OperasiIr::BitInsert { offset, lebar }, _) => {
+                        let mask = crate::ir::types::mask_lebar(*lebar as i64) << offset;
+                        let sisip = if matches!(lebar, 8 | 16 | 32) {
+                            format!("(uint64_t)(uint{}_t){}", lebar, nilai(operand_kanan, Precedence::Prefix))
+                        } else {
+                            format!("((uint64_t){} & 0x{:x})", nilai(operand_kanan, Precedence::Prefix), crate::ir::types::mask_lebar(*lebar as i64))
+                        };
+                        let sisip = if *offset > 0 { format!("({} << {})", sisip, offset) } else { sisip };
+                        (format!("({} & ~0x{:x}) | {}", nilai(operand_kiri, Precedence::BitwiseAnd), mask, sisip), Precedence::BitwiseOr)
+                    },
+                    (_, TipeOperand::Immediate(lebar)) => {
+                        let awalan = if *operasi == OperasiIr::SignExtend { "int" } else { "uint" };
+                        (format!("({}{}_t){}", awalan, lebar, nilai(operand_kiri, Precedence::Prefix)), Precedence::Prefix)
+                    },
+                    _ => (nilai(operand_kiri, parent_prec), parent_prec),
+                };
+                if prec < parent_prec { format!("({})", expr_str) } else { expr_str }
+            },
+            TipeOperand::Expression { operasi: operasi @ (OperasiIr::Intrinsic(_) | OperasiIr::FMin | OperasiIr::FMax | OperasiIr::MulHi | OperasiIr::MulHiU | OperasiIr::MulHiSU), operand_kiri, operand_kanan } => {
+                let nama = match operasi {
+                    OperasiIr::Intrinsic(nama) => nama.as_str(),
+                    OperasiIr::MulHi => "__mulh",
+                    OperasiIr::MulHiU => "__umulh",
+                    OperasiIr::MulHiSU => "__mulhsu",
+                    OperasiIr::FMin => "fmin",
+                    _ => "fmax",
+                };
+                let left = self.format_operand_safe(operand_kiri, types, stack_frame, arch, addr, Precedence::Comma);
+                let right = self.format_operand_safe(operand_kanan, types, stack_frame, arch, addr, Precedence::Comma);
+                format!("{}({}, {})", nama, left, right)
+            },
             TipeOperand::Expression { operasi, operand_kiri, operand_kanan } => {
                 let my_prec = self.get_operator_precedence(operasi);
-                let left = self.format_operand_safe(operand_kiri, types, stack_frame, arch, addr, my_prec);
-                let right = self.format_operand_safe(operand_kanan, types, stack_frame, arch, addr, my_prec);
+                let is_unsigned = operasi.is_perbandingan_unsigned();
+                let child_prec = if is_unsigned { Precedence::Prefix } else { my_prec };
+                let left = self.format_operand_safe(operand_kiri, types, stack_frame, arch, addr, child_prec);
+                let right = self.format_operand_safe(operand_kanan, types, stack_frame, arch, addr, child_prec);
                 let op_str = self.get_operator_str(operasi);
-                let expr_str = format!("{} {} {}", left, op_str, right);
+                let expr_str = if is_unsigned {
+                    format!("(unsigned long){} {} (unsigned long){}", left, op_str, right)
+                } else if *operasi == OperasiIr::Sar {
+                    let left = self.format_operand_safe(operand_kiri, types, stack_frame, arch, addr, Precedence::Prefix);
+                    format!("(long){} {} {}", left, op_str, right)
+                } else {
+                    format!("{} {} {}", left, op_str, right)
+                };
                 if my_prec < parent_prec {
                     format!("({})", expr_str)
                 } else {
@@ -400,6 +522,7 @@ impl CEmitter {
         addr: u64
     ) -> String {
         match op {
+            TipeOperand::Memory(target) => format!("0x{:x}", target),
             TipeOperand::MemoryRef { base, offset } => {
                 let base_clean = self.bersihkan_nama_variabel(base);
                 if base == &arch.dapatkan_frame_pointer() {
@@ -427,16 +550,29 @@ impl CEmitter {
             _ => self.format_operand_safe(op, types, stack_frame, arch, addr, Precedence::Comma)
         }
     }
+    fn nama_tipe_c(&self, tipe: &TipeDataIr) -> Option<&'static str> {
+        match tipe {
+            TipeDataIr::I8 => Some("int8_t"),
+            TipeDataIr::I16 => Some("int16_t"),
+            TipeDataIr::I32 => Some("int32_t"),
+            TipeDataIr::I64 => Some("int64_t"),
+            TipeDataIr::F32 => Some("float"),
+            TipeDataIr::F64 => Some("double"),
+            TipeDataIr::F80 => Some("long double"),
+            _ => None,
+        }
+    }
     fn get_operator_precedence(&self, op: &OperasiIr) -> Precedence {
         match op {
-            OperasiIr::Imul | OperasiIr::Div | OperasiIr::FMul | OperasiIr::FDiv => Precedence::MulDivMod,
+            OperasiIr::Imul | OperasiIr::Mul | OperasiIr::Div | OperasiIr::Idiv | OperasiIr::FMul | OperasiIr::FDiv => Precedence::MulDivMod,
             OperasiIr::Add | OperasiIr::Sub | OperasiIr::FAdd | OperasiIr::FSub => Precedence::AddSub,
-            OperasiIr::Shl | OperasiIr::Shr => Precedence::Shift,
+            OperasiIr::Shl | OperasiIr::Shr | OperasiIr::Sar => Precedence::Shift,
             OperasiIr::And => Precedence::BitwiseAnd,
             OperasiIr::Xor | OperasiIr::VecXor => Precedence::BitwiseXor,
             OperasiIr::Or => Precedence::BitwiseOr,
             OperasiIr::Je | OperasiIr::Jne => Precedence::Equality,
             OperasiIr::Jg | OperasiIr::Jl | OperasiIr::Jge | OperasiIr::Jle |
+            OperasiIr::Ja | OperasiIr::Jb | OperasiIr::Jae | OperasiIr::Jbe |
             OperasiIr::Cmp | OperasiIr::FCmp => Precedence::Relational,
             _ => Precedence::Atom,
         }
@@ -445,19 +581,19 @@ impl CEmitter {
         match op {
             OperasiIr::Add | OperasiIr::FAdd | OperasiIr::VecAdd => "+",
             OperasiIr::Sub | OperasiIr::FSub | OperasiIr::VecSub => "-",
-            OperasiIr::Imul | OperasiIr::FMul | OperasiIr::VecMul => "*",
-            OperasiIr::Div | OperasiIr::FDiv | OperasiIr::VecDiv => "/",
+            OperasiIr::Imul | OperasiIr::Mul | OperasiIr::FMul | OperasiIr::VecMul => "*",
+            OperasiIr::Div | OperasiIr::Idiv | OperasiIr::FDiv | OperasiIr::VecDiv => "/",
             OperasiIr::And | OperasiIr::VecAnd => "&",
             OperasiIr::Or | OperasiIr::VecOr => "|",
             OperasiIr::Xor | OperasiIr::VecXor => "^",
             OperasiIr::Shl => "<<",
-            OperasiIr::Shr => ">>",
+            OperasiIr::Shr | OperasiIr::Sar => ">>",
             OperasiIr::Je => "==",
             OperasiIr::Jne => "!=",
-            OperasiIr::Jg => ">",
-            OperasiIr::Jge => ">=",
-            OperasiIr::Jl => "<",
-            OperasiIr::Jle => "<=",
+            OperasiIr::Jg | OperasiIr::Ja => ">",
+            OperasiIr::Jge | OperasiIr::Jae => ">=",
+            OperasiIr::Jl | OperasiIr::Jb => "<",
+            OperasiIr::Jle | OperasiIr::Jbe => "<=",
             OperasiIr::Cmp | OperasiIr::FCmp => "==", 
             _ => "?",
         }
@@ -1 +1 @@
-pub mod c_gen;
\ No newline at end of file
+pub mod c_gen;pub mod asm_listing;
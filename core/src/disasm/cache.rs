@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::loader::vmem::VirtualMemory;
+use super::engine::DisasmEngine;
+use super::instruction::InstructionNormalized;
+
+const PANJANG_INSTRUKSI_MAKS: usize = 16;
+const JUMLAH_SHARD: usize = 64;
+
+type PetaShard = HashMap<(u64, bool), Option<Arc<InstructionNormalized>>>;
+
+thread_local! {
+    static ENGINE_PER_THREAD: RefCell<HashMap<String, Rc<DisasmEngine>>> = RefCell::new(HashMap::new());
+}
+
+pub fn dengan_engine_lokal<R>(arch: &str, f: impl FnOnce(&DisasmEngine) -> R) -> R {
+    let engine = ENGINE_PER_THREAD.with(|peta| {
+        peta.borrow_mut()
+            .entry(arch.to_string())
+            .or_insert_with(|| Rc::new(DisasmEngine::buat_engine_baru(arch)))
+            .clone()
+    });
+    f(&engine)
+}
+
+pub struct CacheInstruksi {
+    arch: String,
+    shard: Vec<RwLock<PetaShard>>,
+    jumlah_hit: AtomicUsize,
+    jumlah_miss: AtomicUsize,
+}
+
+impl CacheInstruksi {
+    pub fn new(arch: &str) -> Self {
+        Self {
+            arch: arch.to_string(),
+            shard: (0..JUMLAH_SHARD).map(|_| RwLock::new(HashMap::new())).collect(),
+            jumlah_hit: AtomicUsize::new(0),
+            jumlah_miss: AtomicUsize::new(0),
+        }
+    }
+    pub fn arch(&self) -> &str {
+        &self.arch
+    }
+    fn ambil_shard(&self, addr: u64) -> &RwLock<PetaShard> {
+        &self.shard[((addr >> 1) as usize) % JUMLAH_SHARD]
+    }
+    pub fn dekode(&self, vmem: &VirtualMemory, addr: u64, mode_thumb: bool) -> Option<Arc<InstructionNormalized>> {
+        let kunci = (addr, mode_thumb);
+        let shard = self.ambil_shard(addr);
+        if let Some(hasil) = shard.read().ok().and_then(|peta| peta.get(&kunci).cloned()) {
+            self.jumlah_hit.fetch_add(1, Ordering::Relaxed);
+            return hasil;
+        }
+        self.jumlah_miss.fetch_add(1, Ordering::Relaxed);
+        let hasil = vmem.baca_slice(addr, PANJANG_INSTRUKSI_MAKS)
+            .and_then(|bytes| dengan_engine_lokal(&self.arch, |engine| engine.ambil_satu_instruksi_mode(bytes, addr, mode_thumb)))
+            .map(Arc::new);
+        if let Ok(mut peta) = shard.write() {
+            peta.insert(kunci, hasil.clone());
+        }
+        hasil
+    }
+    pub fn ambil_tercache(&self, addr: u64, mode_thumb: bool) -> Option<Arc<InstructionNormalized>> {
+        self.ambil_shard(addr).read().ok()?.get(&(addr, mode_thumb)).cloned().flatten()
+    }
+    pub fn jumlah_entri(&self) -> usize {
+        self.shard.iter()
+            .filter_map(|s| s.read().ok().map(|peta| peta.len()))
+            .sum()
+    }
+    pub fn statistik(&self) -> (usize, usize) {
+        (self.jumlah_hit.load(Ordering::Relaxed), self.jumlah_miss.load(Ordering::Relaxed))
+    }
+    pub fn kosongkan(&self) {
+        for shard in &self.shard {
+            if let Ok(mut peta) = shard.write() {
+                peta.clear();
+            }
+        }
+    }
+}
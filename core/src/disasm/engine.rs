@@ -1,11 +1,18 @@
-use super::instruction::{InstructionNormalized, JenisOperandDisasm};
+use super::instruction::{InstructionNormalized, JenisOperandDisasm, AtributOperand, AksesOperand, GeserOperand};
+use crate::arch::RegisterFile;
 use capstone::prelude::*;
+use capstone::RegAccessType;
 use capstone::arch::x86::{ArchMode, ArchSyntax, X86OperandType};
-use capstone::arch::arm64::Arm64OperandType;
+use capstone::arch::arm64::{Arm64CC, Arm64Extender, Arm64OperandType, Arm64Shift};
+use capstone::arch::arm::{ArmCC, ArmOperandType};
+use capstone::arch::riscv::RiscVOperand;
+use capstone::arch::mips::MipsOperand;
 
 pub struct DisasmEngine {
     cs: Capstone,
+    cs_thumb: Option<Capstone>,
     pub arch: String,
+    register_file: RegisterFile,
 }
 
 unsafe impl Send for DisasmEngine {}
@@ -14,13 +21,52 @@ unsafe impl Sync for DisasmEngine {}
 impl DisasmEngine {
     pub fn buat_engine_baru(arch_target: &str) -> Self {
         let cs_instance = Self::inisialisasi_capstone_instance(arch_target);
+        let cs_thumb = if crate::arch::is_arm32(arch_target) {
+            Some(Self::buat_capstone_arm32(capstone::arch::arm::ArchMode::Thumb))
+        } else {
+            None
+        };
         Self {
             cs: cs_instance,
+            cs_thumb,
             arch: arch_target.to_string(),
+            register_file: crate::arch::buat_arsitektur(arch_target).dapatkan_register_file(),
         }
     }
+    fn buat_capstone_arm32(mode: capstone::arch::arm::ArchMode) -> Capstone {
+        Capstone::new()
+            .arm()
+            .mode(mode)
+            .detail(true)
+            .build()
+            .expect("Gagal inisialisasi Capstone ARM32 - Fatal Error")
+    }
     fn inisialisasi_capstone_instance(arch_target: &str) -> Capstone {
-        if arch_target == "aarch64" || arch_target == "arm64" {
+        if crate::arch::is_arm32(arch_target) {
+            Self::buat_capstone_arm32(capstone::arch::arm::ArchMode::Arm)
+        } else if crate::arch::is_mips(arch_target) {
+            let endian = if arch_target == "mips" { capstone::Endian::Big } else { capstone::Endian::Little };
+            Capstone::new()
+                .mips()
+                .mode(capstone::arch::mips::ArchMode::Mips32)
+                .endian(endian)
+                .detail(true)
+                .build()
+                .expect("Gagal inisialisasi Capstone MIPS - Fatal Error")
+        } else if crate::arch::is_riscv(arch_target) {
+            let mode = if arch_target == "riscv32" {
+                capstone::arch::riscv::ArchMode::RiscV32
+            } else {
+                capstone::arch::riscv::ArchMode::RiscV64
+            };
+            Capstone::new()
+                .riscv()
+                .mode(mode)
+                .extra_mode([capstone::arch::riscv::ArchExtraMode::RiscVC].iter().copied())
+                .detail(true)
+                .build()
+                .expect("Gagal inisialisasi Capstone RISC-V - Fatal Error")
+        } else if arch_target == "aarch64" || arch_target == "arm64" {
             Capstone::new()
                 .arm64()
                 .mode(capstone::arch::arm64::ArchMode::Arm)
@@ -43,10 +89,17 @@ impl DisasmEngine {
         }
     }
     pub fn ambil_satu_instruksi(&self, buffer_data: &[u8], address_loc: u64) -> Option<InstructionNormalized> {
-        match self.cs.disasm_count(buffer_data, address_loc, 1) {
+        self.ambil_satu_instruksi_mode(buffer_data, address_loc, false)
+    }
+    pub fn ambil_satu_instruksi_mode(&self, buffer_data: &[u8], address_loc: u64, mode_thumb: bool) -> Option<InstructionNormalized> {
+        let cs = match (&self.cs_thumb, mode_thumb) {
+            (Some(cs_thumb), true) => cs_thumb,
+            _ => &self.cs,
+        };
+        match cs.disasm_count(buffer_data, address_loc, 1) {
             Ok(insns) => {
                 if let Some(single_instr) = insns.first() {
-                    Some(self.normalisasi_instruksi(single_instr))
+                    Some(self.normalisasi_instruksi(cs, single_instr, mode_thumb))
                 } else {
                     None
                 }
@@ -60,23 +113,24 @@ impl DisasmEngine {
         let mut list_instruksi = Vec::new();
         if let Ok(insns) = self.cs.disasm_all(buffer_data, start_addr) {
             for raw_instr in insns.iter() {
-                list_instruksi.push(self.normalisasi_instruksi(raw_instr));
+                list_instruksi.push(self.normalisasi_instruksi(&self.cs, raw_instr, false));
             }
         }
         list_instruksi
     }
-    fn normalisasi_instruksi(&self, i: &capstone::Insn) -> InstructionNormalized {
+    fn normalisasi_instruksi(&self, cs: &Capstone, i: &capstone::Insn, mode_thumb: bool) -> InstructionNormalized {
         let mnemonic_str = i.mnemonic().unwrap_or("INVALID");
         let op_str_val = i.op_str().unwrap_or("");
         let mut norm_result = InstructionNormalized::new(i.address(), mnemonic_str, op_str_val);
         norm_result.bytes = i.bytes().to_vec();
-        if let Ok(detail_info) = self.cs.insn_detail(i) {
+        norm_result.id_instruksi = i.id().0;
+        if let Ok(detail_info) = cs.insn_detail(i) {
             let arch_detail = detail_info.arch_detail();
             if let capstone::arch::ArchDetail::X86Detail(x86_det) = arch_detail {
                 for op in x86_det.operands() {
                     let op_data = match op.op_type {
                         X86OperandType::Reg(reg_id) => {
-                            let reg_name = self.cs.reg_name(reg_id).unwrap_or_else(|| "INVALID_REG".to_string());
+                            let reg_name = cs.reg_name(reg_id).unwrap_or_else(|| "INVALID_REG".to_string());
                             JenisOperandDisasm::Register(reg_name)
                         },
                         X86OperandType::Imm(val) => {
@@ -86,61 +140,438 @@ impl DisasmEngine {
                             let base_reg = if mem.base().0 == 0 { 
                                 None 
                             } else {
-                                Some(self.cs.reg_name(mem.base()).unwrap_or("INVALID_BASE".to_string()))
+                                Some(cs.reg_name(mem.base()).unwrap_or("INVALID_BASE".to_string()))
                             };
                             let index_reg = if mem.index().0 == 0 { 
                                 None 
                             } else {
-                                Some(self.cs.reg_name(mem.index()).unwrap_or("INVALID_IDX".to_string()))
+                                Some(cs.reg_name(mem.index()).unwrap_or("INVALID_IDX".to_string()))
+                            };
+                            let segment_reg = if mem.segment().0 == 0 {
+                                None
+                            } else {
+                                cs.reg_name(mem.segment())
                             };
-                            JenisOperandDisasm::Memory {
-                                base: base_reg,
-                                index: index_reg,
-                                scale: mem.scale(),
-                                disp: mem.disp(),
+                            if base_reg.as_deref() == Some("rip") && index_reg.is_none() {
+                                let next_ip = i.address().wrapping_add(i.bytes().len() as u64);
+                                JenisOperandDisasm::Memory {
+                                    base: None,
+                                    index: None,
+                                    scale: 1,
+                                    disp: next_ip.wrapping_add(mem.disp() as u64) as i64,
+                                    segment: segment_reg,
+                                }
+                            } else {
+                                JenisOperandDisasm::Memory {
+                                    base: base_reg,
+                                    index: index_reg,
+                                    scale: mem.scale(),
+                                    disp: mem.disp(),
+                                    segment: segment_reg,
+                                }
                             }
                         },
                         _ => JenisOperandDisasm::Unknown,
                     };
                     norm_result.operands_detail.push(op_data);
+                    norm_result.atribut_operand.push(AtributOperand {
+                        ukuran: op.size,
+                        akses: Self::konversi_akses(op.access),
+                        ..Default::default()
+                    });
                 }
+                norm_result.prefix = Self::ambil_prefix_x86(x86_det.prefix());
             } else if let capstone::arch::ArchDetail::Arm64Detail(arm_det) = arch_detail {
-                 for op in arm_det.operands() {
-                     match op.op_type {
-                         Arm64OperandType::Reg(reg_id) => {
-                             let name_reg = self.cs.reg_name(reg_id).unwrap_or("INVALID_REG".to_string());
-                             norm_result.operands_detail.push(JenisOperandDisasm::Register(name_reg));
-                         },
-                         Arm64OperandType::Imm(val) => {
-                             norm_result.operands_detail.push(JenisOperandDisasm::Immediate(val));
-                         },
-                         Arm64OperandType::Mem(mem_op) => {
-                             let base_reg_str = if mem_op.base().0 == 0 {
-                                 None
-                             } else {
-                                 Some(self.cs.reg_name(mem_op.base()).unwrap_or("INVALID_BASE".to_string()))
-                             };
-                             let index_reg_str = if mem_op.index().0 == 0 {
-                                 None
-                             } else {
-                                 Some(self.cs.reg_name(mem_op.index()).unwrap_or("INVALID_IDX".to_string()))
-                             };
-                             let disp_val = mem_op.disp() as i64;
-                             let scale_val = if index_reg_str.is_some() { 1 } else { 0 };
-                             norm_result.operands_detail.push(JenisOperandDisasm::Memory {
-                                 base: base_reg_str,
-                                 index: index_reg_str,
-                                 scale: scale_val,
-                                 disp: disp_val,
-                             });
-                         },
-                         _ => {
-                             norm_result.operands_detail.push(JenisOperandDisasm::Unknown);
-                         }
-                     }
-                 }
+                Self::normalisasi_detail_arm64(cs, &arm_det, &mut norm_result);
+            } else if let capstone::arch::ArchDetail::ArmDetail(arm_det) = arch_detail {
+                Self::normalisasi_detail_arm32(cs, i, &arm_det, mode_thumb, &mut norm_result);
+            } else if let capstone::arch::ArchDetail::RiscVDetail(rv_det) = arch_detail {
+                for op in rv_det.operands() {
+                    let op_data = match op {
+                        RiscVOperand::Reg(reg_id) => {
+                            JenisOperandDisasm::Register(cs.reg_name(reg_id).unwrap_or("INVALID_REG".to_string()))
+                        },
+                        RiscVOperand::Imm(val) => JenisOperandDisasm::Immediate(val),
+                        RiscVOperand::Mem(mem) => JenisOperandDisasm::Memory {
+                            base: if mem.base().0 == 0 { None } else { cs.reg_name(mem.base()) },
+                            index: None,
+                            scale: 1,
+                            disp: mem.disp(),
+                            segment: None,
+                        },
+                        RiscVOperand::Invalid => JenisOperandDisasm::Unknown,
+                    };
+                    norm_result.operands_detail.push(op_data);
+                }
+                Self::normalisasi_detail_riscv(&mut norm_result);
+            } else if let capstone::arch::ArchDetail::MipsDetail(mips_det) = arch_detail {
+                for op in mips_det.operands() {
+                    let op_data = match op {
+                        MipsOperand::Reg(reg_id) => {
+                            JenisOperandDisasm::Register(cs.reg_name(reg_id).unwrap_or("INVALID_REG".to_string()))
+                        },
+                        MipsOperand::Imm(val) => JenisOperandDisasm::Immediate(val),
+                        MipsOperand::Mem(mem) => JenisOperandDisasm::Memory {
+                            base: if mem.base().0 == 0 { None } else { cs.reg_name(mem.base()) },
+                            index: None,
+                            scale: 1,
+                            disp: mem.disp(),
+                            segment: None,
+                        },
+                        MipsOperand::Invalid => JenisOperandDisasm::Unknown,
+                    };
+                    norm_result.operands_detail.push(op_data);
+                }
             }
+            let nama_reg = |reg_id: &RegId| cs.reg_name(*reg_id).map(|r| Self::normalisasi_nama_register_implisit(&self.arch, &r));
+            norm_result.register_dibaca = detail_info.regs_read().iter().filter_map(nama_reg).collect();
+            norm_result.register_ditulis = detail_info.regs_write().iter().filter_map(nama_reg).collect();
+            norm_result.grup = detail_info.groups().iter().filter_map(|g| cs.group_name(*g)).collect();
         }
+        self.lengkapi_atribut_operand(&mut norm_result);
         norm_result
     }
+    fn konversi_akses(akses: Option<RegAccessType>) -> AksesOperand {
+        match akses {
+            Some(RegAccessType::ReadOnly) => AksesOperand::Baca,
+            Some(RegAccessType::WriteOnly) => AksesOperand::Tulis,
+            Some(RegAccessType::ReadWrite) => AksesOperand::BacaTulis,
+            None => AksesOperand::TidakDiketahui,
+        }
+    }
+    fn ambil_prefix_x86(prefix: &[u8; 4]) -> Vec<String> {
+        prefix.iter().filter_map(|&p| match p {
+            0xF0 => Some("lock"),
+            0xF2 => Some("repne"),
+            0xF3 => Some("rep"),
+            0x2E => Some("cs"),
+            0x36 => Some("ss"),
+            0x3E => Some("ds"),
+            0x26 => Some("es"),
+            0x64 => Some("fs"),
+            0x65 => Some("gs"),
+            0x66 => Some("opsize"),
+            0x67 => Some("addrsize"),
+            _ => None,
+        }).map(|p| p.to_string()).collect()
+    }
+    fn normalisasi_nama_register_implisit(arch: &str, nama: &str) -> String {
+        if crate::arch::is_arm32(arch) {
+            Self::normalisasi_nama_register_arm(nama)
+        } else {
+            nama.to_string()
+        }
+    }
+    fn lengkapi_atribut_operand(&self, instr: &mut InstructionNormalized) {
+        instr.atribut_operand.resize(instr.operands_detail.len(), AtributOperand::default());
+        for (op, atribut) in instr.operands_detail.iter().zip(instr.atribut_operand.iter_mut()) {
+            if atribut.ukuran != 0 {
+                continue;
+            }
+            if let JenisOperandDisasm::Register(nama) = op
+                && let Some(info) = self.register_file.cari_register(&nama.to_lowercase())
+            {
+                atribut.ukuran = (info.lebar_bit / 8).min(u8::MAX as u16) as u8;
+            }
+        }
+        let mut dibaca = Vec::new();
+        let mut ditulis = Vec::new();
+        for (op, atribut) in instr.operands_detail.iter().zip(instr.atribut_operand.iter()) {
+            match op {
+                JenisOperandDisasm::Register(nama) => {
+                    if atribut.akses.is_baca() { dibaca.push(nama.clone()); }
+                    if atribut.akses.is_tulis() { ditulis.push(nama.clone()); }
+                },
+                JenisOperandDisasm::Memory { base, index, .. } => {
+                    dibaca.extend(base.iter().chain(index.iter()).cloned());
+                },
+                _ => {}
+            }
+        }
+        for nama in dibaca {
+            if !instr.register_dibaca.contains(&nama) { instr.register_dibaca.push(nama); }
+        }
+        for nama in ditulis {
+            if !instr.register_ditulis.contains(&nama) { instr.register_ditulis.push(nama); }
+        }
+    }
+    fn normalisasi_detail_arm32(
+        cs: &Capstone,
+        i: &capstone::Insn,
+        arm_det: &capstone::arch::arm::ArmInsnDetail,
+        mode_thumb: bool,
+        norm_result: &mut InstructionNormalized
+    ) {
+        let pc_efektif = if mode_thumb { (i.address() + 4) & !3 } else { i.address() + 8 };
+        let mut mnemonic = norm_result.mnemonic.to_lowercase();
+        for akhiran in [".w", ".n"] {
+            if let Some(m) = mnemonic.strip_suffix(akhiran) {
+                mnemonic = m.to_string();
+            }
+        }
+        if let Some(kondisi) = Self::ambil_kode_kondisi_arm(arm_det.cc())
+            && !crate::ir::lift::arm::is_blok_it(&mnemonic)
+        {
+            if let Some(m) = mnemonic.strip_suffix(kondisi) {
+                mnemonic = m.to_string();
+            }
+            norm_result.kondisi = Some(kondisi.to_string());
+        }
+        norm_result.update_flags = arm_det.update_flags();
+        if norm_result.update_flags
+            && !matches!(mnemonic.as_str(), "cmp" | "cmn" | "tst" | "teq")
+            && let Some(m) = mnemonic.strip_suffix('s')
+        {
+            mnemonic = m.to_string();
+        }
+        let is_target_cabang = mnemonic.starts_with('b') || mnemonic.starts_with("cb");
+        let nama_reg = |reg_id| Self::normalisasi_nama_register_arm(&cs.reg_name(reg_id).unwrap_or("INVALID_REG".to_string()));
+        for (idx, op) in arm_det.operands().enumerate() {
+            norm_result.atribut_operand.push(AtributOperand { akses: Self::konversi_akses(op.access), ..Default::default() });
+            let op_data = match op.op_type {
+                ArmOperandType::Reg(reg_id) => {
+                    let nama = nama_reg(reg_id);
+                    if nama == "pc" && idx > 0 && matches!(mnemonic.as_str(), "add" | "sub" | "mov") {
+                        JenisOperandDisasm::Immediate(pc_efektif as i64)
+                    } else {
+                        JenisOperandDisasm::Register(nama)
+                    }
+                },
+                ArmOperandType::Imm(val) => {
+                    if mnemonic == "adr" {
+                        JenisOperandDisasm::Immediate(pc_efektif.wrapping_add(val as i64 as u64) as i64)
+                    } else if is_target_cabang {
+                        JenisOperandDisasm::Immediate(val as u32 as i64)
+                    } else {
+                        JenisOperandDisasm::Immediate(val as i64)
+                    }
+                },
+                ArmOperandType::Mem(mem) => {
+                    let base_reg = if mem.base().0 == 0 { None } else { Some(nama_reg(mem.base())) };
+                    let index_reg = if mem.index().0 == 0 { None } else { Some(nama_reg(mem.index())) };
+                    if base_reg.as_deref() == Some("pc") && index_reg.is_none() {
+                        JenisOperandDisasm::Memory {
+                            base: None,
+                            index: None,
+                            scale: 1,
+                            disp: pc_efektif.wrapping_add(mem.disp() as i64 as u64) as i64,
+                            segment: None,
+                        }
+                    } else {
+                        JenisOperandDisasm::Memory {
+                            base: base_reg,
+                            index: index_reg,
+                            scale: mem.scale(),
+                            disp: mem.disp() as i64,
+                            segment: None,
+                        }
+                    }
+                },
+                _ => JenisOperandDisasm::Unknown,
+            };
+            norm_result.operands_detail.push(op_data);
+        }
+        norm_result.mnemonic = mnemonic;
+    }
+    fn normalisasi_detail_arm64(
+        cs: &Capstone,
+        arm_det: &capstone::arch::arm64::Arm64InsnDetail,
+        norm_result: &mut InstructionNormalized
+    ) {
+        norm_result.kondisi = Self::ambil_kode_kondisi_arm64(arm_det.cc()).map(str::to_string);
+        norm_result.update_flags = arm_det.update_flags();
+        let nama_reg = |reg_id: RegId| cs.reg_name(reg_id).unwrap_or("INVALID_REG".to_string());
+        let token_op: Vec<String> = norm_result.op_str.split(", ").map(|t| t.trim().to_string()).collect();
+        for (idx, op) in arm_det.operands().enumerate() {
+            let geser = Self::konversi_geser_arm64(&op.shift, op.ext);
+            let (op_data, geser) = match op.op_type {
+                Arm64OperandType::Reg(reg_id) => (JenisOperandDisasm::Register(nama_reg(reg_id)), geser),
+                Arm64OperandType::Imm(val) | Arm64OperandType::Cimm(val) => (JenisOperandDisasm::Immediate(val), geser),
+                Arm64OperandType::Mem(mem_op) => {
+                    let base_reg = if mem_op.base().0 == 0 { None } else { Some(nama_reg(mem_op.base())) };
+                    let index_reg = if mem_op.index().0 == 0 { None } else { Some(nama_reg(mem_op.index())) };
+                    let scale = match geser {
+                        GeserOperand::Lsl(n) | GeserOperand::Ekstensi { geser: n, .. } if index_reg.is_some() => 1 << n,
+                        _ => 1,
+                    };
+                    let geser = match geser {
+                        GeserOperand::Ekstensi { bertanda: true, lebar, .. } => GeserOperand::Ekstensi { bertanda: true, lebar, geser: 0 },
+                        _ => GeserOperand::Tidak,
+                    };
+                    let mem = JenisOperandDisasm::Memory {
+                        base: base_reg,
+                        index: index_reg,
+                        scale,
+                        disp: mem_op.disp() as i64,
+                        segment: None,
+                    };
+                    (mem, geser)
+                },
+                Arm64OperandType::RegMrs(_) | Arm64OperandType::RegMsr(_) => match token_op.get(idx) {
+                    Some(nama) => (JenisOperandDisasm::Register(nama.to_lowercase()), GeserOperand::Tidak),
+                    None => (JenisOperandDisasm::Unknown, GeserOperand::Tidak),
+                },
+                _ => (JenisOperandDisasm::Unknown, GeserOperand::Tidak),
+            };
+            norm_result.operands_detail.push(op_data);
+            norm_result.atribut_operand.push(AtributOperand { geser, ..Default::default() });
+        }
+    }
+    fn konversi_geser_arm64(shift: &Arm64Shift, ext: Arm64Extender) -> GeserOperand {
+        let jumlah = match shift {
+            Arm64Shift::Lsl(n) | Arm64Shift::Lsr(n) | Arm64Shift::Asr(n) | Arm64Shift::Ror(n) | Arm64Shift::Msl(n) => *n as u8,
+            _ => 0,
+        };
+        let ekstensi = match ext {
+            Arm64Extender::ARM64_EXT_UXTB => Some((false, 8)),
+            Arm64Extender::ARM64_EXT_UXTH => Some((false, 16)),
+            Arm64Extender::ARM64_EXT_UXTW => Some((false, 32)),
+            Arm64Extender::ARM64_EXT_UXTX => Some((false, 64)),
+            Arm64Extender::ARM64_EXT_SXTB => Some((true, 8)),
+            Arm64Extender::ARM64_EXT_SXTH => Some((true, 16)),
+            Arm64Extender::ARM64_EXT_SXTW => Some((true, 32)),
+            Arm64Extender::ARM64_EXT_SXTX => Some((true, 64)),
+            _ => None,
+        };
+        if let Some((bertanda, lebar)) = ekstensi {
+            return GeserOperand::Ekstensi { bertanda, lebar, geser: jumlah };
+        }
+        match shift {
+            Arm64Shift::Lsl(_) => GeserOperand::Lsl(jumlah),
+            Arm64Shift::Lsr(_) => GeserOperand::Lsr(jumlah),
+            Arm64Shift::Asr(_) => GeserOperand::Asr(jumlah),
+            Arm64Shift::Ror(_) => GeserOperand::Ror(jumlah),
+            _ => GeserOperand::Tidak,
+        }
+    }
+    pub fn ekspansi_blok_it(instr: &InstructionNormalized) -> Vec<String> {
+        let mnem = instr.mnemonic.as_str();
+        if !crate::ir::lift::arm::is_blok_it(mnem) {
+            return Vec::new();
+        }
+        let dasar = instr.op_str.trim().to_lowercase();
+        let kebalikan = Self::balik_kondisi_arm(&dasar);
+        mnem[1..].chars().map(|c| if c == 'e' { kebalikan.clone() } else { dasar.clone() }).collect()
+    }
+    pub fn terapkan_kondisi_it(instr: &mut InstructionNormalized, kondisi: &str) {
+        instr.kondisi = Some(kondisi.to_string());
+        if instr.hitung_panjang_byte() == 2 && !matches!(instr.mnemonic.as_str(), "cmp" | "cmn" | "tst") {
+            instr.update_flags = false;
+        }
+    }
+    fn balik_kondisi_arm(kondisi: &str) -> String {
+        let pasangan = [("eq", "ne"), ("hs", "lo"), ("cs", "cc"), ("mi", "pl"), ("vs", "vc"), ("hi", "ls"), ("ge", "lt"), ("gt", "le")];
+        pasangan.iter()
+            .find_map(|&(a, b)| if kondisi == a { Some(b) } else if kondisi == b { Some(a) } else { None })
+            .unwrap_or(kondisi)
+            .to_string()
+    }
+    fn normalisasi_detail_riscv(norm_result: &mut InstructionNormalized) {
+        let mnemonic = norm_result.mnemonic.to_lowercase();
+        let is_compressed = mnemonic.starts_with("c.");
+        let mut mnemonic = match mnemonic.trim_start_matches("c.") {
+            "addi16sp" | "addi4spn" => "addi".to_string(),
+            m if m.ends_with("sp") && (m.starts_with('l') || m.starts_with('s') || m.starts_with('f')) => {
+                m.trim_end_matches("sp").to_string()
+            },
+            m => m.to_string(),
+        };
+        let ops = &mut norm_result.operands_detail;
+        if ops.len() == 3
+            && let (JenisOperandDisasm::Immediate(disp), JenisOperandDisasm::Register(base)) = (&ops[1], &ops[2])
+            && Self::is_akses_memori_riscv(&mnemonic)
+        {
+            let mem = JenisOperandDisasm::Memory { base: Some(base.clone()), index: None, scale: 1, disp: *disp, segment: None };
+            ops.truncate(1);
+            ops.push(mem);
+        }
+        if is_compressed && ops.len() == 2 && matches!(mnemonic.as_str(),
+            "addi" | "addiw" | "add" | "addw" | "sub" | "subw" | "and" | "andi" | "or" | "xor" | "slli" | "srli" | "srai")
+        {
+            let rd = ops[0].clone();
+            ops.insert(1, rd);
+        }
+        let reg = |nama: &str| JenisOperandDisasm::Register(nama.to_string());
+        match (mnemonic.as_str(), ops.len()) {
+            ("j", 1) => {
+                mnemonic = "jal".to_string();
+                ops.insert(0, reg("zero"));
+            },
+            ("jal", 1) => ops.insert(0, reg("ra")),
+            ("jr", 1) => {
+                mnemonic = "jalr".to_string();
+                ops.insert(0, reg("zero"));
+                ops.push(JenisOperandDisasm::Immediate(0));
+            },
+            ("jalr", 1) => {
+                ops.insert(0, reg("ra"));
+                ops.push(JenisOperandDisasm::Immediate(0));
+            },
+            ("ret", 0) => {
+                mnemonic = "jalr".to_string();
+                ops.extend([reg("zero"), reg("ra"), JenisOperandDisasm::Immediate(0)]);
+            },
+            _ => {}
+        }
+        let is_cabang = mnemonic == "jal" || mnemonic.starts_with('b');
+        if is_cabang
+            && let Some(JenisOperandDisasm::Immediate(rel)) = ops.last_mut()
+        {
+            *rel = norm_result.address.wrapping_add(*rel as u64) as i64;
+        }
+        norm_result.mnemonic = mnemonic;
+    }
+    fn is_akses_memori_riscv(mnemonic: &str) -> bool {
+        matches!(mnemonic,
+            "lb" | "lh" | "lw" | "ld" | "lbu" | "lhu" | "lwu" | "flw" | "fld" |
+            "sb" | "sh" | "sw" | "sd" | "fsw" | "fsd")
+    }
+    fn ambil_kode_kondisi_arm(cc: ArmCC) -> Option<&'static str> {
+        match cc {
+            ArmCC::ARM_CC_EQ => Some("eq"),
+            ArmCC::ARM_CC_NE => Some("ne"),
+            ArmCC::ARM_CC_HS => Some("hs"),
+            ArmCC::ARM_CC_LO => Some("lo"),
+            ArmCC::ARM_CC_MI => Some("mi"),
+            ArmCC::ARM_CC_PL => Some("pl"),
+            ArmCC::ARM_CC_VS => Some("vs"),
+            ArmCC::ARM_CC_VC => Some("vc"),
+            ArmCC::ARM_CC_HI => Some("hi"),
+            ArmCC::ARM_CC_LS => Some("ls"),
+            ArmCC::ARM_CC_GE => Some("ge"),
+            ArmCC::ARM_CC_LT => Some("lt"),
+            ArmCC::ARM_CC_GT => Some("gt"),
+            ArmCC::ARM_CC_LE => Some("le"),
+            _ => None,
+        }
+    }
+    fn ambil_kode_kondisi_arm64(cc: Arm64CC) -> Option<&'static str> {
+        match cc {
+            Arm64CC::ARM64_CC_EQ => Some("eq"),
+            Arm64CC::ARM64_CC_NE => Some("ne"),
+            Arm64CC::ARM64_CC_HS => Some("hs"),
+            Arm64CC::ARM64_CC_LO => Some("lo"),
+            Arm64CC::ARM64_CC_MI => Some("mi"),
+            Arm64CC::ARM64_CC_PL => Some("pl"),
+            Arm64CC::ARM64_CC_VS => Some("vs"),
+            Arm64CC::ARM64_CC_VC => Some("vc"),
+            Arm64CC::ARM64_CC_HI => Some("hi"),
+            Arm64CC::ARM64_CC_LS => Some("ls"),
+            Arm64CC::ARM64_CC_GE => Some("ge"),
+            Arm64CC::ARM64_CC_LT => Some("lt"),
+            Arm64CC::ARM64_CC_GT => Some("gt"),
+            Arm64CC::ARM64_CC_LE => Some("le"),
+            Arm64CC::ARM64_CC_AL | Arm64CC::ARM64_CC_NV => Some("al"),
+            _ => None,
+        }
+    }
+    fn normalisasi_nama_register_arm(nama: &str) -> String {
+        match nama {
+            "sb" => "r9".to_string(),
+            "sl" => "r10".to_string(),
+            "fp" => "r11".to_string(),
+            "ip" => "r12".to_string(),
+            _ => nama.to_string(),
+        }
+    }
 }
\ No newline at end of file
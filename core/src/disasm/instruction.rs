@@ -7,30 +7,107 @@ pub enum JenisOperandDisasm {
         index: Option<String>,
         scale: i32,
         disp: i64,
+        segment: Option<String>,
     },
     Unknown,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AksesOperand {
+    #[default]
+    TidakDiketahui,
+    Baca,
+    Tulis,
+    BacaTulis,
+}
+
+impl AksesOperand {
+    pub fn is_baca(&self) -> bool {
+        matches!(self, AksesOperand::Baca | AksesOperand::BacaTulis)
+    }
+    pub fn is_tulis(&self) -> bool {
+        matches!(self, AksesOperand::Tulis | AksesOperand::BacaTulis)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeserOperand {
+    #[default]
+    Tidak,
+    Lsl(u8),
+    Lsr(u8),
+    Asr(u8),
+    Ror(u8),
+    Ekstensi { bertanda: bool, lebar: u8, geser: u8 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AtributOperand {
+    pub ukuran: u8,
+    pub akses: AksesOperand,
+    pub geser: GeserOperand,
+}
+
 #[derive(Debug, Clone)]
 pub struct InstructionNormalized {
     pub address: u64,
+    pub id_instruksi: u32,
     pub mnemonic: String,
     pub op_str: String,
     pub bytes: Vec<u8>,
     pub operands_detail: Vec<JenisOperandDisasm>,
+    pub kondisi: Option<String>,
+    pub update_flags: bool,
+    pub top_fpu: u8,
+    pub arah_mundur: bool,
+    pub atribut_operand: Vec<AtributOperand>,
+    pub prefix: Vec<String>,
+    pub register_dibaca: Vec<String>,
+    pub register_ditulis: Vec<String>,
+    pub grup: Vec<String>,
+    pub alamat_terlipat: Option<u64>,
 }
 
 impl InstructionNormalized {
     pub fn new(addr: u64, mnem: &str, ops: &str) -> Self {
         Self {
             address: addr,
+            id_instruksi: 0,
             mnemonic: mnem.to_string(),
             op_str: ops.to_string(),
             bytes: Vec::new(),
             operands_detail: Vec::new(),
+            kondisi: None,
+            update_flags: false,
+            top_fpu: 0,
+            arah_mundur: false,
+            atribut_operand: Vec::new(),
+            prefix: Vec::new(),
+            register_dibaca: Vec::new(),
+            register_ditulis: Vec::new(),
+            grup: Vec::new(),
+            alamat_terlipat: None,
         }
     }
     pub fn hitung_panjang_byte(&self) -> usize {
         self.bytes.len()
     }
-}
\ No newline at end of file
+    pub fn ambil_ukuran_operand(&self, index: usize) -> Option<u8> {
+        self.atribut_operand.get(index).map(|a| a.ukuran).filter(|&u| u > 0)
+    }
+    pub fn ambil_ukuran_utama(&self) -> Option<u8> {
+        self.atribut_operand.iter().map(|a| a.ukuran).find(|&u| u > 0)
+    }
+    pub fn ambil_akses_operand(&self, index: usize) -> AksesOperand {
+        self.atribut_operand.get(index).map(|a| a.akses).unwrap_or_default()
+    }
+    pub fn ambil_geser_operand(&self, index: usize) -> GeserOperand {
+        self.atribut_operand.get(index).map(|a| a.geser).unwrap_or_default()
+    }
+    pub fn punya_prefix(&self, nama: &str) -> bool {
+        self.prefix.iter().any(|p| p == nama)
+    }
+    pub fn punya_grup(&self, nama: &str) -> bool {
+        self.grup.iter().any(|g| g == nama)
+    }
+}
@@ -1,5 +1,6 @@
 pub mod engine;
 pub mod instruction;
+pub mod cache;
 
 use self::engine::DisasmEngine;
 
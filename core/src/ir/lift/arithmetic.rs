@@ -1,184 +1,246 @@
 use crate::disasm::instruction::InstructionNormalized;
 use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
+use crate::arch::x86::{FLAG_AF, FLAG_CF, FLAG_OF, FLAG_PF, FLAG_SF, FLAG_ZF};
 use super::IrLifter;
 use super::flow::generate_kondisi_explicit;
+use super::eflags::{self, JenisOperasiFlag, OperandFlag};
 use log::warn;
 
-const FLAG_ZF: &str = "eflags_zf";
-const FLAG_SF: &str = "eflags_sf";
-const FLAG_CF: &str = "eflags_cf";
-const FLAG_OF: &str = "eflags_of";
-const FLAG_PF: &str = "eflags_pf";
-const FLAG_AF: &str = "eflags_af";
 
 pub fn proses_push(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
     if let Some(src_op) = instr.operands_detail.first() {
-        let src = lifter.konversi_operand(src_op);
-        ops.push(StatementIr::new(
-            instr.address,
-            OperasiIr::Sub,
-            TipeOperand::Register("rsp".to_string()),
-            TipeOperand::Immediate(lifter.pointer_size),
-        ).with_type(TipeDataIr::I64));
-        ops.push(StatementIr::new(
-            instr.address,
-            OperasiIr::Mov,
-            TipeOperand::MemoryRef { base: "rsp".to_string(), offset: 0 },
-            src
-        ).with_type(TipeDataIr::I64));
+        dorong_ke_stack(lifter, instr.address, lifter.konversi_operand(src_op), ops);
     }
 }
 
 pub fn proses_pop(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
     if let Some(dst_op) = instr.operands_detail.first() {
-        let dst = lifter.konversi_operand(dst_op);
-        ops.push(StatementIr::new(
-            instr.address,
-            OperasiIr::Mov,
-            dst,
-            TipeOperand::MemoryRef { base: "rsp".to_string(), offset: 0 },
-        ).with_type(TipeDataIr::I64));
-        ops.push(StatementIr::new(
-            instr.address,
-            OperasiIr::Add,
-            TipeOperand::Register("rsp".to_string()),
-            TipeOperand::Immediate(lifter.pointer_size),
-        ).with_type(TipeDataIr::I64));
+        ambil_dari_stack(lifter, instr.address, lifter.konversi_operand(dst_op), ops);
     }
 }
 
+pub fn proses_push_flag(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    dorong_ke_stack(lifter, instr.address, TipeOperand::Register("eflags".to_string()), ops);
+}
+
+pub fn proses_pop_flag(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    ambil_dari_stack(lifter, instr.address, TipeOperand::Register("eflags".to_string()), ops);
+}
+
+fn dorong_ke_stack(lifter: &IrLifter, addr: u64, src: TipeOperand, ops: &mut Vec<StatementIr>) {
+    let sp = lifter.ambil_reg_stack().to_string();
+    ops.push(StatementIr::new(
+        addr,
+        OperasiIr::Sub,
+        TipeOperand::Register(sp.clone()),
+        TipeOperand::Immediate(lifter.pointer_size),
+    ).with_type(lifter.ambil_tipe_pointer()));
+    ops.push(StatementIr::new(
+        addr,
+        OperasiIr::Mov,
+        TipeOperand::MemoryRef { base: sp, offset: 0 },
+        src
+    ).with_type(lifter.ambil_tipe_pointer()));
+}
+
+fn ambil_dari_stack(lifter: &IrLifter, addr: u64, dst: TipeOperand, ops: &mut Vec<StatementIr>) {
+    let sp = lifter.ambil_reg_stack().to_string();
+    ops.push(StatementIr::new(
+        addr,
+        OperasiIr::Mov,
+        dst,
+        TipeOperand::MemoryRef { base: sp.clone(), offset: 0 },
+    ).with_type(lifter.ambil_tipe_pointer()));
+    ops.push(StatementIr::new(
+        addr,
+        OperasiIr::Add,
+        TipeOperand::Register(sp),
+        TipeOperand::Immediate(lifter.pointer_size),
+    ).with_type(lifter.ambil_tipe_pointer()));
+}
+
 pub fn proses_data_movement(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
     let dest = lifter.ambil_operand(instr, 0);
     let src = lifter.ambil_operand(instr, 1);
     ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dest, src));
 }
 
+pub fn proses_ekstensi(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let mnemonic = instr.mnemonic.to_lowercase();
+    let reg = |nama: &str| TipeOperand::Register(nama.to_string());
+    let (dest, src, lebar_asal) = match mnemonic.as_str() {
+        "cbw" => (reg("ax"), reg("al"), 8),
+        "cwde" => (reg("eax"), reg("ax"), 16),
+        "cdqe" => (reg("rax"), reg("eax"), 32),
+        m => {
+            let lebar = match m {
+                "sxtb" | "uxtb" => 8,
+                "sxth" | "uxth" => 16,
+                "sxtw" | "uxtw" => 32,
+                _ => lifter.ambil_lebar_bit(instr, 1),
+            };
+            (lifter.ambil_operand(instr, 0), lifter.ambil_operand(instr, 1), lebar)
+        },
+    };
+    let bertanda = matches!(mnemonic.as_str(), "cbw" | "cwde" | "cdqe") || mnemonic.starts_with("movs") || mnemonic.starts_with("sxt");
+    let op = if bertanda { OperasiIr::SignExtend } else { OperasiIr::ZeroExtend };
+    let nilai = eflags::buat_expr(op, src, TipeOperand::Immediate(lebar_asal as i64));
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dest, nilai));
+}
+
 pub fn proses_lea(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
     let dest = lifter.ambil_operand(instr, 0);
     let src = lifter.ambil_operand(instr, 1);
     ops.push(StatementIr::new(instr.address, OperasiIr::Lea, dest, src));
 }
 
-pub fn proses_arithmetic_extended(
-    lifter: &IrLifter,
-    instr: &InstructionNormalized,
-    ops: &mut Vec<StatementIr>
-) {
-    let mnemonic = instr.mnemonic.to_lowercase();
-    match mnemonic.as_str() {
-        "mul" | "imul" => proses_multiplication(lifter, instr, mnemonic.as_str(), ops),
-        "div" | "idiv" => proses_division(lifter, instr, mnemonic.as_str(), ops),
-        "adc" => proses_add_with_carry(lifter, instr, ops),
-        "sbb" => proses_sub_with_borrow(lifter, instr, ops),
-        "inc" => proses_inc_dec(lifter, instr, OperasiIr::Inc, ops),
-        "dec" => proses_inc_dec(lifter, instr, OperasiIr::Dec, ops),
-        _ => warn!("Unhandled extended arithmetic: {}", mnemonic),
+fn register_implisit(lebar: u32) -> (&'static str, &'static str) {
+    match lebar {
+        8 => ("al", "ah"),
+        16 => ("ax", "dx"),
+        32 => ("eax", "edx"),
+        _ => ("rax", "rdx"),
     }
 }
 
-fn proses_inc_dec(lifter: &IrLifter, instr: &InstructionNormalized, op: OperasiIr, ops: &mut Vec<StatementIr>) {
-    let dest = lifter.ambil_operand(instr, 0);
-    let delta = if op == OperasiIr::Inc { 1 } else { -1 };
-    let res_expr = TipeOperand::Expression {
-        operasi: OperasiIr::Add,
-        operand_kiri: Box::new(dest.clone()),
-        operand_kanan: Box::new(TipeOperand::Immediate(delta)),
-    };
-    ops.push(StatementIr::new(instr.address, op.clone(), dest.clone(), res_expr).with_type(TipeDataIr::I64));
-    hitung_flag_zero(instr.address, &dest, ops);
-    hitung_flag_sign(instr.address, &dest, ops);
-    hitung_flag_parity(instr.address, &dest, ops);
-    hitung_flag_overflow(instr.address, op, &dest, &TipeOperand::Immediate(1), &dest, ops);
-    emit_mov_flag(instr.address, FLAG_AF, TipeOperand::Register("undefined".to_string()), ops);
-}
-
-fn proses_multiplication(lifter: &IrLifter, instr: &InstructionNormalized, mnemonic: &str, ops: &mut Vec<StatementIr>) {
-    let is_signed = mnemonic == "imul";
+pub fn proses_multiplication(lifter: &IrLifter, instr: &InstructionNormalized, is_signed: bool, ops: &mut Vec<StatementIr>) {
     let op_code = if is_signed { OperasiIr::Imul } else { OperasiIr::Mul };
     if instr.operands_detail.len() == 1 {
         let src = lifter.ambil_operand(instr, 0);
-        let rax = TipeOperand::Register("rax".to_string());
-        let rdx = TipeOperand::Register("rdx".to_string());
-        let mul_expr = TipeOperand::Expression {
-            operasi: op_code.clone(),
-            operand_kiri: Box::new(rax.clone()),
-            operand_kanan: Box::new(src.clone()),
-        };
-        ops.push(StatementIr::new(instr.address, op_code.clone(), rax.clone(), mul_expr));
-        let mul_hi_expr = TipeOperand::Expression {
-            operasi: OperasiIr::MulHi,
-            operand_kiri: Box::new(rax.clone()),
-            operand_kanan: Box::new(src.clone()),
+        let lebar = lifter.ambil_lebar_bit(instr, 0);
+        let (nama_akumulator, nama_data) = register_implisit(lebar);
+        let akumulator = TipeOperand::Register(nama_akumulator.to_string());
+        let data = TipeOperand::Register(nama_data.to_string());
+        let tipe = IrLifter::tipe_dari_ukuran((lebar / 8) as u8).unwrap_or(TipeDataIr::I64);
+        if lebar == 8 {
+            let ekstensi = if is_signed { OperasiIr::SignExtend } else { OperasiIr::ZeroExtend };
+            let kali = eflags::buat_expr(
+                op_code.clone(),
+                eflags::buat_expr(ekstensi.clone(), akumulator.clone(), TipeOperand::Immediate(8)),
+                eflags::buat_expr(ekstensi, src, TipeOperand::Immediate(8)),
+            );
+            ops.push(StatementIr::new(instr.address, op_code, TipeOperand::Register("ax".to_string()), kali).with_type(TipeDataIr::I16));
+        } else {
+            let temp_tinggi = TipeOperand::Register("temp_kali_tinggi".to_string());
+            let op_tinggi = if is_signed { OperasiIr::MulHi } else { OperasiIr::MulHiU };
+            let mul_hi_expr = eflags::buat_expr(op_tinggi.clone(), akumulator.clone(), src.clone());
+            ops.push(StatementIr::new(instr.address, op_tinggi, temp_tinggi.clone(), mul_hi_expr).with_type(tipe.clone()));
+            let mul_expr = eflags::buat_expr(op_code.clone(), akumulator.clone(), src);
+            ops.push(StatementIr::new(instr.address, op_code, akumulator.clone(), mul_expr).with_type(tipe.clone()));
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, data.clone(), temp_tinggi).with_type(tipe));
+        }
+        let pembanding = if is_signed {
+            eflags::buat_expr(OperasiIr::Sar, akumulator, TipeOperand::Immediate((lebar - 1) as i64))
+        } else {
+            TipeOperand::Immediate(0)
         };
-        ops.push(StatementIr::new(instr.address, OperasiIr::MulHi, rdx.clone(), mul_hi_expr));
-        emit_mov_flag(instr.address, FLAG_CF, TipeOperand::Register("undefined".to_string()), ops);
-        emit_mov_flag(instr.address, FLAG_OF, TipeOperand::Register("undefined".to_string()), ops);
+        let tidak_muat = eflags::buat_expr(OperasiIr::Jne, data, pembanding);
+        emit_mov_flag(instr.address, FLAG_CF, tidak_muat.clone(), ops);
+        emit_mov_flag(instr.address, FLAG_OF, tidak_muat, ops);
+        for flag in [FLAG_SF, FLAG_ZF, FLAG_PF, FLAG_AF] {
+            emit_mov_flag(instr.address, flag, eflags::flag_undefined(), ops);
+        }
 
     } else if instr.operands_detail.len() >= 2 {
         let dest = lifter.ambil_operand(instr, 0);
+        let lebar = lifter.ambil_lebar_bit(instr, 0);
         let (src1, src2) = if instr.operands_detail.len() == 3 {
              (lifter.ambil_operand(instr, 1), lifter.ambil_operand(instr, 2))
         } else {
              (dest.clone(), lifter.ambil_operand(instr, 1))
         };
+        let (kiri, kanan) = amankan_sumber(instr.address, &dest, &src1, &src2, lebar, ops);
         let expr = TipeOperand::Expression {
             operasi: op_code,
             operand_kiri: Box::new(src1),
             operand_kanan: Box::new(src2),
         };
-        ops.push(StatementIr::new(instr.address, OperasiIr::Imul, dest, expr));
+        ops.push(StatementIr::new(instr.address, OperasiIr::Imul, dest.clone(), expr));
+        eflags::hitung_flags(instr.address, JenisOperasiFlag::Imul, &OperandFlag::new(dest, kiri, kanan, lebar), ops);
     }
 }
 
-fn proses_division(lifter: &IrLifter, instr: &InstructionNormalized, mnemonic: &str, ops: &mut Vec<StatementIr>) {
-    let is_signed = mnemonic == "idiv";
+pub fn proses_division(lifter: &IrLifter, instr: &InstructionNormalized, is_signed: bool, ops: &mut Vec<StatementIr>) {
     let op_code = if is_signed { OperasiIr::Idiv } else { OperasiIr::Div };
     let src = lifter.ambil_operand(instr, 0);
-    let rax = TipeOperand::Register("rax".to_string());
-    let rdx = TipeOperand::Register("rdx".to_string());
-    let div_expr = TipeOperand::Expression {
-        operasi: op_code.clone(),
-        operand_kiri: Box::new(rax.clone()),
-        operand_kanan: Box::new(src.clone()),
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let (nama_hasil, nama_sisa) = register_implisit(lebar);
+    let hasil = TipeOperand::Register(nama_hasil.to_string());
+    let sisa = TipeOperand::Register(nama_sisa.to_string());
+    let tipe = IrLifter::tipe_dari_ukuran((lebar / 8) as u8).unwrap_or(TipeDataIr::I64);
+    let dividen = TipeOperand::Register("temp_dividen".to_string());
+    let (nilai_dividen, tipe_dividen) = if lebar == 8 {
+        (TipeOperand::Register("ax".to_string()), TipeDataIr::I16)
+    } else {
+        let gabung = OperasiIr::Intrinsic(format!("__concat{}", lebar * 2));
+        let tipe_ganda = if lebar == 64 { TipeDataIr::I128 } else { IrLifter::tipe_dari_ukuran((lebar / 4) as u8).unwrap_or(TipeDataIr::I64) };
+        (eflags::buat_expr(gabung, sisa.clone(), hasil.clone()), tipe_ganda)
     };
-    ops.push(StatementIr::new(instr.address, op_code.clone(), rax.clone(), div_expr));
-    ops.push(StatementIr::new(instr.address, OperasiIr::Unknown, rdx, TipeOperand::None));
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dividen.clone(), nilai_dividen).with_type(tipe_dividen));
+    let hasil_bagi = TipeOperand::Register("temp_hasil_bagi".to_string());
+    let div_expr = eflags::buat_expr(op_code.clone(), dividen.clone(), src.clone());
+    ops.push(StatementIr::new(instr.address, op_code, hasil_bagi.clone(), div_expr).with_type(tipe.clone()));
+    let sisa_expr = eflags::buat_expr(OperasiIr::Sub, dividen, eflags::buat_expr(OperasiIr::Imul, hasil_bagi.clone(), src));
+    ops.push(StatementIr::new(instr.address, OperasiIr::Sub, sisa, sisa_expr).with_type(tipe.clone()));
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, hasil, hasil_bagi).with_type(tipe));
+    for flag in [FLAG_CF, FLAG_OF, FLAG_SF, FLAG_ZF, FLAG_PF, FLAG_AF] {
+        emit_mov_flag(instr.address, flag, eflags::flag_undefined(), ops);
+    }
 }
 
-fn proses_add_with_carry(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
-    let dest = lifter.ambil_operand(instr, 0);
-    let src = lifter.ambil_operand(instr, 1);
-    let cf = TipeOperand::Register(FLAG_CF.to_string());
-    let tmp = TipeOperand::Expression {
-        operasi: OperasiIr::Add,
-        operand_kiri: Box::new(dest.clone()),
-        operand_kanan: Box::new(src.clone()),
-    };
-    let res = TipeOperand::Expression {
-        operasi: OperasiIr::Add,
-        operand_kiri: Box::new(tmp),
-        operand_kanan: Box::new(cf),
+pub fn proses_tukar(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let op1 = lifter.ambil_operand(instr, 0);
+    let op2 = lifter.ambil_operand(instr, 1);
+    let tipe = IrLifter::tipe_dari_ukuran((lifter.ambil_lebar_bit(instr, 0) / 8) as u8).unwrap_or(TipeDataIr::I64);
+    let temp = TipeOperand::Register("temp_swap".to_string());
+    let (mem, reg) = match (adalah_memori(&op1), adalah_memori(&op2)) {
+        (true, _) => (op1, op2),
+        (_, true) => (op2, op1),
+        _ => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, temp.clone(), op1.clone()).with_type(tipe.clone()));
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, op1, op2.clone()).with_type(tipe.clone()));
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, op2, temp).with_type(tipe));
+            return;
+        }
     };
-    ops.push(StatementIr::new(instr.address, OperasiIr::Adc, dest.clone(), res).with_type(TipeDataIr::I64));
-    hitung_flags_general(instr.address, &dest, ops);
+    ops.push(StatementIr::new(instr.address, OperasiIr::AtomicLoad, temp.clone(), mem.clone()).with_type(tipe.clone()));
+    ops.push(StatementIr::new(instr.address, OperasiIr::AtomicXchg, mem, reg.clone()).with_type(tipe.clone()));
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, reg, temp).with_type(tipe));
 }
 
-fn proses_sub_with_borrow(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+pub fn proses_tukar_banding(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
     let dest = lifter.ambil_operand(instr, 0);
     let src = lifter.ambil_operand(instr, 1);
-    let cf = TipeOperand::Register(FLAG_CF.to_string());
-    let tmp = TipeOperand::Expression {
-        operasi: OperasiIr::Sub,
-        operand_kiri: Box::new(dest.clone()),
-        operand_kanan: Box::new(src.clone()),
-    };
-    let res = TipeOperand::Expression {
-        operasi: OperasiIr::Sub,
-        operand_kiri: Box::new(tmp),
-        operand_kanan: Box::new(cf),
-    };
-    ops.push(StatementIr::new(instr.address, OperasiIr::Sbb, dest.clone(), res).with_type(TipeDataIr::I64));
-    hitung_flags_general(instr.address, &dest, ops);
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let tipe = IrLifter::tipe_dari_ukuran((lebar / 8) as u8).unwrap_or(TipeDataIr::I64);
+    let acc = TipeOperand::Register(register_implisit(lebar).0.to_string());
+    let lama = TipeOperand::Register("temp_cmpxchg".to_string());
+    let temp_result = TipeOperand::Register("temp_alu_flags".to_string());
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lama.clone(), dest.clone()).with_type(tipe.clone()));
+    ops.push(StatementIr::new(
+        instr.address,
+        OperasiIr::Cmp,
+        temp_result.clone(),
+        eflags::buat_expr(OperasiIr::Sub, acc.clone(), lama.clone())
+    ));
+    eflags::hitung_flags(instr.address, JenisOperasiFlag::Sub, &OperandFlag::new(temp_result, acc.clone(), lama.clone(), lebar), ops);
+    if adalah_memori(&dest) {
+        let mut cas = StatementIr::new(instr.address, OperasiIr::AtomicCas, dest, src).with_type(tipe.clone());
+        cas.operand_tambahan.push(acc.clone());
+        ops.push(cas);
+    } else {
+        let nilai = TipeOperand::Conditional {
+            condition: Box::new(eflags::buat_expr(OperasiIr::Jne, TipeOperand::Register(FLAG_ZF.to_string()), TipeOperand::Immediate(0))),
+            true_val: Box::new(src),
+            false_val: Box::new(lama.clone()),
+        };
+        ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dest, nilai).with_type(tipe.clone()));
+    }
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, acc, lama).with_type(tipe));
+}
+
+fn adalah_memori(op: &TipeOperand) -> bool {
+    matches!(op, TipeOperand::Memory(_) | TipeOperand::MemoryRef { .. } | TipeOperand::MemoryComplex { .. })
 }
 
 pub fn proses_arithmetic_explicit(
@@ -187,36 +249,54 @@ pub fn proses_arithmetic_explicit(
     op_code: OperasiIr, 
     ops: &mut Vec<StatementIr>
 ) {
-    match instr.mnemonic.to_lowercase().as_str() {
-        "mul" | "imul" | "div" | "idiv" | "adc" | "sbb" | "inc" | "dec" => {
-            proses_arithmetic_extended(lifter, instr, ops);
-            return;
-        }
-        _ => {}
-    }
     let dest = lifter.ambil_operand(instr, 0);
     let src = lifter.ambil_operand(instr, 1);
-    let (actual_op1, actual_op2) = (dest.clone(), src);
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let jenis_flag = JenisOperasiFlag::dari_operasi(&op_code);
+    let (kiri, kanan) = if jenis_flag.is_some_and(|j| j != JenisOperasiFlag::Logika) {
+        amankan_sumber(instr.address, &dest, &dest, &src, lebar, ops)
+    } else {
+        (dest.clone(), src.clone())
+    };
     let result_expr = TipeOperand::Expression {
         operasi: op_code.clone(),
-        operand_kiri: Box::new(actual_op1.clone()),
-        operand_kanan: Box::new(actual_op2.clone())
+        operand_kiri: Box::new(dest.clone()),
+        operand_kanan: Box::new(src)
     };
     ops.push(StatementIr::new(
         instr.address,
         op_code.clone(),
         dest.clone(),
         result_expr
-    ).with_type(TipeDataIr::I64));
-    match op_code {
-        OperasiIr::Add => hitung_flags_add(instr.address, &dest, &actual_op1, &actual_op2, ops),
-        OperasiIr::Sub => hitung_flags_sub(instr.address, &dest, &actual_op1, &actual_op2, ops),
-        OperasiIr::And | OperasiIr::Or | OperasiIr::Xor => hitung_flags_logical(instr.address, &dest, ops),
-        OperasiIr::Shl | OperasiIr::Shr => hitung_flags_shift(instr.address, &dest, &actual_op1, &actual_op2, ops),
-        _ => {}
+    ).with_type(IrLifter::tipe_dari_ukuran((lebar / 8) as u8).unwrap_or(TipeDataIr::I64)));
+    if let Some(jenis) = jenis_flag {
+        eflags::hitung_flags(instr.address, jenis, &OperandFlag::new(dest, kiri, kanan, lebar), ops);
     }
 }
 
+pub(super) fn amankan_sumber(
+    addr: u64,
+    dest: &TipeOperand,
+    src1: &TipeOperand,
+    src2: &TipeOperand,
+    lebar: u32,
+    ops: &mut Vec<StatementIr>
+) -> (TipeOperand, TipeOperand) {
+    let kiri = if src1 == dest {
+        eflags::salin_operand_asal(addr, src1, "temp_flag_kiri", lebar, ops)
+    } else {
+        src1.clone()
+    };
+    let kanan = if src2 == src1 {
+        kiri.clone()
+    } else if src2 == dest {
+        eflags::salin_operand_asal(addr, src2, "temp_flag_kanan", lebar, ops)
+    } else {
+        src2.clone()
+    };
+    (kiri, kanan)
+}
+
 pub fn proses_comparison_explicit(
     lifter: &IrLifter, 
     instr: &InstructionNormalized, 
@@ -225,28 +305,21 @@ pub fn proses_comparison_explicit(
 ) {
     let op1 = lifter.ambil_operand(instr, 0);
     let op2 = lifter.ambil_operand(instr, 1);
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
     let temp_result = TipeOperand::Register("temp_alu_flags".to_string());
-    match op_code {
-        OperasiIr::Sub => {
-             ops.push(StatementIr::new(
-                instr.address, 
-                OperasiIr::Cmp, 
-                temp_result.clone(), 
-                TipeOperand::Expression { operasi: OperasiIr::Sub, operand_kiri: Box::new(op1.clone()), operand_kanan: Box::new(op2.clone()) }
-            ));
-            hitung_flags_sub(instr.address, &temp_result, &op1, &op2, ops);
-        },
-        OperasiIr::And => {
-            ops.push(StatementIr::new(
-                instr.address, 
-                OperasiIr::Test, 
-                temp_result.clone(), 
-                TipeOperand::Expression { operasi: OperasiIr::And, operand_kiri: Box::new(op1.clone()), operand_kanan: Box::new(op2.clone()) }
-            ));
-            hitung_flags_logical(instr.address, &temp_result, ops);
-        },
-        _ => {}
-    }
+    let (op_stmt, jenis) = match op_code {
+        OperasiIr::Sub => (OperasiIr::Cmp, JenisOperasiFlag::Sub),
+        OperasiIr::Add => (OperasiIr::Cmp, JenisOperasiFlag::Add),
+        OperasiIr::And | OperasiIr::Xor => (OperasiIr::Test, JenisOperasiFlag::Logika),
+        _ => return,
+    };
+    ops.push(StatementIr::new(
+        instr.address,
+        op_stmt,
+        temp_result.clone(),
+        TipeOperand::Expression { operasi: op_code, operand_kiri: Box::new(op1.clone()), operand_kanan: Box::new(op2.clone()) }
+    ));
+    eflags::hitung_flags(instr.address, jenis, &OperandFlag::new(temp_result, op1, op2, lebar), ops);
 }
 
 pub fn proses_generic_unknown(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
@@ -256,92 +329,6 @@ pub fn proses_generic_unknown(lifter: &IrLifter, instr: &InstructionNormalized,
     ops.push(StatementIr::new(instr.address, OperasiIr::Unknown, op1, op2));
 }
 
-fn hitung_flags_general(addr: u64, result: &TipeOperand, ops: &mut Vec<StatementIr>) {
-    hitung_flag_zero(addr, result, ops);
-    hitung_flag_sign(addr, result, ops);
-    hitung_flag_parity(addr, result, ops); 
-}
-
-fn hitung_flags_logical(addr: u64, result: &TipeOperand, ops: &mut Vec<StatementIr>) {
-    emit_mov_flag(addr, FLAG_CF, TipeOperand::Immediate(0), ops);
-    emit_mov_flag(addr, FLAG_OF, TipeOperand::Immediate(0), ops);
-    hitung_flag_zero(addr, result, ops);
-    hitung_flag_sign(addr, result, ops);
-    hitung_flag_parity(addr, result, ops);
-    emit_mov_flag(addr, FLAG_AF, TipeOperand::Register("undefined".to_string()), ops);
-}
-
-fn hitung_flags_add(addr: u64, result: &TipeOperand, op1: &TipeOperand, op2: &TipeOperand, ops: &mut Vec<StatementIr>) {
-    hitung_flag_zero(addr, result, ops);
-    hitung_flag_sign(addr, result, ops);
-    hitung_flag_parity(addr, result, ops);
-    let check_cf = TipeOperand::Expression {
-        operasi: OperasiIr::Jl,
-        operand_kiri: Box::new(result.clone()),
-        operand_kanan: Box::new(op1.clone()),
-    };
-    emit_mov_flag(addr, FLAG_CF, check_cf, ops);
-    hitung_flag_overflow(addr, OperasiIr::Add, result, op1, op2, ops);
-}
-
-fn hitung_flags_sub(addr: u64, result: &TipeOperand, op1: &TipeOperand, op2: &TipeOperand, ops: &mut Vec<StatementIr>) {
-    hitung_flag_zero(addr, result, ops);
-    hitung_flag_sign(addr, result, ops);
-    hitung_flag_parity(addr, result, ops);
-    let check_cf = TipeOperand::Expression {
-        operasi: OperasiIr::Jl, 
-        operand_kiri: Box::new(op1.clone()),
-        operand_kanan: Box::new(op2.clone()),
-    };
-    emit_mov_flag(addr, FLAG_CF, check_cf, ops);
-    hitung_flag_overflow(addr, OperasiIr::Sub, result, op1, op2, ops);
-}
-
-fn hitung_flag_overflow(addr: u64, _op: OperasiIr, _result: &TipeOperand, _op1: &TipeOperand, _op2: &TipeOperand, ops: &mut Vec<StatementIr>) {
-    emit_mov_flag(addr, FLAG_OF, TipeOperand::Register("calc_overflow_deferred".to_string()), ops);
-}
-
-fn hitung_flags_shift(addr: u64, result: &TipeOperand, _op1: &TipeOperand, _op2: &TipeOperand, ops: &mut Vec<StatementIr>) {
-    hitung_flag_zero(addr, result, ops);
-    hitung_flag_sign(addr, result, ops);
-    hitung_flag_parity(addr, result, ops);
-}
-
-fn hitung_flag_zero(addr: u64, result: &TipeOperand, ops: &mut Vec<StatementIr>) {
-    let cond = make_binary_expr(OperasiIr::Je, result.clone(), TipeOperand::Immediate(0));
-    let val = TipeOperand::Conditional {
-        condition: Box::new(cond),
-        true_val: Box::new(TipeOperand::Immediate(1)),
-        false_val: Box::new(TipeOperand::Immediate(0)),
-    };
-    emit_mov_flag(addr, FLAG_ZF, val, ops);
-}
-
-fn hitung_flag_sign(addr: u64, result: &TipeOperand, ops: &mut Vec<StatementIr>) {
-    let shift = make_binary_expr(OperasiIr::Shr, result.clone(), TipeOperand::Immediate(63));
-    let mask = make_binary_expr(OperasiIr::And, shift, TipeOperand::Immediate(1));
-    emit_mov_flag(addr, FLAG_SF, mask, ops);
-}
-
-fn hitung_flag_parity(addr: u64, result: &TipeOperand, ops: &mut Vec<StatementIr>) {
-    let mask_byte = make_binary_expr(OperasiIr::And, result.clone(), TipeOperand::Immediate(0xFF));
-    let temp_pop = TipeOperand::Register("temp_popcnt".to_string());
-    ops.push(StatementIr::new(
-        addr,
-        OperasiIr::Popcnt,
-        temp_pop.clone(),
-        mask_byte
-    ).with_type(TipeDataIr::I8));
-    let bit_check = make_binary_expr(OperasiIr::And, temp_pop, TipeOperand::Immediate(1));
-    let is_even = make_binary_expr(OperasiIr::Je, bit_check, TipeOperand::Immediate(0));
-    let pf_val = TipeOperand::Conditional {
-        condition: Box::new(is_even),
-        true_val: Box::new(TipeOperand::Immediate(1)),
-        false_val: Box::new(TipeOperand::Immediate(0)),
-    };
-    emit_mov_flag(addr, FLAG_PF, pf_val, ops);
-}
-
 fn emit_mov_flag(addr: u64, flag_reg: &str, val: TipeOperand, ops: &mut Vec<StatementIr>) {
     ops.push(StatementIr::new(
         addr,
@@ -351,14 +338,6 @@ fn emit_mov_flag(addr: u64, flag_reg: &str, val: TipeOperand, ops: &mut Vec<Stat
     ).with_type(TipeDataIr::I8));
 }
 
-fn make_binary_expr(op: OperasiIr, left: TipeOperand, right: TipeOperand) -> TipeOperand {
-    TipeOperand::Expression {
-        operasi: op,
-        operand_kiri: Box::new(left),
-        operand_kanan: Box::new(right),
-    }
-}
-
 pub fn proses_conditional_move(
     lifter: &IrLifter, 
     instr: &InstructionNormalized, 
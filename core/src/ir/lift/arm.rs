@@ -0,0 +1,332 @@
+use crate::arch::arm64::{FLAG_N, FLAG_Z};
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
+use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
+use super::IrLifter;
+use super::{arithmetic, flow};
+use super::arm64::{kondisi_nzcv, nilai_alu, nilai_nzcv, JenisAlu};
+use super::eflags::{self, OperandFlag};
+
+pub fn proses_instruksi_arm32(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let mnemonic = instr.mnemonic.to_lowercase();
+    let mnem = mnemonic.as_str();
+    if let Some(kondisi) = instr.kondisi.as_deref()
+        && mnem == "b"
+    {
+        ops.push(StatementIr::new(
+            instr.address,
+            flow::ambil_operasi_branch(kondisi),
+            lifter.ambil_operand(instr, 0),
+            kondisi_nzcv(kondisi),
+        ));
+        return;
+    }
+    let mut body = Vec::new();
+    proses_body_arm32(lifter, instr, mnem, &mut body);
+    match instr.kondisi.as_deref() {
+        Some(kondisi) => terapkan_predikat(instr.address, kondisi, body, ops),
+        None => ops.extend(body),
+    }
+}
+
+pub fn is_blok_it(mnem: &str) -> bool {
+    mnem.starts_with("it") && mnem[2..].chars().all(|c| c == 't' || c == 'e')
+}
+
+fn proses_body_arm32(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    match mnem {
+        "nop" => {},
+        _ if is_blok_it(mnem) => {},
+        "b" => flow::proses_unconditional_jump(lifter, instr, ops),
+        "bl" | "blx" => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Call, lifter.ambil_operand(instr, 0), TipeOperand::None));
+        },
+        "bx" => {
+            let target = lifter.ambil_operand(instr, 0);
+            if target == TipeOperand::Register("lr".to_string()) {
+                ops.push(StatementIr::new(instr.address, OperasiIr::Ret, TipeOperand::None, TipeOperand::None));
+            } else {
+                ops.push(StatementIr::new(instr.address, OperasiIr::Jmp, target, TipeOperand::None));
+            }
+        },
+        "cbz" | "cbnz" => flow::proses_conditional_branch(lifter, instr, mnem, ops),
+        "push" => proses_push_daftar(instr, ops),
+        "pop" => proses_pop_daftar(instr, ops),
+        "mov" | "mvn" | "movw" | "movt" => proses_mov_arm32(lifter, instr, mnem, ops),
+        "cmp" => proses_banding_arm32(lifter, instr, JenisAlu::Sub, ops),
+        "cmn" => proses_banding_arm32(lifter, instr, JenisAlu::Add, ops),
+        "tst" => proses_banding_arm32(lifter, instr, JenisAlu::And, ops),
+        "teq" => proses_banding_arm32(lifter, instr, JenisAlu::Eor, ops),
+        "ldr" | "ldrb" | "ldrh" | "ldrsb" | "ldrsh" | "ldrex" => proses_load_store(lifter, instr, mnem, true, ops),
+        "str" | "strb" | "strh" => proses_load_store(lifter, instr, mnem, false, ops),
+        "uxtb" | "uxth" | "sxtb" | "sxth" => {
+            let op = if mnem.starts_with('s') { OperasiIr::SignExtend } else { OperasiIr::ZeroExtend };
+            let lebar_asal = if mnem.ends_with('b') { 8 } else { 16 };
+            let expr = buat_expr(op, lifter.ambil_operand(instr, 1), TipeOperand::Immediate(lebar_asal));
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, 0), expr).with_type(TipeDataIr::I32));
+        },
+        "svc" => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Syscall, lifter.ambil_operand(instr, 0), TipeOperand::None));
+        },
+        _ => match ambil_operasi_biner(mnem) {
+            Some(op_ir) => proses_biner_arm32(lifter, instr, mnem, op_ir, ops),
+            None => arithmetic::proses_generic_unknown(lifter, instr, ops),
+        },
+    }
+}
+
+fn ambil_operasi_biner(mnem: &str) -> Option<OperasiIr> {
+    let op_ir = match mnem {
+        "add" => OperasiIr::Add,
+        "sub" | "rsb" => OperasiIr::Sub,
+        "and" | "bic" => OperasiIr::And,
+        "orr" => OperasiIr::Or,
+        "eor" => OperasiIr::Xor,
+        "mul" => OperasiIr::Imul,
+        "lsl" => OperasiIr::Shl,
+        "lsr" => OperasiIr::Shr,
+        "asr" => OperasiIr::Sar,
+        "ror" => OperasiIr::Ror,
+        "adc" => OperasiIr::Adc,
+        "sbc" => OperasiIr::Sbb,
+        "sdiv" => OperasiIr::Idiv,
+        "udiv" => OperasiIr::Div,
+        _ => return None,
+    };
+    Some(op_ir)
+}
+
+fn proses_biner_arm32(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, op_ir: OperasiIr, ops: &mut Vec<StatementIr>) {
+    let dest = lifter.ambil_operand(instr, 0);
+    let (kiri, kanan) = if instr.operands_detail.len() >= 3 {
+        (lifter.ambil_operand(instr, 1), lifter.ambil_operand(instr, 2))
+    } else {
+        (dest.clone(), lifter.ambil_operand(instr, 1))
+    };
+    let (kiri, kanan) = if mnem == "rsb" { (kanan, kiri) } else { (kiri, kanan) };
+    let jenis = ambil_jenis_alu(mnem);
+    let (kiri_flag, kanan_flag) = if instr.update_flags && jenis.is_some_and(is_aritmetika) {
+        arithmetic::amankan_sumber(instr.address, &dest, &kiri, &kanan, 32, ops)
+    } else {
+        (kiri.clone(), kanan.clone())
+    };
+    let (op_ir, expr) = match jenis {
+        Some(jenis) => nilai_alu(jenis, kiri, kanan),
+        None => (op_ir.clone(), buat_expr(op_ir, kiri, kanan)),
+    };
+    ops.push(StatementIr::new(instr.address, op_ir, dest.clone(), expr).with_type(TipeDataIr::I32));
+    if instr.update_flags {
+        tulis_flags_nzcv(instr.address, jenis, &OperandFlag::new(dest, kiri_flag, kanan_flag, 32), ops);
+    }
+}
+
+fn ambil_jenis_alu(mnem: &str) -> Option<JenisAlu> {
+    let jenis = match mnem {
+        "add" => JenisAlu::Add,
+        "adc" => JenisAlu::Adc,
+        "sub" | "rsb" => JenisAlu::Sub,
+        "sbc" => JenisAlu::Sbc,
+        "and" => JenisAlu::And,
+        "orr" => JenisAlu::Orr,
+        "eor" => JenisAlu::Eor,
+        "bic" => JenisAlu::Bic,
+        _ => return None,
+    };
+    Some(jenis)
+}
+
+fn is_aritmetika(jenis: JenisAlu) -> bool {
+    matches!(jenis, JenisAlu::Add | JenisAlu::Adc | JenisAlu::Sub | JenisAlu::Sbc)
+}
+
+fn proses_banding_arm32(lifter: &IrLifter, instr: &InstructionNormalized, jenis: JenisAlu, ops: &mut Vec<StatementIr>) {
+    let kiri = lifter.ambil_operand(instr, 0);
+    let kanan = lifter.ambil_operand(instr, 1);
+    let (_, nilai) = nilai_alu(jenis, kiri.clone(), kanan.clone());
+    let temp_result = TipeOperand::Register("temp_alu_flags".to_string());
+    let op_stmt = if is_aritmetika(jenis) { OperasiIr::Cmp } else { OperasiIr::Test };
+    ops.push(StatementIr::new(instr.address, op_stmt, temp_result.clone(), nilai).with_type(TipeDataIr::I32));
+    tulis_flags_nzcv(instr.address, Some(jenis), &OperandFlag::new(temp_result, kiri, kanan, 32), ops);
+}
+
+fn tulis_flags_nzcv(addr: u64, jenis: Option<JenisAlu>, o: &OperandFlag, ops: &mut Vec<StatementIr>) {
+    match jenis {
+        Some(jenis) if is_aritmetika(jenis) => {
+            for (flag, nilai) in nilai_nzcv(jenis, o) {
+                eflags::tulis_flag(addr, flag, nilai, ops);
+            }
+        },
+        _ => {
+            eflags::tulis_flag(addr, FLAG_N, eflags::nilai_sign(o), ops);
+            eflags::tulis_flag(addr, FLAG_Z, eflags::nilai_zero(o), ops);
+        },
+    }
+}
+
+fn proses_mov_arm32(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let dest = lifter.ambil_operand(instr, 0);
+    let src = lifter.ambil_operand(instr, 1);
+    if dest == TipeOperand::Register("pc".to_string()) {
+        if src == TipeOperand::Register("lr".to_string()) {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Ret, TipeOperand::None, TipeOperand::None));
+        } else {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Jmp, src, TipeOperand::None));
+        }
+        return;
+    }
+    let nilai = match (mnem, &src) {
+        ("mvn", _) => buat_expr(OperasiIr::Xor, src, TipeOperand::Immediate(-1)),
+        ("movt", TipeOperand::Immediate(imm)) => buat_expr(
+            OperasiIr::Or,
+            buat_expr(OperasiIr::And, dest.clone(), TipeOperand::Immediate(0xffff)),
+            TipeOperand::Immediate((imm & 0xffff) << 16),
+        ),
+        _ => src,
+    };
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dest.clone(), nilai).with_type(TipeDataIr::I32));
+    if instr.update_flags {
+        tulis_flags_nzcv(instr.address, None, &OperandFlag::new(dest.clone(), dest, TipeOperand::Immediate(0), 32), ops);
+    }
+}
+
+fn ambil_daftar_register(instr: &InstructionNormalized) -> Vec<String> {
+    instr.operands_detail.iter()
+        .filter_map(|op| match op {
+            JenisOperandDisasm::Register(r) => Some(r.to_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn proses_push_daftar(instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let daftar = ambil_daftar_register(instr);
+    let sp = TipeOperand::Register("sp".to_string());
+    ops.push(StatementIr::new(
+        instr.address,
+        OperasiIr::Sub,
+        sp,
+        TipeOperand::Immediate(4 * daftar.len() as i64),
+    ).with_type(TipeDataIr::I32));
+    for (idx, reg) in daftar.into_iter().enumerate() {
+        ops.push(StatementIr::new(
+            instr.address,
+            OperasiIr::Mov,
+            TipeOperand::MemoryRef { base: "sp".to_string(), offset: 4 * idx as i64 },
+            TipeOperand::Register(reg),
+        ).with_type(TipeDataIr::I32));
+    }
+}
+
+fn proses_pop_daftar(instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let daftar = ambil_daftar_register(instr);
+    let mut is_return = false;
+    for (idx, reg) in daftar.iter().enumerate() {
+        if reg == "pc" {
+            is_return = true;
+            continue;
+        }
+        ops.push(StatementIr::new(
+            instr.address,
+            OperasiIr::Mov,
+            TipeOperand::Register(reg.clone()),
+            TipeOperand::MemoryRef { base: "sp".to_string(), offset: 4 * idx as i64 },
+        ).with_type(TipeDataIr::I32));
+    }
+    ops.push(StatementIr::new(
+        instr.address,
+        OperasiIr::Add,
+        TipeOperand::Register("sp".to_string()),
+        TipeOperand::Immediate(4 * daftar.len() as i64),
+    ).with_type(TipeDataIr::I32));
+    if is_return {
+        ops.push(StatementIr::new(instr.address, OperasiIr::Ret, TipeOperand::None, TipeOperand::None));
+    }
+}
+
+fn proses_load_store(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, is_load: bool, ops: &mut Vec<StatementIr>) {
+    let reg = lifter.ambil_operand(instr, 0);
+    let mem = lifter.ambil_operand(instr, 1);
+    let tipe = match mnem.trim_start_matches("ldr").trim_start_matches("str") {
+        "b" | "sb" => TipeDataIr::I8,
+        "h" | "sh" => TipeDataIr::I16,
+        _ => TipeDataIr::I32,
+    };
+    let base = match instr.operands_detail.get(1) {
+        Some(JenisOperandDisasm::Memory { base: Some(b), disp, .. }) => Some((b.to_lowercase(), *disp)),
+        _ => None,
+    };
+    let is_post_index = instr.operands_detail.len() >= 3;
+    let is_pre_index = instr.op_str.trim_end().ends_with('!');
+    if is_load && reg == TipeOperand::Register("pc".to_string()) {
+        let is_pop_pc = matches!(&base, Some((b, 0)) if b == "sp") && is_post_index;
+        if is_pop_pc {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Ret, TipeOperand::None, TipeOperand::None));
+        } else {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Jmp, mem, TipeOperand::None));
+        }
+        return;
+    }
+    let akses = if is_post_index {
+        match &base {
+            Some((b, _)) => TipeOperand::MemoryRef { base: b.clone(), offset: 0 },
+            None => mem,
+        }
+    } else {
+        mem
+    };
+    if is_load {
+        ops.push(StatementIr::new(instr.address, OperasiIr::Mov, reg, akses).with_type(tipe));
+    } else {
+        ops.push(StatementIr::new(instr.address, OperasiIr::Mov, akses, reg).with_type(tipe));
+    }
+    let geser = if is_post_index {
+        Some(lifter.ambil_operand(instr, 2))
+    } else if is_pre_index {
+        base.as_ref().map(|(_, disp)| TipeOperand::Immediate(*disp))
+    } else {
+        None
+    };
+    if let (Some((b, _)), Some(geser)) = (base, geser) {
+        let reg_base = TipeOperand::Register(b);
+        let expr = buat_expr(OperasiIr::Add, reg_base.clone(), geser);
+        ops.push(StatementIr::new(instr.address, OperasiIr::Add, reg_base, expr).with_type(TipeDataIr::I32));
+    }
+}
+
+fn terapkan_predikat(addr: u64, kondisi: &str, body: Vec<StatementIr>, ops: &mut Vec<StatementIr>) {
+    let syarat = kondisi_nzcv(kondisi);
+    let op_branch = flow::ambil_operasi_branch(kondisi);
+    for stmt in body {
+        match stmt.operation_code {
+            OperasiIr::Call | OperasiIr::Syscall | OperasiIr::Cmp | OperasiIr::Test |
+            OperasiIr::Nop | OperasiIr::Unknown => ops.push(stmt),
+            OperasiIr::Ret => {
+                ops.push(StatementIr::new(addr, op_branch.clone(), TipeOperand::Register("lr".to_string()), syarat.clone()));
+            },
+            OperasiIr::Jmp => {
+                ops.push(StatementIr::new(addr, op_branch.clone(), stmt.operand_satu, syarat.clone()));
+            },
+            _ => {
+                let dest = stmt.operand_satu.clone();
+                let nilai = match (&stmt.operation_code, stmt.operand_dua) {
+                    (OperasiIr::Mov, src) => src,
+                    (_, expr @ TipeOperand::Expression { .. }) => expr,
+                    (op, src) => buat_expr(op.clone(), dest.clone(), src),
+                };
+                let pilihan = TipeOperand::Conditional {
+                    condition: Box::new(syarat.clone()),
+                    true_val: Box::new(nilai),
+                    false_val: Box::new(dest.clone()),
+                };
+                ops.push(StatementIr::new(stmt.address_asal, OperasiIr::Cmov, dest, pilihan).with_type(stmt.tipe_hasil));
+            },
+        }
+    }
+}
+
+fn buat_expr(op: OperasiIr, kiri: TipeOperand, kanan: TipeOperand) -> TipeOperand {
+    TipeOperand::Expression {
+        operasi: op,
+        operand_kiri: Box::new(kiri),
+        operand_kanan: Box::new(kanan),
+    }
+}
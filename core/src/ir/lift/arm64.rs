@@ -0,0 +1,891 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use capstone::arch::arm64::Arm64Insn;
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm, GeserOperand};
+use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr, mask_lebar};
+use crate::arch::arm64::{FLAG_C, FLAG_N, FLAG_V, FLAG_Z};
+use super::IrLifter;
+use super::{arithmetic, flow};
+use super::dispatch::{self, ArsitekturDispatch};
+use super::eflags::{self, buat_expr, JenisOperasiFlag, OperandFlag};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum JenisAlu {
+    Add,
+    Adc,
+    Sub,
+    Sbc,
+    And,
+    Orr,
+    Eor,
+    Bic,
+    Orn,
+    Eon,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BentukAlu {
+    Tiga,
+    Banding,
+    Unary,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AksiArm64 {
+    Alu(JenisAlu, BentukAlu),
+    BandingKondisi(JenisAlu),
+    Mov,
+    Movk,
+    Kali,
+    KaliTinggi,
+    Bagi,
+    Geser,
+    Extr,
+    Bitfield,
+    Ekstensi,
+    Pilih,
+    HitungBit,
+    Load,
+    Store,
+    LoadPasangan,
+    StorePasangan,
+    StoreEksklusif,
+    Adr,
+    Cabang,
+    Call,
+    CallRegister,
+    JumpRegister,
+    Ret,
+    CabangNol,
+    CabangBit,
+    Syscall,
+    Barrier,
+    Nop,
+}
+
+const TABEL_AKSI: &[(Arm64Insn, AksiArm64)] = &[
+    (Arm64Insn::ARM64_INS_ADD, AksiArm64::Alu(JenisAlu::Add, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_ADDS, AksiArm64::Alu(JenisAlu::Add, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_ADC, AksiArm64::Alu(JenisAlu::Adc, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_ADCS, AksiArm64::Alu(JenisAlu::Adc, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_SUB, AksiArm64::Alu(JenisAlu::Sub, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_SUBS, AksiArm64::Alu(JenisAlu::Sub, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_SBC, AksiArm64::Alu(JenisAlu::Sbc, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_SBCS, AksiArm64::Alu(JenisAlu::Sbc, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_AND, AksiArm64::Alu(JenisAlu::And, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_ANDS, AksiArm64::Alu(JenisAlu::And, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_ORR, AksiArm64::Alu(JenisAlu::Orr, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_EOR, AksiArm64::Alu(JenisAlu::Eor, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_BIC, AksiArm64::Alu(JenisAlu::Bic, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_BICS, AksiArm64::Alu(JenisAlu::Bic, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_ORN, AksiArm64::Alu(JenisAlu::Orn, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_EON, AksiArm64::Alu(JenisAlu::Eon, BentukAlu::Tiga)),
+    (Arm64Insn::ARM64_INS_CMP, AksiArm64::Alu(JenisAlu::Sub, BentukAlu::Banding)),
+    (Arm64Insn::ARM64_INS_CMN, AksiArm64::Alu(JenisAlu::Add, BentukAlu::Banding)),
+    (Arm64Insn::ARM64_INS_TST, AksiArm64::Alu(JenisAlu::And, BentukAlu::Banding)),
+    (Arm64Insn::ARM64_INS_NEG, AksiArm64::Alu(JenisAlu::Sub, BentukAlu::Unary)),
+    (Arm64Insn::ARM64_INS_NEGS, AksiArm64::Alu(JenisAlu::Sub, BentukAlu::Unary)),
+    (Arm64Insn::ARM64_INS_NGC, AksiArm64::Alu(JenisAlu::Sbc, BentukAlu::Unary)),
+    (Arm64Insn::ARM64_INS_NGCS, AksiArm64::Alu(JenisAlu::Sbc, BentukAlu::Unary)),
+    (Arm64Insn::ARM64_INS_MVN, AksiArm64::Alu(JenisAlu::Orn, BentukAlu::Unary)),
+    (Arm64Insn::ARM64_INS_CCMP, AksiArm64::BandingKondisi(JenisAlu::Sub)),
+    (Arm64Insn::ARM64_INS_CCMN, AksiArm64::BandingKondisi(JenisAlu::Add)),
+    (Arm64Insn::ARM64_INS_MOV, AksiArm64::Mov),
+    (Arm64Insn::ARM64_INS_MOVZ, AksiArm64::Mov),
+    (Arm64Insn::ARM64_INS_MOVN, AksiArm64::Mov),
+    (Arm64Insn::ARM64_INS_MRS, AksiArm64::Mov),
+    (Arm64Insn::ARM64_INS_MSR, AksiArm64::Mov),
+    (Arm64Insn::ARM64_INS_MOVK, AksiArm64::Movk),
+    (Arm64Insn::ARM64_INS_MUL, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_MADD, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_MSUB, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_MNEG, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_SMULL, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_UMULL, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_SMADDL, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_UMADDL, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_SMSUBL, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_UMSUBL, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_SMNEGL, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_UMNEGL, AksiArm64::Kali),
+    (Arm64Insn::ARM64_INS_SMULH, AksiArm64::KaliTinggi),
+    (Arm64Insn::ARM64_INS_UMULH, AksiArm64::KaliTinggi),
+    (Arm64Insn::ARM64_INS_SDIV, AksiArm64::Bagi),
+    (Arm64Insn::ARM64_INS_UDIV, AksiArm64::Bagi),
+    (Arm64Insn::ARM64_INS_LSL, AksiArm64::Geser),
+    (Arm64Insn::ARM64_INS_LSLV, AksiArm64::Geser),
+    (Arm64Insn::ARM64_INS_LSR, AksiArm64::Geser),
+    (Arm64Insn::ARM64_INS_LSRV, AksiArm64::Geser),
+    (Arm64Insn::ARM64_INS_ASR, AksiArm64::Geser),
+    (Arm64Insn::ARM64_INS_ASRV, AksiArm64::Geser),
+    (Arm64Insn::ARM64_INS_ROR, AksiArm64::Geser),
+    (Arm64Insn::ARM64_INS_RORV, AksiArm64::Geser),
+    (Arm64Insn::ARM64_INS_EXTR, AksiArm64::Extr),
+    (Arm64Insn::ARM64_INS_UBFX, AksiArm64::Bitfield),
+    (Arm64Insn::ARM64_INS_SBFX, AksiArm64::Bitfield),
+    (Arm64Insn::ARM64_INS_UBFIZ, AksiArm64::Bitfield),
+    (Arm64Insn::ARM64_INS_SBFIZ, AksiArm64::Bitfield),
+    (Arm64Insn::ARM64_INS_BFI, AksiArm64::Bitfield),
+    (Arm64Insn::ARM64_INS_BFXIL, AksiArm64::Bitfield),
+    (Arm64Insn::ARM64_INS_SXTB, AksiArm64::Ekstensi),
+    (Arm64Insn::ARM64_INS_SXTH, AksiArm64::Ekstensi),
+    (Arm64Insn::ARM64_INS_SXTW, AksiArm64::Ekstensi),
+    (Arm64Insn::ARM64_INS_UXTB, AksiArm64::Ekstensi),
+    (Arm64Insn::ARM64_INS_UXTH, AksiArm64::Ekstensi),
+    (Arm64Insn::ARM64_INS_UXTW, AksiArm64::Ekstensi),
+    (Arm64Insn::ARM64_INS_CSEL, AksiArm64::Pilih),
+    (Arm64Insn::ARM64_INS_CSINC, AksiArm64::Pilih),
+    (Arm64Insn::ARM64_INS_CSINV, AksiArm64::Pilih),
+    (Arm64Insn::ARM64_INS_CSNEG, AksiArm64::Pilih),
+    (Arm64Insn::ARM64_INS_CSET, AksiArm64::Pilih),
+    (Arm64Insn::ARM64_INS_CSETM, AksiArm64::Pilih),
+    (Arm64Insn::ARM64_INS_CINC, AksiArm64::Pilih),
+    (Arm64Insn::ARM64_INS_CINV, AksiArm64::Pilih),
+    (Arm64Insn::ARM64_INS_CNEG, AksiArm64::Pilih),
+    (Arm64Insn::ARM64_INS_CLZ, AksiArm64::HitungBit),
+    (Arm64Insn::ARM64_INS_CLS, AksiArm64::HitungBit),
+    (Arm64Insn::ARM64_INS_RBIT, AksiArm64::HitungBit),
+    (Arm64Insn::ARM64_INS_REV, AksiArm64::HitungBit),
+    (Arm64Insn::ARM64_INS_REV16, AksiArm64::HitungBit),
+    (Arm64Insn::ARM64_INS_REV32, AksiArm64::HitungBit),
+    (Arm64Insn::ARM64_INS_LDR, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDRB, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDRH, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDRSB, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDRSH, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDRSW, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDUR, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDURB, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDURH, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDURSB, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDURSH, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDURSW, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDTR, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDAR, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDARB, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDARH, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDAPR, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDAPRB, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDAPRH, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDXR, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDXRB, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDXRH, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDAXR, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDAXRB, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_LDAXRH, AksiArm64::Load),
+    (Arm64Insn::ARM64_INS_STR, AksiArm64::Store),
+    (Arm64Insn::ARM64_INS_STRB, AksiArm64::Store),
+    (Arm64Insn::ARM64_INS_STRH, AksiArm64::Store),
+    (Arm64Insn::ARM64_INS_STUR, AksiArm64::Store),
+    (Arm64Insn::ARM64_INS_STURB, AksiArm64::Store),
+    (Arm64Insn::ARM64_INS_STURH, AksiArm64::Store),
+    (Arm64Insn::ARM64_INS_STTR, AksiArm64::Store),
+    (Arm64Insn::ARM64_INS_STLR, AksiArm64::Store),
+    (Arm64Insn::ARM64_INS_STLRB, AksiArm64::Store),
+    (Arm64Insn::ARM64_INS_STLRH, AksiArm64::Store),
+    (Arm64Insn::ARM64_INS_LDP, AksiArm64::LoadPasangan),
+    (Arm64Insn::ARM64_INS_LDPSW, AksiArm64::LoadPasangan),
+    (Arm64Insn::ARM64_INS_LDNP, AksiArm64::LoadPasangan),
+    (Arm64Insn::ARM64_INS_LDXP, AksiArm64::LoadPasangan),
+    (Arm64Insn::ARM64_INS_LDAXP, AksiArm64::LoadPasangan),
+    (Arm64Insn::ARM64_INS_STP, AksiArm64::StorePasangan),
+    (Arm64Insn::ARM64_INS_STNP, AksiArm64::StorePasangan),
+    (Arm64Insn::ARM64_INS_STXR, AksiArm64::StoreEksklusif),
+    (Arm64Insn::ARM64_INS_STXRB, AksiArm64::StoreEksklusif),
+    (Arm64Insn::ARM64_INS_STXRH, AksiArm64::StoreEksklusif),
+    (Arm64Insn::ARM64_INS_STLXR, AksiArm64::StoreEksklusif),
+    (Arm64Insn::ARM64_INS_STLXRB, AksiArm64::StoreEksklusif),
+    (Arm64Insn::ARM64_INS_STLXRH, AksiArm64::StoreEksklusif),
+    (Arm64Insn::ARM64_INS_ADR, AksiArm64::Adr),
+    (Arm64Insn::ARM64_INS_ADRP, AksiArm64::Adr),
+    (Arm64Insn::ARM64_INS_B, AksiArm64::Cabang),
+    (Arm64Insn::ARM64_INS_BL, AksiArm64::Call),
+    (Arm64Insn::ARM64_INS_BLR, AksiArm64::CallRegister),
+    (Arm64Insn::ARM64_INS_BR, AksiArm64::JumpRegister),
+    (Arm64Insn::ARM64_INS_RET, AksiArm64::Ret),
+    (Arm64Insn::ARM64_INS_CBZ, AksiArm64::CabangNol),
+    (Arm64Insn::ARM64_INS_CBNZ, AksiArm64::CabangNol),
+    (Arm64Insn::ARM64_INS_TBZ, AksiArm64::CabangBit),
+    (Arm64Insn::ARM64_INS_TBNZ, AksiArm64::CabangBit),
+    (Arm64Insn::ARM64_INS_SVC, AksiArm64::Syscall),
+    (Arm64Insn::ARM64_INS_DMB, AksiArm64::Barrier),
+    (Arm64Insn::ARM64_INS_DSB, AksiArm64::Barrier),
+    (Arm64Insn::ARM64_INS_ISB, AksiArm64::Barrier),
+    (Arm64Insn::ARM64_INS_NOP, AksiArm64::Nop),
+    (Arm64Insn::ARM64_INS_HINT, AksiArm64::Nop),
+    (Arm64Insn::ARM64_INS_YIELD, AksiArm64::Nop),
+    (Arm64Insn::ARM64_INS_PRFM, AksiArm64::Nop),
+    (Arm64Insn::ARM64_INS_PRFUM, AksiArm64::Nop),
+];
+
+static AKSI_ARM64: LazyLock<HashMap<u32, AksiArm64>> = LazyLock::new(|| {
+    TABEL_AKSI.iter().map(|(id, aksi)| (*id as u32, *aksi)).collect()
+});
+
+pub fn proses_instruksi_arm64(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) -> bool {
+    let Some(aksi) = ambil_aksi(instr) else {
+        return false;
+    };
+    if dispatch::klasifikasi_instruksi(ArsitekturDispatch::Arm64, instr) != dispatch::KelasInstruksi::Umum
+        && !matches!(aksi, AksiArm64::Load | AksiArm64::Store | AksiArm64::LoadPasangan | AksiArm64::StorePasangan)
+    {
+        return false;
+    }
+    let mnemonic = instr.mnemonic.to_lowercase();
+    let mnem = mnemonic.as_str();
+    match aksi {
+        AksiArm64::Alu(..) if let Some(alamat) = instr.alamat_terlipat => {
+            let dest = lifter.ambil_operand(instr, 0);
+            tulis_hasil(instr.address, OperasiIr::Mov, dest, TipeOperand::Immediate(alamat as i64), 64, ops);
+        },
+        AksiArm64::Alu(jenis, bentuk) => proses_alu(lifter, instr, jenis, bentuk, ops),
+        AksiArm64::BandingKondisi(jenis) => proses_banding_kondisi(lifter, instr, jenis, ops),
+        AksiArm64::Mov => {
+            let dest = lifter.ambil_operand(instr, 0);
+            if dest == TipeOperand::None {
+                arithmetic::proses_generic_unknown(lifter, instr, ops);
+            } else {
+                let lebar = lifter.ambil_lebar_bit(instr, 0);
+                tulis_hasil(instr.address, OperasiIr::Mov, dest, ambil_sumber(lifter, instr, 1, lebar), lebar, ops);
+            }
+        },
+        AksiArm64::Movk => {
+            let lebar = lifter.ambil_lebar_bit(instr, 0);
+            let dest = lifter.ambil_operand(instr, 0);
+            let offset = match instr.ambil_geser_operand(1) {
+                GeserOperand::Lsl(n) => n,
+                _ => 0,
+            };
+            let nilai = buat_expr(OperasiIr::BitInsert { offset, lebar: 16 }, dest.clone(), lifter.ambil_operand(instr, 1));
+            tulis_hasil(instr.address, OperasiIr::Mov, dest, nilai, lebar, ops);
+        },
+        AksiArm64::Kali => proses_kali(lifter, instr, mnem, ops),
+        AksiArm64::KaliTinggi => {
+            let lebar = lifter.ambil_lebar_bit(instr, 0);
+            let op_tinggi = if mnem == "umulh" { OperasiIr::MulHiU } else { OperasiIr::MulHi };
+            let nilai = buat_expr(op_tinggi.clone(), ambil_sumber(lifter, instr, 1, lebar), ambil_sumber(lifter, instr, 2, lebar));
+            tulis_hasil(instr.address, op_tinggi, lifter.ambil_operand(instr, 0), nilai, lebar, ops);
+        },
+        AksiArm64::Bagi => {
+            let lebar = lifter.ambil_lebar_bit(instr, 0);
+            let kiri = ambil_sumber(lifter, instr, 1, lebar);
+            let kanan = ambil_sumber(lifter, instr, 2, lebar);
+            let (op, nilai) = if mnem == "sdiv" {
+                (OperasiIr::Idiv, buat_expr(OperasiIr::Idiv, bertanda(kiri, lebar), bertanda(kanan, lebar)))
+            } else {
+                (OperasiIr::Div, buat_expr(OperasiIr::Div, kiri, kanan))
+            };
+            tulis_hasil(instr.address, op, lifter.ambil_operand(instr, 0), nilai, lebar, ops);
+        },
+        AksiArm64::Geser => proses_geser(lifter, instr, mnem, ops),
+        AksiArm64::Extr => {
+            let lebar = lifter.ambil_lebar_bit(instr, 0);
+            let atas = ambil_sumber(lifter, instr, 1, lebar);
+            let bawah = ambil_sumber(lifter, instr, 2, lebar);
+            let nilai = match lifter.ambil_operand(instr, 3) {
+                TipeOperand::Immediate(0) => bawah,
+                TipeOperand::Immediate(lsb) => buat_expr(
+                    OperasiIr::Or,
+                    buat_expr(OperasiIr::Shr, bawah, TipeOperand::Immediate(lsb)),
+                    buat_expr(OperasiIr::Shl, atas, TipeOperand::Immediate(lebar as i64 - lsb)),
+                ),
+                _ => return false,
+            };
+            tulis_hasil(instr.address, OperasiIr::Or, lifter.ambil_operand(instr, 0), nilai, lebar, ops);
+        },
+        AksiArm64::Bitfield => return proses_bitfield(lifter, instr, mnem, ops),
+        AksiArm64::Ekstensi => arithmetic::proses_ekstensi(lifter, instr, ops),
+        AksiArm64::Pilih => proses_pilih(lifter, instr, mnem, ops),
+        AksiArm64::HitungBit => {
+            let lebar = lifter.ambil_lebar_bit(instr, 0);
+            let dest = lifter.ambil_operand(instr, 0);
+            let src = ambil_sumber(lifter, instr, 1, lebar);
+            let akhiran = if lebar == 64 { "ll" } else { "" };
+            let op = match mnem {
+                "clz" => OperasiIr::Lzcnt,
+                "cls" => OperasiIr::Intrinsic(format!("__cls{}", akhiran)),
+                "rbit" => OperasiIr::Intrinsic(format!("__rbit{}", akhiran)),
+                "rev" => OperasiIr::Intrinsic(format!("__rev{}", akhiran)),
+                "rev16" => OperasiIr::Intrinsic(format!("__rev16{}", akhiran)),
+                _ => OperasiIr::Intrinsic("__rev32".to_string()),
+            };
+            ops.push(StatementIr::new(instr.address, op, dest, src).with_type(tipe_lebar(lebar)));
+        },
+        AksiArm64::Load => proses_load(lifter, instr, mnem, ops),
+        AksiArm64::Store => proses_store(lifter, instr, mnem, ops),
+        AksiArm64::LoadPasangan => proses_pasangan(lifter, instr, mnem, true, ops),
+        AksiArm64::StorePasangan => proses_pasangan(lifter, instr, mnem, false, ops),
+        AksiArm64::StoreEksklusif => {
+            let lebar = lifter.ambil_lebar_bit(instr, 1);
+            let mem = ambil_memori(lifter, instr, 2);
+            let tipe = lebar_akses(mnem).map_or(tipe_lebar(lebar), |(bit, _)| tipe_lebar(bit));
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, mem, ambil_sumber(lifter, instr, 1, lebar)).with_type(tipe));
+            tulis_hasil(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, 0), TipeOperand::Immediate(0), 32, ops);
+        },
+        AksiArm64::Adr => arithmetic::proses_lea(lifter, instr, ops),
+        AksiArm64::Cabang => {
+            let kondisi = ambil_kondisi(instr);
+            if kondisi.is_empty() || kondisi == "al" {
+                flow::proses_unconditional_jump(lifter, instr, ops);
+            } else {
+                ops.push(StatementIr::new(
+                    instr.address,
+                    flow::ambil_operasi_branch(&kondisi),
+                    lifter.ambil_operand(instr, 0),
+                    kondisi_nzcv(&kondisi),
+                ));
+            }
+        },
+        AksiArm64::Call | AksiArm64::CallRegister => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Call, lifter.ambil_operand(instr, 0), TipeOperand::None));
+        },
+        AksiArm64::JumpRegister => flow::proses_unconditional_jump(lifter, instr, ops),
+        AksiArm64::Ret => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Ret, TipeOperand::None, TipeOperand::None));
+        },
+        AksiArm64::CabangNol => flow::proses_conditional_branch(lifter, instr, mnem, ops),
+        AksiArm64::CabangBit => {
+            let lebar = lifter.ambil_lebar_bit(instr, 0);
+            let bit = eflags::ambil_bit(ambil_sumber(lifter, instr, 0, lebar), lifter.ambil_operand(instr, 1));
+            let op = if mnem == "tbz" { OperasiIr::Je } else { OperasiIr::Jne };
+            let syarat = buat_expr(op.clone(), bit, TipeOperand::Immediate(0));
+            ops.push(StatementIr::new(instr.address, op, lifter.ambil_operand(instr, 2), syarat));
+        },
+        AksiArm64::Syscall => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Syscall, lifter.ambil_operand(instr, 0), TipeOperand::None));
+        },
+        AksiArm64::Barrier => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Fence, TipeOperand::None, TipeOperand::None));
+        },
+        AksiArm64::Nop => {},
+    }
+    true
+}
+
+pub fn gabungkan_pasangan_adrp(instr: &mut InstructionNormalized, peta_adrp: &mut HashMap<String, u64>) {
+    let aksi = ambil_aksi(instr);
+    let tujuan = match instr.operands_detail.first() {
+        Some(JenisOperandDisasm::Register(r)) => Some(nama_register_64(r)),
+        _ => None,
+    };
+    match aksi {
+        Some(AksiArm64::Adr) => {
+            if let (Some(rd), Some(JenisOperandDisasm::Immediate(alamat))) = (tujuan, instr.operands_detail.get(1)) {
+                peta_adrp.insert(rd, *alamat as u64);
+            }
+            return;
+        },
+        Some(AksiArm64::Call | AksiArm64::CallRegister) => {
+            peta_adrp.retain(|r, _| is_register_callee_saved(r));
+            return;
+        },
+        _ => {},
+    }
+    let basis = match instr.operands_detail.get(1) {
+        Some(JenisOperandDisasm::Register(r)) => peta_adrp.get(&nama_register_64(r)).copied(),
+        _ => None,
+    };
+    let tambahan = match (instr.operands_detail.get(2), instr.ambil_geser_operand(2)) {
+        (Some(JenisOperandDisasm::Immediate(v)), GeserOperand::Tidak) => Some(*v),
+        (Some(JenisOperandDisasm::Immediate(v)), GeserOperand::Lsl(n)) => Some(v << n),
+        _ => None,
+    };
+    let is_tambah = aksi == Some(AksiArm64::Alu(JenisAlu::Add, BentukAlu::Tiga)) && !instr.update_flags;
+    if is_tambah
+        && instr.operands_detail.len() == 3
+        && let (Some(rd), Some(nilai), Some(imm)) = (tujuan.clone(), basis, tambahan)
+    {
+        let alamat = nilai.wrapping_add(imm as u64);
+        instr.alamat_terlipat = Some(alamat);
+        peta_adrp.insert(rd, alamat);
+        return;
+    }
+    let writeback = is_writeback(instr);
+    let mut base_ditulis = None;
+    for op in instr.operands_detail.iter_mut() {
+        if let JenisOperandDisasm::Memory { base: Some(b), index: None, disp, .. } = op {
+            let base = nama_register_64(b);
+            if writeback {
+                base_ditulis = Some(base);
+            } else if let Some(nilai) = peta_adrp.get(&base) {
+                let alamat = nilai.wrapping_add(*disp as u64) as i64;
+                *op = JenisOperandDisasm::Memory { base: None, index: None, scale: 1, disp: alamat, segment: None };
+            }
+        }
+    }
+    let is_tulis = !matches!(
+        aksi,
+        Some(AksiArm64::Store | AksiArm64::StorePasangan | AksiArm64::Cabang | AksiArm64::CabangNol | AksiArm64::CabangBit
+            | AksiArm64::JumpRegister | AksiArm64::Ret | AksiArm64::Barrier | AksiArm64::Nop | AksiArm64::Syscall
+            | AksiArm64::BandingKondisi(_) | AksiArm64::Alu(_, BentukAlu::Banding))
+    );
+    if is_tulis && let Some(rd) = tujuan {
+        peta_adrp.remove(&rd);
+    }
+    if aksi == Some(AksiArm64::LoadPasangan)
+        && let Some(JenisOperandDisasm::Register(r)) = instr.operands_detail.get(1)
+    {
+        peta_adrp.remove(&nama_register_64(r));
+    }
+    if let Some(base) = base_ditulis {
+        peta_adrp.remove(&base);
+    }
+}
+
+fn nama_register_64(nama: &str) -> String {
+    let nama = nama.to_lowercase();
+    match nama.strip_prefix('w') {
+        Some(nomor) if nomor.chars().all(|c| c.is_ascii_digit()) => format!("x{}", nomor),
+        Some("zr") => "xzr".to_string(),
+        _ => nama,
+    }
+}
+
+fn is_register_callee_saved(nama: &str) -> bool {
+    match nama.strip_prefix('x').and_then(|n| n.parse::<u32>().ok()) {
+        Some(nomor) => (19..=29).contains(&nomor),
+        None => matches!(nama, "fp" | "sp"),
+    }
+}
+
+fn is_writeback(instr: &InstructionNormalized) -> bool {
+    let setelah_memori = instr.operands_detail.iter()
+        .skip_while(|op| !matches!(op, JenisOperandDisasm::Memory { .. }))
+        .nth(1);
+    instr.op_str.trim_end().ends_with('!') || matches!(setelah_memori, Some(JenisOperandDisasm::Immediate(_)))
+}
+
+pub fn kondisi_nzcv(kondisi: &str) -> TipeOperand {
+    let flag = |nama: &str| TipeOperand::Register(nama.to_string());
+    let sama = |nama: &str, nilai: i64| buat_expr(OperasiIr::Je, flag(nama), TipeOperand::Immediate(nilai));
+    let n_sama_v = || buat_expr(OperasiIr::Je, flag(FLAG_N), flag(FLAG_V));
+    let n_beda_v = || buat_expr(OperasiIr::Jne, flag(FLAG_N), flag(FLAG_V));
+    match kondisi {
+        "eq" => sama(FLAG_Z, 1),
+        "ne" => sama(FLAG_Z, 0),
+        "hs" | "cs" => sama(FLAG_C, 1),
+        "lo" | "cc" => sama(FLAG_C, 0),
+        "mi" => sama(FLAG_N, 1),
+        "pl" => sama(FLAG_N, 0),
+        "vs" => sama(FLAG_V, 1),
+        "vc" => sama(FLAG_V, 0),
+        "hi" => buat_expr(OperasiIr::And, sama(FLAG_C, 1), sama(FLAG_Z, 0)),
+        "ls" => buat_expr(OperasiIr::Or, sama(FLAG_C, 0), sama(FLAG_Z, 1)),
+        "ge" => n_sama_v(),
+        "lt" => n_beda_v(),
+        "gt" => buat_expr(OperasiIr::And, sama(FLAG_Z, 0), n_sama_v()),
+        "le" => buat_expr(OperasiIr::Or, sama(FLAG_Z, 1), n_beda_v()),
+        _ => TipeOperand::Immediate(1),
+    }
+}
+
+fn ambil_aksi(instr: &InstructionNormalized) -> Option<AksiArm64> {
+    dispatch::ambil_id_instruksi(ArsitekturDispatch::Arm64, instr).and_then(|id| AKSI_ARM64.get(&id).copied())
+}
+
+fn ambil_kondisi(instr: &InstructionNormalized) -> String {
+    if let Some(kondisi) = &instr.kondisi {
+        return kondisi.clone();
+    }
+    let mnemonic = instr.mnemonic.to_lowercase();
+    match mnemonic.strip_prefix("b.") {
+        Some(kondisi) => kondisi.to_string(),
+        None => instr.op_str.rsplit(',').next().map(|t| t.trim().to_lowercase()).unwrap_or_default(),
+    }
+}
+
+fn proses_alu(lifter: &IrLifter, instr: &InstructionNormalized, jenis: JenisAlu, bentuk: BentukAlu, ops: &mut Vec<StatementIr>) {
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let (dest, kiri, kanan) = match bentuk {
+        BentukAlu::Tiga => (
+            lifter.ambil_operand(instr, 0),
+            ambil_sumber(lifter, instr, 1, lebar),
+            ambil_sumber(lifter, instr, 2, lebar),
+        ),
+        BentukAlu::Banding => (
+            TipeOperand::None,
+            ambil_sumber(lifter, instr, 0, lebar),
+            ambil_sumber(lifter, instr, 1, lebar),
+        ),
+        BentukAlu::Unary => (
+            lifter.ambil_operand(instr, 0),
+            TipeOperand::Immediate(0),
+            ambil_sumber(lifter, instr, 1, lebar),
+        ),
+    };
+    let (op, nilai) = nilai_alu(jenis, kiri.clone(), kanan.clone());
+    if !instr.update_flags {
+        tulis_hasil(instr.address, op, dest, nilai, lebar, ops);
+        return;
+    }
+    let temp_result = TipeOperand::Register("temp_alu_flags".to_string());
+    let (hasil, kiri_flag, kanan_flag) = if dest == TipeOperand::None || is_register_nol(&dest) {
+        let op_stmt = if is_logika(jenis) { OperasiIr::Test } else { OperasiIr::Cmp };
+        ops.push(StatementIr::new(instr.address, op_stmt, temp_result.clone(), nilai).with_type(tipe_lebar(lebar)));
+        (temp_result, kiri, kanan)
+    } else {
+        let (kiri_flag, kanan_flag) = arithmetic::amankan_sumber(instr.address, &dest, &kiri, &kanan, lebar, ops);
+        tulis_hasil(instr.address, op, dest.clone(), nilai, lebar, ops);
+        (dest, kiri_flag, kanan_flag)
+    };
+    for (flag, nilai) in nilai_nzcv(jenis, &OperandFlag::new(hasil, kiri_flag, kanan_flag, lebar)) {
+        eflags::tulis_flag(instr.address, flag, nilai, ops);
+    }
+}
+
+pub(super) fn nilai_alu(jenis: JenisAlu, kiri: TipeOperand, kanan: TipeOperand) -> (OperasiIr, TipeOperand) {
+    let carry = || TipeOperand::Register(FLAG_C.to_string());
+    let balik = |op: TipeOperand| buat_expr(OperasiIr::Xor, op, TipeOperand::Immediate(-1));
+    match jenis {
+        JenisAlu::Add => (OperasiIr::Add, buat_expr(OperasiIr::Add, kiri, kanan)),
+        JenisAlu::Adc => (OperasiIr::Add, buat_expr(OperasiIr::Add, buat_expr(OperasiIr::Add, kiri, kanan), carry())),
+        JenisAlu::Sub => (OperasiIr::Sub, buat_expr(OperasiIr::Sub, kiri, kanan)),
+        JenisAlu::Sbc => {
+            let pinjam = buat_expr(OperasiIr::Xor, carry(), TipeOperand::Immediate(1));
+            (OperasiIr::Sub, buat_expr(OperasiIr::Sub, buat_expr(OperasiIr::Sub, kiri, kanan), pinjam))
+        },
+        JenisAlu::And => (OperasiIr::And, buat_expr(OperasiIr::And, kiri, kanan)),
+        JenisAlu::Orr => (OperasiIr::Or, buat_expr(OperasiIr::Or, kiri, kanan)),
+        JenisAlu::Eor => (OperasiIr::Xor, buat_expr(OperasiIr::Xor, kiri, kanan)),
+        JenisAlu::Bic => (OperasiIr::And, buat_expr(OperasiIr::And, kiri, balik(kanan))),
+        JenisAlu::Orn if kiri == TipeOperand::Immediate(0) => (OperasiIr::Not, balik(kanan)),
+        JenisAlu::Orn => (OperasiIr::Or, buat_expr(OperasiIr::Or, kiri, balik(kanan))),
+        JenisAlu::Eon => (OperasiIr::Xor, buat_expr(OperasiIr::Xor, kiri, balik(kanan))),
+    }
+}
+
+fn is_logika(jenis: JenisAlu) -> bool {
+    !matches!(jenis, JenisAlu::Add | JenisAlu::Adc | JenisAlu::Sub | JenisAlu::Sbc)
+}
+
+pub(super) fn nilai_nzcv(jenis: JenisAlu, o: &OperandFlag) -> [(&'static str, TipeOperand); 4] {
+    let carry_lama = TipeOperand::Register(FLAG_C.to_string());
+    let hasil = o.potong(&o.hasil);
+    let kiri = o.potong(&o.kiri);
+    let kanan = o.potong(&o.kanan);
+    let (c, v) = match jenis {
+        JenisAlu::Add => (
+            buat_expr(OperasiIr::Jb, hasil, kiri),
+            eflags::nilai_overflow(JenisOperasiFlag::Add, o),
+        ),
+        JenisAlu::Adc => {
+            let lewat = buat_expr(OperasiIr::Jb, hasil.clone(), kiri.clone());
+            let penuh = buat_expr(OperasiIr::And, carry_lama, buat_expr(OperasiIr::Je, hasil, kiri));
+            (buat_expr(OperasiIr::Or, lewat, penuh), eflags::nilai_overflow(JenisOperasiFlag::Adc, o))
+        },
+        JenisAlu::Sub => (
+            buat_expr(OperasiIr::Jae, kiri, kanan),
+            eflags::nilai_overflow(JenisOperasiFlag::Sub, o),
+        ),
+        JenisAlu::Sbc => {
+            let lebih = buat_expr(OperasiIr::Ja, kiri.clone(), kanan.clone());
+            let sama = buat_expr(OperasiIr::And, carry_lama, buat_expr(OperasiIr::Je, kiri, kanan));
+            (buat_expr(OperasiIr::Or, lebih, sama), eflags::nilai_overflow(JenisOperasiFlag::Sbb, o))
+        },
+        _ => (TipeOperand::Immediate(0), TipeOperand::Immediate(0)),
+    };
+    [
+        (FLAG_N, eflags::nilai_sign(o)),
+        (FLAG_Z, eflags::nilai_zero(o)),
+        (FLAG_C, c),
+        (FLAG_V, v),
+    ]
+}
+
+fn proses_banding_kondisi(lifter: &IrLifter, instr: &InstructionNormalized, jenis: JenisAlu, ops: &mut Vec<StatementIr>) {
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let kiri = ambil_sumber(lifter, instr, 0, lebar);
+    let kanan = ambil_sumber(lifter, instr, 1, lebar);
+    let nzcv_alternatif = match lifter.ambil_operand(instr, 2) {
+        TipeOperand::Immediate(v) => v,
+        _ => 0,
+    };
+    let temp_kondisi = TipeOperand::Register("temp_kondisi_nzcv".to_string());
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, temp_kondisi.clone(), kondisi_nzcv(&ambil_kondisi(instr))).with_type(TipeDataIr::I8));
+    let temp_result = TipeOperand::Register("temp_alu_flags".to_string());
+    let (_, nilai) = nilai_alu(jenis, kiri.clone(), kanan.clone());
+    ops.push(StatementIr::new(instr.address, OperasiIr::Cmp, temp_result.clone(), nilai).with_type(tipe_lebar(lebar)));
+    let flags = nilai_nzcv(jenis, &OperandFlag::new(temp_result, kiri, kanan, lebar));
+    for (posisi, (flag, nilai)) in flags.into_iter().enumerate() {
+        let bit = (nzcv_alternatif >> (3 - posisi)) & 1;
+        let pilihan = TipeOperand::Conditional {
+            condition: Box::new(temp_kondisi.clone()),
+            true_val: Box::new(nilai),
+            false_val: Box::new(TipeOperand::Immediate(bit)),
+        };
+        eflags::tulis_flag(instr.address, flag, pilihan, ops);
+    }
+}
+
+fn proses_kali(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let mut kiri = ambil_sumber(lifter, instr, 1, 64);
+    let mut kanan = ambil_sumber(lifter, instr, 2, 64);
+    if mnem.starts_with("sm") {
+        kiri = bertanda(kiri, 32);
+        kanan = bertanda(kanan, 32);
+    }
+    let kali = buat_expr(OperasiIr::Imul, kiri, kanan);
+    let (op, nilai) = match mnem {
+        "madd" | "smaddl" | "umaddl" => (OperasiIr::Add, buat_expr(OperasiIr::Add, ambil_sumber(lifter, instr, 3, lebar), kali)),
+        "msub" | "smsubl" | "umsubl" => (OperasiIr::Sub, buat_expr(OperasiIr::Sub, ambil_sumber(lifter, instr, 3, lebar), kali)),
+        "mneg" | "smnegl" | "umnegl" => (OperasiIr::Sub, buat_expr(OperasiIr::Sub, TipeOperand::Immediate(0), kali)),
+        _ => (OperasiIr::Imul, kali),
+    };
+    tulis_hasil(instr.address, op, lifter.ambil_operand(instr, 0), nilai, lebar, ops);
+}
+
+fn proses_geser(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let op = match mnem.trim_end_matches('v') {
+        "lsl" => OperasiIr::Shl,
+        "lsr" => OperasiIr::Shr,
+        "asr" => OperasiIr::Sar,
+        _ => OperasiIr::Ror,
+    };
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let nilai = ambil_sumber(lifter, instr, 1, lebar);
+    let jumlah = match ambil_sumber(lifter, instr, 2, lebar) {
+        imm @ TipeOperand::Immediate(_) => imm,
+        reg => buat_expr(OperasiIr::And, reg, TipeOperand::Immediate(lebar as i64 - 1)),
+    };
+    let hasil = match op {
+        OperasiIr::Sar => buat_expr(OperasiIr::Sar, bertanda(nilai, lebar), jumlah),
+        OperasiIr::Ror => putar_kanan(nilai, jumlah, lebar),
+        _ => buat_expr(op.clone(), nilai, jumlah),
+    };
+    tulis_hasil(instr.address, op, lifter.ambil_operand(instr, 0), hasil, lebar, ops);
+}
+
+fn proses_bitfield(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) -> bool {
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let dest = lifter.ambil_operand(instr, 0);
+    let src = ambil_sumber(lifter, instr, 1, lebar);
+    let (TipeOperand::Immediate(lsb), TipeOperand::Immediate(lebar_field)) = (lifter.ambil_operand(instr, 2), lifter.ambil_operand(instr, 3)) else {
+        return false;
+    };
+    let geser_kanan = |v: TipeOperand| if lsb == 0 { v } else { buat_expr(OperasiIr::Shr, v, TipeOperand::Immediate(lsb)) };
+    let geser_kiri = |v: TipeOperand| if lsb == 0 { v } else { buat_expr(OperasiIr::Shl, v, TipeOperand::Immediate(lsb)) };
+    let nilai = match mnem {
+        "ubfx" => potong_field(geser_kanan(src), lebar_field),
+        "sbfx" => perluas_tanda_field(geser_kanan(src), lebar_field),
+        "ubfiz" => geser_kiri(potong_field(src, lebar_field)),
+        "sbfiz" => geser_kiri(perluas_tanda_field(src, lebar_field)),
+        "bfi" => buat_expr(OperasiIr::BitInsert { offset: lsb as u8, lebar: lebar_field as u8 }, dest.clone(), src),
+        _ => buat_expr(OperasiIr::BitInsert { offset: 0, lebar: lebar_field as u8 }, dest.clone(), geser_kanan(src)),
+    };
+    tulis_hasil(instr.address, OperasiIr::Mov, dest, nilai, lebar, ops);
+    true
+}
+
+fn potong_field(nilai: TipeOperand, lebar_field: i64) -> TipeOperand {
+    match lebar_field {
+        64.. => nilai,
+        8 | 16 | 32 => buat_expr(OperasiIr::Truncate, nilai, TipeOperand::Immediate(lebar_field)),
+        _ => buat_expr(OperasiIr::And, nilai, TipeOperand::Immediate(mask_lebar(lebar_field))),
+    }
+}
+
+fn perluas_tanda_field(nilai: TipeOperand, lebar_field: i64) -> TipeOperand {
+    match lebar_field {
+        64.. => nilai,
+        8 | 16 | 32 => buat_expr(OperasiIr::SignExtend, nilai, TipeOperand::Immediate(lebar_field)),
+        _ => {
+            let geser = TipeOperand::Immediate(64 - lebar_field);
+            buat_expr(OperasiIr::Sar, buat_expr(OperasiIr::Shl, nilai, geser.clone()), geser)
+        },
+    }
+}
+
+fn proses_pilih(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let sumber = |idx| ambil_sumber(lifter, instr, idx, lebar);
+    let tambah_satu = |v| buat_expr(OperasiIr::Add, v, TipeOperand::Immediate(1));
+    let balik = |v| buat_expr(OperasiIr::Xor, v, TipeOperand::Immediate(-1));
+    let negasi = |v| buat_expr(OperasiIr::Sub, TipeOperand::Immediate(0), v);
+    let (benar, salah) = match mnem {
+        "csinc" => (sumber(1), tambah_satu(sumber(2))),
+        "csinv" => (sumber(1), balik(sumber(2))),
+        "csneg" => (sumber(1), negasi(sumber(2))),
+        "cset" => (TipeOperand::Immediate(1), TipeOperand::Immediate(0)),
+        "csetm" => (TipeOperand::Immediate(-1), TipeOperand::Immediate(0)),
+        "cinc" => (tambah_satu(sumber(1)), sumber(1)),
+        "cinv" => (balik(sumber(1)), sumber(1)),
+        "cneg" => (negasi(sumber(1)), sumber(1)),
+        _ => (sumber(1), sumber(2)),
+    };
+    let pilihan = TipeOperand::Conditional {
+        condition: Box::new(kondisi_nzcv(&ambil_kondisi(instr))),
+        true_val: Box::new(benar),
+        false_val: Box::new(salah),
+    };
+    tulis_hasil(instr.address, OperasiIr::Cmov, lifter.ambil_operand(instr, 0), pilihan, lebar, ops);
+}
+
+fn lebar_akses(mnem: &str) -> Option<(u32, bool)> {
+    if mnem.ends_with("sb") {
+        Some((8, true))
+    } else if mnem.ends_with("sh") {
+        Some((16, true))
+    } else if mnem.ends_with("sw") {
+        Some((32, true))
+    } else if mnem.ends_with('b') {
+        Some((8, false))
+    } else if mnem.ends_with('h') {
+        Some((16, false))
+    } else {
+        None
+    }
+}
+
+fn nilai_muat(mem: TipeOperand, akses: Option<(u32, bool)>) -> TipeOperand {
+    match akses {
+        Some((bit, true)) => buat_expr(OperasiIr::SignExtend, mem, TipeOperand::Immediate(bit as i64)),
+        Some((bit, false)) => buat_expr(OperasiIr::ZeroExtend, mem, TipeOperand::Immediate(bit as i64)),
+        None => mem,
+    }
+}
+
+fn proses_load(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let akses = lebar_akses(mnem);
+    let (mem, writeback) = ambil_akses_memori(lifter, instr, 1);
+    let tipe = akses.map_or(tipe_lebar(lebar), |(bit, _)| tipe_lebar(bit));
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, 0), nilai_muat(mem, akses)).with_type(tipe));
+    ops.extend(writeback);
+}
+
+fn proses_store(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let (mem, writeback) = ambil_akses_memori(lifter, instr, 1);
+    let tipe = lebar_akses(mnem).map_or(tipe_lebar(lebar), |(bit, _)| tipe_lebar(bit));
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, mem, ambil_sumber(lifter, instr, 0, lebar)).with_type(tipe));
+    ops.extend(writeback);
+}
+
+fn proses_pasangan(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, is_load: bool, ops: &mut Vec<StatementIr>) {
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let akses = lebar_akses(mnem);
+    let ukuran = akses.map_or(lebar, |(bit, _)| bit);
+    let (mem, writeback) = ambil_akses_memori(lifter, instr, 2);
+    for idx in 0..2 {
+        let alamat = geser_alamat(&mem, idx as i64 * (ukuran / 8) as i64);
+        let stmt = if is_load {
+            StatementIr::new(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, idx), nilai_muat(alamat, akses))
+        } else {
+            StatementIr::new(instr.address, OperasiIr::Mov, alamat, ambil_sumber(lifter, instr, idx, lebar))
+        };
+        ops.push(stmt.with_type(tipe_lebar(ukuran)));
+    }
+    ops.extend(writeback);
+}
+
+fn ambil_akses_memori(lifter: &IrLifter, instr: &InstructionNormalized, idx: usize) -> (TipeOperand, Option<StatementIr>) {
+    let mem = ambil_memori(lifter, instr, idx);
+    let Some(JenisOperandDisasm::Memory { base: Some(base), index: None, disp, .. }) = instr.operands_detail.get(idx) else {
+        return (mem, None);
+    };
+    let base = base.to_lowercase();
+    let (akses, geser) = match instr.operands_detail.get(idx + 1) {
+        Some(JenisOperandDisasm::Immediate(geser)) => (TipeOperand::MemoryRef { base: base.clone(), offset: 0 }, *geser),
+        _ if instr.op_str.trim_end().ends_with('!') => (mem, *disp),
+        _ => return (mem, None),
+    };
+    let reg_base = TipeOperand::Register(base);
+    let nilai = buat_expr(OperasiIr::Add, reg_base.clone(), TipeOperand::Immediate(geser));
+    let writeback = StatementIr::new(instr.address, OperasiIr::Add, reg_base, nilai).with_type(TipeDataIr::I64);
+    (akses, Some(writeback))
+}
+
+fn ambil_memori(lifter: &IrLifter, instr: &InstructionNormalized, idx: usize) -> TipeOperand {
+    match (instr.operands_detail.get(idx), instr.ambil_geser_operand(idx)) {
+        (Some(JenisOperandDisasm::Immediate(alamat)), _) => TipeOperand::Memory(*alamat as u64),
+        (
+            Some(JenisOperandDisasm::Memory { base: Some(base), index: Some(index), scale, disp, .. }),
+            GeserOperand::Ekstensi { bertanda: true, lebar, .. },
+        ) => {
+            let mut indeks = bertanda(TipeOperand::Register(index.to_lowercase()), lebar as u32);
+            if *scale != 1 {
+                indeks = buat_expr(OperasiIr::Imul, indeks, TipeOperand::Immediate(*scale as i64));
+            }
+            let alamat = buat_expr(OperasiIr::Add, TipeOperand::Register(base.to_lowercase()), indeks);
+            if *disp != 0 { buat_expr(OperasiIr::Add, alamat, TipeOperand::Immediate(*disp)) } else { alamat }
+        },
+        _ => lifter.ambil_operand(instr, idx),
+    }
+}
+
+fn geser_alamat(mem: &TipeOperand, delta: i64) -> TipeOperand {
+    match mem {
+        _ if delta == 0 => mem.clone(),
+        TipeOperand::MemoryRef { base, offset } => TipeOperand::MemoryRef { base: base.clone(), offset: offset + delta },
+        TipeOperand::Memory(alamat) => TipeOperand::Memory(alamat.wrapping_add(delta as u64)),
+        _ => buat_expr(OperasiIr::Add, mem.clone(), TipeOperand::Immediate(delta)),
+    }
+}
+
+fn ambil_sumber(lifter: &IrLifter, instr: &InstructionNormalized, idx: usize, lebar: u32) -> TipeOperand {
+    let op = lifter.ambil_operand(instr, idx);
+    let op = if is_register_nol(&op) { TipeOperand::Immediate(0) } else { op };
+    terapkan_geser(op, instr.ambil_geser_operand(idx), lebar)
+}
+
+fn terapkan_geser(op: TipeOperand, geser: GeserOperand, lebar: u32) -> TipeOperand {
+    let imm = |n: u8| TipeOperand::Immediate(n as i64);
+    match geser {
+        GeserOperand::Tidak | GeserOperand::Lsl(0) | GeserOperand::Lsr(0) | GeserOperand::Asr(0) | GeserOperand::Ror(0) => op,
+        GeserOperand::Lsl(n) => match op {
+            TipeOperand::Immediate(v) => TipeOperand::Immediate(v.wrapping_shl(n as u32) & mask_lebar(lebar as i64)),
+            _ => buat_expr(OperasiIr::Shl, op, imm(n)),
+        },
+        GeserOperand::Lsr(n) => buat_expr(OperasiIr::Shr, op, imm(n)),
+        GeserOperand::Asr(n) => buat_expr(OperasiIr::Sar, bertanda(op, lebar), imm(n)),
+        GeserOperand::Ror(n) => putar_kanan(op, imm(n), lebar),
+        GeserOperand::Ekstensi { bertanda: tanda, lebar: lebar_asal, geser } => {
+            let ekstensi = if lebar_asal as u32 >= lebar {
+                op
+            } else {
+                let op_lebar = if tanda { OperasiIr::SignExtend } else { OperasiIr::ZeroExtend };
+                buat_expr(op_lebar, op, TipeOperand::Immediate(lebar_asal as i64))
+            };
+            if geser == 0 { ekstensi } else { buat_expr(OperasiIr::Shl, ekstensi, imm(geser)) }
+        },
+    }
+}
+
+fn putar_kanan(nilai: TipeOperand, jumlah: TipeOperand, lebar: u32) -> TipeOperand {
+    if lebar >= 64 {
+        return buat_expr(OperasiIr::Ror, nilai, jumlah);
+    }
+    let sisa = match &jumlah {
+        TipeOperand::Immediate(n) => TipeOperand::Immediate(lebar as i64 - n),
+        _ => buat_expr(OperasiIr::Sub, TipeOperand::Immediate(lebar as i64), jumlah.clone()),
+    };
+    buat_expr(
+        OperasiIr::Or,
+        buat_expr(OperasiIr::Shr, nilai.clone(), jumlah),
+        buat_expr(OperasiIr::Shl, nilai, sisa),
+    )
+}
+
+fn bertanda(op: TipeOperand, lebar: u32) -> TipeOperand {
+    match op {
+        TipeOperand::Immediate(_) => op,
+        _ if lebar >= 64 => op,
+        _ => buat_expr(OperasiIr::SignExtend, op, TipeOperand::Immediate(lebar as i64)),
+    }
+}
+
+fn is_register_nol(op: &TipeOperand) -> bool {
+    matches!(op, TipeOperand::Register(r) if r == "xzr" || r == "wzr")
+}
+
+fn tulis_hasil(addr: u64, op: OperasiIr, dest: TipeOperand, nilai: TipeOperand, lebar: u32, ops: &mut Vec<StatementIr>) {
+    if dest == TipeOperand::None || is_register_nol(&dest) {
+        return;
+    }
+    ops.push(StatementIr::new(addr, op, dest, nilai).with_type(tipe_lebar(lebar)));
+}
+
+fn tipe_lebar(lebar: u32) -> TipeDataIr {
+    IrLifter::tipe_dari_ukuran((lebar / 8) as u8).unwrap_or(TipeDataIr::I64)
+}
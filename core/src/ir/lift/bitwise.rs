@@ -1,12 +1,9 @@
 use crate::disasm::instruction::InstructionNormalized;
 use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
+use crate::arch::x86::{FLAG_AF, FLAG_CF, FLAG_OF, FLAG_PF, FLAG_SF, FLAG_ZF};
 use super::IrLifter;
+use super::eflags::{self, OperandFlag};
 
-const FLAG_CF: &str = "eflags_cf";
-const FLAG_ZF: &str = "eflags_zf";
-const FLAG_SF: &str = "eflags_sf";
-const FLAG_OF: &str = "eflags_of";
-const FLAG_PF: &str = "eflags_pf";
 
 pub fn proses_shift_rotate(
     lifter: &IrLifter, 
@@ -28,12 +25,19 @@ pub fn proses_shift_rotate(
         "ror" => OperasiIr::Ror,
         _ => OperasiIr::Unknown,
     };
-    let mask_val = TipeOperand::Immediate(0x3F);
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let mask_val = TipeOperand::Immediate(if lebar == 64 { 0x3F } else { 0x1F });
     let count_masked = TipeOperand::Expression {
         operasi: OperasiIr::And,
         operand_kiri: Box::new(count_raw),
         operand_kanan: Box::new(mask_val),
     };
+    let is_geser = matches!(mnemonic, "shl" | "sal" | "shr" | "sar");
+    let asal = if is_geser {
+        eflags::salin_operand_asal(instr.address, &dest, "temp_flag_kiri", lebar, ops)
+    } else {
+        dest.clone()
+    };
     let result_expr = TipeOperand::Expression {
         operasi: op_ir.clone(),
         operand_kiri: Box::new(dest.clone()),
@@ -45,103 +49,198 @@ pub fn proses_shift_rotate(
         dest.clone(),
         result_expr.clone()
     ).with_type(TipeDataIr::I64));
+    let o = OperandFlag::new(dest, asal, count_masked, lebar);
     match mnemonic {
-        "shl" | "sal" | "shr" | "sar" => {
-            update_flags_shift(instr.address, mnemonic, &dest, &count_masked, ops);
-        },
-        "rol" | "ror" => {
-            update_flags_rotate(instr.address, mnemonic, &dest, &count_masked, ops);
-        },
+        "shl" | "sal" | "shr" | "sar" => update_flags_shift(instr.address, mnemonic, &o, ops),
+        "rol" | "ror" => update_flags_rotate(instr.address, mnemonic, &o, ops),
         _ => {}
     }
 }
 
-fn update_flags_shift(
-    addr: u64, 
-    mnemonic: &str, 
-    result_op: &TipeOperand, 
-    count_op: &TipeOperand, 
+pub fn proses_geser_ganda(
+    lifter: &IrLifter,
+    instr: &InstructionNormalized,
+    mnemonic: &str,
     ops: &mut Vec<StatementIr>
 ) {
-    let is_count_zero = TipeOperand::Expression {
-        operasi: OperasiIr::Je,
-        operand_kiri: Box::new(count_op.clone()),
-        operand_kanan: Box::new(TipeOperand::Immediate(0))
-    };
-    let apply_conditional_flag = |target_flag: &str, new_calc: TipeOperand, ops_vec: &mut Vec<StatementIr>| {
-        let old_flag = TipeOperand::Register(target_flag.to_string());
-        let final_val = TipeOperand::Conditional {
-            condition: Box::new(is_count_zero.clone()),
-            true_val: Box::new(old_flag),
-            false_val: Box::new(new_calc),
-        };
-        ops_vec.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register(target_flag.to_string()), final_val));
-    };
-    let sf_calc = TipeOperand::Expression {
-        operasi: OperasiIr::Shr,
-        operand_kiri: Box::new(result_op.clone()),
-        operand_kanan: Box::new(TipeOperand::Immediate(63)),
+    let dest = lifter.ambil_operand(instr, 0);
+    let sumber = lifter.ambil_operand(instr, 1);
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let mask = if lebar == 64 { 0x3F } else { 0x1F };
+    let count = match lifter.ambil_operand(instr, 2) {
+        TipeOperand::Immediate(v) => TipeOperand::Immediate(v & mask),
+        lain => eflags::buat_expr(OperasiIr::And, lain, TipeOperand::Immediate(mask)),
     };
-    apply_conditional_flag(FLAG_SF, sf_calc, ops);
-    let zf_calc = TipeOperand::Expression {
-        operasi: OperasiIr::Je,
-        operand_kiri: Box::new(result_op.clone()),
-        operand_kanan: Box::new(TipeOperand::Immediate(0)),
+    if count == TipeOperand::Immediate(0) {
+        return;
+    }
+    let asal = eflags::salin_operand_asal(instr.address, &dest, "temp_flag_kiri", lebar, ops);
+    let sisa = match &count {
+        TipeOperand::Immediate(v) => TipeOperand::Immediate(lebar as i64 - v),
+        c => eflags::buat_expr(OperasiIr::Sub, TipeOperand::Immediate(lebar as i64), c.clone()),
     };
-    apply_conditional_flag(FLAG_ZF, zf_calc, ops);
-    let pf_calc = TipeOperand::Expression {
-        operasi: OperasiIr::Call,
-        operand_kiri: Box::new(TipeOperand::Register("__intrinsic_parity".to_string())),
-        operand_kanan: Box::new(result_op.clone())
+    let (op_ir, bagian_dest, bagian_sumber) = if mnemonic == "shld" {
+        (OperasiIr::Shl, eflags::buat_expr(OperasiIr::Shl, dest.clone(), count.clone()), eflags::buat_expr(OperasiIr::Shr, sumber, sisa))
+    } else {
+        (OperasiIr::Shr, eflags::buat_expr(OperasiIr::Shr, dest.clone(), count.clone()), eflags::buat_expr(OperasiIr::Shl, sumber, sisa))
     };
-    apply_conditional_flag(FLAG_PF, pf_calc, ops);
-    let is_count_one = TipeOperand::Expression {
-        operasi: OperasiIr::Je,
-        operand_kiri: Box::new(count_op.clone()),
-        operand_kanan: Box::new(TipeOperand::Immediate(1))
+    let gabungan = eflags::buat_expr(OperasiIr::Or, bagian_dest, bagian_sumber);
+    let nilai = match count {
+        TipeOperand::Immediate(_) => gabungan,
+        _ => TipeOperand::Conditional {
+            condition: Box::new(eflags::buat_expr(OperasiIr::Je, count.clone(), TipeOperand::Immediate(0))),
+            true_val: Box::new(dest.clone()),
+            false_val: Box::new(gabungan),
+        },
     };
-    let of_defined_val = if mnemonic == "shl" || mnemonic == "sal" {
-         TipeOperand::Expression {
-            operasi: OperasiIr::Xor,
-            operand_kiri: Box::new(TipeOperand::Register(FLAG_SF.to_string())), 
-            operand_kanan: Box::new(TipeOperand::Register(FLAG_CF.to_string()))
+    ops.push(StatementIr::new(instr.address, op_ir, dest.clone(), nilai));
+    update_flags_shift(instr.address, mnemonic, &OperandFlag::new(dest, asal, count, lebar), ops);
+}
+
+pub fn proses_uji_bit(
+    lifter: &IrLifter,
+    instr: &InstructionNormalized,
+    mnemonic: &str,
+    ops: &mut Vec<StatementIr>
+) {
+    let mnem = mnemonic.split_whitespace().last().unwrap_or(mnemonic);
+    let dest = lifter.ambil_operand(instr, 0);
+    let posisi_raw = lifter.ambil_operand(instr, 1);
+    let lebar = lifter.ambil_lebar_bit(instr, 0) as i64;
+    let addr = instr.address;
+    let is_memori = matches!(dest, TipeOperand::Memory(_) | TipeOperand::MemoryRef { .. } | TipeOperand::MemoryComplex { .. });
+    if is_memori && !matches!(posisi_raw, TipeOperand::Immediate(_)) {
+        let nama = match mnem {
+            "bts" => "_bittestandset",
+            "btr" => "_bittestandreset",
+            "btc" => "_bittestandcomplement",
+            _ => "_bittest",
+        };
+        let nama = if lebar == 64 { format!("{}64", nama) } else { nama.to_string() };
+        let alamat = TipeOperand::Register("temp_alamat_bit".to_string());
+        ops.push(StatementIr::new(addr, OperasiIr::Lea, alamat.clone(), dest).with_type(lifter.ambil_tipe_pointer()));
+        let mut uji = StatementIr::new(addr, OperasiIr::Intrinsic(nama), TipeOperand::Register(FLAG_CF.to_string()), TipeOperand::None)
+            .with_type(TipeDataIr::I8);
+        uji.operand_tambahan = vec![alamat, posisi_raw];
+        ops.push(uji);
+    } else {
+        let posisi = match posisi_raw {
+            TipeOperand::Immediate(v) => TipeOperand::Immediate(v & (lebar - 1)),
+            lain => eflags::buat_expr(OperasiIr::And, lain, TipeOperand::Immediate(lebar - 1)),
+        };
+        eflags::tulis_flag(addr, FLAG_CF, eflags::ambil_bit(dest.clone(), posisi.clone()), ops);
+        let topeng = match &posisi {
+            TipeOperand::Immediate(v) => TipeOperand::Immediate(1i64 << v),
+            p => eflags::buat_expr(OperasiIr::Shl, TipeOperand::Immediate(1), p.clone()),
+        };
+        let ubah = match mnem {
+            "bts" => Some((OperasiIr::Or, topeng)),
+            "btr" => Some((OperasiIr::And, match topeng {
+                TipeOperand::Immediate(v) => TipeOperand::Immediate(!v),
+                t => eflags::buat_expr(OperasiIr::Xor, t, TipeOperand::Immediate(-1)),
+            })),
+            "btc" => Some((OperasiIr::Xor, topeng)),
+            _ => None,
+        };
+        if let Some((op_ir, operand)) = ubah {
+            let nilai = eflags::buat_expr(op_ir.clone(), dest.clone(), operand);
+            ops.push(StatementIr::new(addr, op_ir, dest, nilai));
         }
-    } else if mnemonic == "shr" {
-        TipeOperand::Immediate(0)
+    }
+    for flag in [FLAG_OF, FLAG_SF, FLAG_AF, FLAG_PF] {
+        eflags::tulis_flag(addr, flag, eflags::flag_undefined(), ops);
+    }
+}
+
+pub fn proses_bswap(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let dest = lifter.ambil_operand(instr, 0);
+    let nama = format!("__builtin_bswap{}", lifter.ambil_lebar_bit(instr, 0));
+    ops.push(StatementIr::new(instr.address, OperasiIr::Intrinsic(nama), dest.clone(), dest));
+}
+
+pub fn proses_hitung_bit(lifter: &IrLifter, instr: &InstructionNormalized, op: OperasiIr, ops: &mut Vec<StatementIr>) {
+    let dest = lifter.ambil_operand(instr, 0);
+    let src = lifter.ambil_operand(instr, 1);
+    let lebar = lifter.ambil_lebar_bit(instr, 0);
+    let addr = instr.address;
+    let asal = if src == dest {
+        eflags::salin_operand_asal(addr, &src, "temp_flag_kanan", lebar, ops)
     } else {
-        TipeOperand::Immediate(0)
+        src
     };
-    let of_calc = TipeOperand::Conditional {
-        condition: Box::new(is_count_one),
-        true_val: Box::new(of_defined_val),
-        false_val: Box::new(TipeOperand::Register("UNDEFINED_FLAG".to_string()))
+    let tipe = IrLifter::tipe_dari_ukuran((lebar / 8) as u8).unwrap_or(TipeDataIr::I64);
+    ops.push(StatementIr::new(addr, op.clone(), dest.clone(), asal.clone()).with_type(tipe));
+    let sumber_nol = eflags::buat_expr(OperasiIr::Je, asal, TipeOperand::Immediate(0));
+    if op == OperasiIr::Popcnt {
+        eflags::tulis_flag(addr, FLAG_ZF, sumber_nol, ops);
+        for flag in [FLAG_CF, FLAG_OF, FLAG_SF, FLAG_AF, FLAG_PF] {
+            eflags::tulis_flag(addr, flag, TipeOperand::Immediate(0), ops);
+        }
+    } else {
+        eflags::tulis_flag(addr, FLAG_CF, sumber_nol, ops);
+        eflags::tulis_flag(addr, FLAG_ZF, eflags::buat_expr(OperasiIr::Je, dest, TipeOperand::Immediate(0)), ops);
+        for flag in [FLAG_OF, FLAG_SF, FLAG_AF, FLAG_PF] {
+            eflags::tulis_flag(addr, flag, eflags::flag_undefined(), ops);
+        }
+    }
+}
+
+fn tulis_flag_bila_digeser(addr: u64, flag: &str, count: &TipeOperand, nilai: TipeOperand, ops: &mut Vec<StatementIr>) {
+    let is_count_zero = eflags::buat_expr(OperasiIr::Je, count.clone(), TipeOperand::Immediate(0));
+    let final_val = TipeOperand::Conditional {
+        condition: Box::new(is_count_zero),
+        true_val: Box::new(TipeOperand::Register(flag.to_string())),
+        false_val: Box::new(nilai),
     };
-    apply_conditional_flag(FLAG_OF, of_calc, ops);
+    eflags::tulis_flag(addr, flag, final_val, ops);
 }
 
-fn update_flags_rotate(
-    addr: u64,
-    _mnemonic: &str,
-    _result_op: &TipeOperand,
-    count_op: &TipeOperand,
-    ops: &mut Vec<StatementIr>
-) {
-    let is_count_zero = TipeOperand::Expression {
-        operasi: OperasiIr::Je,
-        operand_kiri: Box::new(count_op.clone()),
-        operand_kanan: Box::new(TipeOperand::Immediate(0))
+fn nilai_bila_satu(count: &TipeOperand, nilai: TipeOperand) -> TipeOperand {
+    TipeOperand::Conditional {
+        condition: Box::new(eflags::buat_expr(OperasiIr::Je, count.clone(), TipeOperand::Immediate(1))),
+        true_val: Box::new(nilai),
+        false_val: Box::new(eflags::flag_undefined()),
+    }
+}
+
+fn update_flags_shift(addr: u64, mnemonic: &str, o: &OperandFlag, ops: &mut Vec<StatementIr>) {
+    let count = &o.kanan;
+    let cf_calc = if matches!(mnemonic, "shl" | "sal" | "shld") {
+        let posisi = eflags::buat_expr(OperasiIr::Sub, TipeOperand::Immediate(o.lebar as i64), count.clone());
+        eflags::ambil_bit(o.kiri.clone(), posisi)
+    } else {
+        let posisi = eflags::buat_expr(OperasiIr::Sub, count.clone(), TipeOperand::Immediate(1));
+        eflags::ambil_bit(o.kiri.clone(), posisi)
     };
-    let set_undefined = |flag: &str, ops_vec: &mut Vec<StatementIr>| {
-        let old_val = TipeOperand::Register(flag.to_string());
-        let undef_val = TipeOperand::Register("UNDEFINED".to_string());
-        let final_val = TipeOperand::Conditional {
-            condition: Box::new(is_count_zero.clone()),
-            true_val: Box::new(old_val),
-            false_val: Box::new(undef_val)
-        };
-        ops_vec.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register(flag.to_string()), final_val));
+    tulis_flag_bila_digeser(addr, FLAG_CF, count, cf_calc, ops);
+    let of_defined_val = match mnemonic {
+        "shl" | "sal" | "shld" => eflags::buat_expr(
+            OperasiIr::Xor,
+            eflags::nilai_sign(o),
+            TipeOperand::Register(FLAG_CF.to_string()),
+        ),
+        "shr" => o.bit_tanda(o.kiri.clone()),
+        "shrd" => eflags::buat_expr(OperasiIr::Xor, eflags::nilai_sign(o), o.bit_tanda(o.kiri.clone())),
+        _ => TipeOperand::Immediate(0),
+    };
+    tulis_flag_bila_digeser(addr, FLAG_OF, count, nilai_bila_satu(count, of_defined_val), ops);
+    tulis_flag_bila_digeser(addr, FLAG_SF, count, eflags::nilai_sign(o), ops);
+    tulis_flag_bila_digeser(addr, FLAG_ZF, count, eflags::nilai_zero(o), ops);
+    let pf_calc = eflags::nilai_parity(addr, &o.hasil, ops);
+    tulis_flag_bila_digeser(addr, FLAG_PF, count, pf_calc, ops);
+    tulis_flag_bila_digeser(addr, FLAG_AF, count, eflags::flag_undefined(), ops);
+}
+
+fn update_flags_rotate(addr: u64, mnemonic: &str, o: &OperandFlag, ops: &mut Vec<StatementIr>) {
+    let count = &o.kanan;
+    let msb = eflags::nilai_sign(o);
+    let (cf_calc, of_defined_val) = if mnemonic == "rol" {
+        let cf = eflags::ambil_bit(o.hasil.clone(), TipeOperand::Immediate(0));
+        (cf, eflags::buat_expr(OperasiIr::Xor, msb, TipeOperand::Register(FLAG_CF.to_string())))
+    } else {
+        let bit_kedua = eflags::ambil_bit(o.hasil.clone(), TipeOperand::Immediate((o.lebar - 2) as i64));
+        (msb.clone(), eflags::buat_expr(OperasiIr::Xor, msb, bit_kedua))
     };
-    set_undefined(FLAG_ZF, ops);
-    set_undefined(FLAG_SF, ops);
-    set_undefined(FLAG_PF, ops);
-}
\ No newline at end of file
+    tulis_flag_bila_digeser(addr, FLAG_CF, count, cf_calc, ops);
+    tulis_flag_bila_digeser(addr, FLAG_OF, count, nilai_bila_satu(count, of_defined_val), ops);
+}
@@ -1,30 +1,136 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use capstone::arch::arm64::Arm64Insn;
+use capstone::arch::x86::X86Insn;
 use crate::disasm::instruction::InstructionNormalized;
-use crate::ir::types::{StatementIr, TipeOperand, OperasiIr};
+use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
 use super::IrLifter;
+use super::dispatch::{self, ArsitekturDispatch};
+
+#[derive(Debug, Clone, Copy)]
+struct DefinisiCrypto {
+    intrinsic: &'static str,
+    baca_tujuan: bool,
+    implisit: Option<&'static str>,
+}
+
+const fn definisi(intrinsic: &'static str, baca_tujuan: bool) -> DefinisiCrypto {
+    DefinisiCrypto { intrinsic, baca_tujuan, implisit: None }
+}
+
+const INTRINSIC_CRC32_X86: &str = "_mm_crc32_u";
+
+const TABEL_X86: &[(X86Insn, DefinisiCrypto)] = &[
+    (X86Insn::X86_INS_AESENC, definisi("_mm_aesenc_si128", true)),
+    (X86Insn::X86_INS_AESENCLAST, definisi("_mm_aesenclast_si128", true)),
+    (X86Insn::X86_INS_AESDEC, definisi("_mm_aesdec_si128", true)),
+    (X86Insn::X86_INS_AESDECLAST, definisi("_mm_aesdeclast_si128", true)),
+    (X86Insn::X86_INS_AESIMC, definisi("_mm_aesimc_si128", false)),
+    (X86Insn::X86_INS_AESKEYGENASSIST, definisi("_mm_aeskeygenassist_si128", false)),
+    (X86Insn::X86_INS_VAESENC, definisi("_mm_aesenc_si128", false)),
+    (X86Insn::X86_INS_VAESENCLAST, definisi("_mm_aesenclast_si128", false)),
+    (X86Insn::X86_INS_VAESDEC, definisi("_mm_aesdec_si128", false)),
+    (X86Insn::X86_INS_VAESDECLAST, definisi("_mm_aesdeclast_si128", false)),
+    (X86Insn::X86_INS_VAESIMC, definisi("_mm_aesimc_si128", false)),
+    (X86Insn::X86_INS_VAESKEYGENASSIST, definisi("_mm_aeskeygenassist_si128", false)),
+    (X86Insn::X86_INS_SHA1RNDS4, definisi("_mm_sha1rnds4_epu32", true)),
+    (X86Insn::X86_INS_SHA1NEXTE, definisi("_mm_sha1nexte_epu32", true)),
+    (X86Insn::X86_INS_SHA1MSG1, definisi("_mm_sha1msg1_epu32", true)),
+    (X86Insn::X86_INS_SHA1MSG2, definisi("_mm_sha1msg2_epu32", true)),
+    (X86Insn::X86_INS_SHA256RNDS2, DefinisiCrypto { intrinsic: "_mm_sha256rnds2_epu32", baca_tujuan: true, implisit: Some("xmm0") }),
+    (X86Insn::X86_INS_SHA256MSG1, definisi("_mm_sha256msg1_epu32", true)),
+    (X86Insn::X86_INS_SHA256MSG2, definisi("_mm_sha256msg2_epu32", true)),
+    (X86Insn::X86_INS_PCLMULQDQ, definisi("_mm_clmulepi64_si128", true)),
+    (X86Insn::X86_INS_VPCLMULQDQ, definisi("_mm_clmulepi64_si128", false)),
+    (X86Insn::X86_INS_CRC32, definisi(INTRINSIC_CRC32_X86, true)),
+];
+
+const TABEL_ARM64: &[(Arm64Insn, DefinisiCrypto)] = &[
+    (Arm64Insn::ARM64_INS_AESE, definisi("vaeseq_u8", true)),
+    (Arm64Insn::ARM64_INS_AESD, definisi("vaesdq_u8", true)),
+    (Arm64Insn::ARM64_INS_AESMC, definisi("vaesmcq_u8", false)),
+    (Arm64Insn::ARM64_INS_AESIMC, definisi("vaesimcq_u8", false)),
+    (Arm64Insn::ARM64_INS_SHA1C, definisi("vsha1cq_u32", true)),
+    (Arm64Insn::ARM64_INS_SHA1P, definisi("vsha1pq_u32", true)),
+    (Arm64Insn::ARM64_INS_SHA1M, definisi("vsha1mq_u32", true)),
+    (Arm64Insn::ARM64_INS_SHA1H, definisi("vsha1h_u32", false)),
+    (Arm64Insn::ARM64_INS_SHA1SU0, definisi("vsha1su0q_u32", true)),
+    (Arm64Insn::ARM64_INS_SHA1SU1, definisi("vsha1su1q_u32", true)),
+    (Arm64Insn::ARM64_INS_SHA256H, definisi("vsha256hq_u32", true)),
+    (Arm64Insn::ARM64_INS_SHA256H2, definisi("vsha256h2q_u32", true)),
+    (Arm64Insn::ARM64_INS_SHA256SU0, definisi("vsha256su0q_u32", true)),
+    (Arm64Insn::ARM64_INS_SHA256SU1, definisi("vsha256su1q_u32", true)),
+    (Arm64Insn::ARM64_INS_PMULL, definisi("vmull_p64", false)),
+    (Arm64Insn::ARM64_INS_PMULL2, definisi("vmull_high_p64", false)),
+    (Arm64Insn::ARM64_INS_CRC32B, definisi("__crc32b", false)),
+    (Arm64Insn::ARM64_INS_CRC32H, definisi("__crc32h", false)),
+    (Arm64Insn::ARM64_INS_CRC32W, definisi("__crc32w", false)),
+    (Arm64Insn::ARM64_INS_CRC32X, definisi("__crc32d", false)),
+    (Arm64Insn::ARM64_INS_CRC32CB, definisi("__crc32cb", false)),
+    (Arm64Insn::ARM64_INS_CRC32CH, definisi("__crc32ch", false)),
+    (Arm64Insn::ARM64_INS_CRC32CW, definisi("__crc32cw", false)),
+    (Arm64Insn::ARM64_INS_CRC32CX, definisi("__crc32cd", false)),
+];
+
+static DEFINISI_X86: LazyLock<HashMap<u32, DefinisiCrypto>> = LazyLock::new(|| {
+    TABEL_X86.iter().map(|(id, def)| (*id as u32, *def)).collect()
+});
+
+static DEFINISI_ARM64: LazyLock<HashMap<u32, DefinisiCrypto>> = LazyLock::new(|| {
+    TABEL_ARM64.iter().map(|(id, def)| (*id as u32, *def)).collect()
+});
+
+fn ambil_definisi(lifter: &IrLifter, instr: &InstructionNormalized) -> Option<DefinisiCrypto> {
+    let (arch, tabel) = if lifter.mode_arm64 {
+        (ArsitekturDispatch::Arm64, &*DEFINISI_ARM64)
+    } else {
+        (ArsitekturDispatch::X86, &*DEFINISI_X86)
+    };
+    let def = dispatch::ambil_id_instruksi(arch, instr).and_then(|id| tabel.get(&id).copied())?;
+    if def.intrinsic.starts_with("vmull") && !instr.grup.iter().any(|g| g == "crypto") {
+        return None;
+    }
+    Some(def)
+}
+
+pub fn proses_instruksi_crypto(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) -> bool {
+    let Some(def) = ambil_definisi(lifter, instr) else {
+        return false;
+    };
+    let dest = lifter.ambil_operand(instr, 0);
+    let awal = if def.baca_tujuan { 0 } else { 1 };
+    let mut args: Vec<TipeOperand> = (awal..instr.operands_detail.len()).map(|i| lifter.ambil_operand(instr, i)).collect();
+    if let Some(reg) = def.implisit {
+        args.push(TipeOperand::Register(reg.to_string()));
+    }
+    let nama = if def.intrinsic == INTRINSIC_CRC32_X86 {
+        format!("{}{}", INTRINSIC_CRC32_X86, lifter.ambil_lebar_bit(instr, 1))
+    } else {
+        def.intrinsic.to_string()
+    };
+    let tipe = IrLifter::tipe_dari_ukuran((lifter.ambil_lebar_bit(instr, 0) / 8) as u8).unwrap_or(TipeDataIr::V128);
+    let mut stmt = StatementIr::new(instr.address, OperasiIr::Intrinsic(nama), dest, TipeOperand::None).with_type(tipe);
+    stmt.operand_tambahan = args;
+    ops.push(stmt);
+    true
+}
 
 pub fn proses_crypto_instruction(
-    lifter: &IrLifter, 
-    instr: &InstructionNormalized, 
-    mnemonic: &str, 
+    lifter: &IrLifter,
+    instr: &InstructionNormalized,
+    mnemonic: &str,
     ops: &mut Vec<StatementIr>
 ) {
-    let intrinsic = match mnemonic {
-        "aesenc" => "_mm_aesenc_si128",
-        "aesdec" => "_mm_aesdec_si128",
-        "aesenclast" => "_mm_aesenclast_si128",
-        "aesdeclast" => "_mm_aesdeclast_si128",
-        "sha1msg1" => "_mm_sha1msg1_epu128",
-        "sha1msg2" => "_mm_sha1msg2_epu128",
-        _ => mnemonic
-    };
+    if proses_instruksi_crypto(lifter, instr, ops) {
+        return;
+    }
     let dest = lifter.ambil_operand(instr, 0);
-    let src = lifter.ambil_operand(instr, 1);
-    let mut call_stmt = StatementIr::new(
+    let mut stmt = StatementIr::new(
         instr.address,
-        OperasiIr::Call,
-        TipeOperand::Register(intrinsic.to_string()),
+        OperasiIr::Intrinsic(format!("__builtin_{}", mnemonic)),
+        dest,
         TipeOperand::None
-    );
-    call_stmt.operand_tambahan = vec![dest.clone(), src];
-    ops.push(call_stmt);
-}
\ No newline at end of file
+    ).with_type(TipeDataIr::V128);
+    stmt.operand_tambahan = (0..instr.operands_detail.len()).map(|i| lifter.ambil_operand(instr, i)).collect();
+    ops.push(stmt);
+}
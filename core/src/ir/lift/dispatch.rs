@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use capstone::prelude::*;
+use capstone::InsnId;
+use capstone::arch::x86::X86Insn;
+use capstone::arch::arm64::Arm64Insn;
+use crate::disasm::instruction::InstructionNormalized;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArsitekturDispatch {
+    X86,
+    Arm64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KelasInstruksi {
+    Umum,
+    Simd,
+    Fpu,
+    Crypto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AksiLift {
+    Ret,
+    Call,
+    Jump,
+    JumpKondisi,
+    Push,
+    Pop,
+    PushFlag,
+    PopFlag,
+    Mov,
+    MovKondisi,
+    SetKondisi,
+    Ekstensi,
+    Lea,
+    Add,
+    Sub,
+    Mul,
+    Imul,
+    Div,
+    Idiv,
+    And,
+    Or,
+    Xor,
+    Geser,
+    GeserGanda,
+    UjiBit,
+    Bswap,
+    Popcnt,
+    Lzcnt,
+    Tzcnt,
+    Tukar,
+    TukarBanding,
+    Cmp,
+    Cmn,
+    Test,
+    Sistem,
+    Nop,
+}
+
+const GRUP_SIMD_X86: &[&str] = &[
+    "sse1", "sse2", "sse3", "ssse3", "sse41", "sse42", "sse4a",
+    "avx", "avx2", "avx512", "fma", "fma4", "f16c", "mmx", "xop", "3dnow",
+];
+const GRUP_CRYPTO_X86: &[&str] = &["aes", "sha", "pclmul"];
+const GRUP_SIMD_ARM64: &[&str] = &["neon"];
+const GRUP_FPU_ARM64: &[&str] = &["fparmv8"];
+const GRUP_CRYPTO_ARM64: &[&str] = &["crypto"];
+
+const TABEL_X86: &[(X86Insn, AksiLift)] = &[
+    (X86Insn::X86_INS_RET, AksiLift::Ret),
+    (X86Insn::X86_INS_CALL, AksiLift::Call),
+    (X86Insn::X86_INS_JMP, AksiLift::Jump),
+    (X86Insn::X86_INS_JE, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JNE, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JG, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JA, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JGE, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JAE, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JL, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JB, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JLE, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JBE, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JS, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JNS, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JO, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JNO, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JP, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_JNP, AksiLift::JumpKondisi),
+    (X86Insn::X86_INS_PUSH, AksiLift::Push),
+    (X86Insn::X86_INS_POP, AksiLift::Pop),
+    (X86Insn::X86_INS_PUSHF, AksiLift::PushFlag),
+    (X86Insn::X86_INS_PUSHFD, AksiLift::PushFlag),
+    (X86Insn::X86_INS_PUSHFQ, AksiLift::PushFlag),
+    (X86Insn::X86_INS_POPF, AksiLift::PopFlag),
+    (X86Insn::X86_INS_POPFD, AksiLift::PopFlag),
+    (X86Insn::X86_INS_POPFQ, AksiLift::PopFlag),
+    (X86Insn::X86_INS_MOV, AksiLift::Mov),
+    (X86Insn::X86_INS_MOVABS, AksiLift::Mov),
+    (X86Insn::X86_INS_CMOVA, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVAE, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVB, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVBE, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVE, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVG, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVGE, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVL, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVLE, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVNE, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVNO, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVNP, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVNS, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVO, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVP, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_CMOVS, AksiLift::MovKondisi),
+    (X86Insn::X86_INS_SETA, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETAE, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETB, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETBE, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETE, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETG, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETGE, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETL, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETLE, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETNE, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETNO, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETNP, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETNS, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETO, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETP, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_SETS, AksiLift::SetKondisi),
+    (X86Insn::X86_INS_MOVZX, AksiLift::Ekstensi),
+    (X86Insn::X86_INS_MOVSX, AksiLift::Ekstensi),
+    (X86Insn::X86_INS_MOVSXD, AksiLift::Ekstensi),
+    (X86Insn::X86_INS_CBW, AksiLift::Ekstensi),
+    (X86Insn::X86_INS_CWDE, AksiLift::Ekstensi),
+    (X86Insn::X86_INS_CDQE, AksiLift::Ekstensi),
+    (X86Insn::X86_INS_LEA, AksiLift::Lea),
+    (X86Insn::X86_INS_ADD, AksiLift::Add),
+    (X86Insn::X86_INS_SUB, AksiLift::Sub),
+    (X86Insn::X86_INS_IMUL, AksiLift::Imul),
+    (X86Insn::X86_INS_MUL, AksiLift::Mul),
+    (X86Insn::X86_INS_IDIV, AksiLift::Idiv),
+    (X86Insn::X86_INS_DIV, AksiLift::Div),
+    (X86Insn::X86_INS_AND, AksiLift::And),
+    (X86Insn::X86_INS_OR, AksiLift::Or),
+    (X86Insn::X86_INS_XOR, AksiLift::Xor),
+    (X86Insn::X86_INS_SHL, AksiLift::Geser),
+    (X86Insn::X86_INS_SAL, AksiLift::Geser),
+    (X86Insn::X86_INS_SHR, AksiLift::Geser),
+    (X86Insn::X86_INS_SAR, AksiLift::Geser),
+    (X86Insn::X86_INS_ROL, AksiLift::Geser),
+    (X86Insn::X86_INS_ROR, AksiLift::Geser),
+    (X86Insn::X86_INS_SHLD, AksiLift::GeserGanda),
+    (X86Insn::X86_INS_SHRD, AksiLift::GeserGanda),
+    (X86Insn::X86_INS_BT, AksiLift::UjiBit),
+    (X86Insn::X86_INS_BTS, AksiLift::UjiBit),
+    (X86Insn::X86_INS_BTR, AksiLift::UjiBit),
+    (X86Insn::X86_INS_BTC, AksiLift::UjiBit),
+    (X86Insn::X86_INS_BSWAP, AksiLift::Bswap),
+    (X86Insn::X86_INS_POPCNT, AksiLift::Popcnt),
+    (X86Insn::X86_INS_LZCNT, AksiLift::Lzcnt),
+    (X86Insn::X86_INS_TZCNT, AksiLift::Tzcnt),
+    (X86Insn::X86_INS_XCHG, AksiLift::Tukar),
+    (X86Insn::X86_INS_CMPXCHG, AksiLift::TukarBanding),
+    (X86Insn::X86_INS_CMP, AksiLift::Cmp),
+    (X86Insn::X86_INS_TEST, AksiLift::Test),
+    (X86Insn::X86_INS_SYSCALL, AksiLift::Sistem),
+    (X86Insn::X86_INS_SYSENTER, AksiLift::Sistem),
+    (X86Insn::X86_INS_CPUID, AksiLift::Sistem),
+    (X86Insn::X86_INS_RDTSC, AksiLift::Sistem),
+    (X86Insn::X86_INS_ANDN, AksiLift::Sistem),
+    (X86Insn::X86_INS_XADD, AksiLift::Sistem),
+    (X86Insn::X86_INS_LFENCE, AksiLift::Sistem),
+    (X86Insn::X86_INS_SFENCE, AksiLift::Sistem),
+    (X86Insn::X86_INS_MFENCE, AksiLift::Sistem),
+    (X86Insn::X86_INS_NOP, AksiLift::Nop),
+];
+
+const TABEL_ARM64: &[(Arm64Insn, AksiLift)] = &[
+    (Arm64Insn::ARM64_INS_RET, AksiLift::Ret),
+    (Arm64Insn::ARM64_INS_BL, AksiLift::Call),
+    (Arm64Insn::ARM64_INS_B, AksiLift::Jump),
+    (Arm64Insn::ARM64_INS_CBZ, AksiLift::JumpKondisi),
+    (Arm64Insn::ARM64_INS_CBNZ, AksiLift::JumpKondisi),
+    (Arm64Insn::ARM64_INS_ADR, AksiLift::Lea),
+    (Arm64Insn::ARM64_INS_ADRP, AksiLift::Lea),
+    (Arm64Insn::ARM64_INS_MOV, AksiLift::Mov),
+    (Arm64Insn::ARM64_INS_SXTB, AksiLift::Ekstensi),
+    (Arm64Insn::ARM64_INS_SXTH, AksiLift::Ekstensi),
+    (Arm64Insn::ARM64_INS_SXTW, AksiLift::Ekstensi),
+    (Arm64Insn::ARM64_INS_UXTB, AksiLift::Ekstensi),
+    (Arm64Insn::ARM64_INS_UXTH, AksiLift::Ekstensi),
+    (Arm64Insn::ARM64_INS_UXTW, AksiLift::Ekstensi),
+    (Arm64Insn::ARM64_INS_AND, AksiLift::And),
+    (Arm64Insn::ARM64_INS_TST, AksiLift::Test),
+    (Arm64Insn::ARM64_INS_ORR, AksiLift::Or),
+    (Arm64Insn::ARM64_INS_EOR, AksiLift::Xor),
+    (Arm64Insn::ARM64_INS_CMP, AksiLift::Cmp),
+    (Arm64Insn::ARM64_INS_CMN, AksiLift::Cmn),
+    (Arm64Insn::ARM64_INS_NOP, AksiLift::Nop),
+];
+
+static AKSI_X86: LazyLock<HashMap<u32, AksiLift>> = LazyLock::new(|| {
+    TABEL_X86.iter().map(|(id, aksi)| (*id as u32, *aksi)).collect()
+});
+
+static AKSI_ARM64: LazyLock<HashMap<u32, AksiLift>> = LazyLock::new(|| {
+    TABEL_ARM64.iter().map(|(id, aksi)| (*id as u32, *aksi)).collect()
+});
+
+static ID_NAMA_X86: LazyLock<HashMap<String, u32>> = LazyLock::new(|| {
+    let cs = Capstone::new().x86().mode(arch::x86::ArchMode::Mode64).build();
+    bangun_peta_nama(cs.ok(), X86Insn::X86_INS_ENDING as u32)
+});
+
+static ID_NAMA_ARM64: LazyLock<HashMap<String, u32>> = LazyLock::new(|| {
+    let cs = Capstone::new().arm64().mode(arch::arm64::ArchMode::Arm).build();
+    bangun_peta_nama(cs.ok(), Arm64Insn::ARM64_INS_ENDING as u32)
+});
+
+fn bangun_peta_nama(cs: Option<Capstone>, id_akhir: u32) -> HashMap<String, u32> {
+    let Some(cs) = cs else { return HashMap::new() };
+    (1..id_akhir)
+        .filter_map(|id| cs.insn_name(InsnId(id)).map(|nama| (nama, id)))
+        .collect()
+}
+
+pub fn ambil_id_instruksi(arch: ArsitekturDispatch, instr: &InstructionNormalized) -> Option<u32> {
+    if instr.id_instruksi != 0 {
+        return Some(instr.id_instruksi);
+    }
+    let mnemonic = instr.mnemonic.to_lowercase();
+    let mnem_inti = mnemonic.split_whitespace().last().unwrap_or("");
+    let mnem_inti = match arch {
+        ArsitekturDispatch::Arm64 => mnem_inti.split('.').next().unwrap_or(mnem_inti),
+        ArsitekturDispatch::X86 => mnem_inti,
+    };
+    let peta = match arch {
+        ArsitekturDispatch::X86 => &*ID_NAMA_X86,
+        ArsitekturDispatch::Arm64 => &*ID_NAMA_ARM64,
+    };
+    peta.get(mnem_inti).copied()
+}
+
+pub fn tentukan_aksi(arch: ArsitekturDispatch, instr: &InstructionNormalized) -> Option<AksiLift> {
+    let id = ambil_id_instruksi(arch, instr)?;
+    let aksi = match arch {
+        ArsitekturDispatch::X86 => AKSI_X86.get(&id).copied()?,
+        ArsitekturDispatch::Arm64 => AKSI_ARM64.get(&id).copied()?,
+    };
+    if arch == ArsitekturDispatch::Arm64 && aksi == AksiLift::Jump && instr.mnemonic.contains('.') {
+        return Some(AksiLift::JumpKondisi);
+    }
+    Some(aksi)
+}
+
+pub fn klasifikasi_instruksi(arch: ArsitekturDispatch, instr: &InstructionNormalized) -> KelasInstruksi {
+    let (grup_crypto, grup_simd, grup_fpu): (&[&str], &[&str], &[&str]) = match arch {
+        ArsitekturDispatch::X86 => (GRUP_CRYPTO_X86, GRUP_SIMD_X86, &["fpu"]),
+        ArsitekturDispatch::Arm64 => (GRUP_CRYPTO_ARM64, GRUP_SIMD_ARM64, GRUP_FPU_ARM64),
+    };
+    let punya = |daftar: &[&str]| instr.grup.iter().any(|g| daftar.contains(&g.as_str()));
+    if punya(grup_crypto) {
+        KelasInstruksi::Crypto
+    } else if punya(grup_simd) {
+        KelasInstruksi::Simd
+    } else if punya(grup_fpu) {
+        KelasInstruksi::Fpu
+    } else {
+        KelasInstruksi::Umum
+    }
+}
@@ -0,0 +1,185 @@
+use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
+use crate::arch::x86::{FLAG_AF, FLAG_CF, FLAG_OF, FLAG_PF, FLAG_SF, FLAG_ZF};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum JenisOperasiFlag {
+    Add,
+    Adc,
+    Sub,
+    Sbb,
+    Inc,
+    Dec,
+    Logika,
+    Imul,
+}
+
+impl JenisOperasiFlag {
+    pub fn dari_operasi(op: &OperasiIr) -> Option<Self> {
+        match op {
+            OperasiIr::Add => Some(Self::Add),
+            OperasiIr::Adc => Some(Self::Adc),
+            OperasiIr::Sub => Some(Self::Sub),
+            OperasiIr::Sbb => Some(Self::Sbb),
+            OperasiIr::Inc => Some(Self::Inc),
+            OperasiIr::Dec => Some(Self::Dec),
+            OperasiIr::And | OperasiIr::Or | OperasiIr::Xor => Some(Self::Logika),
+            OperasiIr::Imul => Some(Self::Imul),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OperandFlag {
+    pub hasil: TipeOperand,
+    pub kiri: TipeOperand,
+    pub kanan: TipeOperand,
+    pub lebar: u32,
+}
+
+impl OperandFlag {
+    pub fn new(hasil: TipeOperand, kiri: TipeOperand, kanan: TipeOperand, lebar: u32) -> Self {
+        Self { hasil, kiri, kanan, lebar: lebar.clamp(8, 64) }
+    }
+    pub fn mask(&self) -> i64 {
+        if self.lebar >= 64 { -1 } else { (1i64 << self.lebar) - 1 }
+    }
+    pub fn potong(&self, op: &TipeOperand) -> TipeOperand {
+        match op {
+            TipeOperand::Immediate(v) => TipeOperand::Immediate(v & self.mask()),
+            _ if self.lebar >= 64 => op.clone(),
+            _ => buat_expr(OperasiIr::And, op.clone(), TipeOperand::Immediate(self.mask())),
+        }
+    }
+    pub fn bit_tanda(&self, op: TipeOperand) -> TipeOperand {
+        ambil_bit(op, TipeOperand::Immediate((self.lebar - 1) as i64))
+    }
+}
+
+pub fn salin_operand_asal(addr: u64, op: &TipeOperand, nama: &str, lebar: u32, ops: &mut Vec<StatementIr>) -> TipeOperand {
+    if matches!(op, TipeOperand::Immediate(_) | TipeOperand::None) {
+        return op.clone();
+    }
+    let temp = TipeOperand::Register(nama.to_string());
+    let tipe = super::IrLifter::tipe_dari_ukuran((lebar / 8) as u8).unwrap_or(TipeDataIr::I64);
+    ops.push(StatementIr::new(addr, OperasiIr::Mov, temp.clone(), op.clone()).with_type(tipe));
+    temp
+}
+
+pub fn hitung_flags(addr: u64, jenis: JenisOperasiFlag, o: &OperandFlag, ops: &mut Vec<StatementIr>) {
+    if let Some(cf) = nilai_carry(jenis, o) {
+        tulis_flag(addr, FLAG_CF, cf, ops);
+    }
+    tulis_flag(addr, FLAG_OF, nilai_overflow(jenis, o), ops);
+    if jenis == JenisOperasiFlag::Imul {
+        for flag in [FLAG_SF, FLAG_ZF, FLAG_PF, FLAG_AF] {
+            tulis_flag(addr, flag, flag_undefined(), ops);
+        }
+        return;
+    }
+    tulis_flag(addr, FLAG_SF, nilai_sign(o), ops);
+    tulis_flag(addr, FLAG_ZF, nilai_zero(o), ops);
+    let pf = nilai_parity(addr, &o.hasil, ops);
+    tulis_flag(addr, FLAG_PF, pf, ops);
+    let af = if jenis == JenisOperasiFlag::Logika { flag_undefined() } else { nilai_aux(o) };
+    tulis_flag(addr, FLAG_AF, af, ops);
+}
+
+pub fn nilai_carry(jenis: JenisOperasiFlag, o: &OperandFlag) -> Option<TipeOperand> {
+    let hasil = o.potong(&o.hasil);
+    let kiri = o.potong(&o.kiri);
+    let kanan = o.potong(&o.kanan);
+    let cf_lama = TipeOperand::Register(FLAG_CF.to_string());
+    match jenis {
+        JenisOperasiFlag::Add => Some(buat_expr(OperasiIr::Jb, hasil, kiri)),
+        JenisOperasiFlag::Adc => {
+            let wrap = buat_expr(OperasiIr::Jb, hasil.clone(), kiri.clone());
+            let penuh = buat_expr(OperasiIr::And, cf_lama, buat_expr(OperasiIr::Je, hasil, kiri));
+            Some(buat_expr(OperasiIr::Or, wrap, penuh))
+        },
+        JenisOperasiFlag::Sub => Some(buat_expr(OperasiIr::Jb, kiri, kanan)),
+        JenisOperasiFlag::Sbb => {
+            let pinjam = buat_expr(OperasiIr::Jb, kiri.clone(), kanan.clone());
+            let sama = buat_expr(OperasiIr::And, cf_lama, buat_expr(OperasiIr::Je, kiri, kanan));
+            Some(buat_expr(OperasiIr::Or, pinjam, sama))
+        },
+        JenisOperasiFlag::Inc | JenisOperasiFlag::Dec => None,
+        JenisOperasiFlag::Logika => Some(TipeOperand::Immediate(0)),
+        JenisOperasiFlag::Imul => Some(nilai_overflow_imul(o)),
+    }
+}
+
+pub fn nilai_overflow(jenis: JenisOperasiFlag, o: &OperandFlag) -> TipeOperand {
+    let xor = |a: &TipeOperand, b: &TipeOperand| buat_expr(OperasiIr::Xor, a.clone(), b.clone());
+    match jenis {
+        JenisOperasiFlag::Add | JenisOperasiFlag::Adc | JenisOperasiFlag::Inc => {
+            o.bit_tanda(buat_expr(OperasiIr::And, xor(&o.kiri, &o.hasil), xor(&o.kanan, &o.hasil)))
+        },
+        JenisOperasiFlag::Sub | JenisOperasiFlag::Sbb | JenisOperasiFlag::Dec => {
+            o.bit_tanda(buat_expr(OperasiIr::And, xor(&o.kiri, &o.kanan), xor(&o.kiri, &o.hasil)))
+        },
+        JenisOperasiFlag::Logika => TipeOperand::Immediate(0),
+        JenisOperasiFlag::Imul => nilai_overflow_imul(o),
+    }
+}
+
+fn nilai_overflow_imul(o: &OperandFlag) -> TipeOperand {
+    let tinggi = buat_expr(OperasiIr::MulHi, o.kiri.clone(), o.kanan.clone());
+    let ekstensi_tanda = buat_expr(OperasiIr::Sar, o.hasil.clone(), TipeOperand::Immediate((o.lebar - 1) as i64));
+    buat_expr(OperasiIr::Jne, tinggi, ekstensi_tanda)
+}
+
+pub fn nilai_sign(o: &OperandFlag) -> TipeOperand {
+    o.bit_tanda(o.hasil.clone())
+}
+
+pub fn nilai_zero(o: &OperandFlag) -> TipeOperand {
+    TipeOperand::Conditional {
+        condition: Box::new(buat_expr(OperasiIr::Je, o.potong(&o.hasil), TipeOperand::Immediate(0))),
+        true_val: Box::new(TipeOperand::Immediate(1)),
+        false_val: Box::new(TipeOperand::Immediate(0)),
+    }
+}
+
+pub fn nilai_aux(o: &OperandFlag) -> TipeOperand {
+    let campur = buat_expr(OperasiIr::Xor, buat_expr(OperasiIr::Xor, o.kiri.clone(), o.kanan.clone()), o.hasil.clone());
+    ambil_bit(campur, TipeOperand::Immediate(4))
+}
+
+pub fn nilai_parity(addr: u64, hasil: &TipeOperand, ops: &mut Vec<StatementIr>) -> TipeOperand {
+    let temp_pop = TipeOperand::Register("temp_popcnt".to_string());
+    let byte_rendah = buat_expr(OperasiIr::And, hasil.clone(), TipeOperand::Immediate(0xFF));
+    ops.push(StatementIr::new(addr, OperasiIr::Popcnt, temp_pop.clone(), byte_rendah).with_type(TipeDataIr::I8));
+    let genap = buat_expr(OperasiIr::Je, buat_expr(OperasiIr::And, temp_pop, TipeOperand::Immediate(1)), TipeOperand::Immediate(0));
+    TipeOperand::Conditional {
+        condition: Box::new(genap),
+        true_val: Box::new(TipeOperand::Immediate(1)),
+        false_val: Box::new(TipeOperand::Immediate(0)),
+    }
+}
+
+pub fn flag_undefined() -> TipeOperand {
+    TipeOperand::Register("undefined".to_string())
+}
+
+pub fn ambil_bit(op: TipeOperand, posisi: TipeOperand) -> TipeOperand {
+    buat_expr(OperasiIr::And, buat_expr(OperasiIr::Shr, op, posisi), TipeOperand::Immediate(1))
+}
+
+pub fn tulis_flag(addr: u64, flag_reg: &str, val: TipeOperand, ops: &mut Vec<StatementIr>) {
+    ops.push(StatementIr::new(
+        addr,
+        OperasiIr::Mov,
+        TipeOperand::Register(flag_reg.to_string()),
+        val
+    ).with_type(TipeDataIr::I8));
+}
+
+pub fn buat_expr(op: OperasiIr, kiri: TipeOperand, kanan: TipeOperand) -> TipeOperand {
+    TipeOperand::Expression {
+        operasi: op,
+        operand_kiri: Box::new(kiri),
+        operand_kanan: Box::new(kanan),
+    }
+}
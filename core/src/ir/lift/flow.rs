@@ -1,19 +1,20 @@
-use crate::disasm::instruction::InstructionNormalized;
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
 use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
+use crate::arch::x86::{FLAG_CF, FLAG_OF, FLAG_PF, FLAG_SF, FLAG_ZF};
 use super::IrLifter;
 
-const FLAG_ZF: &str = "eflags_zf";
-const FLAG_CF: &str = "eflags_cf";
-const FLAG_SF: &str = "eflags_sf";
-const FLAG_OF: &str = "eflags_of";
 
 pub fn proses_ret(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let pembersihan = match instr.operands_detail.first() {
+        Some(JenisOperandDisasm::Immediate(n)) => *n,
+        _ => 0,
+    };
     ops.push(StatementIr::new(
         instr.address,
         OperasiIr::Add,
-        TipeOperand::Register("rsp".to_string()),
-        TipeOperand::Immediate(lifter.pointer_size),
-    ).with_type(TipeDataIr::I64));
+        TipeOperand::Register(lifter.ambil_reg_stack().to_string()),
+        TipeOperand::Immediate(lifter.pointer_size + pembersihan),
+    ).with_type(lifter.ambil_tipe_pointer()));
     ops.push(StatementIr::new(instr.address, OperasiIr::Ret, TipeOperand::None, TipeOperand::None));
 }
 
@@ -21,9 +22,9 @@ pub fn proses_call(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut V
     ops.push(StatementIr::new(
         instr.address,
         OperasiIr::Sub,
-        TipeOperand::Register("rsp".to_string()),
+        TipeOperand::Register(lifter.ambil_reg_stack().to_string()),
         TipeOperand::Immediate(lifter.pointer_size),
-    ).with_type(TipeDataIr::I64));
+    ).with_type(lifter.ambil_tipe_pointer()));
     let target = lifter.ambil_operand(instr, 0);
     ops.push(StatementIr::new(instr.address, OperasiIr::Call, target, TipeOperand::None));
 }
@@ -68,15 +69,7 @@ pub fn proses_conditional_branch(
         ""
     };
     let condition_expr = generate_kondisi_explicit(suffix);
-    let op_ir = match suffix {
-        "e" | "z" | "eq" => OperasiIr::Je,
-        "ne" | "nz" => OperasiIr::Jne,
-        "g" | "gt" | "a" | "ja" => OperasiIr::Jg,
-        "ge" | "ae" | "jge" => OperasiIr::Jge,
-        "l" | "lt" | "b" | "jb" => OperasiIr::Jl,
-        "le" | "be" | "jle" => OperasiIr::Jle,
-        _ => OperasiIr::Jmp
-    };
+    let op_ir = ambil_operasi_branch(suffix);
     ops.push(StatementIr::new(
         instr.address,
         op_ir,
@@ -85,6 +78,44 @@ pub fn proses_conditional_branch(
     ));
 }
 
+pub fn proses_set_kondisi(
+    lifter: &IrLifter,
+    instr: &InstructionNormalized,
+    mnemonic: &str,
+    ops: &mut Vec<StatementIr>
+) {
+    let dest = lifter.ambil_operand(instr, 0);
+    let suffix = mnemonic.strip_prefix("set").unwrap_or("");
+    let nilai = TipeOperand::Conditional {
+        condition: Box::new(generate_kondisi_explicit(suffix)),
+        true_val: Box::new(TipeOperand::Immediate(1)),
+        false_val: Box::new(TipeOperand::Immediate(0)),
+    };
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dest, nilai).with_type(TipeDataIr::I8));
+}
+
+pub fn ambil_operasi_branch(suffix: &str) -> OperasiIr {
+    match suffix {
+        "e" | "z" | "eq" => OperasiIr::Je,
+        "ne" | "nz" => OperasiIr::Jne,
+        "g" | "gt" | "nle" => OperasiIr::Jg,
+        "ge" | "nl" => OperasiIr::Jge,
+        "l" | "lt" | "nge" => OperasiIr::Jl,
+        "le" | "ng" => OperasiIr::Jle,
+        "a" | "nbe" | "hi" => OperasiIr::Ja,
+        "ae" | "nb" | "nc" | "hs" | "cs" => OperasiIr::Jae,
+        "b" | "c" | "nae" | "lo" | "cc" => OperasiIr::Jb,
+        "be" | "na" | "ls" => OperasiIr::Jbe,
+        "s" | "mi" => OperasiIr::Js,
+        "ns" | "pl" => OperasiIr::Jns,
+        "o" | "vs" => OperasiIr::Jo,
+        "no" | "vc" => OperasiIr::Jno,
+        "p" | "pe" => OperasiIr::Jp,
+        "np" | "po" => OperasiIr::Jnp,
+        _ => OperasiIr::Jmp
+    }
+}
+
 pub fn generate_kondisi_explicit(mnemonic_suffix: &str) -> TipeOperand {
     let suffix = if mnemonic_suffix.starts_with("b.") {
         mnemonic_suffix.strip_prefix("b.").unwrap_or("")
@@ -95,6 +126,7 @@ pub fn generate_kondisi_explicit(mnemonic_suffix: &str) -> TipeOperand {
     let cf = TipeOperand::Register(FLAG_CF.to_string());
     let sf = TipeOperand::Register(FLAG_SF.to_string());
     let of = TipeOperand::Register(FLAG_OF.to_string());
+    let pf = TipeOperand::Register(FLAG_PF.to_string());
     let one = TipeOperand::Immediate(1);
     let zero = TipeOperand::Immediate(0);
     match suffix {
@@ -110,34 +142,40 @@ pub fn generate_kondisi_explicit(mnemonic_suffix: &str) -> TipeOperand {
         "ns" | "pl" => {
             make_comparison(OperasiIr::Je, sf, zero)
         },
-        "b" | "c" | "nae" | "lo" => {
+        "b" | "c" | "nae" | "lo" | "cc" => {
             make_comparison(OperasiIr::Je, cf, one)
         },
-        "nb" | "ae" | "nc" => {
+        "nb" | "ae" | "nc" | "hs" | "cs" => {
             make_comparison(OperasiIr::Je, cf, zero)
         },
-        "l" | "lt" => {
+        "l" | "lt" | "nge" => {
             make_comparison(OperasiIr::Jne, sf, of)
         },
         "ge" | "nl" => {
             make_comparison(OperasiIr::Je, sf, of)
         },
-        "le" => {
+        "p" | "pe" => {
+            make_comparison(OperasiIr::Je, pf, one)
+        },
+        "np" | "po" => {
+            make_comparison(OperasiIr::Je, pf, zero)
+        },
+        "le" | "ng" => {
             let zf_set = make_comparison(OperasiIr::Je, zf, one.clone());
             let sf_neq_of = make_comparison(OperasiIr::Jne, sf, of);
             make_binary(OperasiIr::Or, zf_set, sf_neq_of)
         },
-        "g" | "gt" => {
+        "g" | "gt" | "nle" => {
             let zf_clear = make_comparison(OperasiIr::Je, zf, zero);
             let sf_eq_of = make_comparison(OperasiIr::Je, sf, of);
             make_binary(OperasiIr::And, zf_clear, sf_eq_of)
         },
-        "a" | "ja" => {
+        "a" | "nbe" | "hi" => {
             let cf_clear = make_comparison(OperasiIr::Je, cf, zero.clone());
             let zf_clear = make_comparison(OperasiIr::Je, zf, zero);
             make_binary(OperasiIr::And, cf_clear, zf_clear)
         },
-        "be" | "jna" => {
+        "be" | "na" | "ls" => {
              let cf_set = make_comparison(OperasiIr::Je, cf, one.clone());
              let zf_set = make_comparison(OperasiIr::Je, zf, one);
              make_binary(OperasiIr::Or, cf_set, zf_set)
@@ -145,6 +183,9 @@ pub fn generate_kondisi_explicit(mnemonic_suffix: &str) -> TipeOperand {
         "o" | "vs" => {
             make_comparison(OperasiIr::Je, of, one)
         },
+        "no" | "vc" => {
+            make_comparison(OperasiIr::Je, of, zero)
+        },
         _ => TipeOperand::Immediate(1)
     }
 }
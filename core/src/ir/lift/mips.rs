@@ -0,0 +1,418 @@
+use std::collections::{HashMap, HashSet};
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
+use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
+use super::IrLifter;
+use super::arithmetic;
+
+pub fn proses_instruksi_mips(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let mnemonic = instr.mnemonic.to_lowercase();
+    let mnem = mnemonic.as_str();
+    if is_tulis_register_nol(instr, mnem) {
+        return;
+    }
+    let addr = instr.address;
+    match mnem {
+        "nop" | "ssnop" | "ehb" | "pause" => {},
+        "sync" => ops.push(StatementIr::new(addr, OperasiIr::Fence, TipeOperand::None, TipeOperand::None)),
+        "syscall" => {
+            ops.push(StatementIr::new(addr, OperasiIr::Syscall, TipeOperand::Register("v0".to_string()), TipeOperand::None));
+        },
+        "lui" => {
+            let imm = match instr.operands_detail.get(1) {
+                Some(JenisOperandDisasm::Immediate(v)) => ((*v << 16) as i32) as i64,
+                _ => 0,
+            };
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 0), TipeOperand::Immediate(imm)).with_type(TipeDataIr::I32));
+        },
+        "li" | "move" => {
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 0), ambil_sumber(lifter, instr, 1)).with_type(TipeDataIr::I32));
+        },
+        "j" | "b" => {
+            ops.push(StatementIr::new(addr, OperasiIr::Jmp, ambil_target(lifter, instr), TipeOperand::None));
+        },
+        "jal" | "bal" | "jalr" | "bltzal" | "bgezal" => {
+            ops.push(StatementIr::new(addr, OperasiIr::Call, ambil_target(lifter, instr), TipeOperand::None));
+        },
+        "jr" => {
+            let target = ambil_target(lifter, instr);
+            if target == TipeOperand::Register("ra".to_string()) {
+                ops.push(StatementIr::new(addr, OperasiIr::Ret, TipeOperand::None, TipeOperand::None));
+            } else {
+                ops.push(StatementIr::new(addr, OperasiIr::Jmp, target, TipeOperand::None));
+            }
+        },
+        "mult" | "multu" | "div" | "divu" if instr.operands_detail.len() == 2 => proses_hi_lo(lifter, instr, mnem, ops),
+        "mfhi" | "mflo" => {
+            let sumber = if mnem == "mfhi" { "hi" } else { "lo" };
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 0), TipeOperand::Register(sumber.to_string())).with_type(TipeDataIr::I32));
+        },
+        "mthi" | "mtlo" => {
+            let tujuan = if mnem == "mthi" { "hi" } else { "lo" };
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register(tujuan.to_string()), ambil_sumber(lifter, instr, 0)).with_type(TipeDataIr::I32));
+        },
+        "slt" | "slti" | "sltu" | "sltiu" => {
+            let kondisi = buat_expr(OperasiIr::Jl, ambil_sumber(lifter, instr, 1), ambil_sumber(lifter, instr, 2));
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 0), buat_kondisional(kondisi)).with_type(TipeDataIr::I32));
+        },
+        "movn" | "movz" => {
+            let dest = lifter.ambil_operand(instr, 0);
+            let op_banding = if mnem == "movn" { OperasiIr::Jne } else { OperasiIr::Je };
+            let nilai = TipeOperand::Conditional {
+                condition: Box::new(buat_expr(op_banding, ambil_sumber(lifter, instr, 2), TipeOperand::Immediate(0))),
+                true_val: Box::new(ambil_sumber(lifter, instr, 1)),
+                false_val: Box::new(dest.clone()),
+            };
+            ops.push(StatementIr::new(addr, OperasiIr::Cmov, dest, nilai).with_type(TipeDataIr::I32));
+        },
+        "negu" | "neg" | "not" => {
+            let dest = lifter.ambil_operand(instr, 0);
+            let src = ambil_sumber(lifter, instr, 1);
+            let (op_ir, expr) = if mnem == "not" {
+                (OperasiIr::Xor, buat_expr(OperasiIr::Xor, src, TipeOperand::Immediate(-1)))
+            } else {
+                (OperasiIr::Sub, buat_expr(OperasiIr::Sub, TipeOperand::Immediate(0), src))
+            };
+            ops.push(StatementIr::new(addr, op_ir, dest, expr).with_type(TipeDataIr::I32));
+        },
+        "seb" | "seh" => {
+            let tipe = if mnem == "seb" { TipeDataIr::I8 } else { TipeDataIr::I16 };
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 0), ambil_sumber(lifter, instr, 1)).with_type(tipe));
+        },
+        "clz" => {
+            ops.push(StatementIr::new(addr, OperasiIr::Lzcnt, lifter.ambil_operand(instr, 0), ambil_sumber(lifter, instr, 1)).with_type(TipeDataIr::I32));
+        },
+        "ll" => {
+            ops.push(StatementIr::new(addr, OperasiIr::AtomicLoad, lifter.ambil_operand(instr, 0), lifter.ambil_operand(instr, 1)));
+        },
+        "sc" => {
+            ops.push(StatementIr::new(addr, OperasiIr::AtomicStore, lifter.ambil_operand(instr, 1), ambil_sumber(lifter, instr, 0)));
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 0), TipeOperand::Immediate(1)).with_type(TipeDataIr::I32));
+        },
+        "mfc1" | "mtc1" | "mov.s" | "mov.d" => {
+            let (dest, src) = if mnem == "mtc1" { (1, 0) } else { (0, 1) };
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, dest), ambil_sumber(lifter, instr, src)));
+        },
+        _ if is_branch_kondisional(mnem) => proses_branch(lifter, instr, mnem, ops),
+        _ => {
+            if let Some(tipe) = ambil_tipe_load(mnem) {
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 0), lifter.ambil_operand(instr, 1)).with_type(tipe));
+            } else if let Some(tipe) = ambil_tipe_store(mnem) {
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 1), ambil_sumber(lifter, instr, 0)).with_type(tipe));
+            } else if let Some(op_ir) = ambil_operasi_biner(mnem) {
+                proses_biner_mips(lifter, instr, mnem, op_ir, ops);
+            } else if mnem.contains('.') {
+                proses_float(lifter, instr, mnem, ops);
+            } else {
+                arithmetic::proses_generic_unknown(lifter, instr, ops);
+            }
+        },
+    }
+}
+
+pub fn is_punya_delay_slot(mnem: &str) -> bool {
+    matches!(mnem, "j" | "jal" | "jr" | "jalr" | "b" | "bal" | "bltzal" | "bgezal") || is_branch_kondisional(mnem)
+}
+
+pub fn is_branch_kondisional(mnem: &str) -> bool {
+    let dasar = mnem.strip_suffix('l').filter(|m| m.len() > 3).unwrap_or(mnem);
+    matches!(dasar,
+        "beq" | "bne" | "beqz" | "bnez" | "blez" | "bgtz" | "bltz" | "bgez" | "bc1t" | "bc1f")
+}
+
+pub fn gabungkan_delay_slot(alamat_branch: u64, ops_branch: Vec<StatementIr>, ops_slot: Vec<StatementIr>) -> Vec<StatementIr> {
+    let mut dibaca = HashSet::new();
+    for stmt in &ops_branch {
+        kumpulkan_register(&stmt.operand_satu, &mut dibaca);
+        kumpulkan_register(&stmt.operand_dua, &mut dibaca);
+    }
+    let ditulis: HashSet<String> = ops_slot.iter()
+        .filter_map(|stmt| match &stmt.operand_satu {
+            TipeOperand::Register(r) => Some(r.clone()),
+            _ => None,
+        })
+        .collect();
+    let mut konflik: Vec<String> = dibaca.intersection(&ditulis).cloned().collect();
+    konflik.sort();
+    let mut hasil = Vec::new();
+    for reg in &konflik {
+        hasil.push(StatementIr::new(
+            alamat_branch,
+            OperasiIr::Mov,
+            TipeOperand::Register(format!("{}_ds", reg)),
+            TipeOperand::Register(reg.clone()),
+        ).with_type(TipeDataIr::I32));
+    }
+    for mut stmt in ops_slot {
+        stmt.address_asal = alamat_branch;
+        hasil.push(stmt);
+    }
+    for mut stmt in ops_branch {
+        for reg in &konflik {
+            ganti_register(&mut stmt.operand_satu, reg);
+            ganti_register(&mut stmt.operand_dua, reg);
+        }
+        hasil.push(stmt);
+    }
+    hasil
+}
+
+pub fn lipat_akses_gp(instr: &mut InstructionNormalized, nilai_gp: Option<u64>, peta_slot: &mut HashMap<String, u64>) {
+    let mnem = instr.mnemonic.to_lowercase();
+    if let Some(gp) = nilai_gp {
+        for op in instr.operands_detail.iter_mut() {
+            if let JenisOperandDisasm::Memory { base: Some(b), disp, .. } = op
+                && b == "gp"
+            {
+                let alamat = gp.wrapping_add(*disp as u64) as i64;
+                *op = JenisOperandDisasm::Memory { base: None, index: None, scale: 1, disp: alamat, segment: None };
+            }
+        }
+    }
+    if matches!(mnem.as_str(), "jalr" | "jr")
+        && let Some(JenisOperandDisasm::Register(r)) = instr.operands_detail.last()
+        && let Some(slot) = peta_slot.get(r)
+    {
+        let idx = instr.operands_detail.len() - 1;
+        instr.operands_detail[idx] = JenisOperandDisasm::Memory { base: None, index: None, scale: 1, disp: *slot as i64, segment: None };
+    }
+    if matches!(mnem.as_str(), "jal" | "jalr" | "bal") {
+        peta_slot.clear();
+        return;
+    }
+    let tujuan = match instr.operands_detail.first() {
+        Some(JenisOperandDisasm::Register(r)) if ambil_tipe_store(&mnem).is_none() && !is_punya_delay_slot(&mnem) => r.clone(),
+        _ => return,
+    };
+    match (mnem.as_str(), instr.operands_detail.get(1)) {
+        ("lw", Some(JenisOperandDisasm::Memory { base: None, disp, .. })) => {
+            peta_slot.insert(tujuan, *disp as u64);
+        },
+        _ => {
+            peta_slot.remove(&tujuan);
+        },
+    }
+}
+
+fn kumpulkan_register(op: &TipeOperand, hasil: &mut HashSet<String>) {
+    match op {
+        TipeOperand::Register(r) => {
+            hasil.insert(r.clone());
+        },
+        TipeOperand::MemoryRef { base, .. } => {
+            hasil.insert(base.clone());
+        },
+        TipeOperand::Expression { operand_kiri, operand_kanan, .. } => {
+            kumpulkan_register(operand_kiri, hasil);
+            kumpulkan_register(operand_kanan, hasil);
+        },
+        TipeOperand::Conditional { condition, true_val, false_val } => {
+            kumpulkan_register(condition, hasil);
+            kumpulkan_register(true_val, hasil);
+            kumpulkan_register(false_val, hasil);
+        },
+        _ => {}
+    }
+}
+
+fn ganti_register(op: &mut TipeOperand, reg: &str) {
+    match op {
+        TipeOperand::Register(r) if r == reg => *r = format!("{}_ds", reg),
+        TipeOperand::MemoryRef { base, .. } if base == reg => *base = format!("{}_ds", reg),
+        TipeOperand::Expression { operand_kiri, operand_kanan, .. } => {
+            ganti_register(operand_kiri, reg);
+            ganti_register(operand_kanan, reg);
+        },
+        TipeOperand::Conditional { condition, true_val, false_val } => {
+            ganti_register(condition, reg);
+            ganti_register(true_val, reg);
+            ganti_register(false_val, reg);
+        },
+        _ => {}
+    }
+}
+
+fn is_tulis_register_nol(instr: &InstructionNormalized, mnem: &str) -> bool {
+    let is_tulis = !is_punya_delay_slot(mnem) && ambil_tipe_store(mnem).is_none()
+        && !matches!(mnem, "sc" | "mtc1" | "mthi" | "mtlo" | "mult" | "multu" | "div" | "divu");
+    is_tulis && matches!(instr.operands_detail.first(), Some(JenisOperandDisasm::Register(r)) if r == "zero")
+}
+
+fn ambil_sumber(lifter: &IrLifter, instr: &InstructionNormalized, index: usize) -> TipeOperand {
+    match lifter.ambil_operand(instr, index) {
+        TipeOperand::Register(r) if r == "zero" => TipeOperand::Immediate(0),
+        op => op,
+    }
+}
+
+fn ambil_target(lifter: &IrLifter, instr: &InstructionNormalized) -> TipeOperand {
+    let idx = instr.operands_detail.len().saturating_sub(1);
+    lifter.ambil_operand(instr, idx)
+}
+
+fn buat_expr(op: OperasiIr, kiri: TipeOperand, kanan: TipeOperand) -> TipeOperand {
+    TipeOperand::Expression {
+        operasi: op,
+        operand_kiri: Box::new(kiri),
+        operand_kanan: Box::new(kanan),
+    }
+}
+
+fn buat_kondisional(kondisi: TipeOperand) -> TipeOperand {
+    TipeOperand::Conditional {
+        condition: Box::new(kondisi),
+        true_val: Box::new(TipeOperand::Immediate(1)),
+        false_val: Box::new(TipeOperand::Immediate(0)),
+    }
+}
+
+fn ambil_tipe_load(mnem: &str) -> Option<TipeDataIr> {
+    let tipe = match mnem {
+        "lb" | "lbu" => TipeDataIr::I8,
+        "lh" | "lhu" => TipeDataIr::I16,
+        "lw" => TipeDataIr::I32,
+        "lwc1" => TipeDataIr::F32,
+        "ldc1" => TipeDataIr::F64,
+        _ => return None,
+    };
+    Some(tipe)
+}
+
+fn ambil_tipe_store(mnem: &str) -> Option<TipeDataIr> {
+    let tipe = match mnem {
+        "sb" => TipeDataIr::I8,
+        "sh" => TipeDataIr::I16,
+        "sw" => TipeDataIr::I32,
+        "swc1" => TipeDataIr::F32,
+        "sdc1" => TipeDataIr::F64,
+        _ => return None,
+    };
+    Some(tipe)
+}
+
+fn ambil_operasi_biner(mnem: &str) -> Option<OperasiIr> {
+    let op_ir = match mnem {
+        "add" | "addi" | "addu" | "addiu" => OperasiIr::Add,
+        "sub" | "subu" => OperasiIr::Sub,
+        "and" | "andi" => OperasiIr::And,
+        "or" | "ori" | "nor" => OperasiIr::Or,
+        "xor" | "xori" => OperasiIr::Xor,
+        "sll" | "sllv" => OperasiIr::Shl,
+        "srl" | "srlv" => OperasiIr::Shr,
+        "sra" | "srav" => OperasiIr::Sar,
+        "rotr" | "rotrv" => OperasiIr::Ror,
+        "mul" => OperasiIr::Imul,
+        "div" => OperasiIr::Idiv,
+        "divu" => OperasiIr::Div,
+        _ => return None,
+    };
+    Some(op_ir)
+}
+
+fn proses_biner_mips(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, op_ir: OperasiIr, ops: &mut Vec<StatementIr>) {
+    let dest = lifter.ambil_operand(instr, 0);
+    let kiri = ambil_sumber(lifter, instr, 1);
+    let kanan = ambil_sumber(lifter, instr, 2);
+    if matches!(op_ir, OperasiIr::Add | OperasiIr::Or | OperasiIr::Xor) && mnem != "nor" {
+        let nilai_tunggal = match (&kiri, &kanan) {
+            (TipeOperand::Immediate(0), _) => Some(kanan.clone()),
+            (_, TipeOperand::Immediate(0)) => Some(kiri.clone()),
+            _ => None,
+        };
+        if let Some(nilai) = nilai_tunggal {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dest, nilai).with_type(TipeDataIr::I32));
+            return;
+        }
+    }
+    let mut expr = buat_expr(op_ir.clone(), kiri, kanan);
+    let mut op_ir = op_ir;
+    if mnem == "nor" {
+        expr = buat_expr(OperasiIr::Xor, expr, TipeOperand::Immediate(-1));
+        op_ir = OperasiIr::Xor;
+    }
+    ops.push(StatementIr::new(instr.address, op_ir, dest, expr).with_type(TipeDataIr::I32));
+}
+
+fn proses_hi_lo(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let kiri = ambil_sumber(lifter, instr, 0);
+    let kanan = ambil_sumber(lifter, instr, 1);
+    let lo = TipeOperand::Register("lo".to_string());
+    let hi = TipeOperand::Register("hi".to_string());
+    let (nilai_lo, nilai_hi) = if mnem.starts_with("mult") {
+        let (op_kali, op_tinggi) = if mnem == "multu" { (OperasiIr::Mul, OperasiIr::MulHiU) } else { (OperasiIr::Imul, OperasiIr::MulHi) };
+        (
+            buat_expr(op_kali, kiri.clone(), kanan.clone()),
+            buat_expr(op_tinggi, kiri, kanan),
+        )
+    } else {
+        let op_bagi = if mnem == "divu" { OperasiIr::Div } else { OperasiIr::Idiv };
+        let hasil_bagi = buat_expr(op_bagi, kiri.clone(), kanan.clone());
+        let sisa = buat_expr(OperasiIr::Sub, kiri, buat_expr(OperasiIr::Imul, hasil_bagi.clone(), kanan));
+        (hasil_bagi, sisa)
+    };
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lo, nilai_lo).with_type(TipeDataIr::I32));
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, hi, nilai_hi).with_type(TipeDataIr::I32));
+}
+
+fn proses_branch(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let target = ambil_target(lifter, instr);
+    let dasar = mnem.strip_suffix('l').filter(|m| m.len() > 3).unwrap_or(mnem);
+    let (op_ir, kiri, kanan) = match dasar {
+        "bc1t" => (OperasiIr::Jne, TipeOperand::Register("fcc0".to_string()), TipeOperand::Immediate(0)),
+        "bc1f" => (OperasiIr::Je, TipeOperand::Register("fcc0".to_string()), TipeOperand::Immediate(0)),
+        "beq" | "bne" => {
+            let op = if dasar == "beq" { OperasiIr::Je } else { OperasiIr::Jne };
+            (op, ambil_sumber(lifter, instr, 0), ambil_sumber(lifter, instr, 1))
+        },
+        _ => {
+            let op = match dasar {
+                "beqz" => OperasiIr::Je,
+                "bnez" => OperasiIr::Jne,
+                "blez" => OperasiIr::Jle,
+                "bgtz" => OperasiIr::Jg,
+                "bltz" => OperasiIr::Jl,
+                _ => OperasiIr::Jge,
+            };
+            (op, ambil_sumber(lifter, instr, 0), TipeOperand::Immediate(0))
+        },
+    };
+    let kondisi = buat_expr(op_ir.clone(), kiri, kanan);
+    ops.push(StatementIr::new(instr.address, op_ir, target, kondisi));
+}
+
+fn proses_float(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let (dasar, presisi) = mnem.split_once('.').unwrap_or((mnem, ""));
+    let tipe = if presisi.starts_with('d') { TipeDataIr::F64 } else { TipeDataIr::F32 };
+    let dest = lifter.ambil_operand(instr, 0);
+    let kiri = lifter.ambil_operand(instr, 1);
+    let kanan = lifter.ambil_operand(instr, 2);
+    let op_biner = match dasar {
+        "add" => Some(OperasiIr::FAdd),
+        "sub" => Some(OperasiIr::FSub),
+        "mul" => Some(OperasiIr::FMul),
+        "div" => Some(OperasiIr::FDiv),
+        _ => None,
+    };
+    if let Some(op_ir) = op_biner {
+        ops.push(StatementIr::new(instr.address, op_ir.clone(), dest, buat_expr(op_ir, kiri, kanan)).with_type(tipe));
+        return;
+    }
+    match dasar {
+        "sqrt" => ops.push(StatementIr::new(instr.address, OperasiIr::FSqrt, dest, kiri).with_type(tipe)),
+        "neg" => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::FSub, dest, buat_expr(OperasiIr::FSub, TipeOperand::Immediate(0), kiri)).with_type(tipe));
+        },
+        "cvt" | "trunc" | "round" | "floor" | "ceil" => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::FConv, dest, kiri));
+        },
+        "c" => {
+            let op_banding = match presisi.split('.').next().unwrap_or("") {
+                "eq" | "seq" | "ueq" => OperasiIr::Je,
+                "lt" | "olt" | "ult" => OperasiIr::Jl,
+                _ => OperasiIr::Jle,
+            };
+            let kondisi = buat_expr(op_banding, dest, kiri);
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, TipeOperand::Register("fcc0".to_string()), buat_kondisional(kondisi)));
+        },
+        _ => arithmetic::proses_generic_unknown(lifter, instr, ops),
+    }
+}
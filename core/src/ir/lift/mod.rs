@@ -1,5 +1,6 @@
 use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
-use crate::ir::types::{StatementIr, TipeOperand, OperasiIr};
+use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
+use crate::arch::{KelasRegister, RegisterFile};
 use std::sync::Arc;
 
 pub mod arithmetic;
@@ -9,11 +10,29 @@ pub mod crypto;
 pub mod system;
 pub mod semantic; 
 pub mod bitwise;
+pub mod arm;
+pub mod arm64;
+pub mod riscv;
+pub mod mips;
+pub mod x87;
+pub mod sse;
+pub mod string;
+pub mod dispatch;
+pub mod eflags;
+pub mod subreg;
+
+use dispatch::{AksiLift, ArsitekturDispatch, KelasInstruksi};
 
 #[derive(Clone)]
 pub struct IrLifter {
     pub pointer_size: i64,
     pub semantic_engine: Arc<semantic::SemanticEngine>,
+    pub mode_arm32: bool,
+    pub mode_riscv: bool,
+    pub mode_mips: bool,
+    pub mode_x86_32: bool,
+    pub mode_arm64: bool,
+    pub register_file: Arc<RegisterFile>,
 }
 
 impl IrLifter {
@@ -21,52 +40,190 @@ impl IrLifter {
         Self { 
             pointer_size: 8,
             semantic_engine: Arc::new(semantic::SemanticEngine::new()),
+            mode_arm32: false,
+            mode_riscv: false,
+            mode_mips: false,
+            mode_x86_32: false,
+            mode_arm64: false,
+            register_file: Arc::new(crate::arch::buat_arsitektur("x86_64").dapatkan_register_file()),
+        }
+    }
+    pub fn untuk_arsitektur(arch: &str) -> Self {
+        let mut lifter = Self::new();
+        lifter.semantic_engine = Arc::new(semantic::SemanticEngine::untuk_arsitektur(arch));
+        lifter.register_file = Arc::new(crate::arch::buat_arsitektur(arch).dapatkan_register_file());
+        if crate::arch::is_arm32(arch) {
+            lifter.pointer_size = 4;
+            lifter.mode_arm32 = true;
+        } else if crate::arch::is_riscv(arch) {
+            lifter.pointer_size = if arch == "riscv32" { 4 } else { 8 };
+            lifter.mode_riscv = true;
+        } else if crate::arch::is_mips(arch) {
+            lifter.pointer_size = 4;
+            lifter.mode_mips = true;
+        } else if crate::arch::is_x86_32(arch) {
+            lifter.pointer_size = 4;
+            lifter.mode_x86_32 = true;
+        } else if crate::arch::is_arm64(arch) {
+            lifter.mode_arm64 = true;
         }
+        lifter
     }
     pub fn konversi_instruksi_ke_microcode(&self, instr: &InstructionNormalized) -> Vec<StatementIr> {
+        let mut micro_ops = self.lift_instruksi(instr);
+        self.lengkapi_tipe_hasil(instr, &mut micro_ops);
+        subreg::presisikan_sub_register(self, &mut micro_ops);
+        micro_ops
+    }
+    fn lift_instruksi(&self, instr: &InstructionNormalized) -> Vec<StatementIr> {
         let mut micro_ops = Vec::new();
+        if self.mode_arm32 {
+            arm::proses_instruksi_arm32(self, instr, &mut micro_ops);
+            return micro_ops;
+        }
+        if self.mode_riscv {
+            riscv::proses_instruksi_riscv(self, instr, &mut micro_ops);
+            return micro_ops;
+        }
+        if self.mode_mips {
+            mips::proses_instruksi_mips(self, instr, &mut micro_ops);
+            return micro_ops;
+        }
+        if crypto::proses_instruksi_crypto(self, instr, &mut micro_ops) {
+            return micro_ops;
+        }
+        if self.mode_arm64 && arm64::proses_instruksi_arm64(self, instr, &mut micro_ops) {
+            return micro_ops;
+        }
+        if !self.mode_arm64 && x87::proses_instruksi_x87(self, instr, &mut micro_ops) {
+            return micro_ops;
+        }
+        if !self.mode_arm64 && sse::proses_instruksi_sse_skalar(self, instr, &mut micro_ops) {
+            return micro_ops;
+        }
+        if !self.mode_arm64 && string::proses_instruksi_string(self, instr, &mut micro_ops) {
+            return micro_ops;
+        }
         if self.semantic_engine.proses_lifting_otomatis(self, instr, &mut micro_ops) {
             return micro_ops;
         }
         let mnemonic = instr.mnemonic.to_lowercase();
         let mnem_str = mnemonic.as_str();
-        if simd::cek_is_simd_instruction(mnem_str) {
-            simd::proses_simd_to_intrinsic(self, instr, mnem_str, &mut micro_ops);
+        let arch_dispatch = if self.mode_arm64 { ArsitekturDispatch::Arm64 } else { ArsitekturDispatch::X86 };
+        let Some(aksi) = dispatch::tentukan_aksi(arch_dispatch, instr) else {
+            match dispatch::klasifikasi_instruksi(arch_dispatch, instr) {
+                KelasInstruksi::Crypto => crypto::proses_crypto_instruction(self, instr, mnem_str, &mut micro_ops),
+                KelasInstruksi::Simd => simd::proses_simd_to_intrinsic(self, instr, mnem_str, &mut micro_ops),
+                KelasInstruksi::Fpu | KelasInstruksi::Umum => arithmetic::proses_generic_unknown(self, instr, &mut micro_ops),
+            }
             return micro_ops;
-        }
-        match mnem_str {
-            "ret" | "retn" => flow::proses_ret(self, instr, &mut micro_ops),
-            "call" | "bl" => flow::proses_call(self, instr, &mut micro_ops),
-            "jmp" | "b" => flow::proses_unconditional_jump(self, instr, &mut micro_ops),
-            "je" | "jz" | "jne" | "jnz" | "jg" | "ja" | "jge" | "jae" | 
-            "jl" | "jb" | "jle" | "jbe" | "js" | "jns" | "cbz" | "cbnz" | "b.eq" | "b.ne" => {
-                flow::proses_conditional_branch(self, instr, mnem_str, &mut micro_ops);
-            },
-            "push" => arithmetic::proses_push(self, instr, &mut micro_ops),
-            "pop" => arithmetic::proses_pop(self, instr, &mut micro_ops),
-            "mov" | "movabs" | "movzx" | "movsx" => arithmetic::proses_data_movement(self, instr, &mut micro_ops),
-            "lea" | "adr" | "adrp" => arithmetic::proses_lea(self, instr, &mut micro_ops),
-            "add" | "inc" => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::Add, &mut micro_ops),
-            "sub" | "dec" => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::Sub, &mut micro_ops),
-            "imul" | "mul" => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::Imul, &mut micro_ops),
-            "idiv" | "div" => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::Div, &mut micro_ops),
-            "and" | "tst" => if mnem_str == "tst" { 
-                arithmetic::proses_comparison_explicit(self, instr, OperasiIr::And, &mut micro_ops) 
-            } else { 
-                arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::And, &mut micro_ops) 
-            },
-            "or" | "orr" => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::Or, &mut micro_ops),
-            "xor" | "eor" => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::Xor, &mut micro_ops),
-            "shl" | "sal" | "shr" | "sar" | "rol" | "ror" => {
-                bitwise::proses_shift_rotate(self, instr, mnem_str, &mut micro_ops);
-            },
-            "cmp" | "cmn" => arithmetic::proses_comparison_explicit(self, instr, if mnem_str == "cmn" { OperasiIr::Add } else { OperasiIr::Sub }, &mut micro_ops),
-            "syscall" | "cpuid" | "rdtsc" | "andn" | "popcnt" => system::proses_system_instruction(self, instr, mnem_str, &mut micro_ops),
-            "nop" => {},
-            _ => arithmetic::proses_generic_unknown(self, instr, &mut micro_ops),
+        };
+        match aksi {
+            AksiLift::Ret => flow::proses_ret(self, instr, &mut micro_ops),
+            AksiLift::Call => flow::proses_call(self, instr, &mut micro_ops),
+            AksiLift::Jump => flow::proses_unconditional_jump(self, instr, &mut micro_ops),
+            AksiLift::JumpKondisi => flow::proses_conditional_branch(self, instr, mnem_str, &mut micro_ops),
+            AksiLift::Push => arithmetic::proses_push(self, instr, &mut micro_ops),
+            AksiLift::Pop => arithmetic::proses_pop(self, instr, &mut micro_ops),
+            AksiLift::PushFlag => arithmetic::proses_push_flag(self, instr, &mut micro_ops),
+            AksiLift::PopFlag => arithmetic::proses_pop_flag(self, instr, &mut micro_ops),
+            AksiLift::Mov => arithmetic::proses_data_movement(self, instr, &mut micro_ops),
+            AksiLift::MovKondisi => arithmetic::proses_conditional_move(self, instr, mnem_str, &mut micro_ops),
+            AksiLift::SetKondisi => flow::proses_set_kondisi(self, instr, mnem_str, &mut micro_ops),
+            AksiLift::Ekstensi => arithmetic::proses_ekstensi(self, instr, &mut micro_ops),
+            AksiLift::Lea => arithmetic::proses_lea(self, instr, &mut micro_ops),
+            AksiLift::Add => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::Add, &mut micro_ops),
+            AksiLift::Sub => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::Sub, &mut micro_ops),
+            AksiLift::Mul => arithmetic::proses_multiplication(self, instr, false, &mut micro_ops),
+            AksiLift::Imul => arithmetic::proses_multiplication(self, instr, true, &mut micro_ops),
+            AksiLift::Div => arithmetic::proses_division(self, instr, false, &mut micro_ops),
+            AksiLift::Idiv => arithmetic::proses_division(self, instr, true, &mut micro_ops),
+            AksiLift::And => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::And, &mut micro_ops),
+            AksiLift::Or => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::Or, &mut micro_ops),
+            AksiLift::Xor => arithmetic::proses_arithmetic_explicit(self, instr, OperasiIr::Xor, &mut micro_ops),
+            AksiLift::Geser => bitwise::proses_shift_rotate(self, instr, mnem_str, &mut micro_ops),
+            AksiLift::GeserGanda => bitwise::proses_geser_ganda(self, instr, mnem_str, &mut micro_ops),
+            AksiLift::UjiBit => bitwise::proses_uji_bit(self, instr, mnem_str, &mut micro_ops),
+            AksiLift::Bswap => bitwise::proses_bswap(self, instr, &mut micro_ops),
+            AksiLift::Popcnt => bitwise::proses_hitung_bit(self, instr, OperasiIr::Popcnt, &mut micro_ops),
+            AksiLift::Lzcnt => bitwise::proses_hitung_bit(self, instr, OperasiIr::Lzcnt, &mut micro_ops),
+            AksiLift::Tzcnt => bitwise::proses_hitung_bit(self, instr, OperasiIr::Tzcnt, &mut micro_ops),
+            AksiLift::Tukar => arithmetic::proses_tukar(self, instr, &mut micro_ops),
+            AksiLift::TukarBanding => arithmetic::proses_tukar_banding(self, instr, &mut micro_ops),
+            AksiLift::Cmp => arithmetic::proses_comparison_explicit(self, instr, OperasiIr::Sub, &mut micro_ops),
+            AksiLift::Cmn => arithmetic::proses_comparison_explicit(self, instr, OperasiIr::Add, &mut micro_ops),
+            AksiLift::Test => arithmetic::proses_comparison_explicit(self, instr, OperasiIr::And, &mut micro_ops),
+            AksiLift::Sistem => system::proses_system_instruction(self, instr, mnem_str, &mut micro_ops),
+            AksiLift::Nop => {},
         }
         micro_ops
     }
+    pub fn lengkapi_tipe_hasil(&self, instr: &InstructionNormalized, ops: &mut [StatementIr]) {
+        let operand_tujuan = self.ambil_operand(instr, 0);
+        for stmt in ops.iter_mut() {
+            if stmt.tipe_hasil != TipeDataIr::Unknown || Self::is_tanpa_nilai(&stmt.operation_code) {
+                continue;
+            }
+            let info_register = match &stmt.operand_satu {
+                TipeOperand::Register(r) => self.register_file.cari_register(r),
+                _ => None,
+            };
+            let ukuran = if info_register.is_some_and(|i| i.kelas == KelasRegister::Flag) {
+                Some(1)
+            } else if operand_tujuan != TipeOperand::None && stmt.operand_satu == operand_tujuan {
+                instr.ambil_ukuran_operand(0).or_else(|| info_register.map(|i| (i.lebar_bit / 8) as u8))
+            } else if let Some(info) = info_register {
+                Some((info.lebar_bit / 8) as u8)
+            } else {
+                instr.ambil_ukuran_utama()
+            };
+            if let Some(tipe) = ukuran.and_then(Self::tipe_dari_ukuran) {
+                stmt.tipe_hasil = tipe;
+            }
+        }
+    }
+    fn is_tanpa_nilai(op: &OperasiIr) -> bool {
+        op.is_jump_kondisi() || matches!(op,
+            OperasiIr::Jmp | OperasiIr::Call | OperasiIr::Ret | OperasiIr::Nop |
+            OperasiIr::Syscall | OperasiIr::Fence | OperasiIr::Phi
+        )
+    }
+    pub fn tipe_dari_ukuran(ukuran: u8) -> Option<TipeDataIr> {
+        match ukuran {
+            1 => Some(TipeDataIr::I8),
+            2 => Some(TipeDataIr::I16),
+            4 => Some(TipeDataIr::I32),
+            8 => Some(TipeDataIr::I64),
+            10 => Some(TipeDataIr::F80),
+            16 => Some(TipeDataIr::V128),
+            32 => Some(TipeDataIr::V256),
+            64 => Some(TipeDataIr::V512),
+            _ => None,
+        }
+    }
+    pub fn ambil_lebar_bit(&self, instr: &InstructionNormalized, index: usize) -> u32 {
+        if let Some(ukuran) = instr.ambil_ukuran_operand(index) {
+            return ukuran as u32 * 8;
+        }
+        match instr.operands_detail.get(index) {
+            Some(JenisOperandDisasm::Register(reg)) => self.register_file.cari_register(&reg.to_lowercase())
+                .map(|info| info.lebar_bit as u32)
+                .unwrap_or(self.pointer_size as u32 * 8),
+            _ => self.pointer_size as u32 * 8,
+        }
+    }
+    pub fn ambil_reg_stack(&self) -> &'static str {
+        if self.mode_x86_32 { "esp" } else { "rsp" }
+    }
+    pub fn ambil_reg_akumulator(&self) -> &'static str {
+        if self.mode_x86_32 { "eax" } else { "rax" }
+    }
+    pub fn ambil_reg_data(&self) -> &'static str {
+        if self.mode_x86_32 { "edx" } else { "rdx" }
+    }
+    pub fn ambil_tipe_pointer(&self) -> TipeDataIr {
+        if self.pointer_size == 4 { TipeDataIr::I32 } else { TipeDataIr::I64 }
+    }
     pub fn ambil_operand(&self, instr: &InstructionNormalized, index: usize) -> TipeOperand {
         if let Some(o) = instr.operands_detail.get(index) {
             self.konversi_operand(o)
@@ -78,7 +235,18 @@ impl IrLifter {
         match op_detail {
             JenisOperandDisasm::Register(reg) => TipeOperand::Register(reg.to_lowercase()),
             JenisOperandDisasm::Immediate(val) => TipeOperand::Immediate(*val),
-            JenisOperandDisasm::Memory { base, index, scale, disp } => {
+            JenisOperandDisasm::Memory { base, index, scale, disp, segment } => {
+                if let Some(seg) = segment.as_deref().map(str::to_lowercase)
+                    && matches!(seg.as_str(), "fs" | "gs")
+                {
+                    return TipeOperand::MemoryComplex {
+                        base: base.as_ref().map(|b| b.to_lowercase()),
+                        index: index.as_ref().map(|i| i.to_lowercase()),
+                        scale: *scale,
+                        disp: *disp,
+                        segment: Some(seg),
+                    };
+                }
                 if base.is_none() && index.is_none() { return TipeOperand::Memory(*disp as u64); }
                 let mut expr = if let Some(b) = base { TipeOperand::Register(b.to_lowercase()) } else { TipeOperand::Immediate(0) };
                 if let Some(idx) = index {
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
+use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
+use super::IrLifter;
+use super::arithmetic;
+
+pub fn proses_instruksi_riscv(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let mnemonic = instr.mnemonic.to_lowercase();
+    let mnem = mnemonic.as_str();
+    let tipe_word = if lifter.pointer_size == 4 { TipeDataIr::I32 } else { TipeDataIr::I64 };
+    if is_tulis_register_nol(instr, mnem) {
+        return;
+    }
+    match mnem {
+        "addi" if let Some(alamat) = instr.alamat_terlipat => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, 0), TipeOperand::Immediate(alamat as i64)).with_type(tipe_word));
+        },
+        "nop" | "fence.i" => {},
+        "fence" => ops.push(StatementIr::new(instr.address, OperasiIr::Fence, TipeOperand::None, TipeOperand::None)),
+        "ecall" => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Syscall, TipeOperand::Register("a7".to_string()), TipeOperand::None));
+        },
+        "lui" | "auipc" => {
+            let imm = match instr.operands_detail.get(1) {
+                Some(JenisOperandDisasm::Immediate(v)) => ((*v << 12) as i32) as i64,
+                _ => 0,
+            };
+            let nilai = if mnem == "auipc" { (instr.address as i64).wrapping_add(imm) } else { imm };
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, 0), TipeOperand::Immediate(nilai)).with_type(tipe_word));
+        },
+        "li" | "mv" => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, 0), ambil_sumber(lifter, instr, 1)).with_type(tipe_word));
+        },
+        "jal" => proses_jal(lifter, instr, ops),
+        "jalr" => proses_jalr(lifter, instr, ops),
+        "beq" | "bne" | "blt" | "bge" | "bltu" | "bgeu" | "bgt" | "ble" | "bgtu" | "bleu" |
+        "beqz" | "bnez" | "blez" | "bgez" | "bltz" | "bgtz" => proses_branch(lifter, instr, mnem, ops),
+        "slt" | "sltu" | "slti" | "sltiu" | "seqz" | "snez" | "sltz" | "sgtz" => proses_set_kondisi(lifter, instr, mnem, tipe_word, ops),
+        "rem" | "remu" | "remw" | "remuw" => {
+            let dest = lifter.ambil_operand(instr, 0);
+            let kiri = ambil_sumber(lifter, instr, 1);
+            let kanan = ambil_sumber(lifter, instr, 2);
+            let op_bagi = if mnem.starts_with("remu") { OperasiIr::Div } else { OperasiIr::Idiv };
+            let hasil_bagi = buat_expr(op_bagi, kiri.clone(), kanan.clone());
+            let expr = buat_expr(OperasiIr::Sub, kiri, buat_expr(OperasiIr::Imul, hasil_bagi, kanan));
+            ops.push(StatementIr::new(instr.address, OperasiIr::Sub, dest, expr).with_type(ambil_tipe_hasil(mnem, &tipe_word)));
+        },
+        "neg" | "negw" | "not" => {
+            let dest = lifter.ambil_operand(instr, 0);
+            let src = ambil_sumber(lifter, instr, 1);
+            let (op_ir, expr) = if mnem == "not" {
+                (OperasiIr::Xor, buat_expr(OperasiIr::Xor, src, TipeOperand::Immediate(-1)))
+            } else {
+                (OperasiIr::Sub, buat_expr(OperasiIr::Sub, TipeOperand::Immediate(0), src))
+            };
+            ops.push(StatementIr::new(instr.address, op_ir, dest, expr).with_type(ambil_tipe_hasil(mnem, &tipe_word)));
+        },
+        "sext.w" => {
+            let nilai = buat_expr(OperasiIr::SignExtend, ambil_sumber(lifter, instr, 1), TipeOperand::Immediate(32));
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, 0), nilai).with_type(tipe_word));
+        },
+        _ if mnem.starts_with("lr.") => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::AtomicLoad, lifter.ambil_operand(instr, 0), lifter.ambil_operand(instr, 1)));
+        },
+        _ if mnem.starts_with("sc.") => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::AtomicStore, lifter.ambil_operand(instr, 2), ambil_sumber(lifter, instr, 1)));
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, 0), TipeOperand::Immediate(0)).with_type(tipe_word));
+        },
+        _ if mnem.starts_with("amo") => proses_amo(lifter, instr, mnem, ops),
+        _ if mnem.starts_with('f') && ambil_tipe_load(mnem).is_none() && ambil_tipe_store(mnem).is_none() => proses_float(lifter, instr, mnem, ops),
+        _ => {
+            if let Some(tipe) = ambil_tipe_load(mnem) {
+                ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, 0), lifter.ambil_operand(instr, 1)).with_type(tipe));
+            } else if let Some(tipe) = ambil_tipe_store(mnem) {
+                ops.push(StatementIr::new(instr.address, OperasiIr::Mov, lifter.ambil_operand(instr, 1), ambil_sumber(lifter, instr, 0)).with_type(tipe));
+            } else if let Some(op_ir) = ambil_operasi_biner(mnem) {
+                proses_biner_riscv(lifter, instr, mnem, op_ir, &tipe_word, ops);
+            } else {
+                arithmetic::proses_generic_unknown(lifter, instr, ops);
+            }
+        },
+    }
+}
+
+pub fn gabungkan_pasangan_auipc(instr: &mut InstructionNormalized, peta_auipc: &mut HashMap<String, u64>) {
+    let mnem = instr.mnemonic.to_lowercase();
+    let tujuan = match instr.operands_detail.first() {
+        Some(JenisOperandDisasm::Register(r)) => Some(r.to_lowercase()),
+        _ => None,
+    };
+    if mnem == "auipc" {
+        if let (Some(rd), Some(JenisOperandDisasm::Immediate(imm))) = (tujuan, instr.operands_detail.get(1)) {
+            let nilai = instr.address.wrapping_add((((*imm << 12) as i32) as i64) as u64);
+            peta_auipc.insert(rd, nilai);
+        }
+        return;
+    }
+    let basis = |op: Option<&JenisOperandDisasm>| match op {
+        Some(JenisOperandDisasm::Register(r)) => peta_auipc.get(&r.to_lowercase()).copied(),
+        _ => None,
+    };
+    let imm = match instr.operands_detail.get(2) {
+        Some(JenisOperandDisasm::Immediate(v)) => Some(*v),
+        _ => None,
+    };
+    match (mnem.as_str(), basis(instr.operands_detail.get(1)), imm) {
+        ("addi" | "jalr", Some(nilai), Some(imm)) => {
+            instr.alamat_terlipat = Some(nilai.wrapping_add(imm as u64));
+        },
+        _ => {
+            for op in instr.operands_detail.iter_mut() {
+                if let JenisOperandDisasm::Memory { base: Some(b), disp, .. } = op
+                    && let Some(nilai) = peta_auipc.get(&b.to_lowercase())
+                {
+                    let alamat = nilai.wrapping_add(*disp as u64) as i64;
+                    *op = JenisOperandDisasm::Memory { base: None, index: None, scale: 1, disp: alamat, segment: None };
+                }
+            }
+        },
+    }
+    let is_tulis = !mnem.starts_with('b') && ambil_tipe_store(&mnem).is_none();
+    if is_tulis && let Some(rd) = tujuan {
+        peta_auipc.remove(&rd);
+    }
+}
+
+fn is_tulis_register_nol(instr: &InstructionNormalized, mnem: &str) -> bool {
+    let is_tulis = !matches!(mnem, "jal" | "jalr") && !mnem.starts_with('b') && ambil_tipe_store(mnem).is_none()
+        && !mnem.starts_with("sc.");
+    is_tulis && matches!(instr.operands_detail.first(), Some(JenisOperandDisasm::Register(r)) if r == "zero")
+}
+
+fn ambil_sumber(lifter: &IrLifter, instr: &InstructionNormalized, index: usize) -> TipeOperand {
+    match lifter.ambil_operand(instr, index) {
+        TipeOperand::Register(r) if r == "zero" => TipeOperand::Immediate(0),
+        op => op,
+    }
+}
+
+fn buat_expr(op: OperasiIr, kiri: TipeOperand, kanan: TipeOperand) -> TipeOperand {
+    TipeOperand::Expression {
+        operasi: op,
+        operand_kiri: Box::new(kiri),
+        operand_kanan: Box::new(kanan),
+    }
+}
+
+fn ambil_tipe_hasil(mnem: &str, tipe_word: &TipeDataIr) -> TipeDataIr {
+    if mnem.ends_with('w') && mnem != "sw" { TipeDataIr::I32 } else { tipe_word.clone() }
+}
+
+fn ambil_tipe_load(mnem: &str) -> Option<TipeDataIr> {
+    let tipe = match mnem {
+        "lb" | "lbu" => TipeDataIr::I8,
+        "lh" | "lhu" => TipeDataIr::I16,
+        "lw" | "lwu" => TipeDataIr::I32,
+        "ld" => TipeDataIr::I64,
+        "flw" => TipeDataIr::F32,
+        "fld" => TipeDataIr::F64,
+        _ => return None,
+    };
+    Some(tipe)
+}
+
+fn ambil_tipe_store(mnem: &str) -> Option<TipeDataIr> {
+    let tipe = match mnem {
+        "sb" => TipeDataIr::I8,
+        "sh" => TipeDataIr::I16,
+        "sw" => TipeDataIr::I32,
+        "sd" => TipeDataIr::I64,
+        "fsw" => TipeDataIr::F32,
+        "fsd" => TipeDataIr::F64,
+        _ => return None,
+    };
+    Some(tipe)
+}
+
+fn ambil_operasi_biner(mnem: &str) -> Option<OperasiIr> {
+    let dasar = mnem.strip_suffix('w').unwrap_or(mnem);
+    let op_ir = match dasar {
+        "add" | "addi" => OperasiIr::Add,
+        "sub" => OperasiIr::Sub,
+        "and" | "andi" => OperasiIr::And,
+        "or" | "ori" => OperasiIr::Or,
+        "xor" | "xori" => OperasiIr::Xor,
+        "sll" | "slli" => OperasiIr::Shl,
+        "srl" | "srli" => OperasiIr::Shr,
+        "sra" | "srai" => OperasiIr::Sar,
+        "mul" => OperasiIr::Imul,
+        "mulh" => OperasiIr::MulHi,
+        "mulhu" => OperasiIr::MulHiU,
+        "mulhsu" => OperasiIr::MulHiSU,
+        "div" => OperasiIr::Idiv,
+        "divu" => OperasiIr::Div,
+        _ => return None,
+    };
+    Some(op_ir)
+}
+
+fn proses_biner_riscv(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, op_ir: OperasiIr, tipe_word: &TipeDataIr, ops: &mut Vec<StatementIr>) {
+    let dest = lifter.ambil_operand(instr, 0);
+    let kiri = ambil_sumber(lifter, instr, 1);
+    let kanan = ambil_sumber(lifter, instr, 2);
+    let tipe = ambil_tipe_hasil(mnem, tipe_word);
+    if op_ir == OperasiIr::Add || op_ir == OperasiIr::Or || op_ir == OperasiIr::Xor {
+        let nilai_tunggal = match (&kiri, &kanan) {
+            (TipeOperand::Immediate(0), _) => Some(kanan.clone()),
+            (_, TipeOperand::Immediate(0)) => Some(kiri.clone()),
+            _ => None,
+        };
+        if let Some(nilai) = nilai_tunggal {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dest, nilai).with_type(tipe));
+            return;
+        }
+    }
+    let expr = buat_expr(op_ir.clone(), kiri, kanan);
+    ops.push(StatementIr::new(instr.address, op_ir, dest, expr).with_type(tipe));
+}
+
+fn proses_set_kondisi(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, tipe_word: TipeDataIr, ops: &mut Vec<StatementIr>) {
+    let dest = lifter.ambil_operand(instr, 0);
+    let src = ambil_sumber(lifter, instr, 1);
+    let kondisi = match mnem {
+        "seqz" => buat_expr(OperasiIr::Je, src, TipeOperand::Immediate(0)),
+        "snez" => buat_expr(OperasiIr::Jne, src, TipeOperand::Immediate(0)),
+        "sltz" => buat_expr(OperasiIr::Jl, src, TipeOperand::Immediate(0)),
+        "sgtz" => buat_expr(OperasiIr::Jg, src, TipeOperand::Immediate(0)),
+        _ => buat_expr(OperasiIr::Jl, src, ambil_sumber(lifter, instr, 2)),
+    };
+    let nilai = TipeOperand::Conditional {
+        condition: Box::new(kondisi),
+        true_val: Box::new(TipeOperand::Immediate(1)),
+        false_val: Box::new(TipeOperand::Immediate(0)),
+    };
+    ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dest, nilai).with_type(tipe_word));
+}
+
+fn proses_jal(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let target = lifter.ambil_operand(instr, 1);
+    if lifter.ambil_operand(instr, 0) == TipeOperand::Register("zero".to_string()) {
+        ops.push(StatementIr::new(instr.address, OperasiIr::Jmp, target, TipeOperand::None));
+    } else {
+        ops.push(StatementIr::new(instr.address, OperasiIr::Call, target, TipeOperand::None));
+    }
+}
+
+fn proses_jalr(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) {
+    let rd = lifter.ambil_operand(instr, 0);
+    let rs = lifter.ambil_operand(instr, 1);
+    let offset = lifter.ambil_operand(instr, 2);
+    let is_rd_nol = rd == TipeOperand::Register("zero".to_string());
+    if is_rd_nol && rs == TipeOperand::Register("ra".to_string()) && offset == TipeOperand::Immediate(0) {
+        ops.push(StatementIr::new(instr.address, OperasiIr::Ret, TipeOperand::None, TipeOperand::None));
+        return;
+    }
+    let target = match (instr.alamat_terlipat, offset) {
+        (Some(alamat), _) => TipeOperand::Immediate(alamat as i64),
+        (None, TipeOperand::Immediate(0) | TipeOperand::None) => rs,
+        (None, offset) => buat_expr(OperasiIr::Add, rs, offset),
+    };
+    let op_ir = if is_rd_nol { OperasiIr::Jmp } else { OperasiIr::Call };
+    ops.push(StatementIr::new(instr.address, op_ir, target, TipeOperand::None));
+}
+
+fn proses_branch(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let is_banding_nol = mnem.ends_with('z');
+    let kiri = ambil_sumber(lifter, instr, 0);
+    let (kanan, target) = if is_banding_nol {
+        (TipeOperand::Immediate(0), lifter.ambil_operand(instr, 1))
+    } else {
+        (ambil_sumber(lifter, instr, 1), lifter.ambil_operand(instr, 2))
+    };
+    let kode = mnem.trim_start_matches('b').trim_end_matches('z').trim_end_matches('u');
+    let (op_ir, kiri, kanan) = match kode {
+        "eq" => (OperasiIr::Je, kiri, kanan),
+        "ne" => (OperasiIr::Jne, kiri, kanan),
+        "lt" => (OperasiIr::Jl, kiri, kanan),
+        "ge" => (OperasiIr::Jge, kiri, kanan),
+        "gt" => (OperasiIr::Jg, kiri, kanan),
+        "le" => (OperasiIr::Jle, kiri, kanan),
+        _ => (OperasiIr::Jmp, kiri, kanan),
+    };
+    let kondisi = buat_expr(op_ir.clone(), kiri, kanan);
+    ops.push(StatementIr::new(instr.address, op_ir, target, kondisi));
+}
+
+fn proses_amo(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let dest = lifter.ambil_operand(instr, 0);
+    let src = ambil_sumber(lifter, instr, 1);
+    let mem = lifter.ambil_operand(instr, 2);
+    let op_ir = match mnem.trim_start_matches("amo").split('.').next().unwrap_or("") {
+        "swap" => OperasiIr::AtomicXchg,
+        "add" => OperasiIr::AtomicAdd,
+        "and" => OperasiIr::AtomicAnd,
+        "or" => OperasiIr::AtomicOr,
+        "xor" => OperasiIr::AtomicXor,
+        _ => {
+            arithmetic::proses_generic_unknown(lifter, instr, ops);
+            return;
+        },
+    };
+    if dest != TipeOperand::Register("zero".to_string()) {
+        ops.push(StatementIr::new(instr.address, OperasiIr::AtomicLoad, dest, mem.clone()));
+    }
+    ops.push(StatementIr::new(instr.address, op_ir, mem, src));
+}
+
+fn proses_float(lifter: &IrLifter, instr: &InstructionNormalized, mnem: &str, ops: &mut Vec<StatementIr>) {
+    let (dasar, presisi) = mnem.rsplit_once('.').unwrap_or((mnem, ""));
+    let tipe = if presisi == "d" { TipeDataIr::F64 } else { TipeDataIr::F32 };
+    let dest = lifter.ambil_operand(instr, 0);
+    let kiri = ambil_sumber(lifter, instr, 1);
+    let kanan = ambil_sumber(lifter, instr, 2);
+    let op_biner = match dasar {
+        "fadd" => Some(OperasiIr::FAdd),
+        "fsub" => Some(OperasiIr::FSub),
+        "fmul" => Some(OperasiIr::FMul),
+        "fdiv" => Some(OperasiIr::FDiv),
+        "fmin" => Some(OperasiIr::FMin),
+        "fmax" => Some(OperasiIr::FMax),
+        _ => None,
+    };
+    if let Some(op_ir) = op_biner {
+        ops.push(StatementIr::new(instr.address, op_ir.clone(), dest, buat_expr(op_ir, kiri, kanan)).with_type(tipe));
+        return;
+    }
+    match dasar {
+        "fsqrt" => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::FSqrt, dest, kiri).with_type(tipe));
+        },
+        "fmadd" | "fmsub" => {
+            let op_luar = if dasar == "fmadd" { OperasiIr::FAdd } else { OperasiIr::FSub };
+            let expr = buat_expr(op_luar.clone(), buat_expr(OperasiIr::FMul, kiri, kanan), ambil_sumber(lifter, instr, 3));
+            ops.push(StatementIr::new(instr.address, op_luar, dest, expr).with_type(tipe));
+        },
+        "feq" | "flt" | "fle" => {
+            let op_banding = match dasar {
+                "feq" => OperasiIr::Je,
+                "flt" => OperasiIr::Jl,
+                _ => OperasiIr::Jle,
+            };
+            let nilai = TipeOperand::Conditional {
+                condition: Box::new(buat_expr(op_banding, kiri, kanan)),
+                true_val: Box::new(TipeOperand::Immediate(1)),
+                false_val: Box::new(TipeOperand::Immediate(0)),
+            };
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dest, nilai));
+        },
+        "fmv" | "fmv.x" | "fmv.w" | "fmv.d" | "fsgnj" => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::Mov, dest, kiri).with_type(tipe));
+        },
+        "fneg" => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::FSub, dest, buat_expr(OperasiIr::FSub, TipeOperand::Immediate(0), kiri)).with_type(tipe));
+        },
+        _ if dasar.starts_with("fcvt") => {
+            ops.push(StatementIr::new(instr.address, OperasiIr::FConv, dest, kiri));
+        },
+        _ => arithmetic::proses_generic_unknown(lifter, instr, ops),
+    }
+}
@@ -1,7 +1,32 @@
 use std::collections::HashMap;
+use std::fs;
+use std::sync::LazyLock;
+use log::info;
+use serde::Deserialize;
+use thiserror::Error;
 use crate::disasm::instruction::InstructionNormalized;
 use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
+use crate::arch::x86::{FLAG_AF, FLAG_CF, FLAG_OF, FLAG_PF, FLAG_SF, FLAG_ZF};
 use super::IrLifter;
+use super::eflags::{self, JenisOperasiFlag, OperandFlag};
+
+static SPEC_X86: LazyLock<SpecSemantik> = LazyLock::new(|| {
+    SpecSemantik::dari_str(include_str!("spec/x86.json")).expect("spec semantik x86 bawaan harus valid")
+});
+
+#[derive(Debug, Error)]
+pub enum SemantikError {
+    #[error("Gagal membaca spec semantik: {0}")]
+    IoError(String),
+    #[error("Format spec semantik tidak valid: {0}")]
+    ParseError(String),
+    #[error("Arsitektur spec '{0}' tidak dikenali")]
+    ArsitekturTidakDikenal(String),
+    #[error("Spec untuk arsitektur '{spec}' tidak bisa dipakai pada '{engine}'")]
+    ArsitekturTidakCocok { spec: String, engine: String },
+    #[error("Definisi '{mnemonic}' tidak valid: {pesan}")]
+    DefinisiTidakValid { mnemonic: String, pesan: String },
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EfekFlag {
@@ -10,67 +35,326 @@ pub enum EfekFlag {
     SetOverflow,
     SetCarry,
     SetParity,
-    Undefined,
+    SetAuxiliary,
+    ClearCarry,
+    ClearOverflow,
+    Undefined(&'static str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum PeranOperand {
+    Baca,
+    Tulis,
+    BacaTulis,
+}
+
+impl PeranOperand {
+    fn bisa_dibaca(self) -> bool {
+        self != PeranOperand::Tulis
+    }
+    fn bisa_ditulis(self) -> bool {
+        self != PeranOperand::Baca
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RujukanOperand {
+    Operand(usize),
+    Implisit(usize),
+    Konstanta(i64),
+    Temp(String),
+}
+
+impl RujukanOperand {
+    fn parse(teks: &str) -> Option<Self> {
+        let teks = teks.trim();
+        if let Some(angka) = teks.strip_prefix('#') {
+            let (negatif, angka) = match angka.strip_prefix('-') {
+                Some(sisa) => (true, sisa),
+                None => (false, angka),
+            };
+            let nilai = match angka.strip_prefix("0x") {
+                Some(hex) => i64::from_str_radix(hex, 16).ok()?,
+                None => angka.parse::<i64>().ok()?,
+            };
+            return Some(RujukanOperand::Konstanta(if negatif { -nilai } else { nilai }));
+        }
+        if let Some(indeks) = teks.strip_prefix("op").and_then(|s| s.parse().ok()) {
+            return Some(RujukanOperand::Operand(indeks));
+        }
+        if let Some(indeks) = teks.strip_prefix("imp").and_then(|s| s.parse().ok()) {
+            return Some(RujukanOperand::Implisit(indeks));
+        }
+        if teks.starts_with("temp_") && teks.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Some(RujukanOperand::Temp(teks.to_string()));
+        }
+        None
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct TemplateMicroOp {
     pub operasi: OperasiIr,
     pub tipe_hasil: TipeDataIr,
-    pub pakai_dest_sebagai_src1: bool,
+    pub tujuan: RujukanOperand,
+    pub kiri: RujukanOperand,
+    pub kanan: Option<RujukanOperand>,
+    pub ekspresi: OperasiIr,
+    pub bawaan: Option<RujukanOperand>,
     pub is_vector_op: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct DeskripsiInstruksi {
     pub mnemonic: String,
+    pub peran_operand: Vec<PeranOperand>,
     pub micro_ops: Vec<TemplateMicroOp>,
     pub efek_flags: Vec<EfekFlag>,
-    pub operand_implisit: Vec<TipeOperand>, 
+    pub jenis_flag: Option<JenisOperasiFlag>,
+    pub sumber_flag: usize,
+    pub operand_implisit: Vec<TipeOperand>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpecMicroOp {
+    pub operasi: OperasiIr,
+    pub tujuan: String,
+    pub kiri: String,
+    #[serde(default)]
+    pub kanan: Option<String>,
+    #[serde(default)]
+    pub ekspresi: Option<OperasiIr>,
+    #[serde(default)]
+    pub bawaan: Option<String>,
+    #[serde(default)]
+    pub tipe: Option<TipeDataIr>,
+    #[serde(default)]
+    pub vektor: bool,
+    #[serde(default)]
+    pub sumber_flag: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpecInstruksi {
+    pub mnemonic: String,
+    #[serde(default)]
+    pub operand: Vec<PeranOperand>,
+    #[serde(default)]
+    pub implisit: Vec<String>,
+    pub micro_ops: Vec<SpecMicroOp>,
+    #[serde(default)]
+    pub flags: Vec<String>,
+    #[serde(default)]
+    pub jenis_flag: Option<JenisOperasiFlag>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpecSemantik {
+    pub arsitektur: String,
+    pub instruksi: Vec<SpecInstruksi>,
+}
+
+impl SpecSemantik {
+    pub fn dari_str(teks: &str) -> Result<Self, SemantikError> {
+        let spec: SpecSemantik = serde_json::from_str(teks).map_err(|e| SemantikError::ParseError(e.to_string()))?;
+        SemanticEngine::kosong(&spec.arsitektur)?.terapkan_spec(&spec)?;
+        Ok(spec)
+    }
+    pub fn dari_file(file_path: &str) -> Result<Self, SemantikError> {
+        let teks = fs::read_to_string(file_path).map_err(|e| SemantikError::IoError(e.to_string()))?;
+        Self::dari_str(&teks)
+    }
+}
+
+fn keluarga_arsitektur(arch: &str) -> Option<&'static str> {
+    if crate::arch::is_x86(arch) {
+        Some("x86_64")
+    } else if crate::arch::is_arm32(arch) {
+        Some("arm")
+    } else if crate::arch::is_riscv(arch) {
+        Some("riscv64")
+    } else if crate::arch::is_mips(arch) {
+        Some("mips")
+    } else if crate::arch::is_arm64(arch) {
+        Some("aarch64")
+    } else {
+        None
+    }
+}
+
+fn parse_efek_flag(teks: &str) -> Option<EfekFlag> {
+    let (nama, nilai) = teks.split_once('=')?;
+    let flag = match nama.trim() {
+        "cf" => FLAG_CF,
+        "of" => FLAG_OF,
+        "sf" => FLAG_SF,
+        "zf" => FLAG_ZF,
+        "pf" => FLAG_PF,
+        "af" => FLAG_AF,
+        _ => return None,
+    };
+    match (flag, nilai.trim()) {
+        (_, "undefined") => Some(EfekFlag::Undefined(flag)),
+        (FLAG_CF, "hasil") => Some(EfekFlag::SetCarry),
+        (FLAG_OF, "hasil") => Some(EfekFlag::SetOverflow),
+        (FLAG_SF, "hasil") => Some(EfekFlag::SetSign),
+        (FLAG_ZF, "hasil") => Some(EfekFlag::SetZero),
+        (FLAG_PF, "hasil") => Some(EfekFlag::SetParity),
+        (FLAG_AF, "hasil") => Some(EfekFlag::SetAuxiliary),
+        (FLAG_CF, "0") => Some(EfekFlag::ClearCarry),
+        (FLAG_OF, "0") => Some(EfekFlag::ClearOverflow),
+        _ => None,
+    }
 }
 
 pub struct SemanticEngine {
-    pub tabel_definisi: HashMap<String, DeskripsiInstruksi>,
+    pub arsitektur: String,
+    pub tabel_definisi: HashMap<String, Vec<DeskripsiInstruksi>>,
 }
 
 impl SemanticEngine {
     pub fn new() -> Self {
+        Self::untuk_arsitektur("x86_64")
+    }
+    pub fn untuk_arsitektur(arch: &str) -> Self {
         let mut engine = Self {
+            arsitektur: arch.to_string(),
             tabel_definisi: HashMap::new(),
         };
-        engine.inisialisasi_definisi_standar();
-        engine.inisialisasi_definisi_simd();
+        if crate::arch::is_x86(arch) {
+            engine.terapkan_spec(&SPEC_X86).expect("spec semantik x86 bawaan harus valid");
+        }
         engine
     }
-    fn registrasi_instruksi(&mut self, mnemonic: &str, ops: Vec<TemplateMicroOp>, flags: Vec<EfekFlag>) {
-        self.tabel_definisi.insert(mnemonic.to_string(), DeskripsiInstruksi {
-            mnemonic: mnemonic.to_string(),
-            micro_ops: ops,
-            efek_flags: flags,
-            operand_implisit: Vec::new(),
-        });
-    }
-    fn inisialisasi_definisi_standar(&mut self) {
-        self.registrasi_instruksi("add", vec![
-            TemplateMicroOp { operasi: OperasiIr::Add, tipe_hasil: TipeDataIr::Unknown, pakai_dest_sebagai_src1: true, is_vector_op: false }
-        ], vec![EfekFlag::SetZero, EfekFlag::SetSign, EfekFlag::SetOverflow, EfekFlag::SetCarry, EfekFlag::SetParity]);
-        
-        self.registrasi_instruksi("sub", vec![
-            TemplateMicroOp { operasi: OperasiIr::Sub, tipe_hasil: TipeDataIr::Unknown, pakai_dest_sebagai_src1: true, is_vector_op: false }
-        ], vec![EfekFlag::SetZero, EfekFlag::SetSign, EfekFlag::SetOverflow, EfekFlag::SetCarry, EfekFlag::SetParity]);
-        
-        self.registrasi_instruksi("imul", vec![
-            TemplateMicroOp { operasi: OperasiIr::Imul, tipe_hasil: TipeDataIr::Unknown, pakai_dest_sebagai_src1: true, is_vector_op: false }
-        ], vec![EfekFlag::SetOverflow, EfekFlag::SetCarry]); 
-
-        self.registrasi_instruksi("xor", vec![
-            TemplateMicroOp { operasi: OperasiIr::Xor, tipe_hasil: TipeDataIr::Unknown, pakai_dest_sebagai_src1: true, is_vector_op: false }
-        ], vec![EfekFlag::SetZero, EfekFlag::SetSign, EfekFlag::SetParity, EfekFlag::Undefined]); 
-    }
-    fn inisialisasi_definisi_simd(&mut self) {
-        self.registrasi_instruksi("addps", vec![
-            TemplateMicroOp { operasi: OperasiIr::VecAdd, tipe_hasil: TipeDataIr::V128F32, pakai_dest_sebagai_src1: true, is_vector_op: true }
-        ], vec![]);
+    pub fn untuk_arsitektur_dengan_spec(arch: &str, daftar_spec: &[SpecSemantik]) -> Self {
+        let mut engine = Self::untuk_arsitektur(arch);
+        for spec in daftar_spec {
+            match engine.terapkan_spec(spec) {
+                Ok(jumlah) => info!("Spec semantik {}: {} definisi diterapkan", spec.arsitektur, jumlah),
+                Err(e) => info!("Spec semantik dilewati: {}", e),
+            }
+        }
+        engine
+    }
+    fn kosong(arch: &str) -> Result<Self, SemantikError> {
+        keluarga_arsitektur(arch).ok_or_else(|| SemantikError::ArsitekturTidakDikenal(arch.to_string()))?;
+        Ok(Self { arsitektur: arch.to_string(), tabel_definisi: HashMap::new() })
+    }
+    pub fn muat_spec_file(&mut self, file_path: &str) -> Result<usize, SemantikError> {
+        let spec = SpecSemantik::dari_file(file_path)?;
+        self.terapkan_spec(&spec)
+    }
+    pub fn terapkan_spec(&mut self, spec: &SpecSemantik) -> Result<usize, SemantikError> {
+        let keluarga = keluarga_arsitektur(&spec.arsitektur).ok_or_else(|| SemantikError::ArsitekturTidakDikenal(spec.arsitektur.clone()))?;
+        if keluarga_arsitektur(&self.arsitektur) != Some(keluarga) {
+            return Err(SemantikError::ArsitekturTidakCocok { spec: spec.arsitektur.clone(), engine: self.arsitektur.clone() });
+        }
+        let register_file = crate::arch::buat_arsitektur(keluarga).dapatkan_register_file();
+        let mut definisi_baru: Vec<DeskripsiInstruksi> = Vec::new();
+        for entri in &spec.instruksi {
+            let def = self.validasi_definisi(entri, &register_file)?;
+            if definisi_baru.iter().any(|d| d.mnemonic == def.mnemonic && d.peran_operand.len() == def.peran_operand.len()) {
+                return Err(SemantikError::DefinisiTidakValid {
+                    mnemonic: def.mnemonic,
+                    pesan: "didefinisikan lebih dari sekali dengan jumlah operand yang sama".to_string(),
+                });
+            }
+            definisi_baru.push(def);
+        }
+        let jumlah = definisi_baru.len();
+        for def in definisi_baru {
+            let varian = self.tabel_definisi.entry(def.mnemonic.clone()).or_default();
+            varian.retain(|d| d.peran_operand.len() != def.peran_operand.len());
+            varian.push(def);
+        }
+        Ok(jumlah)
+    }
+    fn validasi_definisi(&self, entri: &SpecInstruksi, register_file: &crate::arch::RegisterFile) -> Result<DeskripsiInstruksi, SemantikError> {
+        let mnemonic = entri.mnemonic.trim().to_lowercase();
+        let gagal = |pesan: String| SemantikError::DefinisiTidakValid { mnemonic: mnemonic.clone(), pesan };
+        if mnemonic.is_empty() {
+            return Err(gagal("mnemonic kosong".to_string()));
+        }
+        if entri.micro_ops.is_empty() {
+            return Err(gagal("micro_ops kosong".to_string()));
+        }
+        let mut operand_implisit = Vec::new();
+        for nama in &entri.implisit {
+            let nama = nama.trim().to_lowercase();
+            if register_file.cari_register(&nama).is_none() {
+                return Err(gagal(format!("register implisit '{}' tidak dikenal", nama)));
+            }
+            operand_implisit.push(TipeOperand::Register(nama));
+        }
+        let cek_rujukan = |teks: &str, tulis: bool| -> Result<RujukanOperand, SemantikError> {
+            let rujukan = RujukanOperand::parse(teks).ok_or_else(|| gagal(format!("rujukan operand '{}' tidak valid", teks)))?;
+            match &rujukan {
+                RujukanOperand::Operand(i) => {
+                    let peran = entri.operand.get(*i).ok_or_else(|| gagal(format!("op{} melebihi jumlah operand", i)))?;
+                    if tulis && !peran.bisa_ditulis() {
+                        return Err(gagal(format!("op{} bukan operand tujuan", i)));
+                    }
+                    if !tulis && !peran.bisa_dibaca() {
+                        return Err(gagal(format!("op{} hanya boleh ditulis", i)));
+                    }
+                },
+                RujukanOperand::Implisit(i) if *i >= operand_implisit.len() => {
+                    return Err(gagal(format!("imp{} melebihi jumlah operand implisit", i)));
+                },
+                RujukanOperand::Konstanta(_) if tulis => {
+                    return Err(gagal("konstanta tidak bisa menjadi tujuan".to_string()));
+                },
+                _ => {},
+            }
+            Ok(rujukan)
+        };
+        let mut micro_ops = Vec::new();
+        for op in &entri.micro_ops {
+            if op.bawaan.is_some() && op.kanan.is_none() {
+                return Err(gagal("bawaan membutuhkan operand kanan".to_string()));
+            }
+            micro_ops.push(TemplateMicroOp {
+                operasi: op.operasi.clone(),
+                tipe_hasil: op.tipe.clone().unwrap_or(TipeDataIr::Unknown),
+                tujuan: cek_rujukan(&op.tujuan, true)?,
+                kiri: cek_rujukan(&op.kiri, false)?,
+                kanan: op.kanan.as_deref().map(|k| cek_rujukan(k, false)).transpose()?,
+                ekspresi: op.ekspresi.clone().unwrap_or_else(|| op.operasi.clone()),
+                bawaan: op.bawaan.as_deref().map(|b| cek_rujukan(b, false)).transpose()?,
+                is_vector_op: op.vektor,
+            });
+        }
+        let penanda: Vec<usize> = entri.micro_ops.iter().enumerate().filter(|(_, op)| op.sumber_flag).map(|(i, _)| i).collect();
+        if penanda.len() > 1 {
+            return Err(gagal("hanya satu micro-op boleh menjadi sumber_flag".to_string()));
+        }
+        let sumber_flag = penanda.first().copied().unwrap_or(micro_ops.len() - 1);
+        let efek_flags = entri.flags.iter()
+            .map(|f| parse_efek_flag(f).ok_or_else(|| gagal(format!("efek flag '{}' tidak dikenal", f))))
+            .collect::<Result<Vec<_>, _>>()?;
+        let jenis_flag = entri.jenis_flag.or_else(|| JenisOperasiFlag::dari_operasi(&micro_ops[sumber_flag].operasi));
+        if !efek_flags.is_empty() {
+            if !crate::arch::is_x86(&self.arsitektur) {
+                return Err(gagal("efek flag hanya didukung untuk x86".to_string()));
+            }
+            if jenis_flag.is_none() {
+                return Err(gagal("jenis_flag wajib diisi untuk operasi ini".to_string()));
+            }
+        }
+        Ok(DeskripsiInstruksi {
+            mnemonic,
+            peran_operand: entri.operand.clone(),
+            micro_ops,
+            efek_flags,
+            jenis_flag,
+            sumber_flag,
+            operand_implisit,
+        })
+    }
+    pub fn cari_definisi(&self, instr: &InstructionNormalized) -> Option<&DeskripsiInstruksi> {
+        self.tabel_definisi.get(&instr.mnemonic.to_lowercase())?
+            .iter()
+            .find(|d| d.peran_operand.len() == instr.operands_detail.len())
     }
     pub fn proses_lifting_otomatis(
         &self,
@@ -78,14 +362,27 @@ impl SemanticEngine {
         instr: &InstructionNormalized,
         ops_output: &mut Vec<StatementIr>
     ) -> bool {
-        let mnemonic_lower = instr.mnemonic.to_lowercase();
-        if let Some(definisi) = self.tabel_definisi.get(&mnemonic_lower) {
+        if let Some(definisi) = self.cari_definisi(instr) {
             self.generate_ir_dari_template(lifter, instr, definisi, ops_output);
             true
         } else {
             false
         }
     }
+    fn ambil_rujukan(
+        &self,
+        lifter: &IrLifter,
+        instr: &InstructionNormalized,
+        def: &DeskripsiInstruksi,
+        rujukan: &RujukanOperand
+    ) -> TipeOperand {
+        match rujukan {
+            RujukanOperand::Operand(i) => lifter.ambil_operand(instr, *i),
+            RujukanOperand::Implisit(i) => def.operand_implisit[*i].clone(),
+            RujukanOperand::Konstanta(v) => TipeOperand::Immediate(*v),
+            RujukanOperand::Temp(nama) => TipeOperand::Register(nama.clone()),
+        }
+    }
     fn generate_ir_dari_template(
         &self,
         lifter: &IrLifter,
@@ -93,32 +390,36 @@ impl SemanticEngine {
         def: &DeskripsiInstruksi,
         ops_output: &mut Vec<StatementIr>
     ) {
-        let op_dest = lifter.ambil_operand(instr, 0);
-        let op_src1 = lifter.ambil_operand(instr, 1);
-        let op_src2 = lifter.ambil_operand(instr, 2);
-        for micro_op in &def.micro_ops {
-            let (final_op1, final_op2) = if micro_op.pakai_dest_sebagai_src1 {
-                (op_dest.clone(), if op_src1 != TipeOperand::None { op_src1.clone() } else { op_dest.clone() })
+        let lebar = lifter.ambil_lebar_bit(instr, 0);
+        for (indeks, micro_op) in def.micro_ops.iter().enumerate() {
+            let tujuan = self.ambil_rujukan(lifter, instr, def, &micro_op.tujuan);
+            let kiri = self.ambil_rujukan(lifter, instr, def, &micro_op.kiri);
+            let kanan = micro_op.kanan.as_ref().map(|r| self.ambil_rujukan(lifter, instr, def, r));
+            let jenis_flag = def.jenis_flag.filter(|_| indeks == def.sumber_flag && !def.efek_flags.is_empty());
+            let kanan_flag = kanan.clone().unwrap_or(TipeOperand::None);
+            let (kiri_flag, kanan_flag) = if jenis_flag.is_some_and(|j| j != JenisOperasiFlag::Logika) {
+                super::arithmetic::amankan_sumber(instr.address, &tujuan, &kiri, &kanan_flag, lebar, ops_output)
             } else {
-                if op_src2 != TipeOperand::None {
-                    (op_src1.clone(), op_src2.clone())
-                } else {
-                    (op_dest.clone(), op_src1.clone())
-                }
+                (kiri.clone(), kanan_flag)
             };
-            let result_expr = TipeOperand::Expression {
-                operasi: micro_op.operasi.clone(),
-                operand_kiri: Box::new(final_op1.clone()),
-                operand_kanan: Box::new(final_op2.clone()),
+            let nilai = match (kanan, &micro_op.bawaan) {
+                (Some(kanan), Some(bawaan)) => {
+                    let bawaan = self.ambil_rujukan(lifter, instr, def, bawaan);
+                    let sementara = eflags::buat_expr(micro_op.ekspresi.clone(), kiri, kanan);
+                    eflags::buat_expr(micro_op.ekspresi.clone(), sementara, bawaan)
+                },
+                (Some(kanan), None) => eflags::buat_expr(micro_op.ekspresi.clone(), kiri, kanan),
+                (None, _) => kiri,
             };
             ops_output.push(StatementIr::new(
                 instr.address,
                 micro_op.operasi.clone(),
-                op_dest.clone(),
-                result_expr.clone()
+                tujuan.clone(),
+                nilai
             ).with_type(micro_op.tipe_hasil.clone()));
-            if !def.efek_flags.is_empty() {
-                self.generate_efek_flags(instr.address, &def.efek_flags, &op_dest, ops_output);
+            if let Some(jenis) = jenis_flag {
+                let o = OperandFlag::new(tujuan, kiri_flag, kanan_flag, lebar);
+                self.generate_efek_flags(instr.address, &def.efek_flags, jenis, &o, ops_output);
             }
         }
     }
@@ -126,40 +427,29 @@ impl SemanticEngine {
         &self,
         addr: u64,
         flags: &[EfekFlag],
-        result: &TipeOperand,
+        jenis: JenisOperasiFlag,
+        o: &OperandFlag,
         ops_output: &mut Vec<StatementIr>
     ) {
         for flag in flags {
             match flag {
-                EfekFlag::SetZero => {
-                    let cond = TipeOperand::Expression {
-                        operasi: OperasiIr::Je,
-                        operand_kiri: Box::new(result.clone()),
-                        operand_kanan: Box::new(TipeOperand::Immediate(0)),
-                    };
-                    ops_output.push(StatementIr::new(
-                        addr,
-                        OperasiIr::Mov,
-                        TipeOperand::Register("eflags_zf".to_string()),
-                        cond
-                    ).with_type(TipeDataIr::I8));
-                },
-                EfekFlag::SetSign => {
-                     ops_output.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register("eflags_sf".to_string()), result.clone()));
-                },
+                EfekFlag::SetZero => eflags::tulis_flag(addr, FLAG_ZF, eflags::nilai_zero(o), ops_output),
+                EfekFlag::SetSign => eflags::tulis_flag(addr, FLAG_SF, eflags::nilai_sign(o), ops_output),
                 EfekFlag::SetCarry => {
-                    ops_output.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register("eflags_cf".to_string()), result.clone()));
-                },
-                EfekFlag::SetOverflow => {
-                    ops_output.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register("eflags_of".to_string()), result.clone()));
+                    if let Some(cf) = eflags::nilai_carry(jenis, o) {
+                        eflags::tulis_flag(addr, FLAG_CF, cf, ops_output);
+                    }
                 },
+                EfekFlag::SetOverflow => eflags::tulis_flag(addr, FLAG_OF, eflags::nilai_overflow(jenis, o), ops_output),
                 EfekFlag::SetParity => {
-                    ops_output.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register("eflags_pf".to_string()), result.clone()));
-                },
-                EfekFlag::Undefined => {
-                     ops_output.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register("eflags_undef".to_string()), TipeOperand::Immediate(1)));
+                    let pf = eflags::nilai_parity(addr, &o.hasil, ops_output);
+                    eflags::tulis_flag(addr, FLAG_PF, pf, ops_output);
                 },
+                EfekFlag::SetAuxiliary => eflags::tulis_flag(addr, FLAG_AF, eflags::nilai_aux(o), ops_output),
+                EfekFlag::ClearCarry => eflags::tulis_flag(addr, FLAG_CF, TipeOperand::Immediate(0), ops_output),
+                EfekFlag::ClearOverflow => eflags::tulis_flag(addr, FLAG_OF, TipeOperand::Immediate(0), ops_output),
+                EfekFlag::Undefined(nama) => eflags::tulis_flag(addr, nama, eflags::flag_undefined(), ops_output),
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -3,14 +3,6 @@ use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr};
 use super::IrLifter;
 use log::debug;
 
-pub fn cek_is_simd_instruction(mnemonic: &str) -> bool {
-    mnemonic.starts_with('v') ||
-    mnemonic.starts_with('p') ||
-    mnemonic.ends_with("ps") || mnemonic.ends_with("pd") ||
-    mnemonic.ends_with("ss") || mnemonic.ends_with("sd") ||
-    mnemonic.contains("xmm") || mnemonic.contains("ymm") || mnemonic.contains("zmm")
-}
-
 pub fn proses_simd_to_intrinsic(
     lifter: &IrLifter, 
     instr: &InstructionNormalized, 
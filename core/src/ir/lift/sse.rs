@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use capstone::arch::x86::X86Insn;
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
+use crate::ir::types::{StatementIr, OperasiIr, TipeDataIr};
+use super::{x87, IrLifter};
+use super::dispatch::{self, ArsitekturDispatch};
+use super::eflags::buat_expr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Presisi {
+    Tunggal,
+    Ganda,
+}
+
+impl Presisi {
+    fn tipe(self) -> TipeDataIr {
+        match self {
+            Presisi::Tunggal => TipeDataIr::F32,
+            Presisi::Ganda => TipeDataIr::F64,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JenisBiner {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Min,
+    Max,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AksiSse {
+    Pindah,
+    Biner(JenisBiner),
+    Akar,
+    DariInteger,
+    KeInteger { potong: bool },
+    UbahPresisi,
+    Banding,
+}
+
+const TABEL_AKSI: &[(X86Insn, AksiSse, Presisi)] = &[
+    (X86Insn::X86_INS_MOVSS, AksiSse::Pindah, Presisi::Tunggal),
+    (X86Insn::X86_INS_MOVSD, AksiSse::Pindah, Presisi::Ganda),
+    (X86Insn::X86_INS_VMOVSS, AksiSse::Pindah, Presisi::Tunggal),
+    (X86Insn::X86_INS_VMOVSD, AksiSse::Pindah, Presisi::Ganda),
+    (X86Insn::X86_INS_ADDSS, AksiSse::Biner(JenisBiner::Add), Presisi::Tunggal),
+    (X86Insn::X86_INS_ADDSD, AksiSse::Biner(JenisBiner::Add), Presisi::Ganda),
+    (X86Insn::X86_INS_VADDSS, AksiSse::Biner(JenisBiner::Add), Presisi::Tunggal),
+    (X86Insn::X86_INS_VADDSD, AksiSse::Biner(JenisBiner::Add), Presisi::Ganda),
+    (X86Insn::X86_INS_SUBSS, AksiSse::Biner(JenisBiner::Sub), Presisi::Tunggal),
+    (X86Insn::X86_INS_SUBSD, AksiSse::Biner(JenisBiner::Sub), Presisi::Ganda),
+    (X86Insn::X86_INS_VSUBSS, AksiSse::Biner(JenisBiner::Sub), Presisi::Tunggal),
+    (X86Insn::X86_INS_VSUBSD, AksiSse::Biner(JenisBiner::Sub), Presisi::Ganda),
+    (X86Insn::X86_INS_MULSS, AksiSse::Biner(JenisBiner::Mul), Presisi::Tunggal),
+    (X86Insn::X86_INS_MULSD, AksiSse::Biner(JenisBiner::Mul), Presisi::Ganda),
+    (X86Insn::X86_INS_VMULSS, AksiSse::Biner(JenisBiner::Mul), Presisi::Tunggal),
+    (X86Insn::X86_INS_VMULSD, AksiSse::Biner(JenisBiner::Mul), Presisi::Ganda),
+    (X86Insn::X86_INS_DIVSS, AksiSse::Biner(JenisBiner::Div), Presisi::Tunggal),
+    (X86Insn::X86_INS_DIVSD, AksiSse::Biner(JenisBiner::Div), Presisi::Ganda),
+    (X86Insn::X86_INS_VDIVSS, AksiSse::Biner(JenisBiner::Div), Presisi::Tunggal),
+    (X86Insn::X86_INS_VDIVSD, AksiSse::Biner(JenisBiner::Div), Presisi::Ganda),
+    (X86Insn::X86_INS_MINSS, AksiSse::Biner(JenisBiner::Min), Presisi::Tunggal),
+    (X86Insn::X86_INS_MINSD, AksiSse::Biner(JenisBiner::Min), Presisi::Ganda),
+    (X86Insn::X86_INS_VMINSS, AksiSse::Biner(JenisBiner::Min), Presisi::Tunggal),
+    (X86Insn::X86_INS_VMINSD, AksiSse::Biner(JenisBiner::Min), Presisi::Ganda),
+    (X86Insn::X86_INS_MAXSS, AksiSse::Biner(JenisBiner::Max), Presisi::Tunggal),
+    (X86Insn::X86_INS_MAXSD, AksiSse::Biner(JenisBiner::Max), Presisi::Ganda),
+    (X86Insn::X86_INS_VMAXSS, AksiSse::Biner(JenisBiner::Max), Presisi::Tunggal),
+    (X86Insn::X86_INS_VMAXSD, AksiSse::Biner(JenisBiner::Max), Presisi::Ganda),
+    (X86Insn::X86_INS_SQRTSS, AksiSse::Akar, Presisi::Tunggal),
+    (X86Insn::X86_INS_SQRTSD, AksiSse::Akar, Presisi::Ganda),
+    (X86Insn::X86_INS_VSQRTSS, AksiSse::Akar, Presisi::Tunggal),
+    (X86Insn::X86_INS_VSQRTSD, AksiSse::Akar, Presisi::Ganda),
+    (X86Insn::X86_INS_CVTSI2SS, AksiSse::DariInteger, Presisi::Tunggal),
+    (X86Insn::X86_INS_CVTSI2SD, AksiSse::DariInteger, Presisi::Ganda),
+    (X86Insn::X86_INS_VCVTSI2SS, AksiSse::DariInteger, Presisi::Tunggal),
+    (X86Insn::X86_INS_VCVTSI2SD, AksiSse::DariInteger, Presisi::Ganda),
+    (X86Insn::X86_INS_CVTTSS2SI, AksiSse::KeInteger { potong: true }, Presisi::Tunggal),
+    (X86Insn::X86_INS_CVTTSD2SI, AksiSse::KeInteger { potong: true }, Presisi::Ganda),
+    (X86Insn::X86_INS_VCVTTSS2SI, AksiSse::KeInteger { potong: true }, Presisi::Tunggal),
+    (X86Insn::X86_INS_VCVTTSD2SI, AksiSse::KeInteger { potong: true }, Presisi::Ganda),
+    (X86Insn::X86_INS_CVTSS2SI, AksiSse::KeInteger { potong: false }, Presisi::Tunggal),
+    (X86Insn::X86_INS_CVTSD2SI, AksiSse::KeInteger { potong: false }, Presisi::Ganda),
+    (X86Insn::X86_INS_VCVTSS2SI, AksiSse::KeInteger { potong: false }, Presisi::Tunggal),
+    (X86Insn::X86_INS_VCVTSD2SI, AksiSse::KeInteger { potong: false }, Presisi::Ganda),
+    (X86Insn::X86_INS_CVTSD2SS, AksiSse::UbahPresisi, Presisi::Tunggal),
+    (X86Insn::X86_INS_CVTSS2SD, AksiSse::UbahPresisi, Presisi::Ganda),
+    (X86Insn::X86_INS_VCVTSD2SS, AksiSse::UbahPresisi, Presisi::Tunggal),
+    (X86Insn::X86_INS_VCVTSS2SD, AksiSse::UbahPresisi, Presisi::Ganda),
+    (X86Insn::X86_INS_UCOMISS, AksiSse::Banding, Presisi::Tunggal),
+    (X86Insn::X86_INS_UCOMISD, AksiSse::Banding, Presisi::Ganda),
+    (X86Insn::X86_INS_COMISS, AksiSse::Banding, Presisi::Tunggal),
+    (X86Insn::X86_INS_COMISD, AksiSse::Banding, Presisi::Ganda),
+    (X86Insn::X86_INS_VUCOMISS, AksiSse::Banding, Presisi::Tunggal),
+    (X86Insn::X86_INS_VUCOMISD, AksiSse::Banding, Presisi::Ganda),
+    (X86Insn::X86_INS_VCOMISS, AksiSse::Banding, Presisi::Tunggal),
+    (X86Insn::X86_INS_VCOMISD, AksiSse::Banding, Presisi::Ganda),
+];
+
+static AKSI_SSE: LazyLock<HashMap<u32, (AksiSse, Presisi)>> = LazyLock::new(|| {
+    TABEL_AKSI.iter().map(|(id, aksi, presisi)| (*id as u32, (*aksi, *presisi))).collect()
+});
+
+fn ambil_aksi(instr: &InstructionNormalized) -> Option<(AksiSse, Presisi)> {
+    dispatch::ambil_id_instruksi(ArsitekturDispatch::X86, instr).and_then(|id| AKSI_SSE.get(&id).copied())
+}
+
+fn is_register_xmm(op: &JenisOperandDisasm) -> bool {
+    matches!(op, JenisOperandDisasm::Register(r) if r.to_lowercase().starts_with("xmm"))
+}
+
+pub fn proses_instruksi_sse_skalar(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) -> bool {
+    let Some((aksi, presisi)) = ambil_aksi(instr) else {
+        return false;
+    };
+    let jumlah = instr.operands_detail.len();
+    if jumlah < 2 || !instr.operands_detail.iter().any(is_register_xmm) {
+        return false;
+    }
+    let addr = instr.address;
+    let dest = lifter.ambil_operand(instr, 0);
+    let sumber = lifter.ambil_operand(instr, jumlah - 1);
+    match aksi {
+        AksiSse::Pindah => {
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, dest, sumber).with_type(presisi.tipe()));
+        },
+        AksiSse::Biner(jenis) => {
+            let op = match jenis {
+                JenisBiner::Add => OperasiIr::FAdd,
+                JenisBiner::Sub => OperasiIr::FSub,
+                JenisBiner::Mul => OperasiIr::FMul,
+                JenisBiner::Div => OperasiIr::FDiv,
+                JenisBiner::Min => OperasiIr::FMin,
+                JenisBiner::Max => OperasiIr::FMax,
+            };
+            let kiri = lifter.ambil_operand(instr, jumlah - 2);
+            let nilai = buat_expr(op.clone(), kiri, sumber);
+            ops.push(StatementIr::new(addr, op, dest, nilai).with_type(presisi.tipe()));
+        },
+        AksiSse::Akar => {
+            ops.push(StatementIr::new(addr, OperasiIr::FSqrt, dest, sumber).with_type(presisi.tipe()));
+        },
+        AksiSse::DariInteger | AksiSse::UbahPresisi => {
+            ops.push(StatementIr::new(addr, OperasiIr::FConv, dest, sumber).with_type(presisi.tipe()));
+        },
+        AksiSse::KeInteger { potong } => {
+            let tipe = instr.ambil_ukuran_operand(0).and_then(IrLifter::tipe_dari_ukuran).unwrap_or(TipeDataIr::I32);
+            let op = match (potong, presisi) {
+                (true, _) => OperasiIr::FConv,
+                (false, Presisi::Tunggal) => OperasiIr::Intrinsic("lrintf".to_string()),
+                (false, Presisi::Ganda) => OperasiIr::Intrinsic("lrint".to_string()),
+            };
+            ops.push(StatementIr::new(addr, op, dest, sumber).with_type(tipe));
+        },
+        AksiSse::Banding => {
+            x87::proses_banding(addr, dest, sumber, true, presisi.tipe(), ops);
+        },
+    }
+    true
+}
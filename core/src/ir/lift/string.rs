@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use capstone::arch::x86::X86Insn;
+use crate::arch::x86::{FLAG_CF, FLAG_DF, FLAG_ZF};
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
+use crate::ir::types::{StatementIr, OperasiIr, TipeDataIr, TipeOperand};
+use super::{arithmetic, IrLifter};
+use super::dispatch::{self, ArsitekturDispatch};
+use super::eflags::{buat_expr, flag_undefined};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AksiString {
+    Salin,
+    Isi,
+    Muat,
+    Pindai,
+    Banding,
+    AturArah(bool),
+}
+
+const TABEL_AKSI: &[(X86Insn, AksiString)] = &[
+    (X86Insn::X86_INS_MOVSB, AksiString::Salin),
+    (X86Insn::X86_INS_MOVSW, AksiString::Salin),
+    (X86Insn::X86_INS_MOVSD, AksiString::Salin),
+    (X86Insn::X86_INS_MOVSQ, AksiString::Salin),
+    (X86Insn::X86_INS_STOSB, AksiString::Isi),
+    (X86Insn::X86_INS_STOSW, AksiString::Isi),
+    (X86Insn::X86_INS_STOSD, AksiString::Isi),
+    (X86Insn::X86_INS_STOSQ, AksiString::Isi),
+    (X86Insn::X86_INS_LODSB, AksiString::Muat),
+    (X86Insn::X86_INS_LODSW, AksiString::Muat),
+    (X86Insn::X86_INS_LODSD, AksiString::Muat),
+    (X86Insn::X86_INS_LODSQ, AksiString::Muat),
+    (X86Insn::X86_INS_SCASB, AksiString::Pindai),
+    (X86Insn::X86_INS_SCASW, AksiString::Pindai),
+    (X86Insn::X86_INS_SCASD, AksiString::Pindai),
+    (X86Insn::X86_INS_SCASQ, AksiString::Pindai),
+    (X86Insn::X86_INS_CMPSB, AksiString::Banding),
+    (X86Insn::X86_INS_CMPSW, AksiString::Banding),
+    (X86Insn::X86_INS_CMPSD, AksiString::Banding),
+    (X86Insn::X86_INS_CMPSQ, AksiString::Banding),
+    (X86Insn::X86_INS_CLD, AksiString::AturArah(false)),
+    (X86Insn::X86_INS_STD, AksiString::AturArah(true)),
+];
+
+static AKSI_STRING: LazyLock<HashMap<u32, AksiString>> = LazyLock::new(|| {
+    TABEL_AKSI.iter().map(|(id, aksi)| (*id as u32, *aksi)).collect()
+});
+
+fn ambil_aksi(instr: &InstructionNormalized) -> Option<AksiString> {
+    dispatch::ambil_id_instruksi(ArsitekturDispatch::X86, instr).and_then(|id| AKSI_STRING.get(&id).copied())
+}
+
+pub fn lacak_flag_arah(instr: &mut InstructionNormalized, peta_register: &mut HashMap<String, u64>) {
+    instr.arah_mundur = peta_register.contains_key(FLAG_DF);
+    match ambil_aksi(instr) {
+        Some(AksiString::AturArah(true)) => {
+            peta_register.insert(FLAG_DF.to_string(), 1);
+        },
+        Some(AksiString::AturArah(false)) => {
+            peta_register.remove(FLAG_DF);
+        },
+        _ => {},
+    }
+}
+
+struct KonteksString {
+    addr: u64,
+    lebar: i64,
+    mundur: bool,
+    penghitung: TipeOperand,
+}
+
+impl KonteksString {
+    fn kali_lebar(&self, jumlah: TipeOperand) -> TipeOperand {
+        match jumlah {
+            _ if self.lebar == 1 => jumlah,
+            TipeOperand::Immediate(n) => TipeOperand::Immediate(n * self.lebar),
+            lain => buat_expr(OperasiIr::Imul, lain, TipeOperand::Immediate(self.lebar)),
+        }
+    }
+    fn awal_region(&self, pointer: &TipeOperand) -> TipeOperand {
+        if !self.mundur {
+            return pointer.clone();
+        }
+        let sisa = buat_expr(OperasiIr::Sub, self.penghitung.clone(), TipeOperand::Immediate(1));
+        buat_expr(OperasiIr::Sub, pointer.clone(), self.kali_lebar(sisa))
+    }
+    fn geser_pointer(&self, pointer: &TipeOperand, jumlah: TipeOperand, ops: &mut Vec<StatementIr>) {
+        let op = if self.mundur { OperasiIr::Sub } else { OperasiIr::Add };
+        let nilai = buat_expr(op, pointer.clone(), self.kali_lebar(jumlah));
+        ops.push(StatementIr::new(self.addr, OperasiIr::Mov, pointer.clone(), nilai));
+    }
+    fn kosongkan_penghitung(&self, ops: &mut Vec<StatementIr>) {
+        ops.push(StatementIr::new(self.addr, OperasiIr::Mov, self.penghitung.clone(), TipeOperand::Immediate(0)));
+    }
+    fn akhiran(&self) -> char {
+        match self.lebar {
+            2 => 'w',
+            4 => 'd',
+            8 => 'q',
+            _ => 'b',
+        }
+    }
+}
+
+fn panggil_intrinsic(addr: u64, nama: &str, dest: TipeOperand, args: Vec<TipeOperand>) -> StatementIr {
+    let mut stmt = StatementIr::new(addr, OperasiIr::Intrinsic(nama.to_string()), dest, TipeOperand::None);
+    stmt.operand_tambahan = args;
+    stmt
+}
+
+fn basis_memori(instr: &InstructionNormalized, index: usize) -> Option<String> {
+    match instr.operands_detail.get(index) {
+        Some(JenisOperandDisasm::Memory { base: Some(b), .. }) => Some(b.to_lowercase()),
+        _ => None,
+    }
+}
+
+fn is_register_xmm(op: &JenisOperandDisasm) -> bool {
+    matches!(op, JenisOperandDisasm::Register(r) if r.to_lowercase().starts_with("xmm"))
+}
+
+pub fn proses_instruksi_string(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) -> bool {
+    let Some(aksi) = ambil_aksi(instr) else {
+        return false;
+    };
+    let addr = instr.address;
+    if let AksiString::AturArah(mundur) = aksi {
+        ops.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register(FLAG_DF.to_string()), TipeOperand::Immediate(mundur as i64)).with_type(TipeDataIr::I8));
+        return true;
+    }
+    if instr.operands_detail.iter().any(is_register_xmm) {
+        return false;
+    }
+    let (idx_tujuan, idx_sumber) = match aksi {
+        AksiString::Salin => (Some(0), Some(1)),
+        AksiString::Isi => (Some(0), None),
+        AksiString::Muat => (None, Some(1)),
+        AksiString::Pindai => (Some(1), None),
+        AksiString::Banding => (Some(1), Some(0)),
+        AksiString::AturArah(_) => return false,
+    };
+    let tujuan = idx_tujuan.map(|i| basis_memori(instr, i));
+    let sumber = idx_sumber.map(|i| basis_memori(instr, i));
+    if matches!(tujuan, Some(None)) || matches!(sumber, Some(None)) {
+        return false;
+    }
+    let tujuan = tujuan.flatten().map(TipeOperand::Register);
+    let sumber = sumber.flatten().map(TipeOperand::Register);
+    let lebar_pointer_32 = match tujuan.as_ref().or(sumber.as_ref()) {
+        Some(TipeOperand::Register(r)) => r.starts_with('e'),
+        _ => lifter.mode_x86_32,
+    };
+    let ukuran = instr.ambil_ukuran_utama().unwrap_or(1);
+    let tipe = IrLifter::tipe_dari_ukuran(ukuran).unwrap_or(TipeDataIr::I8);
+    let ctx = KonteksString {
+        addr,
+        lebar: ukuran as i64,
+        mundur: instr.arah_mundur,
+        penghitung: TipeOperand::Register(if lebar_pointer_32 { "ecx" } else { "rcx" }.to_string()),
+    };
+    let repne = instr.punya_prefix("repne");
+    let diulang = repne || instr.punya_prefix("rep");
+    let satu = TipeOperand::Immediate(1);
+    let n = ctx.penghitung.clone();
+    match aksi {
+        AksiString::Salin => {
+            let (Some(dst), Some(src)) = (tujuan, sumber) else { return false };
+            if diulang {
+                let nama = if ctx.mundur { "memmove" } else { "memcpy" };
+                ops.push(panggil_intrinsic(addr, nama, TipeOperand::None, vec![ctx.awal_region(&dst), ctx.awal_region(&src), ctx.kali_lebar(n.clone())]));
+                ctx.geser_pointer(&dst, n.clone(), ops);
+                ctx.geser_pointer(&src, n, ops);
+                ctx.kosongkan_penghitung(ops);
+            } else {
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 0), lifter.ambil_operand(instr, 1)).with_type(tipe));
+                ctx.geser_pointer(&dst, satu.clone(), ops);
+                ctx.geser_pointer(&src, satu, ops);
+            }
+        },
+        AksiString::Isi => {
+            let Some(dst) = tujuan else { return false };
+            let nilai = lifter.ambil_operand(instr, 1);
+            if diulang {
+                let nama = if ctx.lebar == 1 { "memset".to_string() } else { format!("__stos{}", ctx.akhiran()) };
+                ops.push(panggil_intrinsic(addr, &nama, TipeOperand::None, vec![ctx.awal_region(&dst), nilai, n.clone()]));
+                ctx.geser_pointer(&dst, n, ops);
+                ctx.kosongkan_penghitung(ops);
+            } else {
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 0), nilai).with_type(tipe));
+                ctx.geser_pointer(&dst, satu, ops);
+            }
+        },
+        AksiString::Muat => {
+            let Some(src) = sumber else { return false };
+            let akumulator = lifter.ambil_operand(instr, 0);
+            let elemen = lifter.ambil_operand(instr, 1);
+            if diulang {
+                let TipeOperand::Register(basis) = &src else { return false };
+                let terakhir = TipeOperand::MemoryComplex {
+                    base: Some(basis.clone()),
+                    index: Some(match &n { TipeOperand::Register(r) => r.clone(), _ => return false }),
+                    scale: if ctx.mundur { -(ctx.lebar as i32) } else { ctx.lebar as i32 },
+                    disp: if ctx.mundur { ctx.lebar } else { -ctx.lebar },
+                    segment: None,
+                };
+                let nilai = TipeOperand::Conditional {
+                    condition: Box::new(buat_expr(OperasiIr::Jne, n.clone(), TipeOperand::Immediate(0))),
+                    true_val: Box::new(terakhir),
+                    false_val: Box::new(akumulator.clone()),
+                };
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, akumulator, nilai).with_type(tipe));
+                ctx.geser_pointer(&src, n, ops);
+                ctx.kosongkan_penghitung(ops);
+            } else {
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, akumulator, elemen).with_type(tipe));
+                ctx.geser_pointer(&src, satu, ops);
+            }
+        },
+        AksiString::Pindai => {
+            let Some(dst) = tujuan else { return false };
+            if diulang {
+                let nama = match (repne, ctx.lebar, ctx.mundur) {
+                    (true, 1, false) => "memchr".to_string(),
+                    (true, 1, true) => "memrchr".to_string(),
+                    (true, _, _) => format!("__repne_scas{}", ctx.akhiran()),
+                    (false, _, _) => format!("__repe_scas{}", ctx.akhiran()),
+                };
+                let hasil = TipeOperand::Register("temp_string".to_string());
+                let akumulator = lifter.ambil_operand(instr, 0);
+                ops.push(panggil_intrinsic(addr, &nama, hasil.clone(), vec![ctx.awal_region(&dst), akumulator, n.clone()]));
+                proses_sisa_pindai(&ctx, &dst, hasil, repne, ops);
+            } else {
+                arithmetic::proses_comparison_explicit(lifter, instr, OperasiIr::Sub, ops);
+                ctx.geser_pointer(&dst, satu, ops);
+            }
+        },
+        AksiString::Banding => {
+            let (Some(dst), Some(src)) = (tujuan, sumber) else { return false };
+            if diulang {
+                let nama = if !repne && ctx.lebar == 1 { "memcmp".to_string() } else { format!("__rep{}_cmps{}", if repne { "ne" } else { "e" }, ctx.akhiran()) };
+                let hasil = TipeOperand::Register("temp_string".to_string());
+                let jumlah = if nama == "memcmp" { ctx.kali_lebar(n.clone()) } else { n.clone() };
+                ops.push(panggil_intrinsic(addr, &nama, hasil.clone(), vec![ctx.awal_region(&src), ctx.awal_region(&dst), jumlah]));
+                let sama = buat_expr(OperasiIr::Je, hasil.clone(), TipeOperand::Immediate(0));
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register(FLAG_ZF.to_string()), sama.clone()).with_type(TipeDataIr::I8));
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register(FLAG_CF.to_string()), buat_expr(OperasiIr::Jl, hasil, TipeOperand::Immediate(0))).with_type(TipeDataIr::I8));
+                let dikonsumsi = TipeOperand::Conditional {
+                    condition: Box::new(sama),
+                    true_val: Box::new(n.clone()),
+                    false_val: Box::new(flag_undefined()),
+                };
+                let temp_jumlah = TipeOperand::Register("temp_string_jumlah".to_string());
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, temp_jumlah.clone(), dikonsumsi));
+                ctx.geser_pointer(&src, temp_jumlah.clone(), ops);
+                ctx.geser_pointer(&dst, temp_jumlah.clone(), ops);
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, n.clone(), buat_expr(OperasiIr::Sub, n, temp_jumlah)));
+            } else {
+                arithmetic::proses_comparison_explicit(lifter, instr, OperasiIr::Sub, ops);
+                ctx.geser_pointer(&src, satu.clone(), ops);
+                ctx.geser_pointer(&dst, satu, ops);
+            }
+        },
+        AksiString::AturArah(_) => return false,
+    }
+    true
+}
+
+fn proses_sisa_pindai(ctx: &KonteksString, pointer: &TipeOperand, hasil: TipeOperand, repne: bool, ops: &mut Vec<StatementIr>) {
+    let ketemu = buat_expr(OperasiIr::Jne, hasil.clone(), TipeOperand::Immediate(0));
+    let zf = TipeOperand::Conditional {
+        condition: Box::new(ketemu.clone()),
+        true_val: Box::new(TipeOperand::Immediate(repne as i64)),
+        false_val: Box::new(TipeOperand::Immediate(!repne as i64)),
+    };
+    ops.push(StatementIr::new(ctx.addr, OperasiIr::Mov, TipeOperand::Register(FLAG_ZF.to_string()), zf).with_type(TipeDataIr::I8));
+    let selisih = if ctx.mundur {
+        buat_expr(OperasiIr::Sub, pointer.clone(), hasil)
+    } else {
+        buat_expr(OperasiIr::Sub, hasil, pointer.clone())
+    };
+    let indeks = if ctx.lebar == 1 { selisih } else { buat_expr(OperasiIr::Div, selisih, TipeOperand::Immediate(ctx.lebar)) };
+    let dikonsumsi = TipeOperand::Conditional {
+        condition: Box::new(ketemu),
+        true_val: Box::new(buat_expr(OperasiIr::Add, indeks, TipeOperand::Immediate(1))),
+        false_val: Box::new(ctx.penghitung.clone()),
+    };
+    let temp_jumlah = TipeOperand::Register("temp_string_jumlah".to_string());
+    ops.push(StatementIr::new(ctx.addr, OperasiIr::Mov, temp_jumlah.clone(), dikonsumsi));
+    ctx.geser_pointer(pointer, temp_jumlah.clone(), ops);
+    let n = ctx.penghitung.clone();
+    ops.push(StatementIr::new(ctx.addr, OperasiIr::Mov, n.clone(), buat_expr(OperasiIr::Sub, n, temp_jumlah)));
+}
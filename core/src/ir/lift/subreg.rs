@@ -0,0 +1,127 @@
+use crate::arch::KelasRegister;
+use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, mask_lebar};
+use super::IrLifter;
+use super::eflags::buat_expr;
+
+const TEMP_SUB_REGISTER: &str = "temp_sub_register";
+
+struct LetakSubRegister {
+    akar: String,
+    offset: u16,
+    lebar: u16,
+    lebar_akar: u16,
+}
+
+fn cari_letak(lifter: &IrLifter, nama: &str) -> Option<LetakSubRegister> {
+    let file = &lifter.register_file;
+    let info = file.cari_register(nama)?;
+    if info.kelas != KelasRegister::Umum || info.induk.is_none() {
+        return None;
+    }
+    let akar = file.dapatkan_register_akar(nama);
+    let lebar_akar = file.cari_register(&akar)?.lebar_bit;
+    if info.lebar_bit >= lebar_akar {
+        return None;
+    }
+    Some(LetakSubRegister {
+        offset: file.hitung_offset_absolut(nama)?,
+        lebar: info.lebar_bit,
+        lebar_akar,
+        akar,
+    })
+}
+
+pub fn presisikan_sub_register(lifter: &IrLifter, ops: &mut Vec<StatementIr>) {
+    if lifter.mode_arm32 || lifter.mode_riscv || lifter.mode_mips {
+        return;
+    }
+    let mut hasil = Vec::with_capacity(ops.len());
+    for mut stmt in ops.drain(..) {
+        let menulis = !IrLifter::is_tanpa_nilai(&stmt.operation_code);
+        let letak_tulis = match &stmt.operand_satu {
+            TipeOperand::Register(nama) if menulis => cari_letak(lifter, nama),
+            _ => None,
+        };
+        baca_presisi(lifter, &mut stmt.operand_dua);
+        for op in stmt.operand_tambahan.iter_mut() {
+            baca_presisi(lifter, op);
+        }
+        let Some(letak) = letak_tulis else {
+            if !menulis || !matches!(stmt.operand_satu, TipeOperand::Register(_)) {
+                baca_presisi(lifter, &mut stmt.operand_satu);
+            }
+            hasil.push(stmt);
+            continue;
+        };
+        let akar = TipeOperand::Register(letak.akar.clone());
+        match stmt.operation_code {
+            OperasiIr::Unknown => {
+                stmt.operand_satu = akar;
+                hasil.push(stmt);
+            },
+            _ if is_nilai_penuh(&stmt.operation_code) => {
+                let nilai = std::mem::replace(&mut stmt.operand_dua, TipeOperand::None);
+                stmt.operand_dua = tulis_presisi(&letak, nilai);
+                stmt.operand_satu = akar;
+                hasil.push(stmt);
+            },
+            _ => {
+                let temp = TipeOperand::Register(TEMP_SUB_REGISTER.to_string());
+                let salin = StatementIr::new(stmt.address_asal, OperasiIr::Mov, akar, tulis_presisi(&letak, temp.clone()))
+                    .with_type(stmt.tipe_hasil.clone());
+                stmt.operand_satu = temp;
+                hasil.push(stmt);
+                hasil.push(salin);
+            },
+        }
+    }
+    *ops = hasil;
+}
+
+fn is_nilai_penuh(op: &OperasiIr) -> bool {
+    matches!(op,
+        OperasiIr::Mov | OperasiIr::Cmov | OperasiIr::Neg | OperasiIr::Not |
+        OperasiIr::Add | OperasiIr::Sub | OperasiIr::Imul | OperasiIr::Mul |
+        OperasiIr::Div | OperasiIr::Idiv | OperasiIr::Adc | OperasiIr::Sbb |
+        OperasiIr::Inc | OperasiIr::Dec | OperasiIr::And | OperasiIr::Or | OperasiIr::Xor |
+        OperasiIr::Shl | OperasiIr::Shr | OperasiIr::Sar | OperasiIr::Rol | OperasiIr::Ror
+    ) || op.is_operasi_lebar()
+}
+
+fn tulis_presisi(letak: &LetakSubRegister, nilai: TipeOperand) -> TipeOperand {
+    if letak.offset == 0 && letak.lebar == 32 && letak.lebar_akar == 64 {
+        if let TipeOperand::Immediate(v) = nilai {
+            return TipeOperand::Immediate(v & mask_lebar(32));
+        }
+        return buat_expr(OperasiIr::ZeroExtend, nilai, TipeOperand::Immediate(32));
+    }
+    let sisip = OperasiIr::BitInsert { offset: letak.offset as u8, lebar: letak.lebar as u8 };
+    buat_expr(sisip, TipeOperand::Register(letak.akar.clone()), nilai)
+}
+
+fn baca_presisi(lifter: &IrLifter, op: &mut TipeOperand) {
+    match op {
+        TipeOperand::Register(nama) => {
+            if let Some(letak) = cari_letak(lifter, nama) {
+                let mut akar = TipeOperand::Register(letak.akar);
+                if letak.offset > 0 {
+                    akar = buat_expr(OperasiIr::Shr, akar, TipeOperand::Immediate(letak.offset as i64));
+                }
+                *op = buat_expr(OperasiIr::Truncate, akar, TipeOperand::Immediate(letak.lebar as i64));
+            }
+        },
+        TipeOperand::Expression { operasi, operand_kiri, operand_kanan } => {
+            baca_presisi(lifter, operand_kiri);
+            if !operasi.is_operasi_lebar() || matches!(operasi, OperasiIr::BitInsert { .. }) {
+                baca_presisi(lifter, operand_kanan);
+            }
+        },
+        TipeOperand::Conditional { condition, true_val, false_val } => {
+            baca_presisi(lifter, condition);
+            baca_presisi(lifter, true_val);
+            baca_presisi(lifter, false_val);
+        },
+        TipeOperand::VectorLane { operand, .. } => baca_presisi(lifter, operand),
+        _ => {},
+    }
+}
@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use capstone::arch::x86::X86Insn;
+use crate::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
+use crate::ir::types::{StatementIr, TipeOperand, OperasiIr, TipeDataIr, OrderedFloat};
+use crate::arch::x86::{FLAG_AF, FLAG_CF, FLAG_OF, FLAG_PF, FLAG_SF, FLAG_ZF, FPU_C0, FPU_C2, FPU_C3};
+use super::{flow, IrLifter};
+use super::dispatch::{self, ArsitekturDispatch};
+use super::eflags::buat_expr;
+
+pub const KUNCI_TOP_FPU: &str = "fpu_top";
+const TEMP_FPU: &str = "temp_fpu";
+const TEMP_FPU_FLAGS: &str = "temp_fpu_flags";
+const INTRINSIK_UNORDERED: &str = "__builtin_isunordered";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JenisBiner {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JenisUnary {
+    Negasi,
+    Absolut,
+    Akar,
+    Bulatkan,
+    Sinus,
+    Cosinus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AksiX87 {
+    Muat,
+    MuatKonstanta,
+    Simpan,
+    Biner { jenis: JenisBiner, terbalik: bool },
+    Unary(JenisUnary),
+    Banding { ke_eflags: bool },
+    Tukar,
+    PindahKondisi,
+    SimpanStatus,
+    MuatFlagStatus,
+    KontrolWord { simpan: bool },
+    GeserTop(i64),
+    Inisialisasi,
+    Bebaskan,
+    Nop,
+}
+
+const TABEL_AKSI: &[(X86Insn, AksiX87)] = &[
+    (X86Insn::X86_INS_FLD, AksiX87::Muat),
+    (X86Insn::X86_INS_FILD, AksiX87::Muat),
+    (X86Insn::X86_INS_FLDZ, AksiX87::MuatKonstanta),
+    (X86Insn::X86_INS_FLD1, AksiX87::MuatKonstanta),
+    (X86Insn::X86_INS_FLDPI, AksiX87::MuatKonstanta),
+    (X86Insn::X86_INS_FLDL2E, AksiX87::MuatKonstanta),
+    (X86Insn::X86_INS_FLDL2T, AksiX87::MuatKonstanta),
+    (X86Insn::X86_INS_FLDLG2, AksiX87::MuatKonstanta),
+    (X86Insn::X86_INS_FLDLN2, AksiX87::MuatKonstanta),
+    (X86Insn::X86_INS_FST, AksiX87::Simpan),
+    (X86Insn::X86_INS_FSTP, AksiX87::Simpan),
+    (X86Insn::X86_INS_FIST, AksiX87::Simpan),
+    (X86Insn::X86_INS_FISTP, AksiX87::Simpan),
+    (X86Insn::X86_INS_FISTTP, AksiX87::Simpan),
+    (X86Insn::X86_INS_FADD, AksiX87::Biner { jenis: JenisBiner::Add, terbalik: false }),
+    (X86Insn::X86_INS_FIADD, AksiX87::Biner { jenis: JenisBiner::Add, terbalik: false }),
+    (X86Insn::X86_INS_FSUB, AksiX87::Biner { jenis: JenisBiner::Sub, terbalik: false }),
+    (X86Insn::X86_INS_FSUBP, AksiX87::Biner { jenis: JenisBiner::Sub, terbalik: false }),
+    (X86Insn::X86_INS_FISUB, AksiX87::Biner { jenis: JenisBiner::Sub, terbalik: false }),
+    (X86Insn::X86_INS_FSUBR, AksiX87::Biner { jenis: JenisBiner::Sub, terbalik: true }),
+    (X86Insn::X86_INS_FSUBRP, AksiX87::Biner { jenis: JenisBiner::Sub, terbalik: true }),
+    (X86Insn::X86_INS_FISUBR, AksiX87::Biner { jenis: JenisBiner::Sub, terbalik: true }),
+    (X86Insn::X86_INS_FMUL, AksiX87::Biner { jenis: JenisBiner::Mul, terbalik: false }),
+    (X86Insn::X86_INS_FMULP, AksiX87::Biner { jenis: JenisBiner::Mul, terbalik: false }),
+    (X86Insn::X86_INS_FIMUL, AksiX87::Biner { jenis: JenisBiner::Mul, terbalik: false }),
+    (X86Insn::X86_INS_FDIV, AksiX87::Biner { jenis: JenisBiner::Div, terbalik: false }),
+    (X86Insn::X86_INS_FDIVP, AksiX87::Biner { jenis: JenisBiner::Div, terbalik: false }),
+    (X86Insn::X86_INS_FIDIV, AksiX87::Biner { jenis: JenisBiner::Div, terbalik: false }),
+    (X86Insn::X86_INS_FDIVR, AksiX87::Biner { jenis: JenisBiner::Div, terbalik: true }),
+    (X86Insn::X86_INS_FDIVRP, AksiX87::Biner { jenis: JenisBiner::Div, terbalik: true }),
+    (X86Insn::X86_INS_FIDIVR, AksiX87::Biner { jenis: JenisBiner::Div, terbalik: true }),
+    (X86Insn::X86_INS_FCHS, AksiX87::Unary(JenisUnary::Negasi)),
+    (X86Insn::X86_INS_FABS, AksiX87::Unary(JenisUnary::Absolut)),
+    (X86Insn::X86_INS_FSQRT, AksiX87::Unary(JenisUnary::Akar)),
+    (X86Insn::X86_INS_FRNDINT, AksiX87::Unary(JenisUnary::Bulatkan)),
+    (X86Insn::X86_INS_FSIN, AksiX87::Unary(JenisUnary::Sinus)),
+    (X86Insn::X86_INS_FCOS, AksiX87::Unary(JenisUnary::Cosinus)),
+    (X86Insn::X86_INS_FCOM, AksiX87::Banding { ke_eflags: false }),
+    (X86Insn::X86_INS_FCOMP, AksiX87::Banding { ke_eflags: false }),
+    (X86Insn::X86_INS_FCOMPP, AksiX87::Banding { ke_eflags: false }),
+    (X86Insn::X86_INS_FUCOM, AksiX87::Banding { ke_eflags: false }),
+    (X86Insn::X86_INS_FUCOMP, AksiX87::Banding { ke_eflags: false }),
+    (X86Insn::X86_INS_FUCOMPP, AksiX87::Banding { ke_eflags: false }),
+    (X86Insn::X86_INS_FICOM, AksiX87::Banding { ke_eflags: false }),
+    (X86Insn::X86_INS_FICOMP, AksiX87::Banding { ke_eflags: false }),
+    (X86Insn::X86_INS_FTST, AksiX87::Banding { ke_eflags: false }),
+    (X86Insn::X86_INS_FCOMI, AksiX87::Banding { ke_eflags: true }),
+    (X86Insn::X86_INS_FCOMPI, AksiX87::Banding { ke_eflags: true }),
+    (X86Insn::X86_INS_FUCOMI, AksiX87::Banding { ke_eflags: true }),
+    (X86Insn::X86_INS_FUCOMPI, AksiX87::Banding { ke_eflags: true }),
+    (X86Insn::X86_INS_FXCH, AksiX87::Tukar),
+    (X86Insn::X86_INS_FCMOVB, AksiX87::PindahKondisi),
+    (X86Insn::X86_INS_FCMOVBE, AksiX87::PindahKondisi),
+    (X86Insn::X86_INS_FCMOVE, AksiX87::PindahKondisi),
+    (X86Insn::X86_INS_FCMOVU, AksiX87::PindahKondisi),
+    (X86Insn::X86_INS_FCMOVNB, AksiX87::PindahKondisi),
+    (X86Insn::X86_INS_FCMOVNBE, AksiX87::PindahKondisi),
+    (X86Insn::X86_INS_FCMOVNE, AksiX87::PindahKondisi),
+    (X86Insn::X86_INS_FCMOVNU, AksiX87::PindahKondisi),
+    (X86Insn::X86_INS_FNSTSW, AksiX87::SimpanStatus),
+    (X86Insn::X86_INS_SAHF, AksiX87::MuatFlagStatus),
+    (X86Insn::X86_INS_FNSTCW, AksiX87::KontrolWord { simpan: true }),
+    (X86Insn::X86_INS_FLDCW, AksiX87::KontrolWord { simpan: false }),
+    (X86Insn::X86_INS_FINCSTP, AksiX87::GeserTop(-1)),
+    (X86Insn::X86_INS_FDECSTP, AksiX87::GeserTop(1)),
+    (X86Insn::X86_INS_FNINIT, AksiX87::Inisialisasi),
+    (X86Insn::X86_INS_FFREE, AksiX87::Bebaskan),
+    (X86Insn::X86_INS_FFREEP, AksiX87::Bebaskan),
+    (X86Insn::X86_INS_FNOP, AksiX87::Nop),
+    (X86Insn::X86_INS_FNCLEX, AksiX87::Nop),
+];
+
+static AKSI_X87: LazyLock<HashMap<u32, AksiX87>> = LazyLock::new(|| {
+    TABEL_AKSI.iter().map(|(id, aksi)| (*id as u32, *aksi)).collect()
+});
+
+fn ambil_aksi(instr: &InstructionNormalized) -> Option<AksiX87> {
+    dispatch::ambil_id_instruksi(ArsitekturDispatch::X86, instr).and_then(|id| AKSI_X87.get(&id).copied())
+}
+
+pub fn lacak_top_fpu(instr: &mut InstructionNormalized, peta_register: &mut HashMap<String, u64>) {
+    let top = peta_register.get(KUNCI_TOP_FPU).copied().unwrap_or(0);
+    instr.top_fpu = top as u8;
+    let Some(aksi) = ambil_aksi(instr) else {
+        return;
+    };
+    let baru = match aksi {
+        AksiX87::Inisialisasi => 0,
+        _ => top.wrapping_sub(hitung_jumlah_push(aksi, &instr.mnemonic.to_lowercase()) as u64) & 7,
+    };
+    if baru == 0 {
+        peta_register.remove(KUNCI_TOP_FPU);
+    } else {
+        peta_register.insert(KUNCI_TOP_FPU.to_string(), baru);
+    }
+}
+
+fn hitung_jumlah_push(aksi: AksiX87, mnem: &str) -> i64 {
+    match aksi {
+        AksiX87::Muat | AksiX87::MuatKonstanta => 1,
+        AksiX87::GeserTop(delta) => delta,
+        AksiX87::Simpan | AksiX87::Biner { .. } | AksiX87::Banding { .. } | AksiX87::Bebaskan => -jumlah_pop(mnem),
+        _ => 0,
+    }
+}
+
+fn jumlah_pop(mnem: &str) -> i64 {
+    if mnem.ends_with("pp") {
+        2
+    } else if mnem.ends_with('p') || mnem.ends_with("pi") {
+        1
+    } else {
+        0
+    }
+}
+
+pub fn proses_instruksi_x87(lifter: &IrLifter, instr: &InstructionNormalized, ops: &mut Vec<StatementIr>) -> bool {
+    let Some(aksi) = ambil_aksi(instr) else {
+        return false;
+    };
+    let mnemonic = instr.mnemonic.to_lowercase();
+    let mnem = mnemonic.as_str();
+    let addr = instr.address;
+    let st0 = slot_stack(instr, 0);
+    match aksi {
+        AksiX87::Muat => {
+            let dest = slot_stack(instr, -1);
+            match instr.operands_detail.first() {
+                Some(JenisOperandDisasm::Register(_)) => {
+                    ops.push(StatementIr::new(addr, OperasiIr::Mov, dest, operand_fpu(lifter, instr, 0)).with_type(TipeDataIr::F80));
+                },
+                _ if instr.ambil_ukuran_operand(0) == Some(10) => {
+                    ops.push(StatementIr::new(addr, OperasiIr::Mov, dest, lifter.ambil_operand(instr, 0)).with_type(TipeDataIr::F80));
+                },
+                _ => ops.push(StatementIr::new(addr, OperasiIr::FConv, dest, lifter.ambil_operand(instr, 0)).with_type(TipeDataIr::F80)),
+            }
+        },
+        AksiX87::MuatKonstanta => {
+            let nilai = match mnem {
+                "fld1" => 1.0,
+                "fldpi" => std::f64::consts::PI,
+                "fldl2e" => std::f64::consts::LOG2_E,
+                "fldl2t" => std::f64::consts::LOG2_10,
+                "fldlg2" => std::f64::consts::LOG10_2,
+                "fldln2" => std::f64::consts::LN_2,
+                _ => 0.0,
+            };
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, slot_stack(instr, -1), TipeOperand::FloatImmediate(OrderedFloat(nilai))).with_type(TipeDataIr::F80));
+        },
+        AksiX87::Simpan => match instr.operands_detail.first() {
+            Some(JenisOperandDisasm::Register(_)) => {
+                let dest = operand_fpu(lifter, instr, 0);
+                if dest != st0 {
+                    ops.push(StatementIr::new(addr, OperasiIr::Mov, dest, st0).with_type(TipeDataIr::F80));
+                }
+            },
+            _ => {
+                let ukuran = instr.ambil_ukuran_operand(0).unwrap_or(8);
+                let dest = lifter.ambil_operand(instr, 0);
+                let tipe = if mnem.starts_with("fi") {
+                    IrLifter::tipe_dari_ukuran(ukuran).unwrap_or(TipeDataIr::I32)
+                } else {
+                    tipe_float(ukuran)
+                };
+                let op = if tipe == TipeDataIr::F80 { OperasiIr::Mov } else { OperasiIr::FConv };
+                ops.push(StatementIr::new(addr, op, dest, st0).with_type(tipe));
+            },
+        },
+        AksiX87::Biner { jenis, terbalik } => {
+            let (dest, src) = ambil_operand_biner(lifter, instr, jumlah_pop(mnem) > 0);
+            let op = match jenis {
+                JenisBiner::Add => OperasiIr::FAdd,
+                JenisBiner::Sub => OperasiIr::FSub,
+                JenisBiner::Mul => OperasiIr::FMul,
+                JenisBiner::Div => OperasiIr::FDiv,
+            };
+            let nilai = if terbalik { buat_expr(op.clone(), src, dest.clone()) } else { buat_expr(op.clone(), dest.clone(), src) };
+            ops.push(StatementIr::new(addr, op, dest, nilai).with_type(TipeDataIr::F80));
+        },
+        AksiX87::Unary(jenis) => {
+            let (op, nilai) = match jenis {
+                JenisUnary::Negasi => (OperasiIr::FSub, buat_expr(OperasiIr::FSub, TipeOperand::FloatImmediate(OrderedFloat(0.0)), st0.clone())),
+                JenisUnary::Akar => (OperasiIr::FSqrt, st0.clone()),
+                JenisUnary::Absolut => (OperasiIr::Intrinsic("fabsl".to_string()), st0.clone()),
+                JenisUnary::Bulatkan => (OperasiIr::Intrinsic("rintl".to_string()), st0.clone()),
+                JenisUnary::Sinus => (OperasiIr::Intrinsic("sinl".to_string()), st0.clone()),
+                JenisUnary::Cosinus => (OperasiIr::Intrinsic("cosl".to_string()), st0.clone()),
+            };
+            ops.push(StatementIr::new(addr, op, st0, nilai).with_type(TipeDataIr::F80));
+        },
+        AksiX87::Banding { ke_eflags } => {
+            let src = match instr.operands_detail.last() {
+                _ if mnem == "ftst" => TipeOperand::FloatImmediate(OrderedFloat(0.0)),
+                Some(_) => operand_fpu(lifter, instr, instr.operands_detail.len() - 1),
+                None => slot_stack(instr, 1),
+            };
+            proses_banding(addr, st0, src, ke_eflags, TipeDataIr::F80, ops);
+        },
+        AksiX87::Tukar => {
+            let lain = match instr.operands_detail.len() {
+                0 => slot_stack(instr, 1),
+                n => operand_fpu(lifter, instr, n - 1),
+            };
+            let temp = TipeOperand::Register(TEMP_FPU.to_string());
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, temp.clone(), st0.clone()).with_type(TipeDataIr::F80));
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, st0, lain.clone()).with_type(TipeDataIr::F80));
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, lain, temp).with_type(TipeDataIr::F80));
+        },
+        AksiX87::PindahKondisi => {
+            let suffix = match mnem.trim_start_matches("fcmov") {
+                "u" => "p",
+                "nu" => "np",
+                lain => lain,
+            };
+            let src = operand_fpu(lifter, instr, instr.operands_detail.len().saturating_sub(1));
+            let nilai = TipeOperand::Conditional {
+                condition: Box::new(flow::generate_kondisi_explicit(suffix)),
+                true_val: Box::new(src),
+                false_val: Box::new(st0.clone()),
+            };
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, st0, nilai).with_type(TipeDataIr::F80));
+        },
+        AksiX87::SimpanStatus => {
+            let bit = |flag: &str, posisi: i64| buat_expr(OperasiIr::Shl, TipeOperand::Register(flag.to_string()), TipeOperand::Immediate(posisi));
+            let status = buat_expr(OperasiIr::Or, buat_expr(OperasiIr::Or, bit(FPU_C0, 8), bit(FPU_C2, 10)), bit(FPU_C3, 14));
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, lifter.ambil_operand(instr, 0), status).with_type(TipeDataIr::I16));
+        },
+        AksiX87::MuatFlagStatus => {
+            let ah = TipeOperand::Register("ah".to_string());
+            for (flag, posisi) in [(FLAG_CF, 0), (FLAG_PF, 2), (FLAG_AF, 4), (FLAG_ZF, 6), (FLAG_SF, 7)] {
+                let nilai = buat_expr(OperasiIr::And, buat_expr(OperasiIr::Shr, ah.clone(), TipeOperand::Immediate(posisi)), TipeOperand::Immediate(1));
+                ops.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register(flag.to_string()), nilai).with_type(TipeDataIr::I8));
+            }
+        },
+        AksiX87::KontrolWord { simpan } => {
+            let cw = TipeOperand::Register("fpcw".to_string());
+            let mem = lifter.ambil_operand(instr, 0);
+            let (dest, src) = if simpan { (mem, cw) } else { (cw, mem) };
+            ops.push(StatementIr::new(addr, OperasiIr::Mov, dest, src).with_type(TipeDataIr::I16));
+        },
+        AksiX87::GeserTop(_) | AksiX87::Inisialisasi | AksiX87::Bebaskan | AksiX87::Nop => {
+            ops.push(StatementIr::new(addr, OperasiIr::Nop, TipeOperand::None, TipeOperand::None));
+        },
+    }
+    true
+}
+
+pub(super) fn proses_banding(addr: u64, kiri: TipeOperand, kanan: TipeOperand, ke_eflags: bool, tipe: TipeDataIr, ops: &mut Vec<StatementIr>) {
+    ops.push(StatementIr::new(
+        addr,
+        OperasiIr::FCmp,
+        TipeOperand::Register(TEMP_FPU_FLAGS.to_string()),
+        buat_expr(OperasiIr::FSub, kiri.clone(), kanan.clone()),
+    ).with_type(tipe));
+    let tak_berurut = buat_expr(OperasiIr::Intrinsic(INTRINSIK_UNORDERED.to_string()), kiri.clone(), kanan.clone());
+    let atau_tak_berurut = |op: OperasiIr| buat_expr(OperasiIr::Or, buat_expr(op, kiri.clone(), kanan.clone()), tak_berurut.clone());
+    let (flag_kecil, flag_paritas, flag_sama) = if ke_eflags { (FLAG_CF, FLAG_PF, FLAG_ZF) } else { (FPU_C0, FPU_C2, FPU_C3) };
+    let mut hasil = vec![
+        (flag_kecil, atau_tak_berurut(OperasiIr::Jl)),
+        (flag_paritas, tak_berurut.clone()),
+        (flag_sama, atau_tak_berurut(OperasiIr::Je)),
+    ];
+    if ke_eflags {
+        hasil.extend([FLAG_OF, FLAG_SF, FLAG_AF].map(|f| (f, TipeOperand::Immediate(0))));
+    }
+    for (flag, nilai) in hasil {
+        ops.push(StatementIr::new(addr, OperasiIr::Mov, TipeOperand::Register(flag.to_string()), nilai).with_type(TipeDataIr::I8));
+    }
+}
+
+fn ambil_operand_biner(lifter: &IrLifter, instr: &InstructionNormalized, is_pop: bool) -> (TipeOperand, TipeOperand) {
+    let st0 = slot_stack(instr, 0);
+    match (instr.operands_detail.first(), instr.operands_detail.get(1)) {
+        (Some(JenisOperandDisasm::Register(_)), Some(_)) => (operand_fpu(lifter, instr, 0), operand_fpu(lifter, instr, 1)),
+        (Some(JenisOperandDisasm::Register(_)), None) if is_pop => (operand_fpu(lifter, instr, 0), st0),
+        (Some(JenisOperandDisasm::Register(_)), None) => (st0, operand_fpu(lifter, instr, 0)),
+        (Some(_), _) => (st0, lifter.ambil_operand(instr, 0)),
+        (None, _) => (slot_stack(instr, 1), st0),
+    }
+}
+
+fn operand_fpu(lifter: &IrLifter, instr: &InstructionNormalized, idx: usize) -> TipeOperand {
+    match instr.operands_detail.get(idx) {
+        Some(JenisOperandDisasm::Register(r)) if r.to_lowercase().starts_with("st") => slot_stack(instr, indeks_stack(r)),
+        _ => lifter.ambil_operand(instr, idx),
+    }
+}
+
+fn indeks_stack(nama: &str) -> i64 {
+    nama.trim_start_matches("st")
+        .trim_matches(|c| c == '(' || c == ')')
+        .parse()
+        .unwrap_or(0)
+}
+
+fn slot_stack(instr: &InstructionNormalized, indeks: i64) -> TipeOperand {
+    TipeOperand::Register(format!("fpr{}", (instr.top_fpu as i64 + indeks).rem_euclid(8)))
+}
+
+fn tipe_float(ukuran: u8) -> TipeDataIr {
+    match ukuran {
+        4 => TipeDataIr::F32,
+        8 => TipeDataIr::F64,
+        _ => TipeDataIr::F80,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HasilBandingFloat {
+    pub lebih_besar: bool,
+    pub lebih_kecil: bool,
+    pub sama: bool,
+    pub tak_berurut: bool,
+}
+
+pub fn evaluasi_branch_float(op_branch: &OperasiIr, mask_status: Option<i64>) -> Option<HasilBandingFloat> {
+    let mut hasil = HasilBandingFloat::default();
+    for (status_ah, slot) in [
+        (0x00, &mut hasil.lebih_besar),
+        (0x01, &mut hasil.lebih_kecil),
+        (0x40, &mut hasil.sama),
+        (0x45, &mut hasil.tak_berurut),
+    ] {
+        let (zf, pf, cf, sf) = match mask_status {
+            Some(mask) => {
+                let v = status_ah & mask & 0xFF;
+                (v == 0, v.count_ones() % 2 == 0, false, v & 0x80 != 0)
+            },
+            None => (status_ah & 0x40 != 0, status_ah & 0x04 != 0, status_ah & 0x01 != 0, false),
+        };
+        *slot = match op_branch {
+            OperasiIr::Je => zf,
+            OperasiIr::Jne => !zf,
+            OperasiIr::Jp => pf,
+            OperasiIr::Jnp => !pf,
+            OperasiIr::Jb => cf,
+            OperasiIr::Jae => !cf,
+            OperasiIr::Ja => !cf && !zf,
+            OperasiIr::Jbe => cf || zf,
+            OperasiIr::Js => sf,
+            OperasiIr::Jns => !sf,
+            OperasiIr::Jg => !zf && !sf,
+            OperasiIr::Jge => !sf,
+            OperasiIr::Jl => sf,
+            OperasiIr::Jle => zf || sf,
+            _ => return None,
+        };
+    }
+    Some(hasil)
+}
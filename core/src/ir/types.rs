@@ -1,6 +1,7 @@
 use std::hash::{Hash, Hasher};
 use std::cmp::Ordering;
 use std::fmt;
+use serde::Deserialize;
 
 #[derive(Debug, Clone, Copy)]
 pub struct OrderedFloat(pub f64);
@@ -77,17 +78,29 @@ pub enum TipeOperand {
     None,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+impl TipeOperand {
+    pub fn tanpa_lebar(&self) -> &TipeOperand {
+        match self {
+            TipeOperand::Expression { operasi, operand_kiri, operand_kanan }
+                if matches!(operasi, OperasiIr::ZeroExtend | OperasiIr::SignExtend | OperasiIr::Truncate)
+                    && matches!(**operand_kanan, TipeOperand::Immediate(_)) => operand_kiri.tanpa_lebar(),
+            _ => self,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
 pub enum OperasiIr {
     Mov, Lea, Cmov,
     Add, Sub, Imul, Mul, Div, Idiv,
     Adc, Sbb,
-    MulHi,
+    MulHi, MulHiU, MulHiSU,
     Neg, Inc, Dec,
     And, Or, Xor, Not,
     Shl, Shr, Sar, Rol, Ror,
     Bsr, Bsf, Lzcnt, Tzcnt, Popcnt,
     Jmp, Je, Jne, Jg, Jge, Jl, Jle,
+    Ja, Jae, Jb, Jbe, Js, Jns, Jo, Jno, Jp, Jnp,
     Cmp, Test, Call, Ret, Syscall,
     FAdd, FSub, FMul, FDiv,
     FSqrt, FCmp, FMin, FMax,
@@ -100,10 +113,46 @@ pub enum OperasiIr {
     AtomicAdd, AtomicSub, AtomicAnd, AtomicOr, AtomicXor,
     AtomicCas,
     Fence,
+    ZeroExtend, SignExtend, Truncate,
+    BitInsert { offset: u8, lebar: u8 },
     Nop, Phi, Unknown, Intrinsic(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+impl OperasiIr {
+    pub fn is_jump_kondisi(&self) -> bool {
+        matches!(self,
+            OperasiIr::Je | OperasiIr::Jne | OperasiIr::Jg | OperasiIr::Jge | OperasiIr::Jl | OperasiIr::Jle |
+            OperasiIr::Ja | OperasiIr::Jae | OperasiIr::Jb | OperasiIr::Jbe |
+            OperasiIr::Js | OperasiIr::Jns | OperasiIr::Jo | OperasiIr::Jno | OperasiIr::Jp | OperasiIr::Jnp
+        )
+    }
+    pub fn is_perbandingan_unsigned(&self) -> bool {
+        matches!(self, OperasiIr::Ja | OperasiIr::Jae | OperasiIr::Jb | OperasiIr::Jbe)
+    }
+    pub fn is_operasi_lebar(&self) -> bool {
+        matches!(self, OperasiIr::ZeroExtend | OperasiIr::SignExtend | OperasiIr::Truncate | OperasiIr::BitInsert { .. })
+    }
+    pub fn evaluasi_lebar(&self, kiri: i64, kanan: i64) -> Option<i64> {
+        match self {
+            OperasiIr::ZeroExtend | OperasiIr::Truncate => Some(kiri & mask_lebar(kanan)),
+            OperasiIr::SignExtend => {
+                let geser = 64 - kanan.clamp(1, 64) as u32;
+                Some(kiri.wrapping_shl(geser).wrapping_shr(geser))
+            },
+            OperasiIr::BitInsert { offset, lebar } => {
+                let mask = mask_lebar(*lebar as i64).wrapping_shl(*offset as u32);
+                Some((kiri & !mask) | (kanan.wrapping_shl(*offset as u32) & mask))
+            },
+            _ => None,
+        }
+    }
+}
+
+pub fn mask_lebar(lebar: i64) -> i64 {
+    if lebar >= 64 { -1 } else { (1i64 << lebar.max(0)) - 1 }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
 pub enum TipeDataIr {
     Unknown,
     I8, I16, I32, I64, I128,
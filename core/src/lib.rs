@@ -7,16 +7,34 @@ pub mod arch;
 
 use libc::{c_char, c_int};
 use std::ffi::CStr;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use log::{info, error};
 use crate::arch::Architecture;
 use crate::loader::LoaderError;
-use crate::loader::vmem::VirtualMemory;
+use crate::loader::vmem::{VirtualMemory, JenisEntryPoint};
+use crate::analysis::recovery::explorer::FunctionContext;
+use crate::analysis::recovery::strings::TabelString;
+use crate::analysis::recovery::crypto::TabelKripto;
+use crate::analysis::recovery::xref::{DatabaseXref, EntriXref};
+use crate::codegen::asm_listing::SintaksAsm;
+use crate::ir::lift::semantic::{SemanticEngine, SpecSemantik};
 
 pub struct ContextDecompiler {
     pub is_initialized: bool,
     pub last_error: String,
     pub vmem: Option<VirtualMemory>,
+    pub base_rebase: Option<u64>,
+    pub tabel_string: TabelString,
+    pub indeks_string: Vec<u64>,
+    pub tabel_kripto: TabelKripto,
+    pub xref_db: DatabaseXref,
+    pub sembunyikan_crt: bool,
+    pub fungsi_crt: BTreeSet<u64>,
+    pub sintaks_listing: SintaksAsm,
+    pub listing_asm: Option<String>,
+    pub fungsi: HashMap<u64, FunctionContext>,
+    pub jump_targets: HashMap<u64, Vec<u64>>,
+    pub spec_semantik: Vec<SpecSemantik>,
 }
 
 #[unsafe(no_mangle)]
@@ -25,10 +43,94 @@ pub extern "C" fn buat_konteks_decompiler() -> *mut ContextDecompiler {
         is_initialized: true,
         last_error: String::new(),
         vmem: None,
+        base_rebase: None,
+        tabel_string: TabelString::new(),
+        indeks_string: Vec::new(),
+        tabel_kripto: TabelKripto::new(),
+        xref_db: DatabaseXref::new(),
+        sembunyikan_crt: false,
+        fungsi_crt: BTreeSet::new(),
+        sintaks_listing: SintaksAsm::Intel,
+        listing_asm: None,
+        fungsi: HashMap::new(),
+        jump_targets: HashMap::new(),
+        spec_semantik: Vec::new(),
     };
     Box::into_raw(Box::new(context))
 }
 
+/// # Safety
+/// `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn atur_base_rebase(ctx_ptr: *mut ContextDecompiler, base_baru: u64) -> c_int {
+    if ctx_ptr.is_null() {
+        return -1;
+    }
+    let context = unsafe { &mut *ctx_ptr };
+    context.base_rebase = Some(base_baru);
+    0
+}
+
+/// # Safety
+/// `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn atur_sembunyikan_crt(ctx_ptr: *mut ContextDecompiler, aktif: c_int) -> c_int {
+    if ctx_ptr.is_null() {
+        return -1;
+    }
+    let context = unsafe { &mut *ctx_ptr };
+    context.sembunyikan_crt = aktif != 0;
+    0
+}
+
+/// # Safety
+/// `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn atur_sintaks_listing(ctx_ptr: *mut ContextDecompiler, sintaks: c_int) -> c_int {
+    if ctx_ptr.is_null() {
+        return -1;
+    }
+    let context = unsafe { &mut *ctx_ptr };
+    match SintaksAsm::dari_kode(sintaks) {
+        Some(s) => {
+            if context.sintaks_listing != s {
+                context.listing_asm = None;
+            }
+            context.sintaks_listing = s;
+            0
+        },
+        None => -1,
+    }
+}
+
+/// # Safety
+/// `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+/// `path_ptr` harus null atau string C yang diakhiri nul.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn tambah_spec_semantik(ctx_ptr: *mut ContextDecompiler, path_ptr: *const c_char) -> c_int {
+    if ctx_ptr.is_null() || path_ptr.is_null() {
+        return -1;
+    }
+    let context = unsafe { &mut *ctx_ptr };
+    let Ok(path_str) = unsafe { CStr::from_ptr(path_ptr) }.to_str() else {
+        context.last_error = "Invalid UTF-8 path".to_string();
+        return -1;
+    };
+    match SpecSemantik::dari_file(path_str) {
+        Ok(spec) => {
+            info!("Spec semantik dimuat: {} ({} instruksi)", path_str, spec.instruksi.len());
+            context.spec_semantik.push(spec);
+            0
+        },
+        Err(e) => {
+            let error_msg = format!("Spec Fail: {}", e);
+            error!("{}", error_msg);
+            context.last_error = error_msg;
+            -2
+        }
+    }
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn hapus_konteks_decompiler(ctx_ptr: *mut ContextDecompiler) {
     if !ctx_ptr.is_null() {
@@ -51,26 +153,51 @@ pub extern "C" fn muat_file_biner(
     match c_str.to_str() {
         Ok(path_str) => {
             match loader::proses_muat_file(path_str) {
-                Ok(vmem) => {
+                Ok(mut vmem) => {
                     info!("Berhasil muat: {}", path_str);
+                    if let Some(base_baru) = context.base_rebase {
+                        let base_lama = vmem.image_base;
+                        vmem.rebase_ke(base_baru);
+                        info!("Rebase image 0x{:x} -> 0x{:x} ({} relokasi)", base_lama, base_baru, vmem.relokasi.len());
+                    }
                     info!("Arsitektur: {} | Format: {}", vmem.arsitektur, vmem.format_biner);
-                    let arsitektur: Box<dyn Architecture> = if vmem.arsitektur == "x86_64" {
-                        Box::new(arch::x86::X86Arsitektur64)
-                    } else if vmem.arsitektur.contains("arm") {
-                        Box::new(arch::arm64::Arm64Arsitektur)
+                    let arsitektur: Box<dyn Architecture> = if vmem.is_arm32() {
+                        Box::new(arch::arm::Arm32Arsitektur::new(vmem.is_thumb(vmem.entry_point)))
                     } else {
-                        Box::new(arch::x86::X86Arsitektur64)
+                        arch::buat_arsitektur(&vmem.arsitektur)
                     };
                     let mut analyzer = analysis::recovery::explorer::RecursiveDescent::new(&vmem.arsitektur);
+                    if !context.spec_semantik.is_empty() {
+                        analyzer.atur_semantic_engine(SemanticEngine::untuk_arsitektur_dengan_spec(&vmem.arsitektur, &context.spec_semantik));
+                    }
                     analyzer.lakukan_analisis_full(&vmem);
+                    for (addr, nama) in analyzer.deteksi_thunk_impor(&vmem) {
+                        vmem.simbol_global.insert(addr, nama);
+                    }
+                    let crt_analyzer = analysis::recovery::crt::CrtAnalyzer::new(&vmem.arsitektur, &vmem.format_biner);
+                    let hasil_crt = crt_analyzer.deteksi_main(&vmem, analyzer.ambil_hasil_fungsi());
+                    if let Some(hasil) = &hasil_crt {
+                        if hasil.main_thumb {
+                            vmem.alamat_thumb.insert(hasil.alamat_main);
+                        }
+                        analyzer.analisis_fungsi_tambahan(&vmem, &[hasil.alamat_main]);
+                    }
+                    for &handler in &analyzer.signal_handlers {
+                        vmem.tambah_entry_point(handler, JenisEntryPoint::SignalHandler, None);
+                    }
+                    vmem.terapkan_nama_default_entry_points();
                     let hasil_fungsi = analyzer.ambil_hasil_fungsi();
                     let jump_targets_map = &analyzer.global_jump_targets;
                     let mut all_source_code = String::new();
                     let mut emitter = codegen::c_gen::CEmitter::new();
                     all_source_code.push_str(&emitter.generate_header_includes());
                     let mut type_sys = analysis::recovery::types::TypeSystem::new();
+                    if let Some(hasil) = &hasil_crt {
+                        analysis::recovery::crt::terapkan_hasil_crt(hasil, &mut vmem, &mut type_sys);
+                    }
                     let std_lib_manager = analysis::recovery::std_lib::StdLibManager::new();
                     std_lib_manager.terapkan_signature_standar(&vmem.simbol_global, &mut type_sys);
+                    type_sys.kelompokkan_method_kelas(&vmem.info_simbol);
                     let mut map_ir_global = HashMap::new();
                     for (addr, ctx) in hasil_fungsi {
                         map_ir_global.insert(*addr, ctx.ir_code.clone());
@@ -78,8 +205,32 @@ pub extern "C" fn muat_file_biner(
                     let ptr_size = if vmem.arsitektur.contains("64") { 8 } else { 4 };
                     let mut vtable_analyzer = analysis::recovery::vtable::VtableAnalyzer::new(ptr_size);
                     vtable_analyzer.jalankan_scan_heuristik(&vmem);
+                    let mut konvensi_x86 = HashMap::new();
+                    if arch::is_x86_32(&vmem.arsitektur) {
+                        let analyzer_konvensi = analysis::recovery::abi::CallingConventionAnalyzer::new(arsitektur.as_ref(), &vmem.format_biner);
+                        for (addr, ctx) in hasil_fungsi {
+                            let is_method = vmem.info_simbol.get(addr).map(|i| i.is_method).unwrap_or(false);
+                            let info = analyzer_konvensi.deteksi_konvensi_x86(&ctx.ir_code, is_method);
+                            let sig = type_sys.global_signatures.entry(*addr).or_insert(analysis::recovery::types::SignatureFungsi {
+                                return_type: analysis::recovery::types::TipePrimitif::Unknown,
+                                arg_types: Vec::new(),
+                                konvensi: info.konvensi,
+                            });
+                            sig.konvensi = info.konvensi;
+                            sig.arg_types.resize(info.daftar_parameter().len(), analysis::recovery::types::TipePrimitif::Unknown);
+                            konvensi_x86.insert(*addr, info);
+                        }
+                    }
                     vtable_analyzer.analisis_dan_rekonstruksi_kelas(&map_ir_global, &mut type_sys);
                     type_sys.analisis_interprosedural(&map_ir_global);
+                    let mut tabel_string = TabelString::new();
+                    tabel_string.pindai_vmem(&vmem);
+                    tabel_string.catat_referensi_dari_ir(&map_ir_global);
+                    emitter.atur_tabel_string(&tabel_string);
+                    let mut tabel_kripto = TabelKripto::new();
+                    tabel_kripto.pindai_vmem(&vmem);
+                    tabel_kripto.catat_referensi_dari_ir(&map_ir_global);
+                    tabel_kripto.terapkan_nama(&mut vmem);
                     all_source_code.push_str(&emitter.generate_struct_defs(&type_sys));
                     let mut fungsi_sorted: Vec<_> = hasil_fungsi.keys().collect();
                     fungsi_sorted.sort();
@@ -90,7 +241,7 @@ pub extern "C" fn muat_file_biner(
                         let stack_frame = analysis::recovery::stack::StackFrame::analisis_stack_frame(ir_statements, arsitektur.as_ref());
                         let mut dom_tree = analysis::graph::dom::DominatorTree::new();
                         dom_tree.hitung_dominators(&cfg);
-                        let mut ssa_trans = analysis::passes::ssa::SsaTransformer::new();
+                        let mut ssa_trans = analysis::passes::ssa::SsaTransformer::untuk_arsitektur(arsitektur.as_ref());
                         ssa_trans.lakukan_transformasi_ssa(&mut cfg, &dom_tree);
                         ssa_trans.optimasi_propagasi_konstanta(&mut cfg);
                         let mut expr_opt = analysis::passes::opt_expr::ExpressionOptimizer::new();
@@ -100,7 +251,13 @@ pub extern "C" fn muat_file_biner(
                             arsitektur.as_ref(), 
                             &vmem.format_biner
                         );
-                        let params = calling_conv.deteksi_entry_params(&cfg);
+                        let params = match konvensi_x86.get(func_addr) {
+                            Some(info) => info.daftar_parameter(),
+                            None => calling_conv.deteksi_entry_params(&cfg),
+                        };
+                        let konvensi = type_sys.global_signatures.get(func_addr)
+                            .map(|sig| sig.konvensi)
+                            .unwrap_or(analysis::recovery::types::KonvensiPanggilan::Bawaan);
                         let mut structurer = analysis::recovery::ast::ControlFlowStructurer::new();
                         let ast = structurer.bangun_tree_struktur(&mut cfg);
                         let nama_fungsi = if let Some(sym) = vmem.simbol_global.get(func_addr) {
@@ -110,11 +267,39 @@ pub extern "C" fn muat_file_biner(
                         } else {
                             format!("sub_{:x}", func_addr)
                         };
-                        let func_code = emitter.hasilkan_fungsi_tunggal(&nama_fungsi, &ast, &type_sys, &stack_frame, &vmem.simbol_global, &params, arsitektur.as_ref());
+                        let is_crt = hasil_crt.as_ref().map(|h| h.fungsi_crt.contains(func_addr)).unwrap_or(false);
+                        if is_crt && context.sembunyikan_crt {
+                            continue;
+                        }
+                        if is_crt {
+                            all_source_code.push_str("// crt helper\n");
+                        }
+                        let jenis_entry: Vec<&str> = vmem.entry_points.iter()
+                            .filter(|ep| ep.alamat == *func_addr)
+                            .map(|ep| ep.jenis.sebagai_str())
+                            .collect();
+                        if !jenis_entry.is_empty() {
+                            all_source_code.push_str(&format!("// entry point: {}\n", jenis_entry.join(", ")));
+                        }
+                        if let Some(komentar) = tabel_kripto.komentar_fungsi(*func_addr) {
+                            all_source_code.push_str(&komentar);
+                        }
+                        if let Some(info) = vmem.info_simbol.get(func_addr) {
+                            all_source_code.push_str(&format!("// {}\n", info.nama_lengkap));
+                        }
+                        let func_code = emitter.hasilkan_fungsi_tunggal(&nama_fungsi, &ast, &type_sys, &stack_frame, &vmem.simbol_global, &params, konvensi, arsitektur.as_ref());
                         all_source_code.push_str(&func_code);
                     }
                     info!("{}", all_source_code);
+                    context.listing_asm = None;
+                    context.fungsi = analyzer.lepas_hasil_fungsi();
+                    context.jump_targets = std::mem::take(&mut analyzer.global_jump_targets);
                     context.vmem = Some(vmem);
+                    context.indeks_string = tabel_string.entri.keys().copied().collect();
+                    context.tabel_string = tabel_string;
+                    context.tabel_kripto = tabel_kripto;
+                    context.xref_db = analyzer.xref_db.clone();
+                    context.fungsi_crt = hasil_crt.map(|h| h.fungsi_crt).unwrap_or_default();
                     0
                 },
                 Err(e) => {
@@ -137,4 +322,162 @@ pub extern "C" fn muat_file_biner(
             -1
         }
     }
-}
\ No newline at end of file
+}
+
+fn salin_ke_buffer_c(teks: &str, buffer: *mut c_char, panjang_buffer: usize) {
+    if buffer.is_null() || panjang_buffer == 0 {
+        return;
+    }
+    let bytes = teks.as_bytes();
+    let n = bytes.len().min(panjang_buffer - 1);
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer as *mut u8, n);
+        *buffer.add(n) = 0;
+    }
+}
+
+/// # Safety
+/// `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+/// `buffer` boleh null atau harus dapat ditulis sebanyak `panjang_buffer` byte.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ambil_listing_asm(
+    ctx_ptr: *mut ContextDecompiler,
+    buffer: *mut c_char,
+    panjang_buffer: usize
+) -> c_int {
+    if ctx_ptr.is_null() {
+        return -1;
+    }
+    let context = unsafe { &mut *ctx_ptr };
+    let Some(vmem) = &context.vmem else {
+        salin_ke_buffer_c("", buffer, panjang_buffer);
+        return 0;
+    };
+    let listing = context.listing_asm.get_or_insert_with(|| {
+        let mut emitter_asm = codegen::asm_listing::AsmListingEmitter::new(context.sintaks_listing);
+        emitter_asm.atur_tabel_string(&context.tabel_string);
+        emitter_asm.hasilkan_listing(vmem, &context.fungsi, &context.jump_targets, &context.xref_db)
+    });
+    salin_ke_buffer_c(listing, buffer, panjang_buffer);
+    listing.len() as c_int
+}
+
+/// # Safety
+/// `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn jumlah_string_terdeteksi(ctx_ptr: *const ContextDecompiler) -> c_int {
+    if ctx_ptr.is_null() {
+        return -1;
+    }
+    let context = unsafe { &*ctx_ptr };
+    context.indeks_string.len() as c_int
+}
+
+/// # Safety
+/// `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+/// `out_alamat` boleh null; `buffer` boleh null atau harus dapat ditulis sebanyak `panjang_buffer` byte.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ambil_string_terdeteksi(
+    ctx_ptr: *const ContextDecompiler,
+    index: usize,
+    out_alamat: *mut u64,
+    buffer: *mut c_char,
+    panjang_buffer: usize
+) -> c_int {
+    if ctx_ptr.is_null() {
+        return -1;
+    }
+    let context = unsafe { &*ctx_ptr };
+    match context.indeks_string.get(index).and_then(|alamat| context.tabel_string.entri.get(alamat)) {
+        Some(entri) => {
+            if !out_alamat.is_null() {
+                unsafe { *out_alamat = entri.alamat; }
+            }
+            salin_ke_buffer_c(&entri.isi, buffer, panjang_buffer);
+            entri.referensi.len() as c_int
+        },
+        None => -1,
+    }
+}
+
+/// # Safety
+/// `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+/// `out_referensi` harus dapat ditulis sebanyak `kapasitas` elemen.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ambil_referensi_string(
+    ctx_ptr: *const ContextDecompiler,
+    index: usize,
+    out_referensi: *mut u64,
+    kapasitas: usize
+) -> c_int {
+    if ctx_ptr.is_null() || out_referensi.is_null() {
+        return -1;
+    }
+    let context = unsafe { &*ctx_ptr };
+    match context.indeks_string.get(index).and_then(|alamat| context.tabel_string.entri.get(alamat)) {
+        Some(entri) => {
+            let n = entri.referensi.len().min(kapasitas);
+            unsafe {
+                std::ptr::copy_nonoverlapping(entri.referensi.as_ptr(), out_referensi, n);
+            }
+            n as c_int
+        },
+        None => -1,
+    }
+}
+
+fn salin_xref_ke_buffer(
+    daftar: &[EntriXref],
+    ambil_alamat: fn(&EntriXref) -> u64,
+    out_alamat: *mut u64,
+    out_jenis: *mut c_int,
+    kapasitas: usize
+) -> c_int {
+    for (i, entri) in daftar.iter().take(kapasitas).enumerate() {
+        unsafe {
+            if !out_alamat.is_null() {
+                *out_alamat.add(i) = ambil_alamat(entri);
+            }
+            if !out_jenis.is_null() {
+                *out_jenis.add(i) = entri.jenis.kode();
+            }
+        }
+    }
+    daftar.len() as c_int
+}
+
+/// # Safety
+/// `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+/// `out_dari` dan `out_jenis` boleh null atau harus dapat ditulis sebanyak `kapasitas` elemen.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ambil_xref_ke(
+    ctx_ptr: *const ContextDecompiler,
+    alamat: u64,
+    out_dari: *mut u64,
+    out_jenis: *mut c_int,
+    kapasitas: usize
+) -> c_int {
+    if ctx_ptr.is_null() {
+        return -1;
+    }
+    let context = unsafe { &*ctx_ptr };
+    salin_xref_ke_buffer(context.xref_db.ambil_xref_ke(alamat), |e| e.dari, out_dari, out_jenis, kapasitas)
+}
+
+/// # Safety
+/// `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+/// `out_ke` dan `out_jenis` boleh null atau harus dapat ditulis sebanyak `kapasitas` elemen.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ambil_xref_dari(
+    ctx_ptr: *const ContextDecompiler,
+    alamat: u64,
+    out_ke: *mut u64,
+    out_jenis: *mut c_int,
+    kapasitas: usize
+) -> c_int {
+    if ctx_ptr.is_null() {
+        return -1;
+    }
+    let context = unsafe { &*ctx_ptr };
+    salin_xref_ke_buffer(context.xref_db.ambil_xref_dari(alamat), |e| e.ke, out_ke, out_jenis, kapasitas)
+}
@@ -0,0 +1,1502 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SkemaMangling {
+    Itanium,
+    RustLegacy,
+    RustV0,
+    Msvc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimbolDemangled {
+    pub nama_mentah: String,
+    pub nama_lengkap: String,
+    pub nama_bersih: String,
+    pub skema: SkemaMangling,
+    pub kualifikasi: Vec<String>,
+    pub nama_dasar: String,
+    pub is_method: bool,
+    pub is_vtable: bool,
+}
+
+impl SimbolDemangled {
+    fn baru(skema: SkemaMangling, nama_lengkap: String, mut komponen: Vec<String>) -> Self {
+        let nama_dasar = komponen.pop().unwrap_or_default();
+        Self {
+            nama_mentah: String::new(),
+            nama_lengkap,
+            nama_bersih: String::new(),
+            skema,
+            kualifikasi: komponen,
+            nama_dasar,
+            is_method: false,
+            is_vtable: false,
+        }
+    }
+    pub fn nama_kelas(&self) -> Option<String> {
+        if self.kualifikasi.is_empty() {
+            None
+        } else {
+            Some(self.kualifikasi.join("::"))
+        }
+    }
+}
+
+const BATAS_REKURSI: usize = 128;
+
+pub fn demangle_simbol(raw: &str) -> Option<SimbolDemangled> {
+    let nama = match raw.split_once(':') {
+        Some((dll, sisa)) if dll.to_ascii_lowercase().ends_with(".dll") => sisa,
+        _ => raw,
+    };
+    let kandidat = if nama.starts_with("__Z") || nama.starts_with("__R") { &nama[1..] } else { nama };
+    let mut hasil = if let Some(sisa) = kandidat.strip_prefix("_R") {
+        RustV0Parser::new(sisa).demangle()?
+    } else if kandidat.starts_with("_Z") {
+        match demangle_rust_legacy(kandidat) {
+            Some(h) => h,
+            None => ItaniumParser::new(kandidat).demangle()?,
+        }
+    } else if kandidat.starts_with('?') {
+        MsvcParser::new(kandidat).demangle()?
+    } else {
+        return None;
+    };
+    hasil.nama_mentah = raw.to_string();
+    let mut komponen = hasil.kualifikasi.clone();
+    komponen.push(hasil.nama_dasar.clone());
+    hasil.nama_bersih = sanitasi_identifier(&komponen.join("::"));
+    Some(hasil)
+}
+
+pub fn sanitasi_identifier(teks: &str) -> String {
+    let mut hasil = String::new();
+    let mut chars = teks.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            hasil.push(c);
+            continue;
+        }
+        let pengganti = match c {
+            ':' if chars.peek() == Some(&':') => {
+                chars.next();
+                "_"
+            },
+            '~' => "_dtor_",
+            '=' if hasil.ends_with("operator") => "_eq",
+            '=' => "eq",
+            '!' => "_not",
+            '<' if hasil.ends_with("operator") => "_lt",
+            '>' if hasil.ends_with("operator") => "_gt",
+            '+' => "_add",
+            '-' => "_sub",
+            '*' => "_mul",
+            '/' => "_div",
+            '%' => "_mod",
+            '&' => "_and",
+            '|' => "_or",
+            '^' => "_xor",
+            '(' if chars.peek() == Some(&')') && hasil.ends_with("operator") => {
+                chars.next();
+                "_call"
+            },
+            '[' if chars.peek() == Some(&']') && hasil.ends_with("operator") => {
+                chars.next();
+                "_index"
+            },
+            _ => "_",
+        };
+        hasil.push_str(pengganti);
+    }
+    let mut bersih = String::with_capacity(hasil.len());
+    for c in hasil.chars() {
+        if c == '_' && bersih.ends_with('_') {
+            continue;
+        }
+        bersih.push(c);
+    }
+    let bersih = bersih.trim_matches('_').to_string();
+    if bersih.is_empty() {
+        "sym".to_string()
+    } else if bersih.chars().next().map(|c| c.is_ascii_digit()).unwrap_or(false) {
+        format!("_{}", bersih)
+    } else {
+        bersih
+    }
+}
+
+fn demangle_rust_legacy(raw: &str) -> Option<SimbolDemangled> {
+    let s = raw.strip_prefix("_ZN")?.as_bytes();
+    let mut pos = 0;
+    let mut komponen = Vec::new();
+    while pos < s.len() && s[pos] != b'E' {
+        let mulai = pos;
+        while pos < s.len() && s[pos].is_ascii_digit() {
+            pos += 1;
+        }
+        let panjang: usize = std::str::from_utf8(&s[mulai..pos]).ok()?.parse().ok()?;
+        let ident = std::str::from_utf8(s.get(pos..pos.checked_add(panjang)?)?).ok()?;
+        komponen.push(ident.to_string());
+        pos += panjang;
+    }
+    let hash = komponen.pop()?;
+    let is_hash = hash.len() == 17 && hash.starts_with('h') && hash[1..].chars().all(|c| c.is_ascii_hexdigit());
+    if !is_hash || komponen.is_empty() {
+        return None;
+    }
+    let komponen: Vec<String> = komponen.iter().map(|k| dekode_escape_rust(k)).collect();
+    let nama_lengkap = komponen.join("::");
+    let mut hasil = SimbolDemangled::baru(SkemaMangling::RustLegacy, nama_lengkap, komponen);
+    hasil.is_method = hasil.kualifikasi.last()
+        .map(|k| k.starts_with('<') || k.chars().next().map(|c| c.is_ascii_uppercase()).unwrap_or(false))
+        .unwrap_or(false);
+    Some(hasil)
+}
+
+fn dekode_escape_rust(ident: &str) -> String {
+    let ident = ident.strip_prefix("_$").map(|s| format!("${}", s)).unwrap_or_else(|| ident.to_string());
+    let mut hasil = String::new();
+    let mut sisa = ident.as_str();
+    while !sisa.is_empty() {
+        if let Some(r) = sisa.strip_prefix("..") {
+            hasil.push_str("::");
+            sisa = r;
+            continue;
+        }
+        if sisa.starts_with('$')
+            && let Some(akhir) = sisa[1..].find('$') {
+            let kode = &sisa[1..akhir + 1];
+            let ganti = match kode {
+                "SP" => Some("@".to_string()),
+                "BP" => Some("*".to_string()),
+                "RF" => Some("&".to_string()),
+                "LT" => Some("<".to_string()),
+                "GT" => Some(">".to_string()),
+                "LP" => Some("(".to_string()),
+                "RP" => Some(")".to_string()),
+                "C" => Some(",".to_string()),
+                _ => kode.strip_prefix('u')
+                    .and_then(|h| u32::from_str_radix(h, 16).ok())
+                    .and_then(char::from_u32)
+                    .map(|c| c.to_string()),
+            };
+            if let Some(g) = ganti {
+                hasil.push_str(&g);
+                sisa = &sisa[akhir + 2..];
+                continue;
+            }
+        }
+        let c = sisa.chars().next().unwrap();
+        hasil.push(c);
+        sisa = &sisa[c.len_utf8()..];
+    }
+    hasil
+}
+
+#[derive(Debug, Clone)]
+enum NodeTipe {
+    Teks(String),
+    Nama(Vec<String>),
+    Fungsi { ret: Box<NodeTipe>, params: Vec<NodeTipe>, cv: String },
+    Pointer(Box<NodeTipe>, &'static str),
+    Qual(Box<NodeTipe>, String),
+    Array(Box<NodeTipe>, String),
+}
+
+impl NodeTipe {
+    fn render(&self) -> String {
+        match self {
+            NodeTipe::Teks(s) => s.clone(),
+            NodeTipe::Nama(k) => k.join("::"),
+            NodeTipe::Fungsi { ret, params, cv } => {
+                format!("{} ({}){}", ret.render(), render_params(params), cv)
+            },
+            NodeTipe::Pointer(inner, simbol) => match inner.as_ref() {
+                NodeTipe::Fungsi { ret, params, cv } => {
+                    format!("{} ({})({}){}", ret.render(), simbol, render_params(params), cv)
+                },
+                NodeTipe::Array(elem, n) => format!("{} ({})[{}]", elem.render(), simbol, n),
+                _ => format!("{}{}", inner.render(), simbol),
+            },
+            NodeTipe::Qual(inner, q) => format!("{}{}", inner.render(), q),
+            NodeTipe::Array(elem, n) => format!("{} [{}]", elem.render(), n),
+        }
+    }
+}
+
+fn render_params(params: &[NodeTipe]) -> String {
+    if params.len() == 1 && matches!(&params[0], NodeTipe::Teks(t) if t == "void") {
+        return String::new();
+    }
+    params.iter().map(|p| p.render()).collect::<Vec<_>>().join(", ")
+}
+
+struct NamaItanium {
+    komponen: Vec<String>,
+    cv: String,
+    is_template: bool,
+    is_ctor_dtor: bool,
+}
+
+struct ItaniumParser<'a> {
+    s: &'a [u8],
+    pos: usize,
+    subs: Vec<NodeTipe>,
+    template_args: Vec<NodeTipe>,
+    depth: usize,
+    dalam_tipe: usize,
+}
+
+impl<'a> ItaniumParser<'a> {
+    fn new(raw: &'a str) -> Self {
+        Self { s: raw.as_bytes(), pos: 0, subs: Vec::new(), template_args: Vec::new(), depth: 0, dalam_tipe: 0 }
+    }
+    fn simpan_template_args(&mut self, args: Vec<NodeTipe>) {
+        if self.dalam_tipe == 0 {
+            self.template_args = args;
+        }
+    }
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.s.get(self.pos + offset).copied()
+    }
+    fn konsumsi(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+    fn demangle(mut self) -> Option<SimbolDemangled> {
+        if !self.s.starts_with(b"_Z") {
+            return None;
+        }
+        self.pos = 2;
+        let mut hasil = self.parse_encoding_simbol()?;
+        if self.peek() == Some(b'.') {
+            let suffix = std::str::from_utf8(&self.s[self.pos..]).ok()?;
+            hasil.nama_lengkap.push_str(&format!(" [clone {}]", suffix));
+            self.pos = self.s.len();
+        }
+        if self.pos != self.s.len() {
+            return None;
+        }
+        Some(hasil)
+    }
+    fn parse_encoding_simbol(&mut self) -> Option<SimbolDemangled> {
+        if self.peek() == Some(b'T') || self.peek() == Some(b'G') {
+            return self.parse_special_name();
+        }
+        let (nama, nama_lengkap) = self.parse_encoding()?;
+        let mut hasil = SimbolDemangled::baru(SkemaMangling::Itanium, nama_lengkap, nama.komponen);
+        hasil.is_method = nama.is_ctor_dtor || !nama.cv.is_empty();
+        Some(hasil)
+    }
+    fn parse_special_name(&mut self) -> Option<SimbolDemangled> {
+        let kode = [self.peek()?, self.peek_at(1)?];
+        self.pos += 2;
+        let (awalan, dasar, is_vtable) = match &kode {
+            b"TV" => ("vtable for ", "vtable", true),
+            b"TT" => ("VTT for ", "VTT", false),
+            b"TI" => ("typeinfo for ", "typeinfo", false),
+            b"TS" => ("typeinfo name for ", "typeinfo_name", false),
+            b"Th" | b"Tv" | b"Tc" => {
+                let awalan = match &kode {
+                    b"Th" => "non-virtual thunk to ",
+                    b"Tv" => "virtual thunk to ",
+                    _ => "covariant return thunk to ",
+                };
+                if kode[1] == b'c' {
+                    self.parse_call_offset()?;
+                    self.parse_call_offset()?;
+                } else {
+                    self.pos -= 1;
+                    self.parse_call_offset()?;
+                }
+                let (nama, lengkap) = self.parse_encoding()?;
+                let mut hasil = SimbolDemangled::baru(SkemaMangling::Itanium, format!("{}{}", awalan, lengkap), nama.komponen);
+                hasil.nama_dasar = format!("{}_thunk", hasil.nama_dasar);
+                hasil.is_method = true;
+                return Some(hasil);
+            },
+            b"GV" | b"GR" | b"TW" | b"TH" => {
+                let awalan = match &kode {
+                    b"GV" => "guard variable for ",
+                    b"GR" => "reference temporary for ",
+                    b"TW" => "TLS wrapper function for ",
+                    _ => "TLS init function for ",
+                };
+                let nama = self.parse_name()?;
+                if kode == *b"GR" {
+                    while self.peek().map(|c| c.is_ascii_alphanumeric()).unwrap_or(false) {
+                        self.pos += 1;
+                    }
+                    self.konsumsi(b'_');
+                }
+                let lengkap = format!("{}{}", awalan, nama.komponen.join("::"));
+                let mut hasil = SimbolDemangled::baru(SkemaMangling::Itanium, lengkap, nama.komponen);
+                hasil.nama_dasar = format!("{}_{}", awalan.split(' ').next().unwrap_or("guard"), hasil.nama_dasar);
+                return Some(hasil);
+            },
+            _ => return None,
+        };
+        let tipe = self.parse_type()?;
+        let mut komponen = match &tipe {
+            NodeTipe::Nama(k) => k.clone(),
+            lain => vec![lain.render()],
+        };
+        komponen.push(dasar.to_string());
+        let mut hasil = SimbolDemangled::baru(SkemaMangling::Itanium, format!("{}{}", awalan, tipe.render()), komponen);
+        hasil.is_vtable = is_vtable;
+        hasil.is_method = true;
+        Some(hasil)
+    }
+    fn parse_call_offset(&mut self) -> Option<()> {
+        match self.peek()? {
+            b'h' => {
+                self.pos += 1;
+                self.parse_angka_bertanda()?;
+                if !self.konsumsi(b'_') { return None; }
+            },
+            b'v' => {
+                self.pos += 1;
+                self.parse_angka_bertanda()?;
+                if !self.konsumsi(b'_') { return None; }
+                self.parse_angka_bertanda()?;
+                if !self.konsumsi(b'_') { return None; }
+            },
+            _ => return None,
+        }
+        Some(())
+    }
+    fn parse_angka_bertanda(&mut self) -> Option<i64> {
+        let negatif = self.konsumsi(b'n');
+        let nilai = i64::try_from(self.parse_angka()?).ok()?;
+        Some(if negatif { nilai.checked_neg()? } else { nilai })
+    }
+    fn parse_angka(&mut self) -> Option<usize> {
+        let mulai = self.pos;
+        while self.peek().map(|c| c.is_ascii_digit()).unwrap_or(false) {
+            self.pos += 1;
+        }
+        if mulai == self.pos {
+            return None;
+        }
+        std::str::from_utf8(&self.s[mulai..self.pos]).ok()?.parse().ok()
+    }
+    fn parse_encoding(&mut self) -> Option<(NamaItanium, String)> {
+        self.depth += 1;
+        if self.depth > BATAS_REKURSI {
+            return None;
+        }
+        let nama = self.parse_name()?;
+        let mut lengkap = nama.komponen.join("::");
+        let ada_tipe_fungsi = !matches!(self.peek(), None | Some(b'E') | Some(b'.'));
+        if ada_tipe_fungsi {
+            if nama.is_template && !nama.is_ctor_dtor {
+                let ret = self.parse_type()?;
+                lengkap = format!("{} {}", ret.render(), lengkap);
+            }
+            let mut params = Vec::new();
+            while !matches!(self.peek(), None | Some(b'E') | Some(b'.')) {
+                params.push(self.parse_type()?);
+            }
+            lengkap = format!("{}({}){}", lengkap, render_params(&params), nama.cv);
+        }
+        self.depth -= 1;
+        Some((nama, lengkap))
+    }
+    fn parse_name(&mut self) -> Option<NamaItanium> {
+        self.depth += 1;
+        if self.depth > BATAS_REKURSI {
+            return None;
+        }
+        let hasil = match self.peek()? {
+            b'N' => self.parse_nested_name()?,
+            b'Z' => {
+                self.pos += 1;
+                let (_, enc) = self.parse_encoding()?;
+                if !self.konsumsi(b'E') { return None; }
+                let mut komponen = vec![enc];
+                if self.konsumsi(b's') {
+                    komponen.push("string literal".to_string());
+                    self.parse_diskriminator();
+                    NamaItanium { komponen, cv: String::new(), is_template: false, is_ctor_dtor: false }
+                } else {
+                    let inner = self.parse_name()?;
+                    self.parse_diskriminator();
+                    komponen.extend(inner.komponen);
+                    NamaItanium { komponen, cv: inner.cv, is_template: inner.is_template, is_ctor_dtor: inner.is_ctor_dtor }
+                }
+            },
+            _ => {
+                let mut komponen = if self.peek() == Some(b'S') && self.peek_at(1) == Some(b't') {
+                    self.pos += 2;
+                    let (k, _) = self.parse_unqualified_name(&[])?;
+                    vec!["std".to_string(), k]
+                } else if self.peek() == Some(b'S') {
+                    let sub = self.parse_substitution()?;
+                    if self.peek() != Some(b'I') {
+                        return None;
+                    }
+                    match sub {
+                        NodeTipe::Nama(k) => k,
+                        lain => vec![lain.render()],
+                    }
+                } else {
+                    let (k, _) = self.parse_unqualified_name(&[])?;
+                    vec![k]
+                };
+                let mut is_template = false;
+                if self.peek() == Some(b'I') {
+                    self.subs.push(NodeTipe::Nama(komponen.clone()));
+                    let args = self.parse_template_args()?;
+                    let terakhir = komponen.last_mut()?;
+                    terakhir.push_str(&render_template_args(&args));
+                    self.simpan_template_args(args);
+                    is_template = true;
+                }
+                NamaItanium { komponen, cv: String::new(), is_template, is_ctor_dtor: false }
+            }
+        };
+        self.depth -= 1;
+        Some(hasil)
+    }
+    fn parse_diskriminator(&mut self) {
+        if self.peek() == Some(b'_') {
+            self.pos += 1;
+            if self.konsumsi(b'_') {
+                let _ = self.parse_angka();
+                self.konsumsi(b'_');
+            } else {
+                let _ = self.parse_angka();
+            }
+        }
+    }
+    fn parse_nested_name(&mut self) -> Option<NamaItanium> {
+        self.pos += 1;
+        let mut cv = String::new();
+        loop {
+            match self.peek()? {
+                b'r' => { cv.push_str(" restrict"); self.pos += 1; },
+                b'V' => { cv.push_str(" volatile"); self.pos += 1; },
+                b'K' => { cv.push_str(" const"); self.pos += 1; },
+                _ => break,
+            }
+        }
+        if self.konsumsi(b'R') {
+            cv.push_str(" &");
+        } else if self.konsumsi(b'O') {
+            cv.push_str(" &&");
+        }
+        let mut komponen: Vec<String> = Vec::new();
+        let mut is_template = false;
+        let mut is_ctor_dtor = false;
+        loop {
+            let c = self.peek()?;
+            if c == b'E' {
+                self.pos += 1;
+                break;
+            }
+            let mut dari_substitusi = false;
+            match c {
+                b'S' if self.peek_at(1) == Some(b't') => {
+                    self.pos += 2;
+                    komponen = vec!["std".to_string()];
+                    continue;
+                },
+                b'S' => {
+                    komponen = match self.parse_substitution()? {
+                        NodeTipe::Nama(k) => k,
+                        lain => vec![lain.render()],
+                    };
+                    dari_substitusi = true;
+                },
+                b'I' => {
+                    let args = self.parse_template_args()?;
+                    let terakhir = komponen.last_mut()?;
+                    terakhir.push_str(&render_template_args(&args));
+                    self.simpan_template_args(args);
+                    is_template = true;
+                },
+                b'T' => {
+                    let param = self.parse_template_param()?;
+                    komponen = vec![param.render()];
+                    is_template = false;
+                },
+                _ => {
+                    let (k, ctor_dtor) = self.parse_unqualified_name(&komponen)?;
+                    komponen.push(k);
+                    is_ctor_dtor = ctor_dtor;
+                    is_template = false;
+                },
+            }
+            if !dari_substitusi && self.peek() != Some(b'E') {
+                self.subs.push(NodeTipe::Nama(komponen.clone()));
+            }
+        }
+        Some(NamaItanium { komponen, cv, is_template, is_ctor_dtor })
+    }
+    fn parse_unqualified_name(&mut self, prefix: &[String]) -> Option<(String, bool)> {
+        let c = self.peek()?;
+        let mut is_ctor_dtor = false;
+        let mut nama = if c.is_ascii_digit() {
+            self.parse_source_name()?
+        } else if c == b'L' && self.peek_at(1).map(|d| d.is_ascii_digit()).unwrap_or(false) {
+            self.pos += 1;
+            let n = self.parse_source_name()?;
+            self.parse_diskriminator();
+            n
+        } else if c == b'C' || (c == b'D' && self.peek_at(1).map(|d| d.is_ascii_digit()).unwrap_or(false)) {
+            self.pos += 1;
+            if c == b'C' && self.konsumsi(b'I') {
+                self.pos += 1;
+                self.parse_type()?;
+            } else {
+                self.pos += 1;
+            }
+            let dasar = prefix.last()?;
+            let dasar = dasar.split('<').next().unwrap_or(dasar).to_string();
+            is_ctor_dtor = true;
+            if c == b'C' { dasar } else { format!("~{}", dasar) }
+        } else if c == b'U' {
+            self.pos += 1;
+            match self.peek()? {
+                b't' => {
+                    self.pos += 1;
+                    let n = self.parse_angka().map(|n| n + 2).unwrap_or(1);
+                    if !self.konsumsi(b'_') { return None; }
+                    format!("{{unnamed type#{}}}", n)
+                },
+                b'l' => {
+                    self.pos += 1;
+                    let mut params = Vec::new();
+                    while self.peek()? != b'E' {
+                        params.push(self.parse_type()?);
+                    }
+                    self.pos += 1;
+                    let n = self.parse_angka().map(|n| n + 2).unwrap_or(1);
+                    if !self.konsumsi(b'_') { return None; }
+                    format!("{{lambda({})#{}}}", render_params(&params), n)
+                },
+                _ => return None,
+            }
+        } else if c.is_ascii_lowercase() {
+            self.parse_operator_name()?
+        } else {
+            return None;
+        };
+        while self.peek() == Some(b'B') {
+            self.pos += 1;
+            let tag = self.parse_source_name()?;
+            nama.push_str(&format!("[abi:{}]", tag));
+        }
+        Some((nama, is_ctor_dtor))
+    }
+    fn parse_source_name(&mut self) -> Option<String> {
+        let panjang = self.parse_angka()?;
+        let ident = std::str::from_utf8(self.s.get(self.pos..self.pos.checked_add(panjang)?)?).ok()?;
+        self.pos += panjang;
+        if ident.starts_with("_GLOBAL__N") {
+            return Some("(anonymous namespace)".to_string());
+        }
+        Some(ident.to_string())
+    }
+    fn parse_operator_name(&mut self) -> Option<String> {
+        let kode = [self.peek()?, self.peek_at(1)?];
+        self.pos += 2;
+        let simbol = match &kode {
+            b"nw" => "new", b"na" => "new[]", b"dl" => "delete", b"da" => "delete[]",
+            b"ps" => "+", b"ng" => "-", b"ad" => "&", b"de" => "*", b"co" => "~",
+            b"pl" => "+", b"mi" => "-", b"ml" => "*", b"dv" => "/", b"rm" => "%",
+            b"an" => "&", b"or" => "|", b"eo" => "^", b"aS" => "=", b"pL" => "+=",
+            b"mI" => "-=", b"mL" => "*=", b"dV" => "/=", b"rM" => "%=", b"aN" => "&=",
+            b"oR" => "|=", b"eO" => "^=", b"ls" => "<<", b"rs" => ">>", b"lS" => "<<=",
+            b"rS" => ">>=", b"eq" => "==", b"ne" => "!=", b"lt" => "<", b"gt" => ">",
+            b"le" => "<=", b"ge" => ">=", b"ss" => "<=>", b"nt" => "!", b"aa" => "&&",
+            b"oo" => "||", b"pp" => "++", b"mm" => "--", b"cm" => ",", b"pm" => "->*",
+            b"pt" => "->", b"cl" => "()", b"ix" => "[]", b"qu" => "?",
+            b"cv" => {
+                let tipe = self.parse_type()?;
+                return Some(format!("operator {}", tipe.render()));
+            },
+            b"li" => {
+                let n = self.parse_source_name()?;
+                return Some(format!("operator\"\" {}", n));
+            },
+            _ => return None,
+        };
+        let spasi = if simbol.starts_with(|c: char| c.is_ascii_alphabetic()) { " " } else { "" };
+        Some(format!("operator{}{}", spasi, simbol))
+    }
+    fn parse_substitution(&mut self) -> Option<NodeTipe> {
+        self.pos += 1;
+        let c = self.peek()?;
+        let std_sub = |nama: &str| Some(NodeTipe::Nama(vec!["std".to_string(), nama.to_string()]));
+        match c {
+            b'a' => { self.pos += 1; std_sub("allocator") },
+            b'b' => { self.pos += 1; std_sub("basic_string") },
+            b's' => { self.pos += 1; std_sub("string") },
+            b'i' => { self.pos += 1; std_sub("istream") },
+            b'o' => { self.pos += 1; std_sub("ostream") },
+            b'd' => { self.pos += 1; std_sub("iostream") },
+            b'_' => {
+                self.pos += 1;
+                self.subs.first().cloned()
+            },
+            _ => {
+                let mut idx: usize = 0;
+                while let Some(d) = self.peek() {
+                    if d == b'_' {
+                        break;
+                    }
+                    let nilai = match d {
+                        b'0'..=b'9' => (d - b'0') as usize,
+                        b'A'..=b'Z' => (d - b'A') as usize + 10,
+                        _ => return None,
+                    };
+                    idx = idx.checked_mul(36)?.checked_add(nilai)?;
+                    self.pos += 1;
+                }
+                if !self.konsumsi(b'_') { return None; }
+                self.subs.get(idx + 1).cloned()
+            }
+        }
+    }
+    fn parse_template_param(&mut self) -> Option<NodeTipe> {
+        self.pos += 1;
+        let idx = if self.konsumsi(b'_') {
+            0
+        } else {
+            let n = self.parse_angka()? + 1;
+            if !self.konsumsi(b'_') { return None; }
+            n
+        };
+        Some(self.template_args.get(idx).cloned().unwrap_or_else(|| NodeTipe::Teks(format!("T{}", idx))))
+    }
+    fn parse_template_args(&mut self) -> Option<Vec<NodeTipe>> {
+        self.pos += 1;
+        let mut args = Vec::new();
+        while self.peek()? != b'E' {
+            args.push(self.parse_template_arg()?);
+        }
+        self.pos += 1;
+        Some(args)
+    }
+    fn parse_template_arg(&mut self) -> Option<NodeTipe> {
+        match self.peek()? {
+            b'L' => self.parse_expr_primary(),
+            b'J' => {
+                self.pos += 1;
+                let mut pack = Vec::new();
+                while self.peek()? != b'E' {
+                    pack.push(self.parse_template_arg()?.render());
+                }
+                self.pos += 1;
+                Some(NodeTipe::Teks(pack.join(", ")))
+            },
+            _ => self.parse_type(),
+        }
+    }
+    fn parse_expr_primary(&mut self) -> Option<NodeTipe> {
+        self.pos += 1;
+        if self.peek() == Some(b'_') && self.peek_at(1) == Some(b'Z') {
+            self.pos += 2;
+            let (_, enc) = self.parse_encoding()?;
+            if !self.konsumsi(b'E') { return None; }
+            return Some(NodeTipe::Teks(enc));
+        }
+        let tipe = self.parse_type()?;
+        let mulai = self.pos;
+        while self.peek()? != b'E' {
+            self.pos += 1;
+        }
+        let nilai = std::str::from_utf8(&self.s[mulai..self.pos]).ok()?.replacen('n', "-", 1);
+        self.pos += 1;
+        let teks = match tipe.render().as_str() {
+            "bool" => if nilai == "0" { "false".to_string() } else { "true".to_string() },
+            "int" => nilai,
+            "unsigned int" => format!("{}u", nilai),
+            "long" => format!("{}l", nilai),
+            "unsigned long" => format!("{}ul", nilai),
+            "long long" => format!("{}ll", nilai),
+            "unsigned long long" => format!("{}ull", nilai),
+            lain => format!("({}){}", lain, nilai),
+        };
+        Some(NodeTipe::Teks(teks))
+    }
+    fn parse_type(&mut self) -> Option<NodeTipe> {
+        self.dalam_tipe += 1;
+        let hasil = self.parse_type_inner();
+        self.dalam_tipe -= 1;
+        hasil
+    }
+    fn parse_type_inner(&mut self) -> Option<NodeTipe> {
+        self.depth += 1;
+        if self.depth > BATAS_REKURSI {
+            return None;
+        }
+        let c = self.peek()?;
+        let builtin = match c {
+            b'v' => Some("void"), b'w' => Some("wchar_t"), b'b' => Some("bool"),
+            b'c' => Some("char"), b'a' => Some("signed char"), b'h' => Some("unsigned char"),
+            b's' => Some("short"), b't' => Some("unsigned short"), b'i' => Some("int"),
+            b'j' => Some("unsigned int"), b'l' => Some("long"), b'm' => Some("unsigned long"),
+            b'x' => Some("long long"), b'y' => Some("unsigned long long"), b'n' => Some("__int128"),
+            b'o' => Some("unsigned __int128"), b'f' => Some("float"), b'd' => Some("double"),
+            b'e' => Some("long double"), b'g' => Some("__float128"), b'z' => Some("..."),
+            _ => None,
+        };
+        if let Some(b) = builtin {
+            self.pos += 1;
+            self.depth -= 1;
+            return Some(NodeTipe::Teks(b.to_string()));
+        }
+        let mut bisa_substitusi = true;
+        let hasil = match c {
+            b'D' => {
+                let d = self.peek_at(1)?;
+                self.pos += 2;
+                let teks = match d {
+                    b'n' => "decltype(nullptr)", b'i' => "char32_t", b's' => "char16_t",
+                    b'u' => "char8_t", b'a' => "auto", b'c' => "decltype(auto)",
+                    b'f' => "decimal32", b'd' => "decimal64", b'e' => "decimal128", b'h' => "half",
+                    b'p' => {
+                        let inner = self.parse_type()?;
+                        self.depth -= 1;
+                        return Some(NodeTipe::Teks(format!("{}...", inner.render())));
+                    },
+                    b'F' => {
+                        let n = self.parse_angka()?;
+                        self.konsumsi(b'x');
+                        if !self.konsumsi(b'_') { return None; }
+                        self.depth -= 1;
+                        return Some(NodeTipe::Teks(format!("_Float{}", n)));
+                    },
+                    _ => return None,
+                };
+                self.depth -= 1;
+                return Some(NodeTipe::Teks(teks.to_string()));
+            },
+            b'u' => {
+                self.pos += 1;
+                NodeTipe::Teks(self.parse_source_name()?)
+            },
+            b'r' | b'V' | b'K' => {
+                let mut cv = String::new();
+                loop {
+                    match self.peek()? {
+                        b'r' => cv.insert_str(0, " restrict"),
+                        b'V' => cv.insert_str(0, " volatile"),
+                        b'K' => cv.insert_str(0, " const"),
+                        _ => break,
+                    }
+                    self.pos += 1;
+                }
+                let inner = self.parse_type()?;
+                match inner {
+                    NodeTipe::Fungsi { ret, params, .. } => NodeTipe::Fungsi { ret, params, cv },
+                    lain => NodeTipe::Qual(Box::new(lain), cv),
+                }
+            },
+            b'P' | b'R' | b'O' => {
+                self.pos += 1;
+                let simbol = match c { b'P' => "*", b'R' => "&", _ => "&&" };
+                NodeTipe::Pointer(Box::new(self.parse_type()?), simbol)
+            },
+            b'C' | b'G' => {
+                self.pos += 1;
+                let suffix = if c == b'C' { " _Complex" } else { " _Imaginary" };
+                NodeTipe::Teks(format!("{}{}", self.parse_type()?.render(), suffix))
+            },
+            b'F' => {
+                self.pos += 1;
+                self.konsumsi(b'Y');
+                let ret = self.parse_type()?;
+                let mut params = Vec::new();
+                loop {
+                    match self.peek()? {
+                        b'E' => { self.pos += 1; break; },
+                        b'R' | b'O' if self.peek_at(1) == Some(b'E') => { self.pos += 2; break; },
+                        _ => params.push(self.parse_type()?),
+                    }
+                }
+                NodeTipe::Fungsi { ret: Box::new(ret), params, cv: String::new() }
+            },
+            b'A' => {
+                self.pos += 1;
+                let dimensi = if self.peek()?.is_ascii_digit() {
+                    self.parse_angka()?.to_string()
+                } else {
+                    String::new()
+                };
+                if !self.konsumsi(b'_') { return None; }
+                NodeTipe::Array(Box::new(self.parse_type()?), dimensi)
+            },
+            b'M' => {
+                self.pos += 1;
+                let kelas = self.parse_type()?.render();
+                match self.parse_type()? {
+                    NodeTipe::Fungsi { ret, params, cv } => {
+                        NodeTipe::Teks(format!("{} ({}::*)({}){}", ret.render(), kelas, render_params(&params), cv))
+                    },
+                    lain => NodeTipe::Teks(format!("{} {}::*", lain.render(), kelas)),
+                }
+            },
+            b'T' if matches!(self.peek_at(1), Some(b's') | Some(b'u') | Some(b'e')) => {
+                self.pos += 2;
+                NodeTipe::Nama(self.parse_name()?.komponen)
+            },
+            b'T' => {
+                let param = self.parse_template_param()?;
+                if self.peek() == Some(b'I') {
+                    self.subs.push(param.clone());
+                    let args = self.parse_template_args()?;
+                    NodeTipe::Teks(format!("{}{}", param.render(), render_template_args(&args)))
+                } else {
+                    param
+                }
+            },
+            b'S' if self.peek_at(1) != Some(b't') => {
+                let sub = self.parse_substitution()?;
+                if self.peek() == Some(b'I') {
+                    let args = self.parse_template_args()?;
+                    let mut komponen = match sub {
+                        NodeTipe::Nama(k) => k,
+                        lain => vec![lain.render()],
+                    };
+                    let terakhir = komponen.last_mut()?;
+                    terakhir.push_str(&render_template_args(&args));
+                    NodeTipe::Nama(komponen)
+                } else {
+                    bisa_substitusi = false;
+                    sub
+                }
+            },
+            b'N' | b'Z' | b'S' | b'0'..=b'9' => NodeTipe::Nama(self.parse_name()?.komponen),
+            _ => return None,
+        };
+        if bisa_substitusi {
+            self.subs.push(hasil.clone());
+        }
+        self.depth -= 1;
+        Some(hasil)
+    }
+}
+
+fn render_template_args(args: &[NodeTipe]) -> String {
+    format!("<{}>", args.iter().map(|a| a.render()).collect::<Vec<_>>().join(", "))
+}
+
+struct RustV0Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> RustV0Parser<'a> {
+    fn new(raw: &'a str) -> Self {
+        Self { s: raw.as_bytes(), pos: 0, depth: 0 }
+    }
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+    fn konsumsi(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+    fn demangle(mut self) -> Option<SimbolDemangled> {
+        while self.peek().map(|c| c.is_ascii_digit()).unwrap_or(false) {
+            self.pos += 1;
+        }
+        let komponen = self.parse_path()?;
+        let nama_lengkap = komponen.join("::");
+        let idx_impl = komponen.iter().rposition(|k| k.starts_with('<') && k.ends_with('>'));
+        let mut hasil = SimbolDemangled::baru(SkemaMangling::RustV0, nama_lengkap, komponen.clone());
+        if let Some(idx) = idx_impl.filter(|&i| i + 1 < komponen.len()) {
+            let inner = &komponen[idx][1..komponen[idx].len() - 1];
+            let kelas = inner.split(" as ").next().unwrap_or(inner);
+            let mut kualifikasi: Vec<String> = kelas.split("::").map(|s| s.to_string()).collect();
+            kualifikasi.extend(komponen[idx + 1..komponen.len() - 1].iter().cloned());
+            hasil.kualifikasi = kualifikasi;
+            hasil.is_method = true;
+        }
+        Some(hasil)
+    }
+    fn masuk(&mut self) -> Option<()> {
+        self.depth += 1;
+        if self.depth > BATAS_REKURSI { None } else { Some(()) }
+    }
+    fn parse_base62(&mut self) -> Option<u64> {
+        if self.konsumsi(b'_') {
+            return Some(0);
+        }
+        let mut nilai: u64 = 0;
+        loop {
+            let c = self.peek()?;
+            self.pos += 1;
+            let digit = match c {
+                b'0'..=b'9' => c - b'0',
+                b'a'..=b'z' => c - b'a' + 10,
+                b'A'..=b'Z' => c - b'A' + 36,
+                b'_' => return nilai.checked_add(1),
+                _ => return None,
+            };
+            nilai = nilai.checked_mul(62)?.checked_add(digit as u64)?;
+        }
+    }
+    fn parse_disambiguator(&mut self) -> Option<u64> {
+        if self.konsumsi(b's') {
+            Some(self.parse_base62()? + 1)
+        } else {
+            Some(0)
+        }
+    }
+    fn parse_ident(&mut self) -> Option<String> {
+        let punycode = self.konsumsi(b'u');
+        let mulai = self.pos;
+        while self.peek().map(|c| c.is_ascii_digit()).unwrap_or(false) {
+            self.pos += 1;
+        }
+        let panjang: usize = std::str::from_utf8(&self.s[mulai..self.pos]).ok()?.parse().ok()?;
+        self.konsumsi(b'_');
+        let ident = std::str::from_utf8(self.s.get(self.pos..self.pos.checked_add(panjang)?)?).ok()?;
+        self.pos += panjang;
+        if punycode {
+            Some(format!("punycode{{{}}}", ident))
+        } else {
+            Some(ident.to_string())
+        }
+    }
+    fn dengan_backref<T>(&mut self, f: impl FnOnce(&mut Self) -> Option<T>) -> Option<T> {
+        let awal = self.pos - 1;
+        let target = self.parse_base62()? as usize;
+        if target >= awal {
+            return None;
+        }
+        let kembali = self.pos;
+        self.pos = target;
+        let hasil = f(self);
+        self.pos = kembali;
+        hasil
+    }
+    fn parse_path(&mut self) -> Option<Vec<String>> {
+        self.masuk()?;
+        let c = self.peek()?;
+        self.pos += 1;
+        let hasil = match c {
+            b'C' => {
+                self.parse_disambiguator()?;
+                vec![self.parse_ident()?]
+            },
+            b'N' => {
+                let ns = self.peek()?;
+                self.pos += 1;
+                let mut komponen = self.parse_path()?;
+                let dis = self.parse_disambiguator()?;
+                let ident = self.parse_ident()?;
+                let elemen = match ns {
+                    b'C' => format!("{{closure{}#{}}}", if ident.is_empty() { String::new() } else { format!(":{}", ident) }, dis),
+                    b'S' => format!("{{shim:{}#{}}}", ident, dis),
+                    b'A'..=b'Z' => format!("{{{}:{}#{}}}", ns as char, ident, dis),
+                    _ => ident,
+                };
+                komponen.push(elemen);
+                komponen
+            },
+            b'M' => {
+                self.parse_disambiguator()?;
+                self.parse_path()?;
+                vec![format!("<{}>", self.parse_type()?)]
+            },
+            b'X' => {
+                self.parse_disambiguator()?;
+                self.parse_path()?;
+                let tipe = self.parse_type()?;
+                let trait_path = self.parse_path()?.join("::");
+                vec![format!("<{} as {}>", tipe, trait_path)]
+            },
+            b'Y' => {
+                let tipe = self.parse_type()?;
+                let trait_path = self.parse_path()?.join("::");
+                vec![format!("<{} as {}>", tipe, trait_path)]
+            },
+            b'I' => {
+                let mut komponen = self.parse_path()?;
+                let mut args = Vec::new();
+                while !self.konsumsi(b'E') {
+                    args.push(self.parse_generic_arg()?);
+                }
+                let terakhir = komponen.last_mut()?;
+                terakhir.push_str(&format!("<{}>", args.join(", ")));
+                komponen
+            },
+            b'B' => self.dengan_backref(|p| p.parse_path())?,
+            _ => return None,
+        };
+        self.depth -= 1;
+        Some(hasil)
+    }
+    fn parse_generic_arg(&mut self) -> Option<String> {
+        if self.konsumsi(b'L') {
+            self.parse_base62()?;
+            Some("'_".to_string())
+        } else if self.konsumsi(b'K') {
+            self.parse_const()
+        } else {
+            self.parse_type()
+        }
+    }
+    fn parse_const(&mut self) -> Option<String> {
+        if self.konsumsi(b'p') {
+            return Some("_".to_string());
+        }
+        if self.konsumsi(b'B') {
+            return self.dengan_backref(|p| p.parse_const());
+        }
+        let tipe = self.parse_tipe_dasar()?;
+        let negatif = self.konsumsi(b'n');
+        let mulai = self.pos;
+        while self.peek()? != b'_' {
+            self.pos += 1;
+        }
+        let hex = std::str::from_utf8(&self.s[mulai..self.pos]).ok()?;
+        self.pos += 1;
+        let nilai = if hex.is_empty() { Some(0) } else { u128::from_str_radix(hex, 16).ok() };
+        let teks = match (tipe, nilai) {
+            ("bool", Some(0)) => "false".to_string(),
+            ("bool", Some(_)) => "true".to_string(),
+            ("char", Some(v)) => char::from_u32(v as u32).map(|c| format!("{:?}", c)).unwrap_or_default(),
+            (_, Some(v)) => format!("{}{}", if negatif { "-" } else { "" }, v),
+            (_, None) => format!("0x{}", hex),
+        };
+        Some(teks)
+    }
+    fn parse_tipe_dasar(&mut self) -> Option<&'static str> {
+        let c = self.peek()?;
+        let tipe = match c {
+            b'a' => "i8", b'b' => "bool", b'c' => "char", b'd' => "f64", b'e' => "str",
+            b'f' => "f32", b'h' => "u8", b'i' => "isize", b'j' => "usize", b'l' => "i32",
+            b'm' => "u32", b'n' => "i128", b'o' => "u128", b's' => "i16", b't' => "u16",
+            b'u' => "()", b'v' => "...", b'x' => "i64", b'y' => "u64", b'z' => "!", b'p' => "_",
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(tipe)
+    }
+    fn parse_type(&mut self) -> Option<String> {
+        if let Some(dasar) = self.parse_tipe_dasar() {
+            return Some(dasar.to_string());
+        }
+        self.masuk()?;
+        let c = self.peek()?;
+        let hasil = match c {
+            b'A' => {
+                self.pos += 1;
+                let elemen = self.parse_type()?;
+                let panjang = self.parse_const()?;
+                format!("[{}; {}]", elemen, panjang)
+            },
+            b'S' => {
+                self.pos += 1;
+                format!("[{}]", self.parse_type()?)
+            },
+            b'T' => {
+                self.pos += 1;
+                let mut elemen = Vec::new();
+                while !self.konsumsi(b'E') {
+                    elemen.push(self.parse_type()?);
+                }
+                if elemen.len() == 1 {
+                    format!("({},)", elemen[0])
+                } else {
+                    format!("({})", elemen.join(", "))
+                }
+            },
+            b'R' | b'Q' => {
+                self.pos += 1;
+                if self.konsumsi(b'L') {
+                    self.parse_base62()?;
+                }
+                let inner = self.parse_type()?;
+                if c == b'R' { format!("&{}", inner) } else { format!("&mut {}", inner) }
+            },
+            b'P' => {
+                self.pos += 1;
+                format!("*const {}", self.parse_type()?)
+            },
+            b'O' => {
+                self.pos += 1;
+                format!("*mut {}", self.parse_type()?)
+            },
+            b'F' => {
+                self.pos += 1;
+                self.parse_binder()?;
+                let unsafe_fn = self.konsumsi(b'U');
+                let abi = if self.konsumsi(b'K') {
+                    if self.konsumsi(b'C') { Some("C".to_string()) } else { Some(self.parse_ident()?.replace('_', "-")) }
+                } else {
+                    None
+                };
+                let mut params = Vec::new();
+                while !self.konsumsi(b'E') {
+                    params.push(self.parse_type()?);
+                }
+                let ret = self.parse_type()?;
+                let mut teks = String::new();
+                if unsafe_fn { teks.push_str("unsafe "); }
+                if let Some(a) = abi { teks.push_str(&format!("extern \"{}\" ", a)); }
+                teks.push_str(&format!("fn({})", params.join(", ")));
+                if ret != "()" { teks.push_str(&format!(" -> {}", ret)); }
+                teks
+            },
+            b'D' => {
+                self.pos += 1;
+                self.parse_binder()?;
+                let mut traits = Vec::new();
+                while !self.konsumsi(b'E') {
+                    let mut jalur = self.parse_path()?.join("::");
+                    let mut binding = Vec::new();
+                    while self.konsumsi(b'p') {
+                        let nama = self.parse_ident()?;
+                        binding.push(format!("{} = {}", nama, self.parse_type()?));
+                    }
+                    if !binding.is_empty() {
+                        jalur.push_str(&format!("<{}>", binding.join(", ")));
+                    }
+                    traits.push(jalur);
+                }
+                if !self.konsumsi(b'L') { return None; }
+                self.parse_base62()?;
+                format!("dyn {}", traits.join(" + "))
+            },
+            b'B' => {
+                self.pos += 1;
+                self.dengan_backref(|p| p.parse_type())?
+            },
+            _ => self.parse_path()?.join("::"),
+        };
+        self.depth -= 1;
+        Some(hasil)
+    }
+    fn parse_binder(&mut self) -> Option<()> {
+        if self.konsumsi(b'G') {
+            self.parse_base62()?;
+        }
+        Some(())
+    }
+}
+
+struct MsvcParser<'a> {
+    s: &'a [u8],
+    pos: usize,
+    nama_backref: Vec<String>,
+    tipe_backref: Vec<String>,
+    depth: usize,
+}
+
+impl<'a> MsvcParser<'a> {
+    fn new(raw: &'a str) -> Self {
+        Self { s: raw.as_bytes(), pos: 0, nama_backref: Vec::new(), tipe_backref: Vec::new(), depth: 0 }
+    }
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+    fn konsumsi(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+    fn demangle(mut self) -> Option<SimbolDemangled> {
+        self.pos = 1;
+        let mut nama_operator = None;
+        let mut is_ctor_dtor = false;
+        let mut is_vtable = false;
+        if self.peek() == Some(b'?') && self.s.get(self.pos + 1) != Some(&b'$') {
+            self.pos += 1;
+            let kode = self.peek()?;
+            self.pos += 1;
+            let op = match kode {
+                b'0' => { is_ctor_dtor = true; "" },
+                b'1' => { is_ctor_dtor = true; "~" },
+                b'2' => "operator new", b'3' => "operator delete", b'4' => "operator=",
+                b'5' => "operator>>", b'6' => "operator<<", b'7' => "operator!",
+                b'8' => "operator==", b'9' => "operator!=", b'A' => "operator[]",
+                b'B' => "operator cast", b'C' => "operator->", b'D' => "operator*",
+                b'E' => "operator++", b'F' => "operator--", b'G' => "operator-",
+                b'H' => "operator+", b'I' => "operator&", b'J' => "operator->*",
+                b'K' => "operator/", b'L' => "operator%", b'M' => "operator<",
+                b'N' => "operator<=", b'O' => "operator>", b'P' => "operator>=",
+                b'Q' => "operator,", b'R' => "operator()", b'S' => "operator~",
+                b'T' => "operator^", b'U' => "operator|", b'V' => "operator&&",
+                b'W' => "operator||", b'X' => "operator*=", b'Y' => "operator+=",
+                b'Z' => "operator-=",
+                b'_' => {
+                    let k = self.peek()?;
+                    self.pos += 1;
+                    match k {
+                        b'0' => "operator/=", b'1' => "operator%=", b'2' => "operator>>=",
+                        b'3' => "operator<<=", b'4' => "operator&=", b'5' => "operator|=",
+                        b'6' => "operator^=", b'7' => { is_vtable = true; "`vftable'" },
+                        b'8' => "`vbtable'", b'U' => "operator new[]", b'V' => "operator delete[]",
+                        _ => return None,
+                    }
+                },
+                _ => return None,
+            };
+            nama_operator = Some(op.to_string());
+        }
+        let nama_pertama = match nama_operator {
+            Some(op) => op,
+            None => self.parse_fragment()?,
+        };
+        let mut scope = Vec::new();
+        while !self.konsumsi(b'@') {
+            scope.push(self.parse_fragment()?);
+        }
+        scope.reverse();
+        let nama_dasar = if is_ctor_dtor {
+            let kelas = scope.last()?;
+            let kelas = kelas.split('<').next().unwrap_or(kelas);
+            format!("{}{}", nama_pertama, kelas)
+        } else {
+            nama_pertama
+        };
+        let mut komponen = scope.clone();
+        komponen.push(nama_dasar);
+        let nama_qualified = komponen.join("::");
+        let kode = self.peek();
+        let mut is_method = is_vtable || is_ctor_dtor;
+        let nama_lengkap = match kode {
+            Some(b'0'..=b'2') => {
+                is_method = true;
+                nama_qualified
+            },
+            Some(b'3'..=b'9') | None => nama_qualified,
+            Some(c) if c.is_ascii_uppercase() => {
+                self.pos += 1;
+                let is_global = c == b'Y' || c == b'Z';
+                let is_static = matches!(c, b'C' | b'D' | b'K' | b'L' | b'S' | b'T');
+                let is_member = !is_global;
+                is_method |= is_member;
+                let mut cv_this = String::new();
+                if is_member && !is_static {
+                    self.konsumsi(b'E');
+                    cv_this = match self.peek()? {
+                        b'B' => " const".to_string(),
+                        b'C' => " volatile".to_string(),
+                        b'D' => " const volatile".to_string(),
+                        _ => String::new(),
+                    };
+                    self.pos += 1;
+                }
+                match self.parse_signature() {
+                    Some(params) => format!("{}({}){}", nama_qualified, params, cv_this),
+                    None => nama_qualified,
+                }
+            },
+            _ => nama_qualified,
+        };
+        let mut hasil = SimbolDemangled::baru(SkemaMangling::Msvc, nama_lengkap, komponen);
+        hasil.is_method = is_method;
+        hasil.is_vtable = is_vtable;
+        if is_vtable {
+            hasil.nama_dasar = "vtable".to_string();
+        }
+        Some(hasil)
+    }
+    fn parse_signature(&mut self) -> Option<String> {
+        self.pos += 1;
+        if !self.konsumsi(b'@') {
+            if self.konsumsi(b'?') {
+                self.pos += 1;
+            }
+            self.parse_tipe()?;
+        }
+        self.parse_params()
+    }
+    fn parse_params(&mut self) -> Option<String> {
+        if self.konsumsi(b'X') {
+            return Some(String::new());
+        }
+        let mut params = Vec::new();
+        loop {
+            match self.peek()? {
+                b'@' => { self.pos += 1; break; },
+                b'Z' => break,
+                _ => {
+                    let mulai = self.pos;
+                    let tipe = self.parse_tipe()?;
+                    if self.pos - mulai > 1 && self.tipe_backref.len() < 10 {
+                        self.tipe_backref.push(tipe.clone());
+                    }
+                    params.push(tipe);
+                }
+            }
+        }
+        Some(params.join(", "))
+    }
+    fn parse_fragment(&mut self) -> Option<String> {
+        let c = self.peek()?;
+        if c.is_ascii_digit() {
+            self.pos += 1;
+            return self.nama_backref.get((c - b'0') as usize).cloned();
+        }
+        if c == b'?' && self.s.get(self.pos + 1) == Some(&b'$') {
+            self.pos += 2;
+            let simpan_nama = std::mem::take(&mut self.nama_backref);
+            let simpan_tipe = std::mem::take(&mut self.tipe_backref);
+            let nama = self.parse_identifier()?;
+            self.nama_backref.push(nama.clone());
+            let mut args = Vec::new();
+            while !self.konsumsi(b'@') {
+                args.push(self.parse_template_arg()?);
+            }
+            self.nama_backref = simpan_nama;
+            self.tipe_backref = simpan_tipe;
+            let hasil = format!("{}<{}>", nama, args.join(","));
+            if self.nama_backref.len() < 10 {
+                self.nama_backref.push(hasil.clone());
+            }
+            return Some(hasil);
+        }
+        if c == b'?' && self.s.get(self.pos + 1) == Some(&b'A') {
+            self.pos += 2;
+            self.parse_identifier()?;
+            return Some("`anonymous namespace'".to_string());
+        }
+        let nama = self.parse_identifier()?;
+        if self.nama_backref.len() < 10 {
+            self.nama_backref.push(nama.clone());
+        }
+        Some(nama)
+    }
+    fn parse_identifier(&mut self) -> Option<String> {
+        let mulai = self.pos;
+        while self.peek()? != b'@' {
+            self.pos += 1;
+        }
+        let ident = std::str::from_utf8(&self.s[mulai..self.pos]).ok()?.to_string();
+        self.pos += 1;
+        if ident.is_empty() { None } else { Some(ident) }
+    }
+    fn parse_template_arg(&mut self) -> Option<String> {
+        if self.peek()? == b'$' && self.s.get(self.pos + 1) == Some(&b'0') {
+            self.pos += 2;
+            return self.parse_angka().map(|n| n.to_string());
+        }
+        self.parse_tipe()
+    }
+    fn parse_angka(&mut self) -> Option<i64> {
+        let negatif = self.konsumsi(b'?');
+        let c = self.peek()?;
+        let nilai = if c.is_ascii_digit() {
+            self.pos += 1;
+            (c - b'0') as i64 + 1
+        } else {
+            let mut v: i64 = 0;
+            while self.peek()? != b'@' {
+                let d = self.peek()?;
+                if !(b'A'..=b'P').contains(&d) {
+                    return None;
+                }
+                v = v.checked_mul(16)?.checked_add((d - b'A') as i64)?;
+                self.pos += 1;
+            }
+            self.pos += 1;
+            v
+        };
+        Some(if negatif { nilai.checked_neg()? } else { nilai })
+    }
+    fn parse_tipe(&mut self) -> Option<String> {
+        self.depth += 1;
+        if self.depth > BATAS_REKURSI {
+            return None;
+        }
+        let c = self.peek()?;
+        self.pos += 1;
+        let hasil = match c {
+            b'C' => "signed char".to_string(), b'D' => "char".to_string(),
+            b'E' => "unsigned char".to_string(), b'F' => "short".to_string(),
+            b'G' => "unsigned short".to_string(), b'H' => "int".to_string(),
+            b'I' => "unsigned int".to_string(), b'J' => "long".to_string(),
+            b'K' => "unsigned long".to_string(), b'M' => "float".to_string(),
+            b'N' => "double".to_string(), b'O' => "long double".to_string(),
+            b'X' => "void".to_string(), b'Z' => "...".to_string(),
+            b'_' => {
+                let d = self.peek()?;
+                self.pos += 1;
+                match d {
+                    b'J' => "__int64", b'K' => "unsigned __int64", b'N' => "bool",
+                    b'W' => "wchar_t", b'S' => "char16_t", b'U' => "char32_t", b'Q' => "char8_t",
+                    b'L' => "__int128", b'M' => "unsigned __int128",
+                    _ => return None,
+                }.to_string()
+            },
+            b'P' | b'Q' | b'R' | b'S' | b'A' => {
+                let simbol = if c == b'A' { "&" } else { "*" };
+                self.parse_pointee(simbol)?
+            },
+            b'$' if self.konsumsi(b'$') => {
+                match self.peek()? {
+                    b'Q' => { self.pos += 1; self.parse_pointee("&&")? },
+                    b'T' => { self.pos += 1; "std::nullptr_t".to_string() },
+                    b'A' | b'C' => { self.pos += 1; self.parse_tipe()? },
+                    _ => return None,
+                }
+            },
+            b'V' | b'U' | b'T' => {
+                let mut nama = Vec::new();
+                while !self.konsumsi(b'@') {
+                    nama.push(self.parse_fragment()?);
+                }
+                nama.reverse();
+                nama.join("::")
+            },
+            b'W' => {
+                self.pos += 1;
+                let mut nama = Vec::new();
+                while !self.konsumsi(b'@') {
+                    nama.push(self.parse_fragment()?);
+                }
+                nama.reverse();
+                nama.join("::")
+            },
+            b'0'..=b'9' => self.tipe_backref.get((c - b'0') as usize)?.clone(),
+            _ => return None,
+        };
+        self.depth -= 1;
+        Some(hasil)
+    }
+    fn parse_pointee(&mut self, simbol: &str) -> Option<String> {
+        self.konsumsi(b'E');
+        self.konsumsi(b'I');
+        if self.peek()? == b'6' {
+            self.pos += 1;
+            self.pos += 1;
+            let ret = self.parse_tipe()?;
+            let params = self.parse_params()?;
+            self.konsumsi(b'Z');
+            return Some(format!("{} ({})({})", ret, simbol, params));
+        }
+        let cv = match self.peek()? {
+            b'A' => "",
+            b'B' => "const ",
+            b'C' => "volatile ",
+            b'D' => "const volatile ",
+            _ => return None,
+        };
+        self.pos += 1;
+        let inner = self.parse_tipe()?;
+        Some(format!("{}{}{}", cv, inner, simbol))
+    }
+}
@@ -1,10 +1,15 @@
 use std::fs;
 use std::path::Path;
-use goblin::elf::{Elf, section_header};
-use crate::loader::vmem::{VirtualMemory, IzinAkses};
+use goblin::elf::{Elf, header, program_header, reloc, section_header, sym};
+use crate::loader::vmem::{VirtualMemory, IzinAkses, EntriRelokasi, JenisEntryPoint, ModeKode};
 use crate::loader::LoaderError;
 use log::{warn};
 
+const DT_PLTGOT: u64 = 3;
+const DT_MIPS_LOCAL_GOTNO: u64 = 0x7000000a;
+const DT_MIPS_GOTSYM: u64 = 0x70000013;
+const OFFSET_GP_MIPS: u64 = 0x7ff0;
+
 pub struct ElfParser {
     pub file_path: String,
 }
@@ -19,7 +24,16 @@ impl ElfParser {
         let path = Path::new(&self.file_path);
         let buffer = fs::read(path).map_err(|e| LoaderError::IoError(e.to_string()))?;
         let elf = Elf::parse(&buffer).map_err(|e| LoaderError::ParseError(e.to_string()))?;
-        let arch_str = if elf.is_64 { "x86_64" } else { "x86" };
+        let arch_str = match elf.header.e_machine {
+            header::EM_ARM => "arm",
+            header::EM_AARCH64 => "aarch64",
+            header::EM_RISCV if elf.is_64 => "riscv64",
+            header::EM_RISCV => "riscv32",
+            header::EM_MIPS if elf.little_endian => "mipsel",
+            header::EM_MIPS => "mips",
+            _ if elf.is_64 => "x86_64",
+            _ => "x86",
+        };
         let mut vmem = VirtualMemory::baru(elf.entry, arch_str, "elf");
         for section in &elf.section_headers {
             if section.sh_flags & (section_header::SHF_ALLOC as u64) != 0 {
@@ -43,20 +57,146 @@ impl ElfParser {
                 }
             }
         }
-        for sym in &elf.syms {
-            if let Some(name) = elf.strtab.get_at(sym.st_name) {
-                if !name.is_empty() && sym.st_value != 0 {
-                    vmem.simbol_global.insert(sym.st_value, name.to_string());
-                }
+        vmem.image_base = elf.program_headers.iter()
+            .filter(|ph| ph.p_type == program_header::PT_LOAD)
+            .map(|ph| ph.p_vaddr)
+            .min()
+            .unwrap_or_else(|| vmem.segments.first().map(|s| s.start_addr).unwrap_or(0));
+        self.muat_relokasi_relatif(&elf, &mut vmem);
+        self.muat_entry_points(&elf, &mut vmem);
+        self.muat_slot_impor(&elf, &mut vmem);
+        if elf.header.e_machine == header::EM_MIPS {
+            self.muat_got_mips(&elf, &mut vmem);
+        }
+        self.muat_simbol(&elf, &mut vmem);
+        Ok(vmem)
+    }
+    fn muat_simbol(&self, elf: &Elf, vmem: &mut VirtualMemory) {
+        let is_arm = elf.header.e_machine == header::EM_ARM;
+        let semua_simbol = elf.syms.iter().map(|s| (s, elf.strtab.get_at(s.st_name)))
+            .chain(elf.dynsyms.iter().map(|s| (s, elf.dynstrtab.get_at(s.st_name))));
+        for (s, nama) in semua_simbol {
+            let nama = match nama {
+                Some(n) if !n.is_empty() && s.st_value != 0 => n,
+                _ => continue,
+            };
+            if is_arm && let Some(mode) = Self::ambil_mode_simbol_pemetaan(nama) {
+                vmem.tandai_mode_kode(s.st_value, mode);
+                continue;
             }
+            let alamat = if s.st_type() == sym::STT_FUNC {
+                vmem.normalisasi_alamat_kode(s.st_value)
+            } else {
+                s.st_value
+            };
+            vmem.simbol_global.insert(alamat, nama.to_string());
         }
-        for sym in &elf.dynsyms {
-            if let Some(name) = elf.dynstrtab.get_at(sym.st_name) {
-                if !name.is_empty() && sym.st_value != 0 {
-                    vmem.simbol_global.insert(sym.st_value, name.to_string());
-                }
+    }
+    fn ambil_mode_simbol_pemetaan(nama: &str) -> Option<ModeKode> {
+        let (awalan, _) = nama.split_once('.').unwrap_or((nama, ""));
+        match awalan {
+            "$a" => Some(ModeKode::Arm),
+            "$t" => Some(ModeKode::Thumb),
+            "$d" => Some(ModeKode::Data),
+            _ => None,
+        }
+    }
+    fn muat_slot_impor(&self, elf: &Elf, vmem: &mut VirtualMemory) {
+        for rel in elf.pltrelocs.iter().chain(elf.dynrelas.iter()).chain(elf.dynrels.iter()) {
+            if rel.r_sym == 0 || vmem.simbol_global.contains_key(&rel.r_offset) {
+                continue;
+            }
+            let sym = match elf.dynsyms.get(rel.r_sym) {
+                Some(s) => s,
+                None => continue,
+            };
+            if sym.st_shndx != section_header::SHN_UNDEF as usize {
+                continue;
+            }
+            if let Some(nama) = elf.dynstrtab.get_at(sym.st_name)
+                && !nama.is_empty()
+            {
+                vmem.simbol_global.insert(rel.r_offset, nama.to_string());
             }
         }
-        Ok(vmem)
+    }
+    fn muat_got_mips(&self, elf: &Elf, vmem: &mut VirtualMemory) {
+        vmem.nilai_gp = elf.syms.iter()
+            .find(|s| elf.strtab.get_at(s.st_name) == Some("_gp"))
+            .map(|s| s.st_value);
+        let ambil_dyn = |tag: u64| elf.dynamic.as_ref()
+            .and_then(|d| d.dyns.iter().find(|e| e.d_tag == tag))
+            .map(|e| e.d_val);
+        let alamat_got = match ambil_dyn(DT_PLTGOT)
+            .or_else(|| vmem.segments.iter().find(|s| s.nama_section == ".got").map(|s| s.start_addr))
+        {
+            Some(a) => a,
+            None => return,
+        };
+        if vmem.nilai_gp.is_none() {
+            vmem.nilai_gp = Some(alamat_got + OFFSET_GP_MIPS);
+        }
+        let (jumlah_lokal, gotsym) = match (ambil_dyn(DT_MIPS_LOCAL_GOTNO), ambil_dyn(DT_MIPS_GOTSYM)) {
+            (Some(l), Some(g)) => (l, g),
+            _ => return,
+        };
+        for (i, s) in elf.dynsyms.iter().enumerate().skip(gotsym as usize) {
+            let slot = alamat_got + (jumlah_lokal + i as u64 - gotsym) * 4;
+            if let Some(nama) = elf.dynstrtab.get_at(s.st_name)
+                && !nama.is_empty()
+            {
+                vmem.simbol_global.entry(slot).or_insert_with(|| nama.to_string());
+            }
+        }
+    }
+    fn muat_entry_points(&self, elf: &Elf, vmem: &mut VirtualMemory) {
+        let tabel_pointer: Vec<(u64, u64, JenisEntryPoint)> = vmem.segments.iter()
+            .filter_map(|seg| {
+                let jenis = match seg.nama_section.as_str() {
+                    ".init_array" | ".ctors" | ".preinit_array" => JenisEntryPoint::Konstruktor,
+                    ".fini_array" | ".dtors" => JenisEntryPoint::Destruktor,
+                    _ => return None,
+                };
+                Some((seg.start_addr, seg.data.len() as u64, jenis))
+            })
+            .collect();
+        for (start, ukuran, jenis) in tabel_pointer {
+            vmem.pindai_tabel_pointer_fungsi(start, ukuran, jenis);
+        }
+        if let Some(dynamic) = &elf.dynamic {
+            vmem.tambah_entry_point(dynamic.info.init, JenisEntryPoint::Konstruktor, Some("_init".to_string()));
+            vmem.tambah_entry_point(dynamic.info.fini, JenisEntryPoint::Destruktor, Some("_fini".to_string()));
+        }
+        for s in &elf.dynsyms {
+            let is_export = s.st_type() == sym::STT_FUNC
+                && s.st_shndx != section_header::SHN_UNDEF as usize
+                && matches!(s.st_bind(), sym::STB_GLOBAL | sym::STB_WEAK);
+            if is_export && s.st_value != 0 {
+                let nama = elf.dynstrtab.get_at(s.st_name).filter(|n| !n.is_empty()).map(|n| n.to_string());
+                vmem.tambah_entry_point(s.st_value, JenisEntryPoint::Export, nama);
+            }
+        }
+    }
+    fn muat_relokasi_relatif(&self, elf: &Elf, vmem: &mut VirtualMemory) {
+        let tipe_relatif = match elf.header.e_machine {
+            header::EM_X86_64 => reloc::R_X86_64_RELATIVE,
+            header::EM_386 => reloc::R_386_RELATIVE,
+            header::EM_AARCH64 => reloc::R_AARCH64_RELATIVE,
+            header::EM_ARM => reloc::R_ARM_RELATIVE,
+            header::EM_MIPS => reloc::R_MIPS_REL32,
+            _ => return,
+        };
+        let lebar: u8 = if elf.is_64 { 8 } else { 4 };
+        for rel in elf.dynrelas.iter().chain(elf.dynrels.iter()) {
+            if rel.r_type != tipe_relatif || rel.r_sym != 0 {
+                continue;
+            }
+            if let Some(addend) = rel.r_addend
+                && !vmem.tulis_pointer(rel.r_offset, addend as u64, lebar) {
+                warn!("Relokasi di 0x{:x} di luar segment termuat.", rel.r_offset);
+                continue;
+            }
+            vmem.relokasi.push(EntriRelokasi { alamat: rel.r_offset, lebar });
+        }
     }
 }
\ No newline at end of file
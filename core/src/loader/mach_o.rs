@@ -1,7 +1,7 @@
 use std::fs;
 use std::path::Path;
 use goblin::mach::{Mach, MachO};
-use crate::loader::vmem::{VirtualMemory, IzinAkses};
+use crate::loader::vmem::{VirtualMemory, IzinAkses, JenisEntryPoint};
 use crate::loader::LoaderError;
 use log::{info, error};
 
@@ -79,6 +79,24 @@ impl MachoLoader {
                 }
             }
         }
+        vmem.image_base = macho.segments.iter()
+            .filter(|seg| seg.name().map(|n| n == "__TEXT").unwrap_or(false))
+            .map(|seg| seg.vmaddr)
+            .next()
+            .unwrap_or_else(|| vmem.segments.first().map(|s| s.start_addr).unwrap_or(0));
+        let tabel_pointer: Vec<(u64, u64, JenisEntryPoint)> = vmem.segments.iter()
+            .filter_map(|seg| {
+                let jenis = match seg.nama_section.as_str() {
+                    "__mod_init_func" => JenisEntryPoint::Konstruktor,
+                    "__mod_term_func" => JenisEntryPoint::Destruktor,
+                    _ => return None,
+                };
+                Some((seg.start_addr, seg.data.len() as u64, jenis))
+            })
+            .collect();
+        for (start, ukuran, jenis) in tabel_pointer {
+            vmem.pindai_tabel_pointer_fungsi(start, ukuran, jenis);
+        }
         for sym in macho.symbols() {
             if let Ok((name, nlist)) = sym {
                 if nlist.n_value != 0 && !name.is_empty() {
@@ -3,6 +3,7 @@ pub mod pe;
 pub mod mach_o;
 pub mod dex;
 pub mod vmem;
+pub mod demangle;
 
 use thiserror::Error;
 use std::fs::File;
@@ -29,7 +30,7 @@ pub fn proses_muat_file(file_path: &str) -> Result<VirtualMemory, LoaderError> {
     if file.read_exact(&mut magic).is_err() {
         return Err(LoaderError::InvalidFormat);
     }
-    if magic[0] == 0x7F && magic[1] == b'E' && magic[2] == b'L' && magic[3] == b'F' {
+    let mut vmem = if magic[0] == 0x7F && magic[1] == b'E' && magic[2] == b'L' && magic[3] == b'F' {
         let mut parser = elf::ElfParser::new(file_path);
         parser.muat_virtual_memory()
     } else if magic[0] == 0x4D && magic[1] == 0x5A {
@@ -45,5 +46,8 @@ pub fn proses_muat_file(file_path: &str) -> Result<VirtualMemory, LoaderError> {
         parser.muat_virtual_memory()
     } else {
         Err(LoaderError::InvalidFormat)
-    }
+    }?;
+    vmem.terapkan_demangling();
+    vmem.terapkan_nama_default_entry_points();
+    Ok(vmem)
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use std::fs;
 use std::path::Path;
 use goblin::pe::PE;
-use crate::loader::vmem::{VirtualMemory, IzinAkses};
+use goblin::pe::relocation;
+use crate::loader::vmem::{VirtualMemory, IzinAkses, EntriRelokasi, JenisEntryPoint};
 use crate::loader::LoaderError;
 use log::warn;
 
@@ -25,6 +26,7 @@ impl PeLoader {
         let entry_rva = pe.entry as u64;
         let absolute_entry = image_base.checked_add(entry_rva).ok_or(LoaderError::OutOfBoundsError)?;
         let mut vmem = VirtualMemory::baru(absolute_entry, arch_str, "pe");
+        vmem.image_base = image_base;
         for section in &pe.sections {
             let start = section.pointer_to_raw_data as usize;
             let size = section.size_of_raw_data as usize;
@@ -44,12 +46,46 @@ impl PeLoader {
                 warn!("Section {} melewati batas buffer file. Diabaikan.", section.name().unwrap_or("?"));
             }
         }
+        if let Some(reloc_data) = &pe.relocation_data {
+            for block in reloc_data.blocks() {
+                let block = match block {
+                    Ok(b) => b,
+                    Err(e) => {
+                        warn!("Blok base relocation rusak: {}", e);
+                        break;
+                    }
+                };
+                for word in block.words().flatten() {
+                    let lebar = match word.reloc_type() as u16 {
+                        relocation::IMAGE_REL_BASED_DIR64 => 8,
+                        relocation::IMAGE_REL_BASED_HIGHLOW => 4,
+                        _ => continue,
+                    };
+                    let rva = block.rva as u64 + word.offset() as u64;
+                    vmem.relokasi.push(EntriRelokasi { alamat: image_base.wrapping_add(rva), lebar });
+                }
+            }
+        }
+        if pe.is_lib {
+            vmem.tambah_entry_point(absolute_entry, JenisEntryPoint::Entry, Some("DllMain".to_string()));
+        }
+        if let Some(tls) = &pe.tls_data {
+            for &callback in &tls.callbacks {
+                vmem.tambah_entry_point(callback, JenisEntryPoint::TlsCallback, None);
+            }
+        }
         for export in &pe.exports {
-            if let Some(name) = export.name {
-                let rva = export.rva as u64;
-                if let Some(addr) = image_base.checked_add(rva) {
+            if export.reexport.is_some() {
+                continue;
+            }
+            let rva = export.rva as u64;
+            if let Some(addr) = image_base.checked_add(rva) {
+                if let Some(name) = export.name {
                     vmem.simbol_global.insert(addr, name.to_string());
                 }
+                if vmem.cek_alamat_executable(addr) {
+                    vmem.tambah_entry_point(addr, JenisEntryPoint::Export, export.name.map(|n| n.to_string()));
+                }
             }
         }
         for import in &pe.imports {
@@ -1,4 +1,5 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use crate::loader::demangle::{self, SimbolDemangled};
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -35,24 +36,113 @@ pub struct SegmentMemori {
     pub nama_section: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntriRelokasi {
+    pub alamat: u64,
+    pub lebar: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JenisEntryPoint {
+    Entry,
+    Konstruktor,
+    Destruktor,
+    TlsCallback,
+    Export,
+    SignalHandler,
+}
+
+impl JenisEntryPoint {
+    pub fn sebagai_str(&self) -> &'static str {
+        match self {
+            JenisEntryPoint::Entry => "entry",
+            JenisEntryPoint::Konstruktor => "ctor",
+            JenisEntryPoint::Destruktor => "dtor",
+            JenisEntryPoint::TlsCallback => "tls_callback",
+            JenisEntryPoint::Export => "export",
+            JenisEntryPoint::SignalHandler => "signal_handler",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModeKode {
+    Arm,
+    Thumb,
+    Data,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryPointBiner {
+    pub alamat: u64,
+    pub jenis: JenisEntryPoint,
+    pub nama: Option<String>,
+}
+
 pub struct VirtualMemory {
     pub segments: Vec<SegmentMemori>,
     pub entry_point: u64,
     pub arsitektur: String,
     pub format_biner: String,
     pub simbol_global: BTreeMap<u64, String>,
+    pub image_base: u64,
+    pub relokasi: Vec<EntriRelokasi>,
+    pub info_simbol: BTreeMap<u64, SimbolDemangled>,
+    pub entry_points: Vec<EntryPointBiner>,
+    pub peta_mode_kode: BTreeMap<u64, ModeKode>,
+    pub alamat_thumb: BTreeSet<u64>,
+    pub big_endian: bool,
+    pub nilai_gp: Option<u64>,
 }
 
 impl VirtualMemory {
     pub fn baru(entry: u64, arch: &str, format: &str) -> Self {
+        let mut alamat_thumb = BTreeSet::new();
+        let entry = if crate::arch::is_arm32(arch) && entry & 1 == 1 {
+            alamat_thumb.insert(entry & !1);
+            entry & !1
+        } else {
+            entry
+        };
         Self {
             segments: Vec::new(),
             entry_point: entry,
             arsitektur: arch.to_string(),
             format_biner: format.to_string(),
             simbol_global: BTreeMap::new(),
+            image_base: 0,
+            relokasi: Vec::new(),
+            info_simbol: BTreeMap::new(),
+            entry_points: vec![EntryPointBiner { alamat: entry, jenis: JenisEntryPoint::Entry, nama: None }],
+            peta_mode_kode: BTreeMap::new(),
+            alamat_thumb,
+            big_endian: crate::arch::is_big_endian(arch),
+            nilai_gp: None,
         }
     }
+    pub fn is_arm32(&self) -> bool {
+        crate::arch::is_arm32(&self.arsitektur)
+    }
+    pub fn tandai_mode_kode(&mut self, alamat: u64, mode: ModeKode) {
+        self.peta_mode_kode.insert(alamat, mode);
+    }
+    pub fn ambil_mode_kode(&self, alamat: u64) -> Option<ModeKode> {
+        if self.alamat_thumb.contains(&alamat) {
+            return Some(ModeKode::Thumb);
+        }
+        let seg = self.temukan_segment_target(alamat)?;
+        self.peta_mode_kode.range(seg.start_addr..=alamat).next_back().map(|(_, mode)| *mode)
+    }
+    pub fn is_thumb(&self, alamat: u64) -> bool {
+        self.ambil_mode_kode(alamat) == Some(ModeKode::Thumb)
+    }
+    pub fn normalisasi_alamat_kode(&mut self, alamat: u64) -> u64 {
+        if self.is_arm32() && alamat & 1 == 1 {
+            self.alamat_thumb.insert(alamat & !1);
+            return alamat & !1;
+        }
+        alamat
+    }
     pub fn tambah_segment(&mut self, start: u64, data: Vec<u8>, perm: IzinAkses, nama: String) {
         let end = start + data.len() as u64;
         self.segments.push(SegmentMemori {
@@ -79,6 +169,15 @@ impl VirtualMemory {
             Err(_) => None,
         }
     }
+    pub fn cek_alamat_valid(&self, addr: u64) -> bool {
+        self.temukan_segment_target(addr).is_some()
+    }
+    pub fn cek_alamat_executable(&self, addr: u64) -> bool {
+        match self.temukan_segment_target(addr) {
+            Some(seg) => matches!(seg.permissions, IzinAkses::Execute | IzinAkses::ReadExecute | IzinAkses::Full),
+            None => false,
+        }
+    }
     pub fn baca_byte(&self, addr: u64) -> Option<u8> {
         if let Some(seg) = self.temukan_segment_target(addr) {
             let offset = (addr - seg.start_addr) as usize;
@@ -95,6 +194,161 @@ impl VirtualMemory {
         }
         None
     }
+    pub fn baca_slice(&self, addr: u64, len_maks: usize) -> Option<&[u8]> {
+        let seg = self.temukan_segment_target(addr)?;
+        let offset = (addr - seg.start_addr) as usize;
+        if offset >= seg.data.len() {
+            return None;
+        }
+        let akhir = (offset + len_maks).min(seg.data.len());
+        Some(&seg.data[offset..akhir])
+    }
+    pub fn tulis_array(&mut self, addr: u64, bytes: &[u8]) -> bool {
+        let idx = match self.segments.iter().position(|s| addr >= s.start_addr && addr < s.end_addr) {
+            Some(i) => i,
+            None => return false,
+        };
+        let seg = &mut self.segments[idx];
+        let offset = (addr - seg.start_addr) as usize;
+        if offset + bytes.len() > seg.data.len() {
+            return false;
+        }
+        seg.data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        true
+    }
+    pub fn baca_pointer(&self, addr: u64, lebar: u8) -> Option<u64> {
+        let raw = self.baca_array(addr, lebar as usize)?;
+        let mut buf = [0u8; 8];
+        if self.big_endian {
+            buf[8 - raw.len()..].copy_from_slice(&raw);
+            return Some(u64::from_be_bytes(buf));
+        }
+        buf[..raw.len()].copy_from_slice(&raw);
+        Some(u64::from_le_bytes(buf))
+    }
+    pub fn tulis_pointer(&mut self, addr: u64, nilai: u64, lebar: u8) -> bool {
+        if self.big_endian {
+            let bytes = nilai.to_be_bytes();
+            return self.tulis_array(addr, &bytes[8 - lebar as usize..]);
+        }
+        let bytes = nilai.to_le_bytes();
+        self.tulis_array(addr, &bytes[..lebar as usize])
+    }
+    pub fn rebase_ke(&mut self, base_baru: u64) -> u64 {
+        let delta = base_baru.wrapping_sub(self.image_base);
+        self.geser_image(delta);
+        delta
+    }
+    pub fn geser_image(&mut self, delta: u64) {
+        if delta == 0 {
+            return;
+        }
+        let daftar_relokasi = self.relokasi.clone();
+        for rel in &daftar_relokasi {
+            if let Some(nilai) = self.baca_pointer(rel.alamat, rel.lebar) {
+                let mask = if rel.lebar >= 8 { u64::MAX } else { (1u64 << (rel.lebar as u32 * 8)) - 1 };
+                self.tulis_pointer(rel.alamat, nilai.wrapping_add(delta) & mask, rel.lebar);
+            }
+        }
+        for seg in &mut self.segments {
+            seg.start_addr = seg.start_addr.wrapping_add(delta);
+            seg.end_addr = seg.end_addr.wrapping_add(delta);
+        }
+        self.segments.sort_by_key(|s| s.start_addr);
+        for rel in &mut self.relokasi {
+            rel.alamat = rel.alamat.wrapping_add(delta);
+        }
+        self.simbol_global = std::mem::take(&mut self.simbol_global)
+            .into_iter()
+            .map(|(addr, nama)| (addr.wrapping_add(delta), nama))
+            .collect();
+        self.info_simbol = std::mem::take(&mut self.info_simbol)
+            .into_iter()
+            .map(|(addr, info)| (addr.wrapping_add(delta), info))
+            .collect();
+        for ep in &mut self.entry_points {
+            ep.alamat = ep.alamat.wrapping_add(delta);
+        }
+        self.peta_mode_kode = std::mem::take(&mut self.peta_mode_kode)
+            .into_iter()
+            .map(|(addr, mode)| (addr.wrapping_add(delta), mode))
+            .collect();
+        self.alamat_thumb = std::mem::take(&mut self.alamat_thumb)
+            .into_iter()
+            .map(|addr| addr.wrapping_add(delta))
+            .collect();
+        self.nilai_gp = self.nilai_gp.map(|gp| gp.wrapping_add(delta));
+        self.entry_point = self.entry_point.wrapping_add(delta);
+        self.image_base = self.image_base.wrapping_add(delta);
+    }
+    pub fn tambah_entry_point(&mut self, alamat: u64, jenis: JenisEntryPoint, nama: Option<String>) {
+        if alamat == 0 {
+            return;
+        }
+        let alamat = self.normalisasi_alamat_kode(alamat);
+        if let Some(ep) = self.entry_points.iter_mut().find(|ep| ep.alamat == alamat && ep.jenis == jenis) {
+            if ep.nama.is_none() {
+                ep.nama = nama;
+            }
+            return;
+        }
+        self.entry_points.push(EntryPointBiner { alamat, jenis, nama });
+    }
+    pub fn atur_entry_point_utama(&mut self, alamat: u64) {
+        self.entry_point = alamat;
+        self.entry_points.retain(|ep| ep.jenis != JenisEntryPoint::Entry);
+        self.entry_points.insert(0, EntryPointBiner { alamat, jenis: JenisEntryPoint::Entry, nama: None });
+    }
+    pub fn pindai_tabel_pointer_fungsi(&mut self, start: u64, ukuran: u64, jenis: JenisEntryPoint) {
+        let lebar: u8 = if self.arsitektur.contains("64") { 8 } else { 4 };
+        let sentinel = if lebar == 8 { u64::MAX } else { u32::MAX as u64 };
+        let mut offset = 0;
+        while offset + lebar as u64 <= ukuran {
+            if let Some(target) = self.baca_pointer(start + offset, lebar)
+                && target != 0
+                && target != sentinel
+                && self.cek_alamat_executable(target)
+            {
+                self.tambah_entry_point(target, jenis, None);
+            }
+            offset += lebar as u64;
+        }
+    }
+    pub fn terapkan_nama_default_entry_points(&mut self) {
+        for ep in &self.entry_points {
+            if self.simbol_global.contains_key(&ep.alamat) {
+                continue;
+            }
+            let nama = match (&ep.nama, ep.jenis) {
+                (Some(n), _) => n.clone(),
+                (None, JenisEntryPoint::Entry) => continue,
+                (None, jenis) => format!("{}_{:x}", jenis.sebagai_str(), ep.alamat),
+            };
+            self.simbol_global.insert(ep.alamat, nama);
+        }
+    }
+    pub fn terapkan_demangling(&mut self) {
+        let mut nama_terpakai: HashSet<String> = self.simbol_global.values()
+            .filter(|n| demangle::demangle_simbol(n).is_none())
+            .cloned()
+            .collect();
+        for (addr, nama) in self.simbol_global.iter_mut() {
+            let mut info = match demangle::demangle_simbol(nama) {
+                Some(i) => i,
+                None => continue,
+            };
+            let mut kandidat = info.nama_bersih.clone();
+            let mut counter = 2;
+            while nama_terpakai.contains(&kandidat) {
+                kandidat = format!("{}_{}", info.nama_bersih, counter);
+                counter += 1;
+            }
+            nama_terpakai.insert(kandidat.clone());
+            info.nama_bersih = kandidat.clone();
+            *nama = kandidat;
+            self.info_simbol.insert(*addr, info);
+        }
+    }
     pub fn ambil_executable_regions(&self) -> Vec<(u64, &[u8])> {
         self.segments.iter()
             .filter(|s| match s.permissions {
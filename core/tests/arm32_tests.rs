@@ -0,0 +1,185 @@
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses, ModeKode};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::analysis::recovery::abi::{ProfilAbi, TipeAbi};
+use rcdecomp_core::arch::arm::Arm32Arsitektur;
+use rcdecomp_core::arch::Architecture;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, TipeOperand, TipeDataIr, StatementIr};
+
+const KODE_CAMPURAN: [u8; 62] = [
+    0x80, 0xB5,
+    0x00, 0xAF,
+    0x00, 0x28,
+    0x08, 0xBF,
+    0x01, 0x20,
+    0x02, 0x49,
+    0x40, 0x18,
+    0x00, 0xF0, 0x04, 0xE8,
+    0x80, 0xBD,
+    0x78, 0x56, 0x34, 0x12,
+    0x10, 0x40, 0x2D, 0xE9,
+    0x10, 0x40, 0x9F, 0xE5,
+    0x04, 0x00, 0x50, 0xE1,
+    0x04, 0x00, 0x80, 0x12,
+    0x04, 0x20, 0x91, 0xE4,
+    0x01, 0x00, 0x00, 0xFA,
+    0x10, 0x80, 0xBD, 0xE8,
+    0xEF, 0xBE, 0xAD, 0xDE,
+    0x01, 0x38,
+    0xFD, 0xD1,
+    0x70, 0x47,
+];
+
+fn buat_vmem_arm(dengan_simbol_pemetaan: bool) -> VirtualMemory {
+    let mut vmem = VirtualMemory::baru(0x8001, "arm", "elf");
+    vmem.tambah_segment(0x8000, KODE_CAMPURAN.to_vec(), IzinAkses::ReadExecute, ".text".to_string());
+    if dengan_simbol_pemetaan {
+        vmem.tandai_mode_kode(0x8000, ModeKode::Thumb);
+        vmem.tandai_mode_kode(0x8014, ModeKode::Data);
+        vmem.tandai_mode_kode(0x8018, ModeKode::Arm);
+        vmem.tandai_mode_kode(0x8034, ModeKode::Data);
+        vmem.tandai_mode_kode(0x8038, ModeKode::Thumb);
+    }
+    vmem
+}
+
+fn jalankan_analisis(vmem: &VirtualMemory) -> RecursiveDescent {
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(vmem);
+    analyzer
+}
+
+fn ambil_ir<'a>(analyzer: &'a RecursiveDescent, addr: u64) -> &'a [StatementIr] {
+    &analyzer.ambil_hasil_fungsi().get(&addr).expect("fungsi harus terdeteksi").ir_code
+}
+
+#[test]
+fn test_interworking_thumb_arm() {
+    let vmem = buat_vmem_arm(false);
+    assert_eq!(vmem.entry_point, 0x8000, "bit thumb harus dibuang dari entry point");
+    let analyzer = jalankan_analisis(&vmem);
+    let fungsi = analyzer.ambil_hasil_fungsi();
+    assert!(fungsi.contains_key(&0x8000));
+    assert!(fungsi.contains_key(&0x8018), "target blx dari thumb harus terdeteksi");
+    assert!(fungsi.contains_key(&0x8038), "target blx dari arm harus terdeteksi");
+    assert!(analyzer.is_fungsi_thumb(0x8000));
+    assert!(!analyzer.is_fungsi_thumb(0x8018));
+    assert!(analyzer.is_fungsi_thumb(0x8038));
+    let ir_arm = ambil_ir(&analyzer, 0x8018);
+    assert!(ir_arm.iter().any(|s| s.operation_code == OperasiIr::Call && s.operand_satu == TipeOperand::Immediate(0x8038)));
+    assert!(ir_arm.iter().any(|s| s.operation_code == OperasiIr::Ret));
+}
+
+#[test]
+fn test_predikat_blok_it_dan_kondisional_arm() {
+    let vmem = buat_vmem_arm(true);
+    let analyzer = jalankan_analisis(&vmem);
+    let ir_thumb = ambil_ir(&analyzer, 0x8000);
+    let moveq = ir_thumb.iter().find(|s| s.address_asal == 0x8008).expect("instruksi dalam blok IT harus di-lift");
+    assert_eq!(moveq.operation_code, OperasiIr::Cmov);
+    assert!(!ir_thumb.iter().any(|s| s.address_asal == 0x8008 && s.operand_satu == TipeOperand::Register("nzcv_z".to_string())),
+        "instruksi 16-bit di dalam blok IT tidak boleh mengubah flag");
+    let ir_arm = ambil_ir(&analyzer, 0x8018);
+    let addne = ir_arm.iter().find(|s| s.address_asal == 0x8024).unwrap();
+    assert_eq!(addne.operation_code, OperasiIr::Cmov);
+    assert_eq!(addne.operand_satu, TipeOperand::Register("r0".to_string()));
+}
+
+#[test]
+fn test_literal_pool_dilipat_menjadi_konstanta() {
+    for dengan_simbol in [true, false] {
+        let vmem = buat_vmem_arm(dengan_simbol);
+        let analyzer = jalankan_analisis(&vmem);
+        let ir_thumb = ambil_ir(&analyzer, 0x8000);
+        assert!(ir_thumb.iter().any(|s| s.operand_satu == TipeOperand::Register("r1".to_string())
+            && s.operand_dua == TipeOperand::Immediate(0x12345678)));
+        let ir_arm = ambil_ir(&analyzer, 0x8018);
+        assert!(ir_arm.iter().any(|s| s.operand_satu == TipeOperand::Register("r4".to_string())
+            && s.operand_dua == TipeOperand::Immediate(0xDEADBEEF)));
+        for ctx in analyzer.ambil_hasil_fungsi().values() {
+            assert!(!ctx.ir_code.iter().any(|s| (0x8014..0x8018).contains(&s.address_asal) || (0x8034..0x8038).contains(&s.address_asal)),
+                "literal pool tidak boleh didisassembly sebagai kode");
+        }
+        assert!(!analyzer.ambil_hasil_fungsi().contains_key(&0x8014));
+        assert!(!analyzer.ambil_hasil_fungsi().contains_key(&0x8034));
+    }
+}
+
+#[test]
+fn test_mode_kode_dari_simbol_pemetaan() {
+    let mut vmem = buat_vmem_arm(true);
+    assert_eq!(vmem.ambil_mode_kode(0x8002), Some(ModeKode::Thumb));
+    assert_eq!(vmem.ambil_mode_kode(0x8016), Some(ModeKode::Data));
+    assert_eq!(vmem.ambil_mode_kode(0x8020), Some(ModeKode::Arm));
+    assert!(vmem.is_thumb(0x803A));
+    assert_eq!(vmem.ambil_mode_kode(0x9000), None);
+    assert_eq!(vmem.normalisasi_alamat_kode(0x8039), 0x8038);
+    let engine = DisasmEngine::buat_engine_baru("arm");
+    let instr_thumb = engine.ambil_satu_instruksi_mode(&KODE_CAMPURAN[0x38..], 0x8038, true).unwrap();
+    assert_eq!(instr_thumb.mnemonic, "sub");
+    assert!(instr_thumb.update_flags);
+    let instr_arm = engine.ambil_satu_instruksi_mode(&KODE_CAMPURAN[0x24..], 0x8024, false).unwrap();
+    assert_eq!(instr_arm.mnemonic, "add");
+    assert_eq!(instr_arm.kondisi.as_deref(), Some("ne"));
+}
+
+#[test]
+fn test_profil_abi_aapcs32() {
+    let arch_thumb = Arm32Arsitektur::new(true);
+    assert_eq!(arch_thumb.dapatkan_frame_pointer(), "r7");
+    assert_eq!(Arm32Arsitektur::new(false).dapatkan_frame_pointer(), "r11");
+    let profil = ProfilAbi::buat_profil_otomatis(&arch_thumb, "elf");
+    assert_eq!(profil.tipe, TipeAbi::Arm32Aapcs);
+    assert_eq!(profil.register_integer, vec!["r0", "r1", "r2", "r3"]);
+    assert!(profil.volatile_registers.contains("r12"));
+    assert!(!profil.volatile_registers.contains("r4"));
+}
+
+fn lift_arm(bytes: &[u8]) -> Vec<StatementIr> {
+    let engine = DisasmEngine::buat_engine_baru("arm");
+    let instr = engine.ambil_satu_instruksi(bytes, 0x1000).expect("instruksi harus terdekode");
+    IrLifter::untuk_arsitektur("arm").konversi_instruksi_ke_microcode(&instr)
+}
+
+fn ambil_tulis_flag<'a>(ir: &'a [StatementIr], flag: &str) -> &'a TipeOperand {
+    &ir.iter().find(|s| s.operand_satu == TipeOperand::Register(flag.to_string())).unwrap_or_else(|| panic!("{} harus ditulis: {:?}", flag, ir)).operand_dua
+}
+
+#[test]
+fn test_ekstensi_byte_halfword_arm() {
+    let kasus: [([u8; 4], OperasiIr, i64); 2] = [
+        ([0x71, 0x00, 0xAF, 0xE6], OperasiIr::SignExtend, 8),
+        ([0x73, 0x20, 0xFF, 0xE6], OperasiIr::ZeroExtend, 16),
+    ];
+    for (bytes, operasi, lebar) in kasus {
+        let ir = lift_arm(&bytes);
+        assert_eq!(ir[0].tipe_hasil, TipeDataIr::I32);
+        match &ir[0].operand_dua {
+            TipeOperand::Expression { operasi: op, operand_kanan, .. } => {
+                assert_eq!(*op, operasi);
+                assert_eq!(**operand_kanan, TipeOperand::Immediate(lebar));
+            },
+            lain => panic!("ekstensi harus berupa ekspresi lebar: {:?}", lain),
+        }
+    }
+}
+
+#[test]
+fn test_flag_nzcv_arm32_carry_bukan_pinjam() {
+    let ir = lift_arm(&[0x01, 0x00, 0x50, 0xE0]);
+    assert!(!ir.iter().any(|s| matches!(&s.operand_satu, TipeOperand::Register(r) if r.starts_with("eflags_"))),
+        "ARM32 tidak boleh memakai flag x86: {:?}", ir);
+    assert!(matches!(ambil_tulis_flag(&ir, "nzcv_c"), TipeOperand::Expression { operasi: OperasiIr::Jae, .. }),
+        "C pada subs adalah NOT borrow");
+    ambil_tulis_flag(&ir, "nzcv_v");
+    let cmp = lift_arm(&[0x04, 0x00, 0x50, 0xE3]);
+    assert_eq!(cmp[0].operation_code, OperasiIr::Cmp);
+    ambil_tulis_flag(&cmp, "nzcv_z");
+    let bhs = lift_arm(&[0x02, 0x00, 0x00, 0x2A]);
+    assert_eq!(bhs[0].operand_dua, TipeOperand::Expression {
+        operasi: OperasiIr::Je,
+        operand_kiri: Box::new(TipeOperand::Register("nzcv_c".to_string())),
+        operand_kanan: Box::new(TipeOperand::Immediate(1)),
+    });
+}
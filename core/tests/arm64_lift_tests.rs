@@ -0,0 +1,381 @@
+use std::collections::HashMap;
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::analysis::recovery::xref::JenisXref;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::disasm::instruction::GeserOperand;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::lift::arm64::kondisi_nzcv;
+use rcdecomp_core::ir::types::{OperasiIr, StatementIr, TipeDataIr, TipeOperand};
+
+const ADD_X0_X1_X2_LSL3: [u8; 4] = [0x20, 0x0C, 0x02, 0x8B];
+const ADD_X0_X1_W2_SXTW2: [u8; 4] = [0x20, 0xC8, 0x22, 0x8B];
+const ADD_W0_W1_1: [u8; 4] = [0x20, 0x04, 0x00, 0x11];
+const SUBS_X0_X1_X2: [u8; 4] = [0x20, 0x00, 0x02, 0xEB];
+const CMP_X0_X1: [u8; 4] = [0x1F, 0x00, 0x01, 0xEB];
+const ADDS_W0_W1_W2: [u8; 4] = [0x20, 0x00, 0x02, 0x2B];
+const ADCS_X0_X1_X2: [u8; 4] = [0x20, 0x00, 0x02, 0xBA];
+const SBCS_X0_X1_X2: [u8; 4] = [0x20, 0x00, 0x02, 0xFA];
+const CMN_W0_1: [u8; 4] = [0x1F, 0x04, 0x00, 0x31];
+const TST_W0_SIGN: [u8; 4] = [0x1F, 0x00, 0x01, 0x72];
+const CCMP_X1_2_0_EQ: [u8; 4] = [0x20, 0x08, 0x42, 0xFA];
+const CSEL_X0_X1_X2_LT: [u8; 4] = [0x20, 0xB0, 0x82, 0x9A];
+const CSINC_X0_X1_X2_EQ: [u8; 4] = [0x20, 0x04, 0x82, 0x9A];
+const CSET_W0_HI: [u8; 4] = [0xE0, 0x97, 0x9F, 0x1A];
+const CNEG_X0_X1_MI: [u8; 4] = [0x20, 0x54, 0x81, 0xDA];
+const MADD_X0: [u8; 4] = [0x20, 0x0C, 0x02, 0x9B];
+const MSUB_X0: [u8; 4] = [0x20, 0x8C, 0x02, 0x9B];
+const SMULL_X0_W1_W2: [u8; 4] = [0x20, 0x7C, 0x22, 0x9B];
+const SMULH_X0_X1_X2: [u8; 4] = [0x20, 0x7C, 0x42, 0x9B];
+const UMULH_X0_X1_X2: [u8; 4] = [0x20, 0x7C, 0xC2, 0x9B];
+const SDIV_W0_W1_W2: [u8; 4] = [0x20, 0x0C, 0xC2, 0x1A];
+const UBFX_X0_X1_4_8: [u8; 4] = [0x20, 0x2C, 0x44, 0xD3];
+const SBFX_X0_X1_4_4: [u8; 4] = [0x20, 0x1C, 0x44, 0x93];
+const BFI_X0_X1_8_4: [u8; 4] = [0x20, 0x0C, 0x78, 0xB3];
+const BFXIL_W0_W1_4_8: [u8; 4] = [0x20, 0x2C, 0x04, 0x33];
+const UBFIZ_X0_X1_3_5: [u8; 4] = [0x20, 0x10, 0x7D, 0xD3];
+const ASR_W0_W1_4: [u8; 4] = [0x20, 0x7C, 0x04, 0x13];
+const ROR_W0_W1_8: [u8; 4] = [0x20, 0x20, 0x81, 0x13];
+const MOVK_X0_LSL16: [u8; 4] = [0x80, 0x46, 0xA2, 0xF2];
+const MVN_X0_X1: [u8; 4] = [0xE0, 0x03, 0x21, 0xAA];
+const NEG_W0_W1: [u8; 4] = [0xE0, 0x03, 0x01, 0x4B];
+const ORR_X0_XZR_FF: [u8; 4] = [0xE0, 0x1F, 0x40, 0xB2];
+const EON_X0_X1_X2_LSR4: [u8; 4] = [0x20, 0x10, 0x62, 0xCA];
+const LDR_X0_PRE: [u8; 4] = [0x20, 0x8C, 0x40, 0xF8];
+const LDR_X0_POST: [u8; 4] = [0x20, 0x84, 0x40, 0xF8];
+const LDRSB_X0: [u8; 4] = [0x20, 0x00, 0x80, 0x39];
+const STP_FP_LR_PRE: [u8; 4] = [0xFD, 0x7B, 0xBF, 0xA9];
+const LDP_FP_LR_POST: [u8; 4] = [0xFD, 0x7B, 0xC1, 0xA8];
+const STR_WZR: [u8; 4] = [0x3F, 0x04, 0x00, 0xB9];
+const TBZ_W0_3: [u8; 4] = [0x00, 0x02, 0x18, 0x36];
+const TBNZ_X0_40: [u8; 4] = [0x00, 0x02, 0x40, 0xB7];
+const B_GT: [u8; 4] = [0x0C, 0x02, 0x00, 0x54];
+const RET: [u8; 4] = [0xC0, 0x03, 0x5F, 0xD6];
+
+const KODE_ADRP: [u8; 44] = [
+    0x00, 0x00, 0x00, 0xB0,
+    0x00, 0x40, 0x00, 0x91,
+    0x13, 0x00, 0x00, 0xB0,
+    0x62, 0x12, 0x40, 0xF9,
+    0x62, 0x2A, 0x00, 0xB9,
+    0x01, 0x00, 0x00, 0xB0,
+    0x04, 0x00, 0x00, 0x94,
+    0x63, 0x06, 0x40, 0xF9,
+    0x24, 0x04, 0x40, 0xF9,
+    0xC0, 0x03, 0x5F, 0xD6,
+    0xC0, 0x03, 0x5F, 0xD6,
+];
+
+#[derive(Default)]
+struct Mesin {
+    reg: HashMap<String, i64>,
+    mem: HashMap<u64, u8>,
+}
+
+impl Mesin {
+    fn baru(awal: &[(&str, i64)]) -> Self {
+        Self { reg: awal.iter().map(|(n, v)| (n.to_string(), *v)).collect(), mem: HashMap::new() }
+    }
+    fn r(&self, nama: &str) -> i64 {
+        self.reg.get(nama).copied().unwrap_or(0)
+    }
+    fn baca(&self, alamat: u64, bit: u32) -> i64 {
+        (0..bit / 8).fold(0u64, |acc, i| acc | (*self.mem.get(&(alamat + i as u64)).unwrap_or(&0) as u64) << (8 * i)) as i64
+    }
+    fn tulis(&mut self, alamat: u64, bit: u32, nilai: i64) {
+        for i in 0..bit / 8 {
+            self.mem.insert(alamat + i as u64, (nilai >> (8 * i)) as u8);
+        }
+    }
+    fn alamat(&self, op: &TipeOperand) -> Option<u64> {
+        match op {
+            TipeOperand::MemoryRef { base, offset } => Some(self.r(base).wrapping_add(*offset) as u64),
+            TipeOperand::Memory(a) => Some(*a),
+            _ => None,
+        }
+    }
+    fn evaluasi(&self, op: &TipeOperand, bit: u32) -> i64 {
+        if let Some(alamat) = self.alamat(op) {
+            return self.baca(alamat, bit);
+        }
+        match op {
+            TipeOperand::Immediate(v) => *v,
+            TipeOperand::Register(r) => self.r(r),
+            TipeOperand::Conditional { condition, true_val, false_val } => {
+                if self.evaluasi(condition, 64) != 0 { self.evaluasi(true_val, bit) } else { self.evaluasi(false_val, bit) }
+            },
+            TipeOperand::Expression { operasi, operand_kiri, operand_kanan } => {
+                let bit_kiri = match (operasi.is_operasi_lebar(), &**operand_kanan) {
+                    (true, TipeOperand::Immediate(lebar)) => *lebar as u32,
+                    _ => bit,
+                };
+                let a = self.evaluasi(operand_kiri, bit_kiri);
+                let b = self.evaluasi(operand_kanan, bit);
+                let (ua, ub) = (a as u64, b as u64);
+                match operasi {
+                    OperasiIr::Add => a.wrapping_add(b),
+                    OperasiIr::Sub => a.wrapping_sub(b),
+                    OperasiIr::Imul => a.wrapping_mul(b),
+                    OperasiIr::MulHi => ((a as i128 * b as i128) >> 64) as i64,
+                    OperasiIr::MulHiU => ((ua as u128 * ub as u128) >> 64) as i64,
+                    OperasiIr::Idiv => a.wrapping_div(b),
+                    OperasiIr::Div => (ua / ub) as i64,
+                    OperasiIr::And => a & b,
+                    OperasiIr::Or => a | b,
+                    OperasiIr::Xor => a ^ b,
+                    OperasiIr::Shl => a.wrapping_shl(b as u32),
+                    OperasiIr::Shr => ua.wrapping_shr(b as u32) as i64,
+                    OperasiIr::Sar => a.wrapping_shr(b as u32),
+                    OperasiIr::Ror => ua.rotate_right(b as u32) as i64,
+                    OperasiIr::Je => (a == b) as i64,
+                    OperasiIr::Jne => (a != b) as i64,
+                    OperasiIr::Jb => (ua < ub) as i64,
+                    OperasiIr::Jae => (ua >= ub) as i64,
+                    OperasiIr::Ja => (ua > ub) as i64,
+                    lain => lain.evaluasi_lebar(a, b).unwrap_or_else(|| panic!("operasi {:?} tidak didukung evaluator test", lain)),
+                }
+            },
+            lain => panic!("operand {:?} tidak didukung evaluator test", lain),
+        }
+    }
+    fn jalankan(&mut self, ir: &[StatementIr]) {
+        for stmt in ir {
+            if stmt.operation_code.is_jump_kondisi() || matches!(stmt.operation_code, OperasiIr::Jmp | OperasiIr::Call | OperasiIr::Ret) {
+                continue;
+            }
+            let bit = match stmt.tipe_hasil {
+                TipeDataIr::I8 => 8,
+                TipeDataIr::I16 => 16,
+                TipeDataIr::I32 => 32,
+                _ => 64,
+            };
+            let nilai = self.evaluasi(&stmt.operand_dua, bit);
+            if let Some(alamat) = self.alamat(&stmt.operand_satu) {
+                self.tulis(alamat, bit, nilai);
+            } else if let TipeOperand::Register(dest) = &stmt.operand_satu {
+                self.reg.insert(dest.clone(), nilai);
+            }
+        }
+    }
+    fn flags(&self) -> [i64; 4] {
+        ["nzcv_n", "nzcv_z", "nzcv_c", "nzcv_v"].map(|f| self.r(f))
+    }
+}
+
+fn lift(bytes: &[u8]) -> Vec<StatementIr> {
+    let engine = DisasmEngine::buat_engine_baru("aarch64");
+    let instr = engine.ambil_satu_instruksi(bytes, 0x1000).expect("instruksi harus terdekode");
+    IrLifter::untuk_arsitektur("aarch64").konversi_instruksi_ke_microcode(&instr)
+}
+
+fn jalankan(bytes: &[u8], awal: &[(&str, i64)]) -> Mesin {
+    let mut mesin = Mesin::baru(awal);
+    mesin.jalankan(&lift(bytes));
+    mesin
+}
+
+fn tidak_ada_unknown(ir: &[StatementIr]) -> bool {
+    ir.iter().all(|s| s.operation_code != OperasiIr::Unknown)
+}
+
+#[test]
+fn test_aritmetika_tiga_operand_dan_operand_tergeser() {
+    assert_eq!(jalankan(&ADD_X0_X1_X2_LSL3, &[("x1", 100), ("x2", 5)]).r("x0"), 140);
+    assert_eq!(jalankan(&ADD_X0_X1_W2_SXTW2, &[("x1", 100), ("x2", 0xFFFF_FFFF)]).r("x0"), 96, "w2 = -1 diperluas bertanda lalu digeser");
+    assert_eq!(jalankan(&ADD_W0_W1_1, &[("x0", 7), ("x1", -1)]).r("x0"), 0, "add w0 memotong ke 32 bit dan membuang bit atas");
+    assert_eq!(jalankan(&ADD_W0_W1_1, &[("x1", 41)]).r("x0"), 42);
+    assert_eq!(jalankan(&EON_X0_X1_X2_LSR4, &[("x1", 0xF0), ("x2", 0xF00)]).r("x0"), 0xF0 ^ !0xF0);
+    assert_eq!(jalankan(&MVN_X0_X1, &[("x1", 0x1234)]).r("x0"), !0x1234);
+    assert_eq!(jalankan(&NEG_W0_W1, &[("x1", 5)]).r("x0"), 0xFFFF_FFFB);
+    assert_eq!(jalankan(&ORR_X0_XZR_FF, &[("x0", -1)]).r("x0"), 0xFF);
+    assert_eq!(jalankan(&ASR_W0_W1_4, &[("x1", 0x8000_0000)]).r("x0"), 0xF800_0000);
+    assert_eq!(jalankan(&ROR_W0_W1_8, &[("x1", 0x1234_5678)]).r("x0"), 0x7812_3456);
+    assert_eq!(jalankan(&MOVK_X0_LSL16, &[("x0", 0x7777_0000_FFFF)]).r("x0"), 0x7777_1234_FFFF);
+    for bytes in [ADD_X0_X1_X2_LSL3, ADD_W0_W1_1, MVN_X0_X1, ROR_W0_W1_8, MOVK_X0_LSL16] {
+        assert!(tidak_ada_unknown(&lift(&bytes)));
+    }
+}
+
+#[test]
+fn test_flag_nzcv_mengikuti_semantik_arm() {
+    let kondisi = ["eq", "ne", "hs", "lo", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le"];
+    let pasangan = [(5i64, 5i64), (3, 7), (7, 3), (-1, 1), (i64::MIN, 1), (0, -1), (i64::MAX, -1)];
+    for (a, b) in pasangan {
+        let mesin = jalankan(&CMP_X0_X1, &[("x0", a), ("x1", b)]);
+        let (ua, ub) = (a as u64, b as u64);
+        let selisih = a.wrapping_sub(b);
+        let harapan = [
+            a == b, a != b, ua >= ub, ua < ub, selisih < 0, selisih >= 0,
+            a.checked_sub(b).is_none(), a.checked_sub(b).is_some(),
+            ua > ub, ua <= ub, a >= b, a < b, a > b, a <= b,
+        ];
+        for (k, harap) in kondisi.iter().zip(harapan) {
+            assert_eq!(mesin.evaluasi(&kondisi_nzcv(k), 64) != 0, harap, "cmp {} {} kondisi {}", a, b, k);
+        }
+    }
+    let subs = jalankan(&SUBS_X0_X1_X2, &[("x1", 3), ("x2", 3)]);
+    assert_eq!((subs.r("x0"), subs.flags()), (0, [0, 1, 1, 0]), "sub tanpa pinjam men-set C");
+    let adds = jalankan(&ADDS_W0_W1_W2, &[("x1", 0x7FFF_FFFF), ("x2", 1)]);
+    assert_eq!((adds.r("x0"), adds.flags()), (0x8000_0000, [1, 0, 0, 1]));
+    let cmn = jalankan(&CMN_W0_1, &[("x0", 0xFFFF_FFFF)]);
+    assert_eq!(cmn.flags(), [0, 1, 1, 0]);
+    let tst = jalankan(&TST_W0_SIGN, &[("x0", 0x8000_0000), ("nzcv_c", 1), ("nzcv_v", 1)]);
+    assert_eq!(tst.flags(), [1, 0, 0, 0]);
+}
+
+#[test]
+fn test_adcs_sbcs_memakai_carry_nzcv() {
+    let adcs = jalankan(&ADCS_X0_X1_X2, &[("x1", -1), ("x2", 0), ("nzcv_c", 1)]);
+    assert_eq!((adcs.r("x0"), adcs.flags()), (0, [0, 1, 1, 0]));
+    let sbcs = jalankan(&SBCS_X0_X1_X2, &[("x1", 5), ("x2", 5), ("nzcv_c", 0)]);
+    assert_eq!((sbcs.r("x0"), sbcs.flags()), (-1, [1, 0, 0, 0]), "C = 0 berarti pinjam satu");
+    let sbcs = jalankan(&SBCS_X0_X1_X2, &[("x1", 5), ("x2", 5), ("nzcv_c", 1)]);
+    assert_eq!((sbcs.r("x0"), sbcs.flags()), (0, [0, 1, 1, 0]));
+}
+
+#[test]
+fn test_ccmp_memilih_flag_perbandingan_atau_immediate() {
+    let ir = lift(&CCMP_X1_2_0_EQ);
+    assert!(ir.iter().any(|s| s.operation_code == OperasiIr::Cmp));
+    let benar = jalankan(&CCMP_X1_2_0_EQ, &[("nzcv_z", 1), ("x1", 2)]);
+    assert_eq!(benar.flags(), [0, 1, 1, 0]);
+    let salah = jalankan(&CCMP_X1_2_0_EQ, &[("nzcv_z", 0), ("nzcv_c", 1), ("x1", 2)]);
+    assert_eq!(salah.flags(), [0, 0, 0, 0], "kondisi gagal memakai nzcv dari immediate");
+}
+
+#[test]
+fn test_pilih_bersyarat() {
+    let awal = [("x1", 10), ("x2", 20)];
+    assert_eq!(jalankan(&CSEL_X0_X1_X2_LT, &[awal[0], awal[1], ("nzcv_n", 1)]).r("x0"), 10);
+    assert_eq!(jalankan(&CSEL_X0_X1_X2_LT, &[awal[0], awal[1], ("nzcv_n", 1), ("nzcv_v", 1)]).r("x0"), 20);
+    assert_eq!(jalankan(&CSINC_X0_X1_X2_EQ, &[awal[0], awal[1], ("nzcv_z", 0)]).r("x0"), 21);
+    assert_eq!(jalankan(&CSET_W0_HI, &[("x0", -1), ("nzcv_c", 1)]).r("x0"), 1);
+    assert_eq!(jalankan(&CSET_W0_HI, &[("x0", -1), ("nzcv_c", 1), ("nzcv_z", 1)]).r("x0"), 0);
+    assert_eq!(jalankan(&CNEG_X0_X1_MI, &[("x1", 9), ("nzcv_n", 1)]).r("x0"), -9);
+    let ir = lift(&CSEL_X0_X1_X2_LT);
+    assert!(matches!(&ir[0].operand_dua, TipeOperand::Conditional { .. }));
+}
+
+#[test]
+fn test_kali_dan_bagi() {
+    let awal = [("x1", 6), ("x2", 7), ("x3", 100)];
+    assert_eq!(jalankan(&MADD_X0, &awal).r("x0"), 142);
+    assert_eq!(jalankan(&MSUB_X0, &awal).r("x0"), 58);
+    assert_eq!(jalankan(&SMULL_X0_W1_W2, &[("x1", 0xFFFF_FFFD), ("x2", 4)]).r("x0"), -12);
+    assert_eq!(jalankan(&SDIV_W0_W1_W2, &[("x1", 0xFFFF_FFF9), ("x2", 2)]).r("x0"), 0xFFFF_FFFD);
+    let awal_tinggi = [("x1", -2), ("x2", 3)];
+    assert_eq!(jalankan(&SMULH_X0_X1_X2, &awal_tinggi).r("x0"), -1);
+    assert_eq!(jalankan(&UMULH_X0_X1_X2, &awal_tinggi).r("x0"), 2);
+}
+
+#[test]
+fn test_bitfield() {
+    assert_eq!(jalankan(&UBFX_X0_X1_4_8, &[("x1", 0xABCD)]).r("x0"), 0xBC);
+    assert_eq!(jalankan(&SBFX_X0_X1_4_4, &[("x1", 0xF0)]).r("x0"), -1);
+    assert_eq!(jalankan(&SBFX_X0_X1_4_4, &[("x1", 0x70)]).r("x0"), 7);
+    assert_eq!(jalankan(&BFI_X0_X1_8_4, &[("x0", 0xFFFF), ("x1", 0x15)]).r("x0"), 0xF5FF);
+    assert_eq!(jalankan(&BFXIL_W0_W1_4_8, &[("x0", 0x7_0000_FF00), ("x1", 0xABC0)]).r("x0"), 0xFFBC);
+    assert_eq!(jalankan(&UBFIZ_X0_X1_3_5, &[("x1", 0xFF)]).r("x0"), 0xF8);
+}
+
+#[test]
+fn test_load_store_dengan_writeback() {
+    let mut mesin = Mesin::baru(&[("x1", 0x2000)]);
+    mesin.tulis(0x2000, 64, 0x0102_0304_0506_0708);
+    mesin.tulis(0x2008, 64, 0x1122_3344_5566_7788);
+    let awal = mesin.mem.clone();
+    mesin.jalankan(&lift(&LDR_X0_PRE));
+    assert_eq!((mesin.r("x0"), mesin.r("x1")), (0x1122_3344_5566_7788, 0x2008), "pre-index memuat dari alamat baru");
+    let mut mesin = Mesin { reg: [("x1".to_string(), 0x2000)].into(), mem: awal.clone() };
+    mesin.jalankan(&lift(&LDR_X0_POST));
+    assert_eq!((mesin.r("x0"), mesin.r("x1")), (0x0102_0304_0506_0708, 0x2008), "post-index memuat dari alamat lama");
+    let mut mesin = Mesin { reg: [("x1".to_string(), 0x2000)].into(), mem: awal };
+    mesin.jalankan(&lift(&LDRSB_X0));
+    assert_eq!(mesin.r("x0"), 8);
+    mesin.tulis(0x2000, 8, 0x80);
+    mesin.jalankan(&lift(&LDRSB_X0));
+    assert_eq!(mesin.r("x0"), -128);
+    mesin.tulis(0x2004, 32, -1);
+    mesin.jalankan(&lift(&STR_WZR));
+    assert_eq!(mesin.baca(0x2004, 32), 0);
+
+    let mut mesin = Mesin::baru(&[("sp", 0x3000), ("fp", 0x11), ("lr", 0x22)]);
+    mesin.jalankan(&lift(&STP_FP_LR_PRE));
+    assert_eq!(mesin.r("sp"), 0x2FF0);
+    assert_eq!((mesin.baca(0x2FF0, 64), mesin.baca(0x2FF8, 64)), (0x11, 0x22));
+    mesin.reg.insert("fp".to_string(), 0);
+    mesin.reg.insert("lr".to_string(), 0);
+    mesin.jalankan(&lift(&LDP_FP_LR_POST));
+    assert_eq!((mesin.r("fp"), mesin.r("lr"), mesin.r("sp")), (0x11, 0x22, 0x3000));
+}
+
+#[test]
+fn test_cabang_kondisi_bit_dan_ret() {
+    let tbz = lift(&TBZ_W0_3);
+    assert_eq!(tbz.len(), 1);
+    assert_eq!((&tbz[0].operation_code, &tbz[0].operand_satu), (&OperasiIr::Je, &TipeOperand::Immediate(0x1040)));
+    let bit_set = Mesin::baru(&[("x0", 8)]);
+    assert_eq!(bit_set.evaluasi(&tbz[0].operand_dua, 64), 0, "tbz tidak melompat ketika bit 3 menyala");
+    assert_eq!(Mesin::baru(&[("x0", 7)]).evaluasi(&tbz[0].operand_dua, 64), 1);
+    let tbnz = lift(&TBNZ_X0_40);
+    assert_eq!(tbnz[0].operation_code, OperasiIr::Jne);
+    assert_eq!(Mesin::baru(&[("x0", 1 << 40)]).evaluasi(&tbnz[0].operand_dua, 64), 1);
+    let bgt = lift(&B_GT);
+    assert_eq!(bgt[0].operation_code, OperasiIr::Jg);
+    assert_eq!(bgt[0].operand_dua, kondisi_nzcv("gt"));
+    let ret = lift(&RET);
+    assert_eq!(ret.len(), 1, "ret ARM64 tidak menyentuh stack");
+    assert_eq!(ret[0].operation_code, OperasiIr::Ret);
+}
+
+#[test]
+fn test_detail_operand_arm64() {
+    let engine = DisasmEngine::buat_engine_baru("aarch64");
+    let cset = engine.ambil_satu_instruksi(&CSET_W0_HI, 0x1000).unwrap();
+    assert_eq!((cset.mnemonic.as_str(), cset.kondisi.as_deref()), ("cset", Some("hi")));
+    let bgt = engine.ambil_satu_instruksi(&B_GT, 0x1000).unwrap();
+    assert_eq!((bgt.mnemonic.as_str(), bgt.kondisi.as_deref()), ("b.gt", Some("gt")));
+    assert!(engine.ambil_satu_instruksi(&ADDS_W0_W1_W2, 0x1000).unwrap().update_flags);
+    assert!(!engine.ambil_satu_instruksi(&ADD_W0_W1_1, 0x1000).unwrap().update_flags);
+    let add = engine.ambil_satu_instruksi(&ADD_X0_X1_X2_LSL3, 0x1000).unwrap();
+    assert_eq!(add.ambil_geser_operand(2), GeserOperand::Lsl(3));
+    let add = engine.ambil_satu_instruksi(&ADD_X0_X1_W2_SXTW2, 0x1000).unwrap();
+    assert_eq!(add.ambil_geser_operand(2), GeserOperand::Ekstensi { bertanda: true, lebar: 32, geser: 2 });
+    let ldr = IrLifter::untuk_arsitektur("aarch64").konversi_instruksi_ke_microcode(&engine.ambil_satu_instruksi(&LDR_X0_PRE, 0x1000).unwrap());
+    assert_eq!(ldr[0].operand_dua, TipeOperand::MemoryRef { base: "x1".to_string(), offset: 8 });
+}
+
+fn buat_vmem_adrp() -> VirtualMemory {
+    let mut vmem = VirtualMemory::baru(0x400000, "aarch64", "elf");
+    vmem.tambah_segment(0x400000, KODE_ADRP.to_vec(), IzinAkses::ReadExecute, ".text".to_string());
+    let mut data = vec![0u8; 0x40];
+    data[0x10..0x15].copy_from_slice(b"halo\0");
+    vmem.tambah_segment(0x401000, data, IzinAkses::ReadWrite, ".data".to_string());
+    vmem
+}
+
+#[test]
+fn test_pasangan_adrp_menjadi_alamat_absolut() {
+    let vmem = buat_vmem_adrp();
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(&vmem);
+    let ctx = analyzer.ambil_hasil_fungsi().get(&0x400000).expect("fungsi harus terdeteksi");
+    let ir = &ctx.ir_code;
+    let di = |addr: u64| ir.iter().filter(move |s| s.address_asal == addr);
+    let add = ctx.instruksi.iter().find(|i| i.address == 0x400004).unwrap();
+    assert_eq!(add.mnemonic, "add", "instruksi asli tidak boleh ditulis ulang menjadi mov");
+    assert_eq!(add.operands_detail.len(), 3);
+    assert_eq!(add.alamat_terlipat, Some(0x401010));
+    assert!(di(0x400004).any(|s| s.operation_code == OperasiIr::Mov
+        && s.operand_satu == TipeOperand::Register("x0".to_string())
+        && s.operand_dua == TipeOperand::Immediate(0x401010)), "adrp+add harus menjadi alamat string");
+    assert!(di(0x40000C).any(|s| s.operand_dua == TipeOperand::Memory(0x401020)), "adrp+ldr harus menjadi load global");
+    assert!(di(0x400010).any(|s| s.operand_satu == TipeOperand::Memory(0x401028)), "adrp+str harus menjadi store global");
+    assert!(di(0x40001C).any(|s| s.operand_dua == TipeOperand::Memory(0x401008)), "x19 callee-saved tetap valid setelah call");
+    assert!(di(0x400020).any(|s| s.operand_dua == TipeOperand::MemoryRef { base: "x1".to_string(), offset: 8 }),
+        "x1 caller-saved tidak boleh dilipat setelah call");
+    let db = &analyzer.xref_db;
+    assert!(db.ambil_xref_ke(0x401010).iter().any(|e| e.dari == 0x400004 && e.jenis == JenisXref::AmbilAlamat));
+    assert!(db.ambil_xref_ke(0x401020).iter().any(|e| e.dari == 0x40000C && e.jenis == JenisXref::Baca));
+    assert!(db.ambil_xref_ke(0x401028).iter().any(|e| e.dari == 0x400010 && e.jenis == JenisXref::Tulis));
+}
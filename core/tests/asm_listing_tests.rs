@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::analysis::recovery::strings::TabelString;
+use rcdecomp_core::analysis::recovery::xref::{EntriXref, JenisXref};
+use rcdecomp_core::codegen::asm_listing::{AsmListingEmitter, SintaksAsm};
+
+const KODE_X64: [u8; 30] = [
+    0x55,
+    0x48, 0x89, 0xE5,
+    0x48, 0x8D, 0x3D, 0xF5, 0x0F, 0x00, 0x00,
+    0xC7, 0x45, 0xFC, 0x00, 0x00, 0x00, 0x00,
+    0x83, 0xFF, 0x00,
+    0x74, 0x05,
+    0xE8, 0x14, 0x00, 0x00, 0x00,
+    0x5D,
+    0xC3,
+];
+const KODE_HELPER: [u8; 3] = [0x31, 0xC0, 0xC3];
+
+fn buat_vmem_x64() -> VirtualMemory {
+    let mut kode = KODE_X64.to_vec();
+    kode.resize(0x30, 0xCC);
+    kode.extend_from_slice(&KODE_HELPER);
+    let mut vmem = VirtualMemory::baru(0x401000, "x86_64", "elf");
+    vmem.tambah_segment(0x401000, kode, IzinAkses::ReadExecute, ".text".to_string());
+    vmem.tambah_segment(0x402000, b"Hello, listing\0".to_vec(), IzinAkses::Read, ".rodata".to_string());
+    vmem.simbol_global.insert(0x401000, "main".to_string());
+    vmem.simbol_global.insert(0x401030, "helper".to_string());
+    vmem
+}
+
+fn hasilkan(vmem: &VirtualMemory, sintaks: SintaksAsm) -> String {
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(vmem);
+    let mut tabel = TabelString::new();
+    tabel.pindai_vmem(vmem);
+    let mut emitter = AsmListingEmitter::new(sintaks);
+    emitter.atur_tabel_string(&tabel);
+    emitter.hasilkan_listing(vmem, analyzer.ambil_hasil_fungsi(), &analyzer.global_jump_targets, &analyzer.xref_db)
+}
+
+fn cari_baris<'a>(listing: &'a str, alamat: u64) -> &'a str {
+    let prefix = format!("  {:08x}  ", alamat);
+    listing.lines().find(|l| l.starts_with(&prefix)).unwrap_or_else(|| panic!("baris 0x{:x} tidak ada di listing:\n{}", alamat, listing))
+}
+
+#[test]
+fn test_listing_intel_dengan_label_dan_simbol() {
+    let listing = hasilkan(&buat_vmem_x64(), SintaksAsm::Intel);
+    assert!(listing.contains("; section .text  0x401000-0x401033  r-x"));
+    assert!(listing.contains("; section .rodata  0x402000-0x40200f  r--"));
+    assert!(listing.contains("; ======== fungsi main (0x401000-0x40101e) ========"));
+    assert!(listing.contains("\nmain:\n") && listing.contains("\nhelper:\n"));
+    assert!(listing.contains("\nloc_40101c:\n"), "target je harus diberi label blok");
+    assert!(cari_baris(&listing, 0x401015).contains("je       loc_40101c"));
+    assert!(cari_baris(&listing, 0x401017).contains("call     helper"));
+    let lea = cari_baris(&listing, 0x401004);
+    assert!(lea.contains("rip") && lea.contains("str_402000") && lea.contains("\"Hello, listing\""));
+    assert!(cari_baris(&listing, 0x401000).contains("55 "));
+}
+
+#[test]
+fn test_listing_memisahkan_kode_dan_data() {
+    let listing = hasilkan(&buat_vmem_x64(), SintaksAsm::Intel);
+    assert!(listing.contains("; 0x40101e-0x401030: 18 byte tidak terjangkau dari analisis"));
+    assert!(!listing.contains("  0040101e  "), "padding tidak boleh didisassembly secara linear");
+    assert!(listing.contains("str_402000:  ; xref: 0x401004 [address]"));
+    assert!(listing.contains("  00402000  db \"Hello, listing\", 0"));
+}
+
+#[test]
+fn test_listing_sintaks_att() {
+    let listing = hasilkan(&buat_vmem_x64(), SintaksAsm::Att);
+    assert!(listing.contains("sintaks att"));
+    assert!(cari_baris(&listing, 0x401000).ends_with("pushq    %rbp"));
+    assert!(cari_baris(&listing, 0x401001).ends_with("movq     %rsp, %rbp"));
+    assert!(cari_baris(&listing, 0x401004).contains("leaq     str_402000(%rip), %rdi"));
+    assert!(cari_baris(&listing, 0x40100b).ends_with("movl     $0, -4(%rbp)"));
+    assert!(cari_baris(&listing, 0x401012).ends_with("cmpl     $0, %edi"));
+    assert!(cari_baris(&listing, 0x401015).ends_with("je       loc_40101c"));
+    assert!(cari_baris(&listing, 0x401017).ends_with("callq    helper"));
+}
+
+#[test]
+fn test_listing_att_segmen_dan_indeks_berskala() {
+    let mut vmem = VirtualMemory::baru(0x401000, "x86_64", "elf");
+    vmem.tambah_segment(0x401000, vec![
+        0x64, 0x48, 0x8B, 0x04, 0x25, 0x28, 0x00, 0x00, 0x00,
+        0x8B, 0x44, 0x8B, 0x08,
+        0x0F, 0xB6, 0x07,
+        0xC3,
+    ], IzinAkses::ReadExecute, ".text".to_string());
+    let listing = hasilkan(&vmem, SintaksAsm::Att);
+    assert!(cari_baris(&listing, 0x401000).ends_with("movq     %fs:0x28, %rax"));
+    assert!(cari_baris(&listing, 0x401009).ends_with("movl     8(%rbx, %rcx, 4), %eax"));
+    assert!(cari_baris(&listing, 0x40100d).ends_with("movzbl   (%rdi), %eax"));
+}
+
+#[test]
+fn test_listing_anotasi_jump_table() {
+    let vmem = buat_vmem_x64();
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(&vmem);
+    let mut jump_targets = HashMap::new();
+    jump_targets.insert(0x401015, vec![0x401017, 0x40101c]);
+    let mut xref_db = analyzer.xref_db.clone();
+    xref_db.tambah_xref(EntriXref { dari: 0x401015, ke: 0x401017, jenis: JenisXref::Jump, ukuran_akses: 0, fungsi_asal: Some(0x401000) });
+    let mut emitter = AsmListingEmitter::new(SintaksAsm::Intel);
+    let listing = emitter.hasilkan_listing(&vmem, analyzer.ambil_hasil_fungsi(), &jump_targets, &xref_db);
+    assert!(cari_baris(&listing, 0x401015).contains("; jump table 2 target: loc_401017, loc_40101c"));
+    assert!(listing.contains("loc_401017:  ; target jump table dari 0x401015"));
+}
+
+#[test]
+fn test_listing_non_x86_memakai_sintaks_bawaan() {
+    let mut vmem = VirtualMemory::baru(0x1000, "aarch64", "elf");
+    vmem.tambah_segment(0x1000, vec![
+        0x02, 0x00, 0x00, 0x94,
+        0xC0, 0x03, 0x5F, 0xD6,
+        0x00, 0x00, 0x80, 0x52,
+        0xC0, 0x03, 0x5F, 0xD6,
+    ], IzinAkses::ReadExecute, ".text".to_string());
+    vmem.simbol_global.insert(0x1008, "helper".to_string());
+    let listing = hasilkan(&vmem, SintaksAsm::Att);
+    assert!(listing.contains("sintaks intel"), "AT&T hanya berlaku untuk x86");
+    assert!(listing.contains("; ======== fungsi entry_point"));
+    assert!(cari_baris(&listing, 0x1000).ends_with("bl       helper"));
+    assert_eq!(SintaksAsm::dari_nama("AT&T"), Some(SintaksAsm::Att));
+    assert_eq!(SintaksAsm::dari_kode(SintaksAsm::Att.kode()), Some(SintaksAsm::Att));
+}
+
+#[test]
+fn test_listing_aarch64_adrp_add_tetap_asli() {
+    let mut vmem = VirtualMemory::baru(0x1000, "aarch64", "elf");
+    vmem.tambah_segment(0x1000, vec![
+        0x00, 0x00, 0x00, 0xB0,
+        0x00, 0x40, 0x00, 0x91,
+        0xC0, 0x03, 0x5F, 0xD6,
+    ], IzinAkses::ReadExecute, ".text".to_string());
+    vmem.tambah_segment(0x2000, vec![0; 0x20], IzinAkses::ReadWrite, ".data".to_string());
+    let listing = hasilkan(&vmem, SintaksAsm::Intel);
+    let add = cari_baris(&listing, 0x1004);
+    assert!(add.contains("add      x0, x0, #0x10"), "add hasil lipatan adrp harus tampil asli: {}", add);
+    assert!(!listing.contains("mov      x0"));
+}
+
+#[test]
+fn test_listing_arm_mempertahankan_kode_kondisi() {
+    let mut vmem = VirtualMemory::baru(0x1000, "arm", "elf");
+    vmem.tambah_segment(0x1000, vec![
+        0x01, 0x00, 0x80, 0x02,
+        0x1E, 0xFF, 0x2F, 0xE1,
+    ], IzinAkses::ReadExecute, ".text".to_string());
+    let listing = hasilkan(&vmem, SintaksAsm::Intel);
+    assert!(cari_baris(&listing, 0x1000).ends_with("addeq    r0, r0, #1"));
+    assert!(cari_baris(&listing, 0x1004).ends_with("bx       lr"));
+}
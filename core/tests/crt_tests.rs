@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::analysis::recovery::crt::{CrtAnalyzer, JenisMain, VarianCrt, is_nama_helper_crt, terapkan_hasil_crt};
+use rcdecomp_core::analysis::recovery::types::{TypeSystem, TipePrimitif};
+
+fn buat_vmem_startup(format: &str, startup: &[u8], nama_slot: &str) -> VirtualMemory {
+    let mut vmem = VirtualMemory::baru(0x1000, "x86_64", format);
+    let mut kode = vec![0x90; 0x80];
+    kode[..startup.len()].copy_from_slice(startup);
+    kode[0x40..0x43].copy_from_slice(&[0x31, 0xC0, 0xC3]);
+    vmem.tambah_segment(0x1000, kode, IzinAkses::ReadExecute, ".text".to_string());
+    vmem.tambah_segment(0x3000, vec![0; 0x40], IzinAkses::ReadWrite, ".got".to_string());
+    vmem.simbol_global.insert(0x3000, nama_slot.to_string());
+    vmem
+}
+
+fn jalankan_deteksi(vmem: &VirtualMemory) -> (RecursiveDescent, Option<rcdecomp_core::analysis::recovery::crt::HasilDeteksiCrt>) {
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(vmem);
+    let hasil = CrtAnalyzer::new(&vmem.arsitektur, &vmem.format_biner).deteksi_main(vmem, analyzer.ambil_hasil_fungsi());
+    (analyzer, hasil)
+}
+
+#[test]
+fn test_deteksi_main_pola_glibc() {
+    let startup = [
+        0x48, 0x8D, 0x3D, 0x39, 0x00, 0x00, 0x00,
+        0xFF, 0x15, 0xF3, 0x1F, 0x00, 0x00,
+        0xF4,
+    ];
+    let vmem = buat_vmem_startup("elf", &startup, "__libc_start_main");
+    let (_, hasil) = jalankan_deteksi(&vmem);
+    let hasil = hasil.expect("main harus terdeteksi dari argumen __libc_start_main");
+    assert_eq!(hasil.alamat_main, 0x1040);
+    assert_eq!(hasil.jenis_main, JenisMain::Main);
+    assert_eq!(hasil.varian, VarianCrt::Glibc);
+    assert!(hasil.fungsi_crt.contains(&0x1000), "_start harus ditandai sebagai helper CRT");
+    assert!(!hasil.fungsi_crt.contains(&0x1040));
+}
+
+#[test]
+fn test_varian_libc_dari_penanda_bukan_kedalaman() {
+    let startup = [
+        0x48, 0x8D, 0x3D, 0x39, 0x00, 0x00, 0x00,
+        0xFF, 0x15, 0xF3, 0x1F, 0x00, 0x00,
+        0xF4,
+    ];
+    let mut vmem = buat_vmem_startup("elf", &startup, "__libc_start_main");
+    vmem.tambah_segment(0x400, b"/lib/ld-musl-x86_64.so.1\0".to_vec(), IzinAkses::Read, ".interp".to_string());
+    let (_, hasil) = jalankan_deteksi(&vmem);
+    let hasil = hasil.expect("main harus terdeteksi dari argumen __libc_start_main");
+    assert_eq!(hasil.alamat_main, 0x1040);
+    assert_eq!(hasil.varian, VarianCrt::Musl, "interpreter ld-musl menandakan musl walau pemanggilan langsung dari entry");
+}
+
+#[test]
+fn test_handler_atexit_bukan_main() {
+    let startup = [
+        0x48, 0x8D, 0x3D, 0x39, 0x00, 0x00, 0x00,
+        0xFF, 0x15, 0xF3, 0x1F, 0x00, 0x00,
+        0xF4,
+    ];
+    let vmem = buat_vmem_startup("elf", &startup, "__cxa_atexit");
+    let (_, hasil) = jalankan_deteksi(&vmem);
+    assert!(hasil.is_none(), "argumen __cxa_atexit tidak boleh dianggap main");
+}
+
+#[test]
+fn test_deteksi_main_pola_msvc() {
+    let startup = [
+        0xE8, 0x3B, 0x00, 0x00, 0x00,
+        0x89, 0xC1,
+        0xFF, 0x15, 0xF3, 0x1F, 0x00, 0x00,
+        0xC3,
+    ];
+    let vmem = buat_vmem_startup("pe", &startup, "exit");
+    let (_, hasil) = jalankan_deteksi(&vmem);
+    let hasil = hasil.expect("main harus terdeteksi dari nilai balik yang diteruskan ke exit");
+    assert_eq!(hasil.alamat_main, 0x1040);
+    assert_eq!(hasil.varian, VarianCrt::Msvc);
+    assert!(hasil.fungsi_crt.contains(&0x1000));
+}
+
+#[test]
+fn test_deteksi_main_dari_simbol() {
+    let mut vmem = buat_vmem_startup("elf", &[0xE8, 0x3B, 0x00, 0x00, 0x00, 0xC3], "puts");
+    vmem.simbol_global.insert(0x1000, "_start".to_string());
+    vmem.simbol_global.insert(0x1040, "_wmain".to_string());
+    let (_, hasil) = jalankan_deteksi(&vmem);
+    let hasil = hasil.unwrap();
+    assert_eq!(hasil.alamat_main, 0x1040);
+    assert_eq!(hasil.jenis_main, JenisMain::WMain);
+    assert_eq!(hasil.varian, VarianCrt::Simbol);
+    assert!(hasil.fungsi_crt.contains(&0x1000));
+}
+
+#[test]
+fn test_signature_dan_nama_main() {
+    let sig = JenisMain::Main.signature();
+    assert_eq!(sig.return_type, TipePrimitif::Integer(4));
+    assert_eq!(sig.arg_types.len(), 3);
+    assert_eq!(JenisMain::WinMain.signature().arg_types.len(), 4);
+    assert_eq!(JenisMain::dari_nama("_WinMain@16"), Some(JenisMain::WinMain));
+    assert_eq!(JenisMain::dari_nama("mainloop"), None);
+    assert!(is_nama_helper_crt("__libc_csu_init"));
+    assert!(is_nama_helper_crt("__scrt_common_main_seh"));
+    assert!(!is_nama_helper_crt("main"));
+}
+
+#[test]
+fn test_terapkan_hasil_crt_memberi_nama_dan_signature() {
+    let startup = [
+        0x48, 0x8D, 0x3D, 0x39, 0x00, 0x00, 0x00,
+        0xFF, 0x15, 0xF3, 0x1F, 0x00, 0x00,
+        0xF4,
+    ];
+    let mut vmem = buat_vmem_startup("elf", &startup, "__libc_start_main");
+    let (_, hasil) = jalankan_deteksi(&vmem);
+    let hasil = hasil.unwrap();
+    let mut type_sys = TypeSystem::new();
+    terapkan_hasil_crt(&hasil, &mut vmem, &mut type_sys);
+    assert_eq!(vmem.simbol_global.get(&0x1040).unwrap(), "main");
+    assert_eq!(type_sys.global_signatures.get(&0x1040).unwrap().arg_types.len(), 3);
+    let fungsi_kosong = HashMap::new();
+    assert!(CrtAnalyzer::new("x86_64", "elf").deteksi_main(&vmem, &fungsi_kosong).is_none());
+}
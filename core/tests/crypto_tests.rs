@@ -0,0 +1,185 @@
+use std::collections::{BTreeSet, HashMap};
+use rcdecomp_core::analysis::recovery::crypto::{AlgoritmaKripto, TabelKripto};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, StatementIr, TipeDataIr, TipeOperand};
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+
+const AES_SBOX_AWAL: [u8; 16] = [0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76];
+const SHA256_K_AWAL: [u32; 8] = [0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5];
+const CRC32_TABEL_AWAL: [u32; 8] = [0x00000000, 0x77073096, 0xee0e612c, 0x990951ba, 0x076dc419, 0x706af48f, 0xe963a535, 0x9e6495a3];
+
+fn lift(arch: &str, bytes: &[u8]) -> Vec<StatementIr> {
+    let engine = DisasmEngine::buat_engine_baru(arch);
+    let instr = engine.ambil_satu_instruksi(bytes, 0x1000).expect("byte uji harus terdekode");
+    IrLifter::untuk_arsitektur(arch).konversi_instruksi_ke_microcode(&instr)
+}
+
+fn reg(nama: &str) -> TipeOperand {
+    TipeOperand::Register(nama.to_string())
+}
+
+fn intrinsic(ir: &[StatementIr]) -> &StatementIr {
+    ir.iter().find(|s| matches!(s.operation_code, OperasiIr::Intrinsic(_))).unwrap_or_else(|| panic!("harus ada intrinsic: {:?}", ir))
+}
+
+fn nama_intrinsic(stmt: &StatementIr) -> &str {
+    match &stmt.operation_code {
+        OperasiIr::Intrinsic(nama) => nama,
+        _ => unreachable!(),
+    }
+}
+
+fn kata_ke_byte(kata: &[u32], big_endian: bool) -> Vec<u8> {
+    kata.iter().flat_map(|k| if big_endian { k.to_be_bytes() } else { k.to_le_bytes() }).collect()
+}
+
+#[test]
+fn test_aes_ni_menjadi_intrinsic_yang_menulis_tujuan() {
+    let ir = lift("x86_64", &[0x66, 0x0F, 0x38, 0xDC, 0xC1]);
+    let stmt = intrinsic(&ir);
+    assert_eq!(nama_intrinsic(stmt), "_mm_aesenc_si128");
+    assert_eq!(stmt.operand_satu, reg("xmm0"));
+    assert_eq!(stmt.operand_tambahan, vec![reg("xmm0"), reg("xmm1")]);
+    assert_eq!(stmt.tipe_hasil, TipeDataIr::V128);
+    let ir = lift("x86_64", &[0x66, 0x0F, 0x3A, 0xDF, 0xC1, 0x01]);
+    let stmt = intrinsic(&ir);
+    assert_eq!(nama_intrinsic(stmt), "_mm_aeskeygenassist_si128");
+    assert_eq!(stmt.operand_tambahan, vec![reg("xmm1"), TipeOperand::Immediate(1)]);
+    let ir = lift("x86_64", &[0xC4, 0xE2, 0x71, 0xDC, 0xC2]);
+    let stmt = intrinsic(&ir);
+    assert_eq!(nama_intrinsic(stmt), "_mm_aesenc_si128");
+    assert_eq!(stmt.operand_satu, reg("xmm0"));
+    assert_eq!(stmt.operand_tambahan, vec![reg("xmm1"), reg("xmm2")], "operand ketiga vaesenc tidak boleh hilang");
+    let ir = lift("x86_64", &[0x66, 0x0F, 0x38, 0xDD, 0x07]);
+    assert!(matches!(intrinsic(&ir).operand_tambahan[1], TipeOperand::MemoryRef { .. } | TipeOperand::MemoryComplex { .. }));
+}
+
+#[test]
+fn test_sha_dan_pclmul_x86() {
+    let kasus: [(&[u8], &str, usize); 4] = [
+        (&[0x0F, 0x3A, 0xCC, 0xC1, 0x02], "_mm_sha1rnds4_epu32", 3),
+        (&[0x0F, 0x38, 0xC9, 0xC1], "_mm_sha1msg1_epu32", 2),
+        (&[0x0F, 0x38, 0xCB, 0xCA], "_mm_sha256rnds2_epu32", 3),
+        (&[0x66, 0x0F, 0x3A, 0x44, 0xC1, 0x11], "_mm_clmulepi64_si128", 3),
+    ];
+    for (bytes, nama, jumlah_arg) in kasus {
+        let ir = lift("x86_64", bytes);
+        let stmt = intrinsic(&ir);
+        assert_eq!(nama_intrinsic(stmt), nama);
+        assert_eq!(stmt.operand_tambahan.len(), jumlah_arg, "{}: {:?}", nama, stmt.operand_tambahan);
+        assert_eq!(stmt.operand_tambahan[0], stmt.operand_satu, "{} membaca tujuan", nama);
+    }
+    let ir = lift("x86_64", &[0x0F, 0x38, 0xCB, 0xCA]);
+    assert_eq!(intrinsic(&ir).operand_tambahan.last(), Some(&reg("xmm0")), "sha256rnds2 memakai xmm0 implisit");
+}
+
+#[test]
+fn test_crc32_x86_mengikuti_ukuran_sumber() {
+    let kasus: [(&[u8], &str, TipeDataIr); 3] = [
+        (&[0xF2, 0x0F, 0x38, 0xF0, 0xC1], "_mm_crc32_u8", TipeDataIr::I32),
+        (&[0x66, 0xF2, 0x0F, 0x38, 0xF1, 0x07], "_mm_crc32_u16", TipeDataIr::I32),
+        (&[0xF2, 0x48, 0x0F, 0x38, 0xF1, 0xC1], "_mm_crc32_u64", TipeDataIr::I64),
+    ];
+    for (bytes, nama, tipe) in kasus {
+        let ir = lift("x86_64", bytes);
+        let stmt = intrinsic(&ir);
+        assert_eq!(nama_intrinsic(stmt), nama);
+        assert_eq!(stmt.tipe_hasil, tipe, "{}", nama);
+        assert_eq!(stmt.operand_tambahan.len(), 2, "crc32 membaca akumulator dan sumber");
+        assert!(ir.iter().any(|s| s.operand_satu == reg("rax")), "{} harus menulis rax: {:?}", nama, ir);
+    }
+    let ir = lift("x86_64", &[0xF2, 0x48, 0x0F, 0x38, 0xF1, 0xC1]);
+    assert_eq!(intrinsic(&ir).operand_tambahan, vec![reg("rax"), reg("rcx")]);
+}
+
+#[test]
+fn test_crypto_arm64_memakai_nama_acle() {
+    let kasus: [(&[u8], &str, &str, usize); 8] = [
+        (&[0x20, 0x48, 0x28, 0x4E], "vaeseq_u8", "v0", 2),
+        (&[0x62, 0x68, 0x28, 0x4E], "vaesmcq_u8", "v2", 1),
+        (&[0x20, 0x40, 0x02, 0x5E], "vsha256hq_u32", "q0", 3),
+        (&[0x20, 0x00, 0x02, 0x5E], "vsha1cq_u32", "q0", 3),
+        (&[0x20, 0x08, 0x28, 0x5E], "vsha1h_u32", "s0", 1),
+        (&[0x20, 0x40, 0xC2, 0x1A], "__crc32b", "x0", 2),
+        (&[0x20, 0x5C, 0xC2, 0x9A], "__crc32cd", "x0", 2),
+        (&[0x20, 0xE0, 0xE2, 0x4E], "vmull_high_p64", "v0", 2),
+    ];
+    for (bytes, nama, tujuan, jumlah_arg) in kasus {
+        let ir = lift("aarch64", bytes);
+        let stmt = intrinsic(&ir);
+        assert_eq!(nama_intrinsic(stmt), nama);
+        assert!(ir.iter().any(|s| s.operand_satu == reg(tujuan)), "{} harus menulis {}: {:?}", nama, tujuan, ir);
+        assert_eq!(stmt.operand_tambahan.len(), jumlah_arg, "{}: {:?}", nama, stmt.operand_tambahan);
+    }
+    let ir = lift("aarch64", &[0x20, 0x40, 0xC2, 0x1A]);
+    assert_eq!(intrinsic(&ir).tipe_hasil, TipeDataIr::I32);
+    assert!(!ir.iter().any(|s| s.operation_code == OperasiIr::Unknown), "crc32b tidak boleh jatuh ke Unknown");
+}
+
+#[test]
+fn test_pindai_konstanta_kripto_di_segment() {
+    let mut data = vec![0u8; 0x20];
+    data.extend_from_slice(&AES_SBOX_AWAL);
+    data.extend(vec![0u8; 0x10]);
+    data.extend(kata_ke_byte(&SHA256_K_AWAL, false));
+    data.extend_from_slice(b"expand 32-byte k");
+    let mut tabel = TabelKripto::new();
+    tabel.pindai_segment(0x402000, &data, false);
+    let aes = tabel.konstanta.get(&0x402020).expect("S-box AES harus ditemukan");
+    assert_eq!(aes.algoritma, AlgoritmaKripto::Aes);
+    assert_eq!(tabel.konstanta.get(&0x402040).map(|k| k.algoritma), Some(AlgoritmaKripto::Sha256));
+    assert_eq!(tabel.konstanta.get(&0x402060).map(|k| k.algoritma), Some(AlgoritmaKripto::ChaCha20));
+    assert_eq!(tabel.cari_konstanta(0x402048).map(|k| k.alamat), Some(0x402040), "alamat di dalam tabel dipetakan ke awal tabel");
+    assert!(tabel.cari_konstanta(0x402000).is_none());
+    let mut tabel_be = TabelKripto::new();
+    tabel_be.pindai_segment(0x1000, &kata_ke_byte(&CRC32_TABEL_AWAL, true), true);
+    assert_eq!(tabel_be.konstanta.get(&0x1000).map(|k| k.algoritma), Some(AlgoritmaKripto::Crc32));
+    let mut tabel_salah_endian = TabelKripto::new();
+    tabel_salah_endian.pindai_segment(0x1000, &kata_ke_byte(&CRC32_TABEL_AWAL, true), false);
+    assert!(tabel_salah_endian.konstanta.is_empty());
+}
+
+#[test]
+fn test_referensi_immediate_butuh_beberapa_konstanta() {
+    let stmt = |nilai: i64| StatementIr::new(0x1000, OperasiIr::Mov, reg("eax"), TipeOperand::Immediate(nilai));
+    let mut semua = HashMap::new();
+    semua.insert(0x1000, vec![stmt(0xd76aa478), stmt(0xe8c7b756u32 as i32 as i64)]);
+    semua.insert(0x2000, vec![stmt(0x6a09e667)]);
+    semua.insert(0x3000, vec![stmt(0xedb88320u32 as i32 as i64)]);
+    let mut tabel = TabelKripto::new();
+    tabel.catat_referensi_dari_ir(&semua);
+    assert_eq!(tabel.algoritma_fungsi(0x1000), Some(&BTreeSet::from([AlgoritmaKripto::Md5])));
+    assert!(tabel.algoritma_fungsi(0x2000).is_none(), "satu konstanta SHA-256 saja belum cukup");
+    assert_eq!(tabel.algoritma_fungsi(0x3000), Some(&BTreeSet::from([AlgoritmaKripto::Crc32])));
+    assert_eq!(tabel.komentar_fungsi(0x1000).as_deref(), Some("// crypto: MD5\n"));
+}
+
+#[test]
+fn test_fungsi_perujuk_konstanta_diberi_nama() {
+    let mut kode = vec![0xE8, 0x0B, 0x00, 0x00, 0x00, 0xE8, 0x16, 0x00, 0x00, 0x00, 0xC3];
+    kode.resize(0x10, 0xCC);
+    kode.extend_from_slice(&[0x0F, 0xB6, 0x87, 0x00, 0x20, 0x40, 0x00, 0xC3]);
+    kode.resize(0x20, 0xCC);
+    kode.extend_from_slice(&[0xB8, 0x78, 0xA4, 0x6A, 0xD7, 0x05, 0x56, 0xB7, 0xC7, 0xE8, 0xC3]);
+    let mut data = AES_SBOX_AWAL.to_vec();
+    data.resize(256, 0);
+    let mut vmem = VirtualMemory::baru(0x401000, "x86_64", "elf");
+    vmem.tambah_segment(0x401000, kode, IzinAkses::ReadExecute, ".text".to_string());
+    vmem.tambah_segment(0x402000, data, IzinAkses::Read, ".rodata".to_string());
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(&vmem);
+    let semua: HashMap<u64, Vec<StatementIr>> = analyzer.ambil_hasil_fungsi().iter().map(|(a, ctx)| (*a, ctx.ir_code.clone())).collect();
+    let mut tabel = TabelKripto::new();
+    tabel.pindai_vmem(&vmem);
+    tabel.catat_referensi_dari_ir(&semua);
+    let sbox = tabel.konstanta.get(&0x402000).expect("S-box harus ditemukan");
+    assert_eq!(sbox.referensi, vec![0x401010]);
+    vmem.simbol_global.insert(0x401020, "md5_transform".to_string());
+    tabel.terapkan_nama(&mut vmem);
+    assert_eq!(vmem.simbol_global.get(&0x401010).map(String::as_str), Some("crypto_aes_401010"));
+    assert_eq!(vmem.simbol_global.get(&0x401020).map(String::as_str), Some("md5_transform"), "simbol asli tidak ditimpa");
+    assert_eq!(tabel.usulan_nama_fungsi(0x401020).as_deref(), Some("crypto_md5_401020"));
+    assert!(!vmem.simbol_global.contains_key(&0x401000));
+}
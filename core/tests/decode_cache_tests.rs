@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use rayon::prelude::*;
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::disasm::cache::{CacheInstruksi, dengan_engine_lokal};
+
+const KODE_X64: [u8; 14] = [
+    0x55,
+    0x48, 0x89, 0xE5,
+    0xE8, 0x03, 0x00, 0x00, 0x00,
+    0x5D,
+    0xC3,
+    0x90,
+    0x31, 0xC0,
+];
+
+fn buat_vmem() -> VirtualMemory {
+    let mut kode = KODE_X64.to_vec();
+    kode.push(0xC3);
+    let mut vmem = VirtualMemory::baru(0x401000, "x86_64", "elf");
+    vmem.tambah_segment(0x401000, kode, IzinAkses::ReadExecute, ".text".to_string());
+    vmem
+}
+
+#[test]
+fn test_cache_mengembalikan_instruksi_yang_sama() {
+    let vmem = buat_vmem();
+    let cache = CacheInstruksi::new("x86_64");
+    let pertama = cache.dekode(&vmem, 0x401001, false).expect("mov rbp, rsp harus terdekode");
+    let kedua = cache.dekode(&vmem, 0x401001, false).unwrap();
+    assert!(Arc::ptr_eq(&pertama, &kedua), "dekode kedua harus diambil dari cache");
+    assert_eq!(pertama.mnemonic, "mov");
+    assert_eq!(cache.statistik(), (1, 1));
+    assert!(cache.dekode(&vmem, 0x500000, false).is_none());
+    assert!(cache.dekode(&vmem, 0x500000, false).is_none());
+    assert_eq!(cache.statistik(), (2, 2), "kegagalan dekode juga harus dicache");
+    assert_eq!(cache.jumlah_entri(), 2);
+}
+
+#[test]
+fn test_instruksi_di_ujung_segment() {
+    let vmem = buat_vmem();
+    let cache = CacheInstruksi::new("x86_64");
+    let ret = cache.dekode(&vmem, 0x40100E, false).expect("ret di byte terakhir segment harus terdekode");
+    assert_eq!(ret.mnemonic, "ret");
+    assert_eq!(ret.hitung_panjang_byte(), 1);
+}
+
+#[test]
+fn test_cache_aman_dipakai_paralel() {
+    let vmem = buat_vmem();
+    let cache = CacheInstruksi::new("x86_64");
+    let alamat: Vec<u64> = vec![0x401000, 0x401001, 0x401004, 0x401009, 0x40100A];
+    let hasil: Vec<String> = (0..64).into_par_iter()
+        .map(|i| cache.dekode(&vmem, alamat[i % alamat.len()], false).unwrap().mnemonic.clone())
+        .collect();
+    assert_eq!(hasil[0], "push");
+    assert_eq!(cache.jumlah_entri(), alamat.len());
+    let (hit, miss) = cache.statistik();
+    assert_eq!(hit + miss, 64);
+    assert!(hit >= 64 - 2 * alamat.len());
+}
+
+#[test]
+fn test_engine_dipakai_ulang_per_thread() {
+    let pertama = dengan_engine_lokal("x86_64", |engine| engine as *const _ as usize);
+    let kedua = dengan_engine_lokal("x86_64", |engine| engine as *const _ as usize);
+    assert_eq!(pertama, kedua);
+    let arm = dengan_engine_lokal("aarch64", |engine| engine.arch.clone());
+    assert_eq!(arm, "aarch64");
+}
+
+#[test]
+fn test_function_context_menyimpan_instruksi() {
+    let vmem = buat_vmem();
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(&vmem);
+    let ctx = analyzer.ambil_hasil_fungsi().get(&0x401000).expect("fungsi harus terdeteksi");
+    let alamat: Vec<u64> = ctx.instruksi.iter().map(|i| i.address).collect();
+    assert_eq!(alamat, vec![0x401000, 0x401001, 0x401004, 0x401009, 0x40100A]);
+    let callee = analyzer.ambil_hasil_fungsi().get(&0x40100C).expect("target call harus terdeteksi");
+    assert_eq!(callee.instruksi.len(), 2);
+    assert!(analyzer.ambil_cache_instruksi().ambil_tercache(0x401004, false).is_some());
+}
@@ -0,0 +1,99 @@
+use rcdecomp_core::loader::demangle::{demangle_simbol, sanitasi_identifier, SkemaMangling};
+use rcdecomp_core::loader::vmem::VirtualMemory;
+use rcdecomp_core::analysis::recovery::types::TypeSystem;
+
+#[test]
+fn test_demangle_itanium_method_const() {
+    let hasil = demangle_simbol("_ZNK3foo3Bar3bazEi").expect("Simbol Itanium valid harus ter-demangle");
+    assert_eq!(hasil.skema, SkemaMangling::Itanium);
+    assert_eq!(hasil.nama_lengkap, "foo::Bar::baz(int) const");
+    assert_eq!(hasil.kualifikasi, vec!["foo".to_string(), "Bar".to_string()]);
+    assert_eq!(hasil.nama_bersih, "foo_Bar_baz");
+    assert!(hasil.is_method, "Method const harus dikenali sebagai member kelas");
+}
+
+#[test]
+fn test_demangle_itanium_template_dan_substitusi() {
+    let hasil = demangle_simbol("_ZNSt6vectorIiSaIiEE9push_backERKi").unwrap();
+    assert_eq!(hasil.nama_lengkap, "std::vector<int, std::allocator<int>>::push_back(int const&)");
+    let hasil = demangle_simbol("_Z3maxIiET_S0_S0_").unwrap();
+    assert_eq!(hasil.nama_lengkap, "int max<int>(int, int)", "Template param T_ harus di-resolve ke argumen template");
+    let ctor = demangle_simbol("_ZN3FooC2Ev").unwrap();
+    assert_eq!(ctor.nama_lengkap, "Foo::Foo()");
+    let vtable = demangle_simbol("_ZTV3Foo").unwrap();
+    assert!(vtable.is_vtable);
+    assert_eq!(vtable.nama_kelas().as_deref(), Some("Foo"));
+}
+
+#[test]
+fn test_demangle_rust_legacy_dan_v0() {
+    let legacy = demangle_simbol("_ZN4core3fmt9Formatter3pad17h1234567890abcdefE").unwrap();
+    assert_eq!(legacy.skema, SkemaMangling::RustLegacy);
+    assert_eq!(legacy.nama_lengkap, "core::fmt::Formatter::pad", "Hash legacy harus dibuang");
+    let v0 = demangle_simbol("_RNvMNtCs1234_7mycrate3modNtB2_3Bar3new").unwrap();
+    assert_eq!(v0.skema, SkemaMangling::RustV0);
+    assert_eq!(v0.nama_lengkap, "<mycrate::mod::Bar>::new");
+    assert_eq!(v0.nama_kelas().as_deref(), Some("mycrate::mod::Bar"));
+    assert!(v0.is_method);
+    let generik = demangle_simbol("_RINvCs1234_7mycrate4funcmEB2_").unwrap();
+    assert_eq!(generik.nama_lengkap, "mycrate::func<u32>");
+}
+
+#[test]
+fn test_demangle_msvc() {
+    let method = demangle_simbol("?bar@foo@@QEAAXXZ").unwrap();
+    assert_eq!(method.skema, SkemaMangling::Msvc);
+    assert_eq!(method.nama_lengkap, "foo::bar()");
+    assert!(method.is_method);
+    let global = demangle_simbol("?func@@YAHHPEBD@Z").unwrap();
+    assert_eq!(global.nama_lengkap, "func(int, const char*)");
+    assert!(!global.is_method);
+    let dtor = demangle_simbol("??1Foo@ns@@UEAA@XZ").unwrap();
+    assert_eq!(dtor.nama_lengkap, "ns::Foo::~Foo()");
+    let assign = demangle_simbol("??4Foo@@QEAAAEAV0@AEBV0@@Z").unwrap();
+    assert_eq!(assign.nama_lengkap, "Foo::operator=(const Foo&)", "Backref nama harus di-resolve");
+}
+
+#[test]
+fn test_nama_biasa_tidak_diubah() {
+    assert!(demangle_simbol("printf").is_none());
+    assert!(demangle_simbol("kernel32.dll:ExitProcess").is_none());
+    assert!(demangle_simbol("_Zgarbage!!").is_none(), "Input rusak harus fallback ke nama mentah");
+    assert_eq!(sanitasi_identifier("std::vector<int>::operator[]"), "std_vector_int_operator_index");
+    assert_eq!(sanitasi_identifier("3dpoint"), "_3dpoint");
+}
+
+#[test]
+fn test_demangling_vmem_dan_pengelompokan_kelas() {
+    let mut vmem = VirtualMemory::baru(0x1000, "x86_64", "elf");
+    vmem.simbol_global.insert(0x1000, "_ZN3FooC2Ev".to_string());
+    vmem.simbol_global.insert(0x1010, "_ZN3Foo3getEv".to_string());
+    vmem.simbol_global.insert(0x1020, "_ZN3Foo3getEi".to_string());
+    vmem.simbol_global.insert(0x1030, "_ZN2ns6helperEv".to_string());
+    vmem.simbol_global.insert(0x1040, "main".to_string());
+    vmem.terapkan_demangling();
+    assert_eq!(vmem.simbol_global[&0x1000], "Foo_Foo");
+    assert_eq!(vmem.simbol_global[&0x1010], "Foo_get");
+    assert_eq!(vmem.simbol_global[&0x1020], "Foo_get_2", "Overload harus mendapat identifier unik");
+    assert_eq!(vmem.simbol_global[&0x1040], "main");
+    assert_eq!(vmem.info_simbol[&0x1020].nama_lengkap, "Foo::get(int)");
+    let mut type_sys = TypeSystem::new();
+    type_sys.kelompokkan_method_kelas(&vmem.info_simbol);
+    let kelas = type_sys.class_definitions.get("Foo").expect("Kelas Foo harus terbentuk dari simbol ctor");
+    assert_eq!(kelas.methods.len(), 3);
+    assert!(kelas.methods.contains_key(&0x1020));
+    assert!(!type_sys.class_definitions.contains_key("ns"), "Namespace tanpa bukti member tidak boleh jadi kelas");
+}
+
+#[test]
+fn test_prefix_panjang_raksasa_tidak_panik() {
+    for simbol in [
+        "_ZN18446744073709551615abcE",
+        "_Z18446744073709551615f",
+        "_ZN3foo18446744073709551615xE",
+        "_RNvC18446744073709551615_a",
+        "_RNvCu18446744073709551615_a",
+    ] {
+        assert!(demangle_simbol(simbol).is_none(), "{} harus ditolak tanpa overflow", simbol);
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, StatementIr, TipeDataIr, TipeOperand};
+use rcdecomp_core::analysis::graph::cfg::{ControlFlowGraph, BasicBlock};
+use rcdecomp_core::analysis::graph::dom::DominatorTree;
+use rcdecomp_core::analysis::passes::ssa::SsaTransformer;
+use rcdecomp_core::analysis::recovery::ast::{ControlFlowStructurer, NodeAst};
+
+const ADD_EAX_EBX: [u8; 2] = [0x01, 0xD8];
+const CMP_EAX_EBX: [u8; 2] = [0x39, 0xD8];
+const ADC_EAX_EBX: [u8; 2] = [0x11, 0xD8];
+const SBB_EAX_EBX: [u8; 2] = [0x19, 0xD8];
+const NEG_EAX: [u8; 2] = [0xF7, 0xD8];
+const AND_EAX_EBX: [u8; 2] = [0x21, 0xD8];
+const XOR_EAX_EBX: [u8; 2] = [0x31, 0xD8];
+const SHL_EAX_1: [u8; 2] = [0xD1, 0xE0];
+const SHR_EAX_1: [u8; 2] = [0xD1, 0xE8];
+const SAR_EAX_1: [u8; 2] = [0xD1, 0xF8];
+const SHL_EAX_CL: [u8; 2] = [0xD3, 0xE0];
+const ADD_RAX_RBX: [u8; 3] = [0x48, 0x01, 0xD8];
+const INC_AL: [u8; 2] = [0xFE, 0xC0];
+const ADC_AX_BX: [u8; 3] = [0x66, 0x11, 0xD8];
+const ADD_AL_BL: [u8; 2] = [0x00, 0xD8];
+
+fn lift(bytes: &[u8]) -> Vec<StatementIr> {
+    let engine = DisasmEngine::buat_engine_baru("x86_64");
+    let instr = engine.ambil_satu_instruksi(bytes, 0x1000).expect("instruksi harus terdekode");
+    IrLifter::untuk_arsitektur("x86_64").konversi_instruksi_ke_microcode(&instr)
+}
+
+fn evaluasi(op: &TipeOperand, reg: &HashMap<String, i64>) -> i64 {
+    match op {
+        TipeOperand::Immediate(v) => *v,
+        TipeOperand::Register(r) => reg.get(r).copied().unwrap_or(0),
+        TipeOperand::Conditional { condition, true_val, false_val } => {
+            if evaluasi(condition, reg) != 0 { evaluasi(true_val, reg) } else { evaluasi(false_val, reg) }
+        },
+        TipeOperand::Expression { operasi, operand_kiri, operand_kanan } => {
+            let a = evaluasi(operand_kiri, reg);
+            let b = evaluasi(operand_kanan, reg);
+            let bool_ke_int = |x: bool| x as i64;
+            match operasi {
+                OperasiIr::Add => a.wrapping_add(b),
+                OperasiIr::Sub => a.wrapping_sub(b),
+                OperasiIr::Imul => a.wrapping_mul(b),
+                OperasiIr::MulHi => ((a as i128 * b as i128) >> 64) as i64,
+                OperasiIr::And => a & b,
+                OperasiIr::Or => a | b,
+                OperasiIr::Xor => a ^ b,
+                OperasiIr::Shl => a.wrapping_shl(b as u32),
+                OperasiIr::Shr => (a as u64).wrapping_shr(b as u32) as i64,
+                OperasiIr::Sar => a.wrapping_shr(b as u32),
+                OperasiIr::Je => bool_ke_int(a == b),
+                OperasiIr::Jne => bool_ke_int(a != b),
+                OperasiIr::Jb => bool_ke_int((a as u64) < (b as u64)),
+                OperasiIr::Ja => bool_ke_int((a as u64) > (b as u64)),
+                lain if lain.is_operasi_lebar() => lain.evaluasi_lebar(a, b).unwrap(),
+                lain => panic!("operasi {:?} tidak didukung evaluator test", lain),
+            }
+        },
+        lain => panic!("operand {:?} tidak didukung evaluator test", lain),
+    }
+}
+
+fn jalankan(bytes: &[u8], awal: &[(&str, i64)]) -> HashMap<String, i64> {
+    let mut reg: HashMap<String, i64> = awal.iter().map(|(n, v)| (n.to_string(), *v)).collect();
+    for stmt in lift(bytes) {
+        if let TipeOperand::Register(dest) = &stmt.operand_satu {
+            let nilai = match stmt.operation_code {
+                OperasiIr::Popcnt => evaluasi(&stmt.operand_dua, &reg).count_ones() as i64,
+                _ => evaluasi(&stmt.operand_dua, &reg),
+            };
+            reg.insert(dest.clone(), nilai);
+        }
+    }
+    reg
+}
+
+fn flag(reg: &HashMap<String, i64>, nama: &str) -> i64 {
+    *reg.get(&format!("eflags_{}", nama)).unwrap_or_else(|| panic!("flag {} tidak ditulis", nama))
+}
+
+fn cek_flags(reg: &HashMap<String, i64>, harapan: &[(&str, i64)]) {
+    for (nama, nilai) in harapan {
+        assert_eq!(flag(reg, nama), *nilai, "flag {} salah, state: {:?}", nama, reg);
+    }
+}
+
+#[test]
+fn test_flags_add_carry_overflow_per_lebar() {
+    let hasil = jalankan(&ADD_EAX_EBX, &[("rax", 0xFFFF_FFFF), ("rbx", 1)]);
+    cek_flags(&hasil, &[("cf", 1), ("zf", 1), ("of", 0), ("sf", 0), ("pf", 1), ("af", 1)]);
+    let hasil = jalankan(&ADD_EAX_EBX, &[("rax", 0x7FFF_FFFF), ("rbx", 1)]);
+    cek_flags(&hasil, &[("cf", 0), ("zf", 0), ("of", 1), ("sf", 1), ("pf", 1), ("af", 1)]);
+    let hasil = jalankan(&ADD_RAX_RBX, &[("rax", 0xFFFF_FFFF), ("rbx", 1)]);
+    cek_flags(&hasil, &[("cf", 0), ("zf", 0), ("of", 0), ("sf", 0)]);
+    let hasil = jalankan(&ADD_RAX_RBX, &[("rax", -1), ("rbx", 2)]);
+    cek_flags(&hasil, &[("cf", 1), ("zf", 0), ("of", 0), ("pf", 0)]);
+}
+
+#[test]
+fn test_flags_cmp_borrow_dan_overflow() {
+    let hasil = jalankan(&CMP_EAX_EBX, &[("rax", 1), ("rbx", 2)]);
+    cek_flags(&hasil, &[("cf", 1), ("sf", 1), ("of", 0), ("zf", 0), ("af", 1)]);
+    assert_eq!(hasil["rax"], 1, "cmp tidak boleh mengubah operand");
+    let hasil = jalankan(&CMP_EAX_EBX, &[("rax", 0x8000_0000), ("rbx", 1)]);
+    cek_flags(&hasil, &[("cf", 0), ("sf", 0), ("of", 1), ("zf", 0)]);
+    let hasil = jalankan(&CMP_EAX_EBX, &[("rax", 7), ("rbx", 7)]);
+    cek_flags(&hasil, &[("cf", 0), ("zf", 1), ("pf", 1)]);
+}
+
+#[test]
+fn test_flags_adc_sbb_memakai_carry_lama() {
+    let hasil = jalankan(&ADC_EAX_EBX, &[("rax", 0xFFFF_FFFF), ("rbx", 0), ("eflags_cf", 1)]);
+    cek_flags(&hasil, &[("cf", 1), ("zf", 1)]);
+    let hasil = jalankan(&ADC_EAX_EBX, &[("rax", 0xFFFF_FFFF), ("rbx", 0), ("eflags_cf", 0)]);
+    cek_flags(&hasil, &[("cf", 0), ("zf", 0), ("sf", 1)]);
+    let hasil = jalankan(&SBB_EAX_EBX, &[("rax", 5), ("rbx", 5), ("eflags_cf", 1)]);
+    cek_flags(&hasil, &[("cf", 1), ("sf", 1), ("zf", 0)]);
+    let hasil = jalankan(&SBB_EAX_EBX, &[("rax", 5), ("rbx", 5), ("eflags_cf", 0)]);
+    cek_flags(&hasil, &[("cf", 0), ("zf", 1)]);
+}
+
+#[test]
+fn test_flags_neg() {
+    let ir = lift(&NEG_EAX);
+    assert!(ir.iter().any(|s| s.operation_code == OperasiIr::Neg), "neg harus dilift, bukan Unknown");
+    cek_flags(&jalankan(&NEG_EAX, &[("rax", 0)]), &[("cf", 0), ("zf", 1), ("of", 0)]);
+    cek_flags(&jalankan(&NEG_EAX, &[("rax", 5)]), &[("cf", 1), ("zf", 0), ("sf", 1)]);
+    cek_flags(&jalankan(&NEG_EAX, &[("rax", 0x8000_0000)]), &[("cf", 1), ("of", 1)]);
+}
+
+#[test]
+fn test_flags_logika_menghapus_cf_of() {
+    let hasil = jalankan(&AND_EAX_EBX, &[("rax", 0x80), ("rbx", 0x80), ("eflags_cf", 1), ("eflags_of", 1)]);
+    cek_flags(&hasil, &[("cf", 0), ("of", 0), ("zf", 0), ("sf", 0), ("pf", 0)]);
+    let hasil = jalankan(&XOR_EAX_EBX, &[("rax", 0x8000_0003), ("rbx", 3), ("eflags_cf", 1)]);
+    cek_flags(&hasil, &[("cf", 0), ("of", 0), ("sf", 1), ("zf", 0)]);
+    let ir = lift(&XOR_EAX_EBX);
+    assert!(ir.iter().any(|s| s.operand_satu == TipeOperand::Register("eflags_af".to_string())
+        && s.operand_dua == TipeOperand::Register("undefined".to_string())));
+}
+
+#[test]
+fn test_flags_shift() {
+    let hasil = jalankan(&SHL_EAX_1, &[("rax", 0x8000_0001)]);
+    cek_flags(&hasil, &[("cf", 1), ("of", 1), ("zf", 0), ("sf", 0)]);
+    let hasil = jalankan(&SHR_EAX_1, &[("rax", 0x8000_0003)]);
+    cek_flags(&hasil, &[("cf", 1), ("of", 1), ("sf", 0)]);
+    let hasil = jalankan(&SAR_EAX_1, &[("rax", 2)]);
+    cek_flags(&hasil, &[("cf", 0), ("of", 0), ("zf", 0)]);
+    let hasil = jalankan(&SHL_EAX_CL, &[("rax", 1), ("rcx", 0), ("eflags_cf", 1), ("eflags_zf", 1)]);
+    cek_flags(&hasil, &[("cf", 1), ("zf", 1)]);
+}
+
+#[test]
+fn test_tipe_hasil_mengikuti_lebar_operand() {
+    let kasus: [(&[u8], OperasiIr, TipeDataIr); 5] = [
+        (&INC_AL, OperasiIr::Inc, TipeDataIr::I8),
+        (&ADC_AX_BX, OperasiIr::Adc, TipeDataIr::I16),
+        (&SBB_EAX_EBX, OperasiIr::Sbb, TipeDataIr::I32),
+        (&ADD_AL_BL, OperasiIr::Add, TipeDataIr::I8),
+        (&ADD_RAX_RBX, OperasiIr::Add, TipeDataIr::I64),
+    ];
+    for (bytes, operasi, tipe) in kasus {
+        let ir = lift(bytes);
+        let stmt = ir.iter().find(|s| s.operation_code == operasi).unwrap_or_else(|| panic!("{:?} harus ada: {:?}", operasi, ir));
+        assert_eq!(stmt.tipe_hasil, tipe, "{:?}: {:?}", operasi, ir);
+    }
+}
+
+#[test]
+fn test_sccp_melipat_perbandingan_unsigned() {
+    let mut cfg = ControlFlowGraph::inisialisasi_graph_kosong();
+    cfg.entry_point = 0;
+    let mut entry = BasicBlock::new(0);
+    entry.instruksi_list = vec![
+        StatementIr::new(0x00, OperasiIr::Mov, TipeOperand::Register("rax".to_string()), TipeOperand::Immediate(-1)),
+        StatementIr::new(0x04, OperasiIr::Ja, TipeOperand::Immediate(100), TipeOperand::Expression {
+            operasi: OperasiIr::Ja,
+            operand_kiri: Box::new(TipeOperand::Register("rax".to_string())),
+            operand_kanan: Box::new(TipeOperand::Immediate(1)),
+        }),
+    ];
+    cfg.blocks.insert(0, entry);
+    for id in [100, 200] {
+        let mut block = BasicBlock::new(id);
+        block.instruksi_list.push(StatementIr::new(id, OperasiIr::Ret, TipeOperand::None, TipeOperand::None));
+        cfg.blocks.insert(id, block);
+    }
+    cfg.hubungkan_manual(0, 100);
+    cfg.hubungkan_manual(0, 200);
+    let mut dom_tree = DominatorTree::new();
+    dom_tree.hitung_dominators(&cfg);
+    let mut transformer = SsaTransformer::new();
+    transformer.lakukan_transformasi_ssa(&mut cfg, &dom_tree);
+    transformer.optimasi_propagasi_konstanta(&mut cfg);
+    assert!(!cfg.blocks.contains_key(&200), "-1 > 1 secara unsigned, cabang false harus mati");
+    assert_eq!(cfg.blocks[&0].successors, vec![100]);
+}
+
+#[test]
+fn test_structurer_kondisi_unsigned() {
+    let mut cfg = ControlFlowGraph::inisialisasi_graph_kosong();
+    cfg.entry_point = 1;
+    let mut header = BasicBlock::new(1);
+    header.instruksi_list.push(StatementIr::new(1, OperasiIr::Cmp, TipeOperand::Register("temp_alu_flags".to_string()), TipeOperand::Expression {
+        operasi: OperasiIr::Sub,
+        operand_kiri: Box::new(TipeOperand::Register("eax".to_string())),
+        operand_kanan: Box::new(TipeOperand::Register("ebx".to_string())),
+    }));
+    header.instruksi_list.push(StatementIr::new(5, OperasiIr::Jb, TipeOperand::Immediate(2), TipeOperand::None));
+    header.successors = vec![2, 3];
+    cfg.blocks.insert(1, header);
+    for (id, succ, tujuan) in [(2, Some(4), "ecx"), (3, Some(4), "edx"), (4, None, "eax")] {
+        let mut block = BasicBlock::new(id);
+        block.instruksi_list.push(StatementIr::new(id, OperasiIr::Mov, TipeOperand::Register(tujuan.to_string()), TipeOperand::Immediate(id as i64)));
+        if let Some(t) = succ {
+            block.instruksi_list.push(StatementIr::new(id + 4, OperasiIr::Jmp, TipeOperand::Immediate(t as i64), TipeOperand::None));
+            block.successors.push(t);
+        }
+        cfg.blocks.insert(id, block);
+    }
+    cfg.blocks.get_mut(&2).unwrap().predecessors = vec![1];
+    cfg.blocks.get_mut(&3).unwrap().predecessors = vec![1];
+    cfg.blocks.get_mut(&4).unwrap().predecessors = vec![2, 3];
+    let ast = ControlFlowStructurer::new().bangun_tree_struktur(&mut cfg);
+    let NodeAst::Sequence(nodes) = &ast else { panic!("AST harus berupa sequence: {:#?}", ast) };
+    let kondisi = nodes.iter().find_map(|n| match n {
+        NodeAst::IfElse { kondisi, .. } => Some(kondisi.clone()),
+        _ => None,
+    }).expect("if-else harus terdeteksi");
+    assert_eq!(kondisi, "(unsigned long)eax < (unsigned long)ebx");
+}
@@ -0,0 +1,117 @@
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses, JenisEntryPoint};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+
+fn buat_vmem_dengan_init_array() -> VirtualMemory {
+    let mut vmem = VirtualMemory::baru(0x1000, "x86_64", "elf");
+    vmem.tambah_segment(0x1000, vec![0xC3; 0x100], IzinAkses::ReadExecute, ".text".to_string());
+    let mut init_array = Vec::new();
+    for ptr in [0x1010u64, 0, u64::MAX, 0x1020, 0x9999] {
+        init_array.extend_from_slice(&ptr.to_le_bytes());
+    }
+    vmem.tambah_segment(0x3000, init_array, IzinAkses::ReadWrite, ".init_array".to_string());
+    vmem.tambah_segment(0x3100, 0x1030u64.to_le_bytes().to_vec(), IzinAkses::ReadWrite, ".fini_array".to_string());
+    vmem
+}
+
+#[test]
+fn test_entry_point_default_berisi_entry_utama() {
+    let vmem = VirtualMemory::baru(0x1000, "x86_64", "elf");
+    assert_eq!(vmem.entry_points.len(), 1);
+    assert_eq!(vmem.entry_points[0].alamat, 0x1000);
+    assert_eq!(vmem.entry_points[0].jenis, JenisEntryPoint::Entry);
+}
+
+#[test]
+fn test_pindai_tabel_konstruktor_dan_destruktor() {
+    let mut vmem = buat_vmem_dengan_init_array();
+    vmem.pindai_tabel_pointer_fungsi(0x3000, 40, JenisEntryPoint::Konstruktor);
+    vmem.pindai_tabel_pointer_fungsi(0x3100, 8, JenisEntryPoint::Destruktor);
+    let ctors: Vec<u64> = vmem.entry_points.iter()
+        .filter(|ep| ep.jenis == JenisEntryPoint::Konstruktor)
+        .map(|ep| ep.alamat)
+        .collect();
+    assert_eq!(ctors, vec![0x1010, 0x1020], "Sentinel, nol dan alamat non-executable harus dilewati");
+    assert!(vmem.entry_points.iter().any(|ep| ep.alamat == 0x1030 && ep.jenis == JenisEntryPoint::Destruktor));
+}
+
+#[test]
+fn test_nama_default_entry_point() {
+    let mut vmem = buat_vmem_dengan_init_array();
+    vmem.simbol_global.insert(0x1020, "frame_dummy".to_string());
+    vmem.pindai_tabel_pointer_fungsi(0x3000, 40, JenisEntryPoint::Konstruktor);
+    vmem.pindai_tabel_pointer_fungsi(0x3100, 8, JenisEntryPoint::Destruktor);
+    vmem.tambah_entry_point(0x1040, JenisEntryPoint::TlsCallback, None);
+    vmem.tambah_entry_point(0x1050, JenisEntryPoint::Export, Some("PluginInit".to_string()));
+    vmem.tambah_entry_point(0x1050, JenisEntryPoint::Export, None);
+    vmem.terapkan_nama_default_entry_points();
+    assert_eq!(vmem.simbol_global.get(&0x1010).unwrap(), "ctor_1010");
+    assert_eq!(vmem.simbol_global.get(&0x1020).unwrap(), "frame_dummy", "Simbol asli tidak boleh ditimpa");
+    assert_eq!(vmem.simbol_global.get(&0x1030).unwrap(), "dtor_1030");
+    assert_eq!(vmem.simbol_global.get(&0x1040).unwrap(), "tls_callback_1040");
+    assert_eq!(vmem.simbol_global.get(&0x1050).unwrap(), "PluginInit");
+    assert!(!vmem.simbol_global.contains_key(&0x1000), "Entry utama tetap memakai nama entry_point dari codegen");
+    assert_eq!(vmem.entry_points.iter().filter(|ep| ep.alamat == 0x1050).count(), 1);
+}
+
+#[test]
+fn test_rebase_menggeser_entry_points() {
+    let mut vmem = buat_vmem_dengan_init_array();
+    vmem.image_base = 0x1000;
+    vmem.tambah_entry_point(0x1010, JenisEntryPoint::Konstruktor, None);
+    vmem.rebase_ke(0x5000);
+    assert_eq!(vmem.entry_points[0].alamat, 0x5000);
+    assert_eq!(vmem.entry_points[1].alamat, 0x5010);
+}
+
+#[test]
+fn test_explorer_seed_dari_entry_point_dan_signal_handler() {
+    let mut vmem = VirtualMemory::baru(0x1000, "x86_64", "elf");
+    let mut kode = vec![0xCC; 0x80];
+    let main = [
+        0x48, 0x8D, 0x35, 0x19, 0x00, 0x00, 0x00,
+        0xBF, 0x02, 0x00, 0x00, 0x00,
+        0xE8, 0x1F, 0x00, 0x00, 0x00,
+        0xC3,
+    ];
+    kode[0x00..0x12].copy_from_slice(&main);
+    kode[0x20] = 0xC3;
+    kode[0x30] = 0xC3;
+    kode[0x40] = 0xC3;
+    vmem.tambah_segment(0x1000, kode, IzinAkses::ReadExecute, ".text".to_string());
+    vmem.simbol_global.insert(0x1000, "main".to_string());
+    vmem.simbol_global.insert(0x1030, "signal".to_string());
+    vmem.tambah_entry_point(0x1040, JenisEntryPoint::Konstruktor, None);
+    let mut analyzer = RecursiveDescent::new("x86_64");
+    analyzer.lakukan_analisis_full(&vmem);
+    let hasil = analyzer.ambil_hasil_fungsi();
+    assert!(hasil.contains_key(&0x1040), "Konstruktor harus jadi seed explorer");
+    assert_eq!(analyzer.signal_handlers, vec![0x1020]);
+    assert!(hasil.contains_key(&0x1020), "Signal handler harus dianalisis sebagai fungsi");
+}
+
+#[test]
+fn test_signal_handler_x86_32_dari_argumen_stack() {
+    let mut vmem = VirtualMemory::baru(0x1000, "x86", "elf");
+    let mut kode = vec![0xCC; 0x80];
+    let main = [
+        0x68, 0x50, 0x10, 0x00, 0x00,
+        0x6A, 0x02,
+        0xE8, 0x64, 0x00, 0x00, 0x00,
+        0x83, 0xEC, 0x1C,
+        0xC7, 0x44, 0x24, 0x04, 0x60, 0x10, 0x00, 0x00,
+        0xC7, 0x04, 0x24, 0x0F, 0x00, 0x00, 0x00,
+        0xE8, 0x4D, 0x00, 0x00, 0x00,
+        0x83, 0xC4, 0x1C,
+        0xC3,
+    ];
+    kode[..main.len()].copy_from_slice(&main);
+    kode[0x50] = 0xC3;
+    kode[0x60] = 0xC3;
+    kode[0x70] = 0xC3;
+    vmem.tambah_segment(0x1000, kode, IzinAkses::ReadExecute, ".text".to_string());
+    vmem.simbol_global.insert(0x1000, "main".to_string());
+    vmem.simbol_global.insert(0x1070, "signal".to_string());
+    let mut analyzer = RecursiveDescent::new("x86");
+    analyzer.lakukan_analisis_full(&vmem);
+    assert_eq!(analyzer.signal_handlers, vec![0x1050, 0x1060], "handler cdecl diambil dari push maupun [esp+4]");
+}
@@ -0,0 +1,229 @@
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::lift::dispatch::{self, AksiLift, ArsitekturDispatch, KelasInstruksi};
+use rcdecomp_core::ir::types::{OperasiIr, StatementIr, TipeDataIr, TipeOperand};
+
+const KASUS_X86: &[(&[u8], &str, OperasiIr)] = &[
+    (&[0xC3], "ret", OperasiIr::Ret),
+    (&[0xE8, 0x00, 0x00, 0x00, 0x00], "call", OperasiIr::Call),
+    (&[0xEB, 0x00], "jmp", OperasiIr::Jmp),
+    (&[0x74, 0x00], "je", OperasiIr::Je),
+    (&[0x75, 0x00], "jne", OperasiIr::Jne),
+    (&[0x7F, 0x00], "jg", OperasiIr::Jg),
+    (&[0x77, 0x00], "ja", OperasiIr::Ja),
+    (&[0x7D, 0x00], "jge", OperasiIr::Jge),
+    (&[0x73, 0x00], "jae", OperasiIr::Jae),
+    (&[0x7C, 0x00], "jl", OperasiIr::Jl),
+    (&[0x72, 0x00], "jb", OperasiIr::Jb),
+    (&[0x7E, 0x00], "jle", OperasiIr::Jle),
+    (&[0x76, 0x00], "jbe", OperasiIr::Jbe),
+    (&[0x78, 0x00], "js", OperasiIr::Js),
+    (&[0x79, 0x00], "jns", OperasiIr::Jns),
+    (&[0x70, 0x00], "jo", OperasiIr::Jo),
+    (&[0x71, 0x00], "jno", OperasiIr::Jno),
+    (&[0x7A, 0x00], "jp", OperasiIr::Jp),
+    (&[0x7B, 0x00], "jnp", OperasiIr::Jnp),
+    (&[0x55], "push", OperasiIr::Sub),
+    (&[0x5D], "pop", OperasiIr::Add),
+    (&[0x48, 0x89, 0xE5], "mov", OperasiIr::Mov),
+    (&[0x48, 0xB8, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11], "movabs", OperasiIr::Mov),
+    (&[0x0F, 0xB6, 0xC1], "movzx", OperasiIr::Mov),
+    (&[0x0F, 0xBE, 0xC1], "movsx", OperasiIr::Mov),
+    (&[0x48, 0x8D, 0x45, 0xF8], "lea", OperasiIr::Lea),
+    (&[0x48, 0x01, 0xD8], "add", OperasiIr::Add),
+    (&[0x48, 0xFF, 0xC0], "inc", OperasiIr::Inc),
+    (&[0x48, 0x29, 0xD8], "sub", OperasiIr::Sub),
+    (&[0x48, 0xFF, 0xC8], "dec", OperasiIr::Dec),
+    (&[0x48, 0xF7, 0xD8], "neg", OperasiIr::Neg),
+    (&[0x48, 0x0F, 0xAF, 0xC1], "imul", OperasiIr::Imul),
+    (&[0x48, 0xF7, 0xE1], "mul", OperasiIr::Mul),
+    (&[0x48, 0xF7, 0xF9], "idiv", OperasiIr::Idiv),
+    (&[0x48, 0xF7, 0xF1], "div", OperasiIr::Div),
+    (&[0x48, 0x11, 0xD8], "adc", OperasiIr::Adc),
+    (&[0x48, 0x19, 0xD8], "sbb", OperasiIr::Sbb),
+    (&[0x48, 0x21, 0xD8], "and", OperasiIr::And),
+    (&[0x48, 0x09, 0xD8], "or", OperasiIr::Or),
+    (&[0x48, 0x31, 0xD8], "xor", OperasiIr::Xor),
+    (&[0x48, 0xD1, 0xE0], "shl", OperasiIr::Shl),
+    (&[0x48, 0xD1, 0xE8], "shr", OperasiIr::Shr),
+    (&[0x48, 0xD1, 0xF8], "sar", OperasiIr::Sar),
+    (&[0x48, 0xD1, 0xC0], "rol", OperasiIr::Rol),
+    (&[0x48, 0xD1, 0xC8], "ror", OperasiIr::Ror),
+    (&[0x48, 0x39, 0xD8], "cmp", OperasiIr::Sub),
+    (&[0x48, 0x85, 0xC0], "test", OperasiIr::And),
+    (&[0x0F, 0x05], "syscall", OperasiIr::Syscall),
+    (&[0xF3, 0x48, 0x0F, 0xB8, 0xC1], "popcnt", OperasiIr::Popcnt),
+    (&[0xF3, 0x0F, 0xBD, 0xC1], "lzcnt", OperasiIr::Lzcnt),
+    (&[0xF3, 0x48, 0x0F, 0xBC, 0xC1], "tzcnt", OperasiIr::Tzcnt),
+    (&[0x48, 0x87, 0x07], "xchg", OperasiIr::AtomicXchg),
+    (&[0xF0, 0x48, 0x0F, 0xB1, 0x0F], "lock cmpxchg", OperasiIr::AtomicCas),
+    (&[0x0F, 0xAE, 0xF0], "mfence", OperasiIr::Fence),
+    (&[0xF0, 0x0F, 0xC1, 0x03], "lock xadd", OperasiIr::AtomicAdd),
+    (&[0x0F, 0x58, 0xC1], "addps", OperasiIr::VecAdd),
+    (&[0x66, 0x0F, 0xFE, 0xC1], "paddd", OperasiIr::VecAdd),
+    (&[0xC5, 0xF0, 0x58, 0xC2], "vaddps", OperasiIr::VecAdd),
+    (&[0x66, 0x0F, 0xEF, 0xC1], "pxor", OperasiIr::VecXor),
+];
+
+fn lift_x86(bytes: &[u8]) -> (InstructionNormalized, Vec<StatementIr>) {
+    let engine = DisasmEngine::buat_engine_baru("x86_64");
+    let instr = engine.ambil_satu_instruksi(bytes, 0x1000).expect("byte uji harus terdekode");
+    let ir = IrLifter::untuk_arsitektur("x86_64").konversi_instruksi_ke_microcode(&instr);
+    (instr, ir)
+}
+
+fn lift_arm64(bytes: &[u8]) -> (InstructionNormalized, Vec<StatementIr>) {
+    let engine = DisasmEngine::buat_engine_baru("aarch64");
+    let instr = engine.ambil_satu_instruksi(bytes, 0x1000).expect("byte uji harus terdekode");
+    let ir = IrLifter::untuk_arsitektur("aarch64").konversi_instruksi_ke_microcode(&instr);
+    (instr, ir)
+}
+
+fn menghasilkan(ir: &[StatementIr], operasi: &OperasiIr) -> bool {
+    ir.iter().any(|s| s.operation_code == *operasi
+        || matches!(&s.operand_dua, TipeOperand::Expression { operasi: op, .. } if op == operasi))
+}
+
+fn punya_intrinsic(ir: &[StatementIr]) -> bool {
+    ir.iter().any(|s| matches!(&s.operation_code, OperasiIr::Intrinsic(n) if n.starts_with("_mm_")))
+}
+
+#[test]
+fn test_regresi_dispatch_x86() {
+    for (bytes, mnemonic, operasi) in KASUS_X86 {
+        let (instr, ir) = lift_x86(bytes);
+        assert_eq!(instr.mnemonic, *mnemonic);
+        assert!(menghasilkan(&ir, operasi), "{} harus menghasilkan {:?}, dapat {:?}", mnemonic, operasi, ir);
+    }
+}
+
+#[test]
+fn test_push_pop_tidak_dianggap_simd() {
+    for bytes in [&[0x55][..], &[0x5D], &[0x9C], &[0x9D], &[0x6A, 0x01]] {
+        let (instr, ir) = lift_x86(bytes);
+        assert!(!punya_intrinsic(&ir), "{} tidak boleh menjadi intrinsic SIMD", instr.mnemonic);
+        assert_eq!(dispatch::klasifikasi_instruksi(ArsitekturDispatch::X86, &instr), KelasInstruksi::Umum);
+    }
+    let (_, pushf) = lift_x86(&[0x9C]);
+    let simpan = pushf.iter().find(|s| s.operation_code == OperasiIr::Mov).expect("pushfq harus menyimpan flag ke stack");
+    assert_eq!(simpan.operand_satu, TipeOperand::MemoryRef { base: "rsp".to_string(), offset: 0 });
+    assert_eq!(simpan.operand_dua, TipeOperand::Register("eflags".to_string()));
+    let (_, popf) = lift_x86(&[0x9D]);
+    assert_eq!(popf[0].operand_satu, TipeOperand::Register("eflags".to_string()));
+}
+
+#[test]
+fn test_movsd_string_dibedakan_dari_sse() {
+    let (string_op, ir_string) = lift_x86(&[0xA5]);
+    assert_eq!(string_op.mnemonic, "movsd");
+    assert!(!punya_intrinsic(&ir_string), "movsd string bukan instruksi SSE");
+    let (cmpsd, ir_cmpsd) = lift_x86(&[0xA7]);
+    assert_eq!(cmpsd.mnemonic, "cmpsd");
+    assert!(!punya_intrinsic(&ir_cmpsd));
+    let (sse, ir_sse) = lift_x86(&[0xF2, 0x0F, 0x10, 0xC1]);
+    assert_eq!(sse.mnemonic, "movsd");
+    assert_eq!(sse.id_instruksi, string_op.id_instruksi, "capstone memakai ID yang sama, grup yang membedakan");
+    assert_eq!(dispatch::klasifikasi_instruksi(ArsitekturDispatch::X86, &sse), KelasInstruksi::Simd);
+    assert!(!punya_intrinsic(&ir_sse), "movsd skalar diangkat sebagai pindah float");
+    assert_eq!(ir_sse[0].operation_code, OperasiIr::Mov);
+    assert_eq!(ir_sse[0].tipe_hasil, TipeDataIr::F64);
+}
+
+#[test]
+fn test_klasifikasi_grup_x86() {
+    let kasus: [(&[u8], KelasInstruksi); 4] = [
+        (&[0x66, 0x0F, 0x38, 0xDC, 0xC1], KelasInstruksi::Crypto),
+        (&[0x66, 0x0F, 0x3A, 0x44, 0xC1, 0x00], KelasInstruksi::Crypto),
+        (&[0xD9, 0xC1], KelasInstruksi::Fpu),
+        (&[0xC5, 0xF0, 0x58, 0xC2], KelasInstruksi::Simd),
+    ];
+    for (bytes, kelas) in kasus {
+        let (instr, _) = lift_x86(bytes);
+        assert_eq!(dispatch::klasifikasi_instruksi(ArsitekturDispatch::X86, &instr), kelas, "{}", instr.mnemonic);
+    }
+    let (fld, ir_fld) = lift_x86(&[0xD9, 0xC1]);
+    assert_eq!(fld.mnemonic, "fld");
+    assert!(!punya_intrinsic(&ir_fld));
+}
+
+#[test]
+fn test_dispatch_arm64_per_arsitektur() {
+    let kasus: [(&[u8], &str, OperasiIr); 14] = [
+        (&[0xC0, 0x03, 0x5F, 0xD6], "ret", OperasiIr::Ret),
+        (&[0x02, 0x00, 0x00, 0x94], "bl", OperasiIr::Call),
+        (&[0x02, 0x00, 0x00, 0x14], "b", OperasiIr::Jmp),
+        (&[0x40, 0x00, 0x00, 0x54], "b.eq", OperasiIr::Je),
+        (&[0x41, 0x00, 0x00, 0x54], "b.ne", OperasiIr::Jne),
+        (&[0x4B, 0x00, 0x00, 0x54], "b.lt", OperasiIr::Jl),
+        (&[0x40, 0x00, 0x00, 0xB4], "cbz", OperasiIr::Je),
+        (&[0x40, 0x00, 0x00, 0xB5], "cbnz", OperasiIr::Jne),
+        (&[0x00, 0x00, 0x00, 0x90], "adrp", OperasiIr::Lea),
+        (&[0xE0, 0x03, 0x01, 0xAA], "mov", OperasiIr::Mov),
+        (&[0x1F, 0x00, 0x01, 0xEA], "tst", OperasiIr::And),
+        (&[0x1F, 0x00, 0x01, 0xEB], "cmp", OperasiIr::Sub),
+        (&[0x20, 0x00, 0x02, 0xCA], "eor", OperasiIr::Xor),
+        (&[0x1F, 0x20, 0x03, 0xD5], "nop", OperasiIr::Nop),
+    ];
+    for (bytes, mnemonic, operasi) in kasus {
+        let (instr, ir) = lift_arm64(bytes);
+        assert_eq!(instr.mnemonic, mnemonic);
+        if operasi == OperasiIr::Nop {
+            assert!(ir.is_empty());
+        } else {
+            assert!(menghasilkan(&ir, &operasi), "{} harus menghasilkan {:?}, dapat {:?}", mnemonic, operasi, ir);
+        }
+    }
+    let (aese, _) = lift_arm64(&[0x20, 0x48, 0x28, 0x4E]);
+    assert_eq!(dispatch::klasifikasi_instruksi(ArsitekturDispatch::Arm64, &aese), KelasInstruksi::Crypto);
+    let (fadd, _) = lift_arm64(&[0x20, 0x28, 0x22, 0x1E]);
+    assert_eq!(dispatch::klasifikasi_instruksi(ArsitekturDispatch::Arm64, &fadd), KelasInstruksi::Fpu);
+}
+
+#[test]
+fn test_instruksi_manual_dipetakan_lewat_nama() {
+    let mut push = InstructionNormalized::new(0x1000, "push", "rbp");
+    push.operands_detail = vec![JenisOperandDisasm::Register("rbp".to_string())];
+    assert_eq!(dispatch::tentukan_aksi(ArsitekturDispatch::X86, &push), Some(AksiLift::Push));
+    let xadd = InstructionNormalized::new(0x1000, "lock xadd", "");
+    assert_eq!(dispatch::tentukan_aksi(ArsitekturDispatch::X86, &xadd), Some(AksiLift::Sistem));
+    let bcc = InstructionNormalized::new(0x1000, "b.gt", "");
+    assert_eq!(dispatch::tentukan_aksi(ArsitekturDispatch::Arm64, &bcc), Some(AksiLift::JumpKondisi));
+    assert_eq!(dispatch::tentukan_aksi(ArsitekturDispatch::X86, &InstructionNormalized::new(0x1000, "pshufb", "")), None);
+}
+
+#[test]
+fn test_keluarga_aritmetika_punya_aksi_sendiri() {
+    let kasus: &[(&[u8], AksiLift)] = &[
+        (&[0x48, 0xF7, 0xE1], AksiLift::Mul),
+        (&[0x48, 0x0F, 0xAF, 0xC1], AksiLift::Imul),
+        (&[0x48, 0xF7, 0xF1], AksiLift::Div),
+        (&[0x48, 0xF7, 0xF9], AksiLift::Idiv),
+        (&[0xF3, 0x0F, 0xB8, 0xC1], AksiLift::Popcnt),
+        (&[0x48, 0x93], AksiLift::Tukar),
+        (&[0x48, 0x0F, 0xB1, 0xCB], AksiLift::TukarBanding),
+    ];
+    for (bytes, aksi) in kasus {
+        let (instr, _) = lift_x86(bytes);
+        assert_eq!(dispatch::tentukan_aksi(ArsitekturDispatch::X86, &instr), Some(*aksi), "{}", instr.mnemonic);
+    }
+}
+
+#[test]
+fn test_xchg_cmpxchg_popcnt_menjaga_aliran_data() {
+    let reg = |nama: &str| TipeOperand::Register(nama.to_string());
+    let (_, xchg) = lift_x86(&[0x48, 0x93]);
+    for nama in ["rax", "rbx"] {
+        assert!(xchg.iter().any(|s| s.operation_code == OperasiIr::Mov && s.operand_satu == reg(nama)), "xchg harus menulis {}", nama);
+    }
+    let (_, xchg_mem) = lift_x86(&[0x48, 0x87, 0x07]);
+    assert!(xchg_mem.iter().any(|s| s.operation_code == OperasiIr::Mov && s.operand_satu == reg("rax")), "xchg memori harus menulis nilai lama ke rax");
+    let (_, cmpxchg) = lift_x86(&[0x48, 0x0F, 0xB1, 0xCB]);
+    assert!(cmpxchg.iter().any(|s| s.operand_satu == reg("rbx") && matches!(s.operand_dua, TipeOperand::Conditional { .. })), "cmpxchg harus menulis tujuan secara kondisional");
+    assert!(cmpxchg.iter().any(|s| s.operand_satu == reg("rax")), "cmpxchg harus memperbarui akumulator");
+    assert!(cmpxchg.iter().any(|s| s.operand_satu == reg("eflags_zf")), "cmpxchg harus menghitung ZF");
+    let (_, popcnt) = lift_x86(&[0xF3, 0x0F, 0xB8, 0xC1]);
+    let hitung = popcnt.iter().find(|s| s.operation_code == OperasiIr::Popcnt).expect("popcnt harus dilift");
+    assert_eq!(hitung.tipe_hasil, TipeDataIr::I32);
+    assert!(popcnt.iter().any(|s| s.operand_satu == reg("eflags_zf")), "popcnt harus menulis ZF");
+}
@@ -88,7 +88,11 @@ fn test_bitwise_lifting_masking() {
     if let TipeOperand::Expression { operasi: _, operand_kiri: _, operand_kanan } = &shl_stmt.operand_dua {
         if let TipeOperand::Expression { operasi: inner_op, operand_kiri: inner_l, operand_kanan: inner_r } = &**operand_kanan {
              assert_eq!(*inner_op, OperasiIr::And, "Shift count harus di-AND");
-             assert_eq!(**inner_l, TipeOperand::Register("cl".to_string()));
+             assert_eq!(**inner_l, TipeOperand::Expression {
+                 operasi: OperasiIr::Truncate,
+                 operand_kiri: Box::new(TipeOperand::Register("rcx".to_string())),
+                 operand_kanan: Box::new(TipeOperand::Immediate(8)),
+             }, "cl dibaca sebagai 8 bit rendah rcx");
              assert_eq!(**inner_r, TipeOperand::Immediate(0x3F), "Masking harus 0x3F");
         } else {
              if let TipeOperand::Expression { operasi: shift_op, operand_kiri: _, operand_kanan: count_expr } = &shl_stmt.operand_dua {
@@ -133,6 +137,8 @@ fn test_system_lifting_cpuid() {
         OperasiIr::Intrinsic(name) => name == "__cpuid",
         _ => false
     }).expect("CPUID harus dilift ke intrinsic __cpuid");
-    let has_eax_arg = intrinsic_stmt.operand_tambahan.contains(&TipeOperand::Register("eax".to_string()));
+    let has_eax_arg = intrinsic_stmt.operand_tambahan.iter().any(|op| matches!(op,
+        TipeOperand::Expression { operasi: OperasiIr::Truncate, operand_kiri, operand_kanan }
+            if **operand_kiri == TipeOperand::Register("rax".to_string()) && **operand_kanan == TipeOperand::Immediate(32)));
     assert!(has_eax_arg, "CPUID harus mengambil eax sebagai input");
 }
\ No newline at end of file
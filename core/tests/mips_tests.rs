@@ -0,0 +1,155 @@
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::analysis::recovery::abi::{ProfilAbi, TipeAbi};
+use rcdecomp_core::analysis::graph::cfg::ControlFlowGraph;
+use rcdecomp_core::arch::mips::MipsArsitektur;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::disasm::instruction::JenisOperandDisasm;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, TipeOperand, StatementIr};
+use std::collections::HashMap;
+
+const KODE_MIPS_BE: [u8; 64] = [
+    0x27, 0xBD, 0xFF, 0xE0,
+    0xAF, 0xBF, 0x00, 0x1C,
+    0x8F, 0x99, 0x80, 0x10,
+    0x03, 0x20, 0xF8, 0x09,
+    0x02, 0x00, 0x20, 0x25,
+    0x10, 0x80, 0x00, 0x07,
+    0x24, 0x84, 0x00, 0x01,
+    0x3C, 0x02, 0x12, 0x34,
+    0x34, 0x42, 0x56, 0x78,
+    0x00, 0x85, 0x00, 0x18,
+    0x00, 0x00, 0x18, 0x12,
+    0x0C, 0x10, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00,
+    0x8F, 0xBF, 0x00, 0x1C,
+    0x03, 0xE0, 0x00, 0x08,
+    0x27, 0xBD, 0x00, 0x20,
+];
+
+fn buat_vmem_mips() -> VirtualMemory {
+    let mut vmem = VirtualMemory::baru(0x400000, "mips", "elf");
+    vmem.tambah_segment(0x400000, KODE_MIPS_BE.to_vec(), IzinAkses::ReadExecute, ".text".to_string());
+    vmem.tambah_segment(0x411000, vec![0; 0x20], IzinAkses::ReadWrite, ".got".to_string());
+    vmem.nilai_gp = Some(0x418FF0);
+    vmem.simbol_global.insert(0x411000, "puts".to_string());
+    vmem
+}
+
+fn reg(nama: &str) -> TipeOperand {
+    TipeOperand::Register(nama.to_string())
+}
+
+fn ambil_ir_fungsi(vmem: &VirtualMemory) -> Vec<StatementIr> {
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(vmem);
+    analyzer.ambil_hasil_fungsi().get(&0x400000).expect("fungsi harus terdeteksi").ir_code.clone()
+}
+
+#[test]
+fn test_dekode_big_endian_dan_little_endian() {
+    let engine_be = DisasmEngine::buat_engine_baru("mips");
+    let addiu = engine_be.ambil_satu_instruksi(&KODE_MIPS_BE[0..], 0x400000).unwrap();
+    assert_eq!(addiu.mnemonic, "addiu");
+    assert_eq!(addiu.operands_detail, vec![
+        JenisOperandDisasm::Register("sp".to_string()),
+        JenisOperandDisasm::Register("sp".to_string()),
+        JenisOperandDisasm::Immediate(-32),
+    ]);
+    let engine_le = DisasmEngine::buat_engine_baru("mipsel");
+    let kode_le: Vec<u8> = KODE_MIPS_BE[8..12].iter().rev().copied().collect();
+    let lw = engine_le.ambil_satu_instruksi(&kode_le, 0x400008).unwrap();
+    assert_eq!(lw.mnemonic, "lw");
+    assert_eq!(lw.operands_detail[1], JenisOperandDisasm::Memory { base: Some("gp".to_string()), index: None, scale: 1, disp: -0x7FF0, segment: None });
+}
+
+#[test]
+fn test_memori_big_endian() {
+    let mut vmem = buat_vmem_mips();
+    assert!(vmem.big_endian);
+    assert_eq!(vmem.baca_pointer(0x400000, 4), Some(0x27BDFFE0));
+    assert!(vmem.tulis_pointer(0x411004, 0x400010, 4));
+    assert_eq!(vmem.baca_array(0x411004, 4), Some(vec![0x00, 0x40, 0x00, 0x10]));
+    assert_eq!(vmem.baca_pointer(0x411004, 4), Some(0x400010));
+    assert!(!VirtualMemory::baru(0, "mipsel", "elf").big_endian);
+}
+
+#[test]
+fn test_delay_slot_dieksekusi_sebelum_branch() {
+    let ir = ambil_ir_fungsi(&buat_vmem_mips());
+    assert!(!ir.iter().any(|s| [0x400010, 0x400018, 0x40003C].contains(&s.address_asal)),
+        "instruksi delay slot harus digabung ke alamat branch");
+    let panggil: Vec<&StatementIr> = ir.iter().filter(|s| s.address_asal == 0x40000C).collect();
+    assert_eq!(panggil.len(), 2);
+    assert_eq!(panggil[0].operation_code, OperasiIr::Mov);
+    assert_eq!(panggil[0].operand_satu, reg("a0"));
+    assert_eq!(panggil[1].operation_code, OperasiIr::Call);
+    let cabang: Vec<&StatementIr> = ir.iter().filter(|s| s.address_asal == 0x400014).collect();
+    assert_eq!(cabang.len(), 3);
+    assert_eq!(cabang[0].operand_satu, reg("a0_ds"));
+    assert_eq!(cabang[1].operation_code, OperasiIr::Add);
+    assert_eq!(cabang[2].operation_code, OperasiIr::Je);
+    assert_eq!(cabang[2].operand_satu, TipeOperand::Immediate(0x400034));
+    assert!(matches!(&cabang[2].operand_dua, TipeOperand::Expression { operand_kiri, .. } if **operand_kiri == reg("a0_ds")),
+        "kondisi branch harus membaca nilai register sebelum delay slot");
+    let epilog: Vec<&StatementIr> = ir.iter().filter(|s| s.address_asal == 0x400038).collect();
+    assert_eq!(epilog.last().unwrap().operation_code, OperasiIr::Ret);
+    assert_eq!(epilog[0].operand_satu, reg("sp"));
+    assert!(ir.iter().any(|s| s.operand_satu == reg("v1") && s.operand_dua == reg("lo")));
+}
+
+#[test]
+fn test_akses_gp_ke_slot_got() {
+    let ir = ambil_ir_fungsi(&buat_vmem_mips());
+    assert!(ir.iter().any(|s| s.operand_satu == reg("t9") && s.operand_dua == TipeOperand::Memory(0x411000)));
+    let call = ir.iter().find(|s| s.address_asal == 0x40000C && s.operation_code == OperasiIr::Call).unwrap();
+    assert_eq!(call.operand_satu, TipeOperand::Memory(0x411000), "jalr $t9 harus diarahkan ke slot GOT bernama");
+    let rekursif = ir.iter().find(|s| s.address_asal == 0x40002C && s.operation_code == OperasiIr::Call).unwrap();
+    assert_eq!(rekursif.operand_satu, TipeOperand::Immediate(0x400000));
+}
+
+#[test]
+fn test_cfg_pertahankan_statement_alamat_sama() {
+    let stmts = vec![
+        StatementIr::new(0x10, OperasiIr::Mov, reg("a0_ds"), reg("a0")),
+        StatementIr::new(0x10, OperasiIr::Add, reg("a0"), TipeOperand::Immediate(1)),
+        StatementIr::new(0x10, OperasiIr::Je, TipeOperand::Immediate(0x18), reg("a0_ds")),
+        StatementIr::new(0x14, OperasiIr::Mov, reg("v0"), TipeOperand::Immediate(0)),
+        StatementIr::new(0x18, OperasiIr::Ret, TipeOperand::None, TipeOperand::None),
+    ];
+    let cfg = ControlFlowGraph::bangun_execution_graph(stmts, &HashMap::new());
+    assert_eq!(cfg.blocks[&0x10].instruksi_list.len(), 3);
+    assert_eq!(cfg.blocks.len(), 3);
+    assert!(cfg.blocks[&0x10].successors.contains(&0x18));
+    assert!(cfg.blocks[&0x10].successors.contains(&0x14));
+}
+
+#[test]
+fn test_mult_dan_multu_beda_tanda() {
+    let engine = DisasmEngine::buat_engine_baru("mips");
+    let lifter = IrLifter::untuk_arsitektur("mips");
+    let kasus: [([u8; 4], OperasiIr, OperasiIr); 2] = [
+        ([0x00, 0x85, 0x00, 0x18], OperasiIr::Imul, OperasiIr::MulHi),
+        ([0x00, 0x85, 0x00, 0x19], OperasiIr::Mul, OperasiIr::MulHiU),
+    ];
+    for (bytes, op_lo, op_hi) in kasus {
+        let instr = engine.ambil_satu_instruksi(&bytes, 0x400000).unwrap();
+        let ir = lifter.konversi_instruksi_ke_microcode(&instr);
+        let nilai = |nama: &str| ir.iter().find(|s| s.operand_satu == reg(nama)).map(|s| s.operand_dua.clone()).unwrap_or_else(|| panic!("{} harus ditulis: {:?}", nama, ir));
+        assert!(matches!(nilai("lo"), TipeOperand::Expression { operasi, .. } if operasi == op_lo), "{}: {:?}", instr.mnemonic, ir);
+        assert!(matches!(nilai("hi"), TipeOperand::Expression { operasi, .. } if operasi == op_hi), "{}: {:?}", instr.mnemonic, ir);
+    }
+}
+
+#[test]
+fn test_profil_abi_mips_o32() {
+    let arch = MipsArsitektur;
+    assert_eq!(arch.dapatkan_global_pointer(), "gp");
+    let profil = ProfilAbi::buat_profil_otomatis(&arch, "elf");
+    assert_eq!(profil.tipe, TipeAbi::MipsO32);
+    assert_eq!(profil.register_integer, vec!["a0", "a1", "a2", "a3"]);
+    assert_eq!(profil.shadow_space, 16);
+    assert!(profil.volatile_registers.contains("t9"));
+    assert!(!profil.volatile_registers.contains("s0"));
+}
@@ -0,0 +1,84 @@
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::disasm::instruction::{AksesOperand, InstructionNormalized, JenisOperandDisasm};
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, TipeOperand, TipeDataIr};
+
+const MOV_EAX_MEM: [u8; 2] = [0x8B, 0x03];
+const MOV_AL_MEM: [u8; 2] = [0x8A, 0x03];
+const MOV_RAX_FS: [u8; 9] = [0x64, 0x48, 0x8B, 0x04, 0x25, 0x28, 0x00, 0x00, 0x00];
+const REP_STOSB: [u8; 2] = [0xF3, 0xAA];
+const LOCK_XADD: [u8; 4] = [0xF0, 0x0F, 0xC1, 0x03];
+const CALL_REL: [u8; 5] = [0xE8, 0x00, 0x00, 0x00, 0x00];
+
+#[test]
+fn test_ukuran_dan_akses_operand_x86() {
+    let engine = DisasmEngine::buat_engine_baru("x86_64");
+    let dword = engine.ambil_satu_instruksi(&MOV_EAX_MEM, 0x1000).unwrap();
+    assert_eq!(dword.ambil_ukuran_operand(0), Some(4));
+    assert_eq!(dword.ambil_ukuran_operand(1), Some(4));
+    assert_eq!(dword.ambil_akses_operand(0), AksesOperand::Tulis);
+    assert_eq!(dword.ambil_akses_operand(1), AksesOperand::Baca);
+    assert!(dword.register_dibaca.contains(&"rbx".to_string()));
+    assert!(dword.register_ditulis.contains(&"eax".to_string()));
+    let byte = engine.ambil_satu_instruksi(&MOV_AL_MEM, 0x1000).unwrap();
+    assert_eq!(byte.ambil_ukuran_operand(1), Some(1));
+}
+
+#[test]
+fn test_lebar_statement_dari_ukuran_operand() {
+    let engine = DisasmEngine::buat_engine_baru("x86_64");
+    let lifter = IrLifter::untuk_arsitektur("x86_64");
+    let ir_dword = lifter.konversi_instruksi_ke_microcode(&engine.ambil_satu_instruksi(&MOV_EAX_MEM, 0x1000).unwrap());
+    let ir_byte = lifter.konversi_instruksi_ke_microcode(&engine.ambil_satu_instruksi(&MOV_AL_MEM, 0x1000).unwrap());
+    assert_eq!(ir_dword[0].tipe_hasil, TipeDataIr::I32);
+    assert_eq!(ir_byte[0].tipe_hasil, TipeDataIr::I8, "mov al harus dibedakan dari mov eax");
+    let mut add = InstructionNormalized::new(0x1000, "add", "");
+    add.operands_detail = vec![JenisOperandDisasm::Register("ecx".to_string()), JenisOperandDisasm::Immediate(1)];
+    let ir_add = lifter.konversi_instruksi_ke_microcode(&add);
+    let stmt_add = ir_add.iter().find(|s| s.operation_code == OperasiIr::Add).unwrap();
+    assert_eq!(stmt_add.tipe_hasil, TipeDataIr::I32, "lebar diturunkan dari register file bila capstone tidak memberi ukuran");
+    assert!(ir_add.iter().filter(|s| matches!(&s.operand_satu, TipeOperand::Register(r) if r.starts_with("eflags_")))
+        .all(|s| s.tipe_hasil == TipeDataIr::I8));
+}
+
+#[test]
+fn test_segment_fs_menjadi_memory_complex() {
+    let engine = DisasmEngine::buat_engine_baru("x86_64");
+    let instr = engine.ambil_satu_instruksi(&MOV_RAX_FS, 0x1000).unwrap();
+    assert!(matches!(&instr.operands_detail[1], JenisOperandDisasm::Memory { segment: Some(s), disp: 0x28, .. } if s == "fs"));
+    assert!(instr.punya_prefix("fs"));
+    let ir = IrLifter::untuk_arsitektur("x86_64").konversi_instruksi_ke_microcode(&instr);
+    assert_eq!(ir[0].operand_dua, TipeOperand::MemoryComplex {
+        base: None,
+        index: None,
+        scale: 1,
+        disp: 0x28,
+        segment: Some("fs".to_string()),
+    }, "akses fs:[0x28] bukan global di alamat 0x28");
+    assert_eq!(ir[0].tipe_hasil, TipeDataIr::I64);
+}
+
+#[test]
+fn test_prefix_register_implisit_dan_grup() {
+    let engine = DisasmEngine::buat_engine_baru("x86_64");
+    let stos = engine.ambil_satu_instruksi(&REP_STOSB, 0x1000).unwrap();
+    assert!(stos.punya_prefix("rep"));
+    assert!(stos.register_dibaca.contains(&"rcx".to_string()));
+    assert!(stos.register_ditulis.contains(&"rdi".to_string()));
+    let xadd = engine.ambil_satu_instruksi(&LOCK_XADD, 0x1000).unwrap();
+    assert!(xadd.punya_prefix("lock"));
+    assert_eq!(xadd.ambil_akses_operand(1), AksesOperand::BacaTulis);
+    let call = engine.ambil_satu_instruksi(&CALL_REL, 0x1000).unwrap();
+    assert!(call.punya_grup("call"));
+    assert!(call.register_ditulis.contains(&"rsp".to_string()));
+}
+
+#[test]
+fn test_ukuran_register_arm64_dari_register_file() {
+    let engine = DisasmEngine::buat_engine_baru("aarch64");
+    let add_w = engine.ambil_satu_instruksi(&[0x20, 0x04, 0x00, 0x11], 0x1000).unwrap();
+    assert_eq!(add_w.ambil_ukuran_operand(0), Some(4));
+    let ir = IrLifter::untuk_arsitektur("aarch64").konversi_instruksi_ke_microcode(&add_w);
+    let stmt = ir.iter().find(|s| s.operation_code == OperasiIr::Add).unwrap();
+    assert_eq!(stmt.tipe_hasil, TipeDataIr::I32);
+}
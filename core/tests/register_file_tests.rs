@@ -0,0 +1,100 @@
+use rcdecomp_core::analysis::graph::cfg::{ControlFlowGraph, BasicBlock};
+use rcdecomp_core::analysis::graph::dom::DominatorTree;
+use rcdecomp_core::analysis::passes::ssa::SsaTransformer;
+use rcdecomp_core::analysis::recovery::abi::ProfilAbi;
+use rcdecomp_core::analysis::recovery::stack::StackFrame;
+use rcdecomp_core::arch::{Architecture, KelasRegister};
+use rcdecomp_core::arch::x86::{X86Arsitektur64, X86Arsitektur32, FLAG_ZF};
+use rcdecomp_core::arch::arm64::Arm64Arsitektur;
+use rcdecomp_core::arch::mips::MipsArsitektur;
+use rcdecomp_core::ir::types::{StatementIr, OperasiIr, TipeOperand};
+
+fn reg(nama: &str) -> TipeOperand {
+    TipeOperand::Register(nama.to_string())
+}
+
+fn jalankan_ssa(arch: &dyn Architecture, stmts: Vec<StatementIr>) -> Vec<StatementIr> {
+    let mut cfg = ControlFlowGraph::inisialisasi_graph_kosong();
+    let mut block = BasicBlock::new(0);
+    block.instruksi_list = stmts;
+    cfg.blocks.insert(0, block);
+    cfg.entry_point = 0;
+    let mut dom_tree = DominatorTree::new();
+    dom_tree.hitung_dominators(&cfg);
+    let mut transformer = SsaTransformer::untuk_arsitektur(arch);
+    transformer.lakukan_transformasi_ssa(&mut cfg, &dom_tree);
+    cfg.blocks[&0].instruksi_list.clone()
+}
+
+#[test]
+fn test_sub_register_x86_dengan_offset_bit() {
+    let file = X86Arsitektur64.dapatkan_register_file();
+    let eax = file.cari_register("eax").expect("eax harus terdaftar");
+    assert_eq!(eax.lebar_bit, 32);
+    assert_eq!(eax.induk.as_deref(), Some("rax"));
+    let ah = file.cari_register("ah").unwrap();
+    assert_eq!((ah.lebar_bit, ah.offset_bit), (8, 8));
+    assert_eq!(file.dapatkan_register_akar("ah"), "rax");
+    assert_eq!(file.dapatkan_register_akar("r10b"), "r10");
+    assert_eq!(file.dapatkan_register_akar("xmm3"), "ymm3");
+    assert_eq!(file.hitung_offset_absolut("ah"), Some(8));
+    let file_32 = X86Arsitektur32.dapatkan_register_file();
+    assert!(file_32.cari_register("eax").unwrap().induk.is_none(), "eax adalah register akar pada x86 32-bit");
+    assert_eq!(X86Arsitektur32.dapatkan_peta_alias().get("sp").map(String::as_str), Some("esp"));
+}
+
+#[test]
+fn test_flag_dan_ukuran_pointer() {
+    let flag = X86Arsitektur64.dapatkan_register_flag();
+    assert!(flag.contains(&FLAG_ZF.to_string()));
+    let file = X86Arsitektur64.dapatkan_register_file();
+    let zf = file.cari_register(FLAG_ZF).unwrap();
+    assert_eq!(zf.kelas, KelasRegister::Flag);
+    assert_eq!((zf.lebar_bit, zf.offset_bit), (1, 6));
+    assert!(!X86Arsitektur64.dapatkan_peta_alias().contains_key(FLAG_ZF), "flag tidak boleh digabung ke eflags pada SSA");
+    assert_eq!(Arm64Arsitektur.dapatkan_register_flag().len(), 4);
+    assert!(MipsArsitektur.dapatkan_register_flag().contains(&"fcc0".to_string()));
+    assert_eq!(X86Arsitektur64.dapatkan_ukuran_pointer(), 8);
+    assert_eq!(X86Arsitektur32.dapatkan_ukuran_pointer(), 4);
+    assert_eq!(MipsArsitektur.dapatkan_ukuran_pointer(), 4);
+}
+
+#[test]
+fn test_callee_saved_menentukan_volatile() {
+    assert!(X86Arsitektur64.dapatkan_callee_saved().contains(&"rbx".to_string()));
+    let sysv = ProfilAbi::buat_profil_otomatis(&X86Arsitektur64, "elf");
+    assert!(sysv.volatile_registers.contains("rdi") && sysv.volatile_registers.contains("r11"));
+    assert!(sysv.volatile_registers.contains("xmm7"));
+    assert!(!sysv.volatile_registers.contains("rbx") && !sysv.volatile_registers.contains("rsp"));
+    let ms = ProfilAbi::buat_profil_otomatis(&X86Arsitektur64, "pe");
+    assert!(ms.volatile_registers.contains("rcx") && ms.volatile_registers.contains("xmm5"));
+    assert!(!ms.volatile_registers.contains("rdi") && !ms.volatile_registers.contains("xmm6"));
+    let aapcs64 = ProfilAbi::buat_profil_otomatis(&Arm64Arsitektur, "elf");
+    assert!(aapcs64.volatile_registers.contains("x18") && aapcs64.volatile_registers.contains("w9"));
+    assert!(!aapcs64.volatile_registers.contains("x19") && !aapcs64.volatile_registers.contains("d8"));
+}
+
+#[test]
+fn test_ssa_arm64_menggabungkan_w_ke_x() {
+    let peta = Arm64Arsitektur.dapatkan_peta_alias();
+    assert_eq!(peta.get("w0").map(String::as_str), Some("x0"));
+    assert_eq!(peta.get("fp").map(String::as_str), Some("x29"));
+    let hasil = jalankan_ssa(&Arm64Arsitektur, vec![
+        StatementIr::new(0x0, OperasiIr::Mov, reg("w0"), TipeOperand::Immediate(1)),
+        StatementIr::new(0x4, OperasiIr::Mov, reg("x1"), reg("x0")),
+    ]);
+    assert_eq!(hasil[0].operand_satu, TipeOperand::SsaVariable("x0".to_string(), 1));
+    assert_eq!(hasil[1].operand_dua, TipeOperand::SsaVariable("x0".to_string(), 1), "w0 dan x0 harus berbagi versi SSA");
+}
+
+#[test]
+fn test_stack_frame_mengenali_alias_frame_pointer() {
+    let stmts = vec![
+        StatementIr::new(0x0, OperasiIr::Mov, TipeOperand::MemoryRef { base: "s8".to_string(), offset: 16 }, reg("a0")),
+        StatementIr::new(0x4, OperasiIr::Mov, reg("v0"), TipeOperand::MemoryRef { base: "fp".to_string(), offset: 16 }),
+    ];
+    let frame = StackFrame::analisis_stack_frame(&stmts, &MipsArsitektur);
+    let vars = frame.map_offset_variabel.get(&16).expect("akses lewat s8 harus dikenali sebagai frame");
+    assert_eq!(vars.len(), 1);
+    assert_eq!(vars[0].element_size, 4);
+}
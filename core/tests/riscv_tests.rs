@@ -0,0 +1,175 @@
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::analysis::recovery::abi::{ProfilAbi, TipeAbi};
+use rcdecomp_core::arch::riscv::RiscVArsitektur;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, TipeOperand, TipeDataIr, StatementIr};
+
+const KODE_RV64: [u8; 50] = [
+    0x41, 0x11,
+    0x06, 0xE4,
+    0x17, 0x15, 0x00, 0x00,
+    0x41, 0x05,
+    0x97, 0x00, 0x00, 0x00,
+    0xE7, 0x80, 0x40, 0x01,
+    0xA2, 0x60,
+    0x41, 0x01,
+    0x82, 0x80,
+    0x01, 0x00, 0x01, 0x00, 0x01, 0x00,
+    0x01, 0xC9,
+    0x97, 0x17, 0x00, 0x00,
+    0x83, 0xB7, 0x87, 0xFF,
+    0x13, 0x25, 0xA5, 0x00,
+    0x11, 0xA0,
+    0x7D, 0x55,
+    0x82, 0x80,
+];
+
+fn buat_vmem_riscv() -> VirtualMemory {
+    let mut vmem = VirtualMemory::baru(0x10000, "riscv64", "elf");
+    vmem.tambah_segment(0x10000, KODE_RV64.to_vec(), IzinAkses::ReadExecute, ".text".to_string());
+    vmem.tambah_segment(0x11000, vec![0; 0x40], IzinAkses::ReadWrite, ".data".to_string());
+    vmem
+}
+
+fn reg(nama: &str) -> TipeOperand {
+    TipeOperand::Register(nama.to_string())
+}
+
+fn reg_disasm(nama: &str) -> JenisOperandDisasm {
+    JenisOperandDisasm::Register(nama.to_string())
+}
+
+fn ambil_ir<'a>(analyzer: &'a RecursiveDescent, addr: u64) -> &'a [StatementIr] {
+    &analyzer.ambil_hasil_fungsi().get(&addr).expect("fungsi harus terdeteksi").ir_code
+}
+
+#[test]
+fn test_dekode_instruksi_terkompresi() {
+    let engine = DisasmEngine::buat_engine_baru("riscv64");
+    let sdsp = engine.ambil_satu_instruksi(&KODE_RV64[2..], 0x10002).unwrap();
+    assert_eq!(sdsp.mnemonic, "sd");
+    assert_eq!(sdsp.hitung_panjang_byte(), 2);
+    assert_eq!(sdsp.operands_detail[1], JenisOperandDisasm::Memory { base: Some("sp".to_string()), index: None, scale: 1, disp: 8, segment: None });
+    let addi = engine.ambil_satu_instruksi(&KODE_RV64[0..], 0x10000).unwrap();
+    assert_eq!(addi.mnemonic, "addi");
+    assert_eq!(addi.operands_detail.len(), 3, "bentuk terkompresi harus diekspansi menjadi tiga operand");
+    let lompat = engine.ambil_satu_instruksi(&KODE_RV64[0x2C..], 0x1002C).unwrap();
+    assert_eq!(lompat.mnemonic, "jal");
+    assert_eq!(lompat.operands_detail, vec![
+        JenisOperandDisasm::Register("zero".to_string()),
+        JenisOperandDisasm::Immediate(0x10030),
+    ]);
+    let ret = engine.ambil_satu_instruksi(&KODE_RV64[0x16..], 0x10016).unwrap();
+    assert_eq!(ret.mnemonic, "jalr");
+    assert_eq!(ret.operands_detail[0], JenisOperandDisasm::Register("zero".to_string()));
+}
+
+#[test]
+fn test_pasangan_auipc_menjadi_alamat_absolut() {
+    let vmem = buat_vmem_riscv();
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(&vmem);
+    assert!(analyzer.ambil_hasil_fungsi().contains_key(&0x1001E), "target auipc+jalr harus terdeteksi sebagai fungsi");
+    let instruksi = &analyzer.ambil_hasil_fungsi().get(&0x10000).unwrap().instruksi;
+    let addi = instruksi.iter().find(|i| i.address == 0x10008).unwrap();
+    assert_eq!(addi.operands_detail[1], reg_disasm("a0"), "operand asli addi harus tetap");
+    assert_eq!(addi.alamat_terlipat, Some(0x11014));
+    let jalr = instruksi.iter().find(|i| i.address == 0x1000E).unwrap();
+    assert_eq!(jalr.mnemonic, "jalr", "jalr tidak boleh ditulis ulang menjadi jal");
+    assert_eq!(jalr.alamat_terlipat, Some(0x1001E));
+    let ir = ambil_ir(&analyzer, 0x10000);
+    assert!(ir.iter().any(|s| s.operation_code == OperasiIr::Mov
+        && s.operand_satu == reg("a0")
+        && s.operand_dua == TipeOperand::Immediate(0x11014)));
+    assert!(ir.iter().any(|s| s.operation_code == OperasiIr::Call && s.operand_satu == TipeOperand::Immediate(0x1001E)));
+    assert_eq!(ir.last().unwrap().operation_code, OperasiIr::Ret);
+}
+
+#[test]
+fn test_akses_global_dan_branch() {
+    let vmem = buat_vmem_riscv();
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(&vmem);
+    let ir = ambil_ir(&analyzer, 0x1001E);
+    let branch = ir.iter().find(|s| s.address_asal == 0x1001E).unwrap();
+    assert_eq!(branch.operation_code, OperasiIr::Je);
+    assert_eq!(branch.operand_satu, TipeOperand::Immediate(0x1002E));
+    assert!(ir.iter().any(|s| s.operand_satu == reg("a5") && s.operand_dua == TipeOperand::Memory(0x11018)),
+        "load relatif auipc harus menjadi akses global absolut");
+    let slti = ir.iter().find(|s| s.address_asal == 0x10028).unwrap();
+    assert!(matches!(slti.operand_dua, TipeOperand::Conditional { .. }));
+    assert!(ir.iter().any(|s| s.address_asal == 0x1002E && s.operand_dua == TipeOperand::Immediate(-1)));
+}
+
+#[test]
+fn test_register_nol_pada_lifter() {
+    let lifter = IrLifter::untuk_arsitektur("riscv64");
+    let mut instr = InstructionNormalized::new(0x1000, "add", "");
+    instr.operands_detail = vec![
+        JenisOperandDisasm::Register("zero".to_string()),
+        JenisOperandDisasm::Register("a0".to_string()),
+        JenisOperandDisasm::Register("a1".to_string()),
+    ];
+    assert!(lifter.konversi_instruksi_ke_microcode(&instr).is_empty(), "penulisan ke zero harus dibuang");
+    instr.operands_detail[0] = JenisOperandDisasm::Register("a2".to_string());
+    instr.operands_detail[1] = JenisOperandDisasm::Register("zero".to_string());
+    let ir = lifter.konversi_instruksi_ke_microcode(&instr);
+    assert_eq!(ir[0].operation_code, OperasiIr::Mov);
+    assert_eq!(ir[0].operand_dua, reg("a1"));
+    let mut jalr = InstructionNormalized::new(0x1004, "jalr", "");
+    jalr.operands_detail = vec![
+        JenisOperandDisasm::Register("ra".to_string()),
+        JenisOperandDisasm::Register("t1".to_string()),
+        JenisOperandDisasm::Immediate(0),
+    ];
+    let ir = lifter.konversi_instruksi_ke_microcode(&jalr);
+    assert_eq!(ir[0].operation_code, OperasiIr::Call);
+    assert_eq!(ir[0].operand_satu, reg("t1"));
+}
+
+#[test]
+fn test_sext_w_menjadi_sign_extend() {
+    let engine = DisasmEngine::buat_engine_baru("riscv64");
+    let instr = engine.ambil_satu_instruksi(&[0x1B, 0x85, 0x05, 0x00], 0x1000).expect("instruksi harus terdekode");
+    assert_eq!(instr.mnemonic, "sext.w");
+    let ir = IrLifter::untuk_arsitektur("riscv64").konversi_instruksi_ke_microcode(&instr);
+    assert_eq!(ir[0].operand_satu, reg("a0"));
+    assert_eq!(ir[0].operand_dua, TipeOperand::Expression {
+        operasi: OperasiIr::SignExtend,
+        operand_kiri: Box::new(reg("a1")),
+        operand_kanan: Box::new(TipeOperand::Immediate(32)),
+    });
+    assert_eq!(ir[0].tipe_hasil, TipeDataIr::I64);
+}
+
+#[test]
+fn test_perkalian_tinggi_menjaga_tanda() {
+    let engine = DisasmEngine::buat_engine_baru("riscv64");
+    let lifter = IrLifter::untuk_arsitektur("riscv64");
+    let kasus: [([u8; 4], &str, OperasiIr); 3] = [
+        ([0x33, 0x95, 0xC5, 0x02], "mulh", OperasiIr::MulHi),
+        ([0x33, 0xB5, 0xC5, 0x02], "mulhu", OperasiIr::MulHiU),
+        ([0x33, 0xA5, 0xC5, 0x02], "mulhsu", OperasiIr::MulHiSU),
+    ];
+    for (bytes, mnemonic, operasi) in kasus {
+        let instr = engine.ambil_satu_instruksi(&bytes, 0x1000).expect("instruksi harus terdekode");
+        assert_eq!(instr.mnemonic, mnemonic);
+        let ir = lifter.konversi_instruksi_ke_microcode(&instr);
+        assert_eq!(ir[0].operation_code, operasi, "{}", mnemonic);
+        assert_eq!(ir[0].operand_satu, reg("a0"));
+    }
+}
+
+#[test]
+fn test_profil_abi_riscv() {
+    let arch = RiscVArsitektur::new(true);
+    let profil = ProfilAbi::buat_profil_otomatis(&arch, "elf");
+    assert_eq!(profil.tipe, TipeAbi::RiscVPsabi);
+    assert_eq!(profil.register_integer, vec!["a0", "a1", "a2", "a3", "a4", "a5", "a6", "a7"]);
+    assert_eq!(profil.register_float.first().map(String::as_str), Some("fa0"));
+    assert!(profil.volatile_registers.contains("t6"));
+    assert!(!profil.volatile_registers.contains("s1"));
+}
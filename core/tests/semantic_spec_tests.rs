@@ -0,0 +1,186 @@
+use std::sync::Arc;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::lift::semantic::{EfekFlag, PeranOperand, SemanticEngine, SemantikError, SpecSemantik};
+use rcdecomp_core::ir::types::{OperasiIr, StatementIr, TipeDataIr, TipeOperand};
+
+const ANDN_EAX_ECX_EDX: [u8; 5] = [0xC4, 0xE2, 0x70, 0xF2, 0xC2];
+const RORX_RAX_RCX_5: [u8; 6] = [0xC4, 0xE3, 0xFB, 0xF0, 0xC1, 0x05];
+const CLC: [u8; 1] = [0xF8];
+const STC: [u8; 1] = [0xF9];
+const CMC: [u8; 1] = [0xF5];
+const CQO: [u8; 2] = [0x48, 0x99];
+const IMUL_RAX_RCX: [u8; 4] = [0x48, 0x0F, 0xAF, 0xC1];
+const IMUL_EAX_ECX_12: [u8; 3] = [0x6B, 0xC1, 0x0C];
+const ADD_RAX_RCX: [u8; 3] = [0x48, 0x01, 0xC8];
+const ADC_RAX_RCX: [u8; 3] = [0x48, 0x11, 0xC8];
+const DEC_RAX: [u8; 3] = [0x48, 0xFF, 0xC8];
+const CMP_RAX_RCX: [u8; 3] = [0x48, 0x39, 0xC8];
+
+const SPEC_PENGGUNA: &str = r##"{
+  "arsitektur": "x86_64",
+  "instruksi": [
+    {
+      "mnemonic": "imul",
+      "operand": ["Tulis", "Baca", "Baca"],
+      "micro_ops": [{ "operasi": "Imul", "tujuan": "op0", "kiri": "op1", "kanan": "op2" }],
+      "flags": ["cf=hasil", "of=hasil", "sf=undefined", "zf=undefined", "pf=undefined", "af=undefined"]
+    },
+    {
+      "mnemonic": "add",
+      "operand": ["BacaTulis", "Baca"],
+      "micro_ops": [{ "operasi": "Add", "tujuan": "op0", "kiri": "op0", "kanan": "op1" }],
+      "flags": ["zf=hasil"]
+    }
+  ]
+}"##;
+
+fn lift_dengan(engine: SemanticEngine, bytes: &[u8]) -> Vec<StatementIr> {
+    let disasm = DisasmEngine::buat_engine_baru("x86_64");
+    let instr = disasm.ambil_satu_instruksi(bytes, 0x1000).expect("instruksi harus terdekode");
+    let mut lifter = IrLifter::untuk_arsitektur("x86_64");
+    lifter.semantic_engine = Arc::new(engine);
+    lifter.konversi_instruksi_ke_microcode(&instr)
+}
+
+fn lift(bytes: &[u8]) -> Vec<StatementIr> {
+    lift_dengan(SemanticEngine::untuk_arsitektur("x86_64"), bytes)
+}
+
+fn reg(nama: &str) -> TipeOperand {
+    TipeOperand::Register(nama.to_string())
+}
+
+fn imm(nilai: i64) -> TipeOperand {
+    TipeOperand::Immediate(nilai)
+}
+
+fn expr(operasi: OperasiIr, kiri: TipeOperand, kanan: TipeOperand) -> TipeOperand {
+    TipeOperand::Expression { operasi, operand_kiri: Box::new(kiri), operand_kanan: Box::new(kanan) }
+}
+
+fn spec_satu(arsitektur: &str, instruksi: &str) -> String {
+    format!(r#"{{ "arsitektur": "{}", "instruksi": [{}] }}"#, arsitektur, instruksi)
+}
+
+fn pesan_gagal(teks: &str) -> SemantikError {
+    SpecSemantik::dari_str(teks).expect_err("spec seharusnya ditolak")
+}
+
+#[test]
+fn test_spec_bawaan_x86_termuat() {
+    let engine = SemanticEngine::untuk_arsitektur("x86_64");
+    for mnemonic in ["add", "sub", "adc", "sbb", "inc", "dec", "neg", "not", "cmp", "test", "and", "or", "xor", "andn", "rorx", "clc", "stc", "cmc", "cwd", "cdq", "cqo", "addps"] {
+        assert!(engine.tabel_definisi.contains_key(mnemonic), "{} harus ada di spec bawaan", mnemonic);
+    }
+    let cqo = &engine.tabel_definisi["cqo"][0];
+    assert_eq!(cqo.operand_implisit, vec![reg("rax"), reg("rdx")]);
+    assert!(cqo.peran_operand.is_empty());
+    let xor = &engine.tabel_definisi["xor"][0];
+    assert_eq!(xor.peran_operand, vec![PeranOperand::BacaTulis, PeranOperand::Baca]);
+    assert!(xor.efek_flags.contains(&EfekFlag::ClearCarry));
+    assert_eq!(engine.tabel_definisi["addps"][0].micro_ops[0].tipe_hasil, TipeDataIr::V128F32);
+    assert!(SemanticEngine::untuk_arsitektur("x86").tabel_definisi.contains_key("cdq"), "x86 32-bit memakai spec yang sama");
+    assert!(SemanticEngine::untuk_arsitektur("aarch64").tabel_definisi.is_empty(), "spec x86 tidak boleh bocor ke arm64");
+}
+
+#[test]
+fn test_lift_dari_spec_bawaan() {
+    let ir = lift(&ANDN_EAX_ECX_EDX);
+    assert_eq!(ir[0].operand_satu, reg("temp_andn"));
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::Xor, expr(OperasiIr::Truncate, reg("rcx"), imm(32)), imm(-1)));
+    let hasil = ir.iter().find(|s| s.operand_satu == reg("rax")).expect("andn harus menulis rax");
+    assert_eq!(hasil.operand_dua, expr(OperasiIr::ZeroExtend, expr(OperasiIr::And, reg("temp_andn"), expr(OperasiIr::Truncate, reg("rdx"), imm(32))), imm(32)));
+    assert!(ir.iter().any(|s| s.operand_satu == reg("eflags_cf") && s.operand_dua == imm(0)));
+    let ir = lift(&RORX_RAX_RCX_5);
+    assert_eq!(ir.len(), 1, "rorx tidak mengubah flag");
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::Ror, reg("rcx"), imm(5)));
+    assert_eq!(lift(&CLC)[0].operand_dua, imm(0));
+    assert_eq!(lift(&STC)[0].operand_dua, imm(1));
+    let ir = lift(&CMC);
+    assert_eq!((ir[0].operand_satu.clone(), ir[0].operand_dua.clone()), (reg("eflags_cf"), expr(OperasiIr::Xor, reg("eflags_cf"), imm(1))));
+    let ir = lift(&CQO);
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::Sar, reg("rax"), imm(63)), "operand implisit diisi dari spec");
+}
+
+#[test]
+fn test_aritmetika_integer_dari_spec_bawaan() {
+    let ir = lift(&ADC_RAX_RCX);
+    let hasil = ir.iter().find(|s| s.operation_code == OperasiIr::Adc).expect("adc harus dilift dari spec");
+    assert_eq!(hasil.operand_dua, expr(OperasiIr::Add, expr(OperasiIr::Add, reg("rax"), reg("rcx")), reg("eflags_cf")), "carry masuk dilipat ke ekspresi");
+    assert_eq!(hasil.tipe_hasil, TipeDataIr::I64);
+    assert!(ir.iter().any(|s| s.operand_satu == reg("temp_flag_kiri") && s.operand_dua == reg("rax")), "nilai lama tujuan harus diamankan untuk flag");
+    let ir = lift(&DEC_RAX);
+    let hasil = ir.iter().find(|s| s.operation_code == OperasiIr::Dec).expect("dec harus dilift dari spec");
+    assert_eq!(hasil.operand_dua, expr(OperasiIr::Sub, reg("rax"), imm(1)));
+    assert!(!ir.iter().any(|s| s.operand_satu == reg("eflags_cf")), "dec tidak mengubah CF");
+    let ir = lift(&CMP_RAX_RCX);
+    assert_eq!(ir[0].operation_code, OperasiIr::Cmp);
+    assert_eq!((ir[0].operand_satu.clone(), ir[0].operand_dua.clone()), (reg("temp_alu_flags"), expr(OperasiIr::Sub, reg("rax"), reg("rcx"))));
+    assert!(!ir.iter().any(|s| s.operand_satu == reg("rax")), "cmp tidak boleh menulis operand");
+}
+
+#[test]
+fn test_spec_pengguna_menambah_dan_menimpa() {
+    let spec = SpecSemantik::dari_str(SPEC_PENGGUNA).expect("spec pengguna harus valid");
+    let engine = SemanticEngine::untuk_arsitektur_dengan_spec("x86_64", &[spec]);
+    let ir = lift_dengan(engine, &ADD_RAX_RCX);
+    let flags: Vec<&TipeOperand> = ir.iter().filter(|s| s.tipe_hasil == TipeDataIr::I8).map(|s| &s.operand_satu).collect();
+    assert_eq!(flags, vec![&reg("eflags_zf")], "definisi add bawaan harus tertimpa");
+    let spec = SpecSemantik::dari_str(SPEC_PENGGUNA).unwrap();
+    let engine = SemanticEngine::untuk_arsitektur_dengan_spec("x86_64", &[spec]);
+    assert_eq!(engine.tabel_definisi["imul"].len(), 1);
+    let ir = lift_dengan(engine, &IMUL_EAX_ECX_12);
+    let hasil = ir.iter().find(|s| s.operand_satu == reg("rax")).unwrap();
+    assert_eq!(hasil.operand_dua, expr(OperasiIr::ZeroExtend, expr(OperasiIr::Imul, expr(OperasiIr::Truncate, reg("rcx"), imm(32)), imm(12)), imm(32)));
+    let spec = SpecSemantik::dari_str(SPEC_PENGGUNA).unwrap();
+    let ir = lift_dengan(SemanticEngine::untuk_arsitektur_dengan_spec("x86_64", &[spec]), &IMUL_RAX_RCX);
+    assert_eq!(ir.iter().filter(|s| s.operand_satu == reg("rax")).count(), 1, "imul dua operand tetap lewat dispatch");
+}
+
+#[test]
+fn test_spec_arsitektur_lain_dilewati() {
+    let spec = SpecSemantik::dari_str(&spec_satu("aarch64", r##"{ "mnemonic": "add", "operand": ["Tulis", "Baca", "Baca"], "micro_ops": [{ "operasi": "Add", "tujuan": "op0", "kiri": "op1", "kanan": "op2" }] }"##)).unwrap();
+    let mut engine = SemanticEngine::untuk_arsitektur("x86_64");
+    assert!(matches!(engine.terapkan_spec(&spec), Err(SemantikError::ArsitekturTidakCocok { .. })));
+    let engine = SemanticEngine::untuk_arsitektur_dengan_spec("x86_64", &[spec.clone()]);
+    assert_eq!(engine.tabel_definisi["add"][0].peran_operand.len(), 2);
+    let engine = SemanticEngine::untuk_arsitektur_dengan_spec("aarch64", &[spec]);
+    assert_eq!(engine.tabel_definisi["add"][0].peran_operand.len(), 3);
+}
+
+#[test]
+fn test_validasi_spec_menolak_kesalahan() {
+    let cek = |instruksi: &str, potongan_pesan: &str| {
+        let e = pesan_gagal(&spec_satu("x86_64", instruksi));
+        assert!(matches!(e, SemantikError::DefinisiTidakValid { .. }), "harus gagal validasi: {}", e);
+        assert!(e.to_string().contains(potongan_pesan), "pesan '{}' harus memuat '{}'", e, potongan_pesan);
+    };
+    cek(r##"{ "mnemonic": "x", "operand": ["BacaTulis"], "micro_ops": [{ "operasi": "Add", "tujuan": "op0", "kiri": "op0", "kanan": "op1" }] }"##, "op1 melebihi");
+    cek(r##"{ "mnemonic": "x", "operand": ["Baca", "Baca"], "micro_ops": [{ "operasi": "Add", "tujuan": "op0", "kiri": "op0", "kanan": "op1" }] }"##, "bukan operand tujuan");
+    cek(r##"{ "mnemonic": "x", "operand": ["Tulis", "Tulis"], "micro_ops": [{ "operasi": "Mov", "tujuan": "op0", "kiri": "op1" }] }"##, "hanya boleh ditulis");
+    cek(r##"{ "mnemonic": "x", "implisit": ["zax"], "micro_ops": [{ "operasi": "Mov", "tujuan": "imp0", "kiri": "#0" }] }"##, "register implisit 'zax'");
+    cek(r##"{ "mnemonic": "x", "micro_ops": [{ "operasi": "Mov", "tujuan": "imp0", "kiri": "#0" }] }"##, "imp0 melebihi");
+    cek(r##"{ "mnemonic": "x", "operand": ["Baca"], "micro_ops": [{ "operasi": "Mov", "tujuan": "rax", "kiri": "op0" }] }"##, "rujukan operand 'rax'");
+    cek(r##"{ "mnemonic": "x", "operand": ["BacaTulis"], "micro_ops": [{ "operasi": "Add", "tujuan": "op0", "kiri": "op0", "kanan": "#1" }], "flags": ["df=hasil"] }"##, "efek flag 'df=hasil'");
+    cek(r##"{ "mnemonic": "x", "operand": ["BacaTulis"], "micro_ops": [{ "operasi": "Ror", "tujuan": "op0", "kiri": "op0", "kanan": "#1" }], "flags": ["cf=hasil"] }"##, "jenis_flag wajib");
+    cek(r##"{ "mnemonic": "x", "micro_ops": [] }"##, "micro_ops kosong");
+    cek(r##"{ "mnemonic": "x", "operand": ["BacaTulis"], "micro_ops": [{ "operasi": "Mov", "tujuan": "op0", "kiri": "op0", "bawaan": "#1" }] }"##, "bawaan membutuhkan operand kanan");
+    let ganda = r##"{ "mnemonic": "x", "operand": ["Tulis"], "micro_ops": [{ "operasi": "Mov", "tujuan": "op0", "kiri": "#0" }] }"##;
+    cek(&format!("{}, {}", ganda, ganda), "lebih dari sekali");
+    assert!(matches!(pesan_gagal(&spec_satu("x86_64", r##"{ "mnemonic": "x", "micro_ops": [{ "operasi": "Modulo", "tujuan": "temp_a", "kiri": "#0" }] }"##)), SemantikError::ParseError(_)));
+    assert!(matches!(pesan_gagal(&spec_satu("sparc", r##"{ "mnemonic": "x", "micro_ops": [{ "operasi": "Mov", "tujuan": "temp_a", "kiri": "#0" }] }"##)), SemantikError::ArsitekturTidakDikenal(_)));
+    let flag_arm = r##"{ "mnemonic": "x", "operand": ["BacaTulis", "Baca"], "micro_ops": [{ "operasi": "Add", "tujuan": "op0", "kiri": "op0", "kanan": "op1" }], "flags": ["zf=hasil"] }"##;
+    assert!(pesan_gagal(&spec_satu("aarch64", flag_arm)).to_string().contains("hanya didukung untuk x86"));
+}
+
+#[test]
+fn test_muat_spec_dari_file() {
+    let path = std::env::temp_dir().join(format!("rcdecomp_spec_{}.json", std::process::id()));
+    std::fs::write(&path, SPEC_PENGGUNA).unwrap();
+    let mut engine = SemanticEngine::untuk_arsitektur("x86_64");
+    assert_eq!(engine.muat_spec_file(path.to_str().unwrap()).expect("file spec harus termuat"), 2);
+    std::fs::remove_file(&path).unwrap();
+    assert!(engine.tabel_definisi["imul"].iter().any(|d| d.peran_operand.len() == 3));
+    assert!(matches!(engine.muat_spec_file(path.to_str().unwrap()), Err(SemantikError::IoError(_))));
+}
@@ -0,0 +1,211 @@
+use std::collections::BTreeMap;
+use rcdecomp_core::analysis::graph::cfg::{ControlFlowGraph, BasicBlock};
+use rcdecomp_core::analysis::recovery::ast::{ControlFlowStructurer, NodeAst};
+use rcdecomp_core::analysis::recovery::stack::StackFrame;
+use rcdecomp_core::analysis::recovery::types::{TypeSystem, KonvensiPanggilan};
+use rcdecomp_core::arch::x86::X86Arsitektur64;
+use rcdecomp_core::codegen::c_gen::CEmitter;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, StatementIr, TipeDataIr, TipeOperand};
+
+const MOVSD_XMM0_MEM: [u8; 4] = [0xF2, 0x0F, 0x10, 0x07];
+const MOVSS_XMM1_MEM: [u8; 5] = [0xF3, 0x0F, 0x10, 0x4E, 0x04];
+const MOVSD_MEM_XMM0: [u8; 4] = [0xF2, 0x0F, 0x11, 0x07];
+const MOVSD_STRING: [u8; 1] = [0xA5];
+const ADDSD_XMM0_XMM1: [u8; 4] = [0xF2, 0x0F, 0x58, 0xC1];
+const MULSS_XMM0_MEM: [u8; 4] = [0xF3, 0x0F, 0x59, 0x00];
+const VADDSD_XMM0_XMM1_XMM2: [u8; 4] = [0xC5, 0xF3, 0x58, 0xC2];
+const SUBSD_XMM0_RBP: [u8; 5] = [0xF2, 0x0F, 0x5C, 0x45, 0xF8];
+const SQRTSD_XMM0_XMM1: [u8; 4] = [0xF2, 0x0F, 0x51, 0xC1];
+const VSQRTSD_XMM0_XMM1_XMM2: [u8; 4] = [0xC5, 0xF3, 0x51, 0xC2];
+const MAXSD_XMM0_XMM1: [u8; 4] = [0xF2, 0x0F, 0x5F, 0xC1];
+const MINSS_XMM0_XMM1: [u8; 4] = [0xF3, 0x0F, 0x5D, 0xC1];
+const CVTSI2SD_XMM0_RAX: [u8; 5] = [0xF2, 0x48, 0x0F, 0x2A, 0xC0];
+const CVTTSS2SI_RAX_XMM1: [u8; 5] = [0xF3, 0x48, 0x0F, 0x2C, 0xC1];
+const CVTTSD2SI_EAX_XMM0: [u8; 4] = [0xF2, 0x0F, 0x2C, 0xC0];
+const CVTSD2SI_EAX_XMM0: [u8; 4] = [0xF2, 0x0F, 0x2D, 0xC0];
+const CVTSS2SD_XMM0_XMM1: [u8; 4] = [0xF3, 0x0F, 0x5A, 0xC1];
+const UCOMISD_XMM0_XMM1: [u8; 4] = [0x66, 0x0F, 0x2E, 0xC1];
+const COMISS_XMM2_MEM: [u8; 3] = [0x0F, 0x2F, 0x10];
+const JA: [u8; 2] = [0x77, 0x10];
+const JAE: [u8; 2] = [0x73, 0x10];
+const JB: [u8; 2] = [0x72, 0x10];
+const JP: [u8; 2] = [0x7A, 0x10];
+
+fn lift(bytes: &[u8]) -> Vec<StatementIr> {
+    let engine = DisasmEngine::buat_engine_baru("x86_64");
+    let instr = engine.ambil_satu_instruksi(bytes, 0x1000).expect("instruksi harus terdekode");
+    IrLifter::untuk_arsitektur("x86_64").konversi_instruksi_ke_microcode(&instr)
+}
+
+fn reg(nama: &str) -> TipeOperand {
+    TipeOperand::Register(nama.to_string())
+}
+
+fn expr(operasi: OperasiIr, kiri: TipeOperand, kanan: TipeOperand) -> TipeOperand {
+    TipeOperand::Expression { operasi, operand_kiri: Box::new(kiri), operand_kanan: Box::new(kanan) }
+}
+
+fn satu_statement(bytes: &[u8]) -> StatementIr {
+    let ir = lift(bytes);
+    assert_eq!(ir.len(), 1, "harus menghasilkan satu statement: {:?}", ir);
+    ir.into_iter().next().unwrap()
+}
+
+fn kondisi_setelah_banding(banding: &[u8], jump: &[u8]) -> String {
+    let engine = DisasmEngine::buat_engine_baru("x86_64");
+    let lifter = IrLifter::untuk_arsitektur("x86_64");
+    let kode = [banding, jump].concat();
+    let mut ir = Vec::new();
+    let mut offset = 0;
+    while offset < kode.len() {
+        let instr = engine.ambil_satu_instruksi(&kode[offset..], 0x1000 + offset as u64).expect("instruksi harus terdekode");
+        offset += instr.hitung_panjang_byte();
+        ir.extend(lifter.konversi_instruksi_ke_microcode(&instr));
+    }
+    let target = 0x1000 + kode.len() as u64 + 0x10;
+    let fallthrough = 0x1000 + kode.len() as u64;
+    let gabungan = 0x1100;
+    let mut cfg = ControlFlowGraph::inisialisasi_graph_kosong();
+    cfg.entry_point = 0x1000;
+    let mut header = BasicBlock::new(0x1000);
+    header.instruksi_list = ir;
+    header.successors = vec![target, fallthrough];
+    cfg.blocks.insert(0x1000, header);
+    for (id, succ, tujuan) in [(target, Some(gabungan), "ecx"), (fallthrough, Some(gabungan), "edx"), (gabungan, None, "eax")] {
+        let mut block = BasicBlock::new(id);
+        block.instruksi_list.push(StatementIr::new(id, OperasiIr::Mov, reg(tujuan), TipeOperand::Immediate(id as i64)));
+        if let Some(t) = succ {
+            block.instruksi_list.push(StatementIr::new(id + 4, OperasiIr::Jmp, TipeOperand::Immediate(t as i64), TipeOperand::None));
+            block.successors.push(t);
+        }
+        cfg.blocks.insert(id, block);
+    }
+    cfg.blocks.get_mut(&target).unwrap().predecessors = vec![0x1000];
+    cfg.blocks.get_mut(&fallthrough).unwrap().predecessors = vec![0x1000];
+    cfg.blocks.get_mut(&gabungan).unwrap().predecessors = vec![target, fallthrough];
+    let ast = ControlFlowStructurer::new().bangun_tree_struktur(&mut cfg);
+    let NodeAst::Sequence(nodes) = &ast else { panic!("AST harus berupa sequence: {:#?}", ast) };
+    nodes.iter().find_map(|n| match n {
+        NodeAst::IfElse { kondisi, .. } => Some(kondisi.clone()),
+        _ => None,
+    }).unwrap_or_else(|| panic!("if-else harus terdeteksi: {:#?}", ast))
+}
+
+#[test]
+fn test_aritmatika_skalar_memakai_operasi_float() {
+    let add = satu_statement(&ADDSD_XMM0_XMM1);
+    assert_eq!(add.operation_code, OperasiIr::FAdd);
+    assert_eq!(add.operand_satu, reg("xmm0"));
+    assert_eq!(add.operand_dua, expr(OperasiIr::FAdd, reg("xmm0"), reg("xmm1")));
+    assert_eq!(add.tipe_hasil, TipeDataIr::F64);
+
+    let mul = satu_statement(&MULSS_XMM0_MEM);
+    assert_eq!(mul.operation_code, OperasiIr::FMul);
+    assert_eq!(mul.tipe_hasil, TipeDataIr::F32, "mulss bekerja pada lane rendah 32-bit");
+
+    let vadd = satu_statement(&VADDSD_XMM0_XMM1_XMM2);
+    assert_eq!(vadd.operand_dua, expr(OperasiIr::FAdd, reg("xmm1"), reg("xmm2")), "bentuk AVX tiga operand");
+
+    let sub = satu_statement(&SUBSD_XMM0_RBP);
+    assert_eq!(sub.operation_code, OperasiIr::FSub);
+    assert!(matches!(&sub.operand_dua, TipeOperand::Expression { operand_kanan, .. } if matches!(**operand_kanan, TipeOperand::MemoryRef { offset: -8, .. })));
+
+    assert_eq!(satu_statement(&MAXSD_XMM0_XMM1).operand_dua, expr(OperasiIr::FMax, reg("xmm0"), reg("xmm1")));
+    let min = satu_statement(&MINSS_XMM0_XMM1);
+    assert_eq!(min.operation_code, OperasiIr::FMin);
+    assert_eq!(min.tipe_hasil, TipeDataIr::F32);
+
+    let akar = satu_statement(&SQRTSD_XMM0_XMM1);
+    assert_eq!((akar.operation_code, akar.operand_dua), (OperasiIr::FSqrt, reg("xmm1")));
+    assert_eq!(satu_statement(&VSQRTSD_XMM0_XMM1_XMM2).operand_dua, reg("xmm2"));
+}
+
+#[test]
+fn test_movss_movsd_skalar_dan_string() {
+    let muat = satu_statement(&MOVSD_XMM0_MEM);
+    assert_eq!(muat.operation_code, OperasiIr::Mov);
+    assert_eq!(muat.operand_satu, reg("xmm0"));
+    assert_eq!(muat.tipe_hasil, TipeDataIr::F64);
+    assert_eq!(satu_statement(&MOVSS_XMM1_MEM).tipe_hasil, TipeDataIr::F32);
+    let simpan = satu_statement(&MOVSD_MEM_XMM0);
+    assert_eq!(simpan.operand_dua, reg("xmm0"));
+    assert_eq!(simpan.tipe_hasil, TipeDataIr::F64);
+    let string = lift(&MOVSD_STRING);
+    assert!(string.iter().all(|s| s.tipe_hasil != TipeDataIr::F64), "movsd string tidak boleh diangkat sebagai float: {:?}", string);
+}
+
+#[test]
+fn test_konversi_integer_dan_presisi() {
+    let dari_int = satu_statement(&CVTSI2SD_XMM0_RAX);
+    assert_eq!((dari_int.operation_code, dari_int.operand_dua, dari_int.tipe_hasil), (OperasiIr::FConv, reg("rax"), TipeDataIr::F64));
+
+    let potong = satu_statement(&CVTTSS2SI_RAX_XMM1);
+    assert_eq!((potong.operation_code, potong.operand_dua, potong.tipe_hasil), (OperasiIr::FConv, reg("xmm1"), TipeDataIr::I64));
+
+    let potong32 = lift(&CVTTSD2SI_EAX_XMM0);
+    assert_eq!(potong32[0].operation_code, OperasiIr::FConv);
+    assert_eq!(potong32[0].tipe_hasil, TipeDataIr::I32);
+    assert!(potong32.iter().any(|s| s.operand_satu == reg("rax")), "tulis eax harus meng-nol-kan bagian atas rax: {:?}", potong32);
+
+    let bulat = lift(&CVTSD2SI_EAX_XMM0);
+    assert_eq!(bulat[0].operation_code, OperasiIr::Intrinsic("lrint".to_string()));
+
+    let presisi = satu_statement(&CVTSS2SD_XMM0_XMM1);
+    assert_eq!((presisi.operation_code, presisi.tipe_hasil), (OperasiIr::FConv, TipeDataIr::F64));
+}
+
+#[test]
+fn test_ucomis_menjadi_perbandingan_float() {
+    let ir = lift(&UCOMISD_XMM0_XMM1);
+    assert_eq!(ir[0].operation_code, OperasiIr::FCmp);
+    assert_eq!(ir[0].tipe_hasil, TipeDataIr::F64);
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::FSub, reg("xmm0"), reg("xmm1")));
+    let flag_ditulis: Vec<String> = ir.iter().filter_map(|s| match &s.operand_satu {
+        TipeOperand::Register(r) if r.starts_with("eflags_") => Some(r.clone()),
+        _ => None,
+    }).collect();
+    for flag in ["eflags_cf", "eflags_pf", "eflags_zf", "eflags_of", "eflags_sf", "eflags_af"] {
+        assert!(flag_ditulis.iter().any(|f| f == flag), "flag {} harus ditulis: {:?}", flag, flag_ditulis);
+    }
+    assert_eq!(lift(&COMISS_XMM2_MEM)[0].tipe_hasil, TipeDataIr::F32);
+}
+
+#[test]
+fn test_kondisi_branch_setelah_ucomisd() {
+    assert_eq!(kondisi_setelah_banding(&UCOMISD_XMM0_XMM1, &JA), "xmm0 > xmm1");
+    assert_eq!(kondisi_setelah_banding(&UCOMISD_XMM0_XMM1, &JAE), "xmm0 >= xmm1");
+    assert_eq!(kondisi_setelah_banding(&UCOMISD_XMM0_XMM1, &JB), "!(xmm0 >= xmm1)");
+    assert_eq!(kondisi_setelah_banding(&UCOMISD_XMM0_XMM1, &JP), "__builtin_isunordered(xmm0, xmm1)");
+}
+
+#[test]
+fn test_cemitter_mencetak_aritmatika_double() {
+    let mut stmts = Vec::new();
+    for kode in [&ADDSD_XMM0_XMM1[..], &SQRTSD_XMM0_XMM1, &MAXSD_XMM0_XMM1, &CVTSI2SD_XMM0_RAX, &CVTTSS2SI_RAX_XMM1, &CVTSD2SI_EAX_XMM0] {
+        stmts.extend(lift(kode));
+    }
+    let mut emitter = CEmitter::new();
+    let kode = emitter.hasilkan_fungsi_tunggal(
+        "hitung",
+        &NodeAst::Block(stmts),
+        &TypeSystem::new(),
+        &StackFrame::new(),
+        &BTreeMap::new(),
+        &[],
+        KonvensiPanggilan::Bawaan,
+        &X86Arsitektur64,
+    );
+    for baris in [
+        "xmm0 = xmm0 + xmm1;",
+        "xmm0 = sqrt(xmm1);",
+        "xmm0 = fmax(xmm0, xmm1);",
+        "xmm0 = (double)rax;",
+        "rax = (int64_t)xmm1;",
+        "= lrint(xmm0);",
+    ] {
+        assert!(kode.contains(baris), "output harus memuat '{}':\n{}", baris, kode);
+    }
+    assert!(!kode.contains("_generic"), "operasi skalar tidak boleh jatuh ke intrinsic generik:\n{}", kode);
+}
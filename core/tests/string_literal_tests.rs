@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use rcdecomp_core::analysis::recovery::strings::{TabelString, EncodingString, escape_literal_c};
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+use rcdecomp_core::ir::types::{StatementIr, OperasiIr, TipeOperand};
+
+fn buat_utf16le(teks: &str) -> Vec<u8> {
+    let mut hasil = Vec::new();
+    for unit in teks.encode_utf16() {
+        hasil.extend_from_slice(&unit.to_le_bytes());
+    }
+    hasil.extend_from_slice(&[0, 0]);
+    hasil
+}
+
+#[test]
+fn test_pindai_string_ascii_dan_utf8() {
+    let mut data = Vec::new();
+    data.extend_from_slice(b"Usage: %s\n\0");
+    data.extend_from_slice(&[0x01, 0x02, 0x03]);
+    data.extend_from_slice("héllo wörld\0".as_bytes());
+    data.extend_from_slice(b"ab\0");
+    let mut tabel = TabelString::new();
+    tabel.pindai_segment(0x2000, &data);
+    let ascii = tabel.cari_string(0x2000).expect("String ascii harus ditemukan");
+    assert_eq!(ascii.isi, "Usage: %s\n");
+    assert_eq!(ascii.encoding, EncodingString::Ascii);
+    assert_eq!(ascii.panjang_byte, 11);
+    let utf8 = tabel.cari_string(0x2000 + 14).expect("String utf8 harus ditemukan");
+    assert_eq!(utf8.isi, "héllo wörld");
+    assert_eq!(utf8.encoding, EncodingString::Utf8);
+    assert_eq!(tabel.entri.len(), 2, "String pendek dan byte sampah tidak boleh masuk tabel");
+}
+
+#[test]
+fn test_pindai_string_utf16le() {
+    let mut data = vec![0xFF, 0xFF];
+    data.extend_from_slice(&buat_utf16le("Kernel32"));
+    let mut tabel = TabelString::new();
+    tabel.pindai_segment(0x3000, &data);
+    let entri = tabel.cari_string(0x3002).expect("String UTF-16LE harus ditemukan");
+    assert_eq!(entri.isi, "Kernel32");
+    assert_eq!(entri.encoding, EncodingString::Utf16Le);
+    assert_eq!(tabel.format_literal_c(0x3002).unwrap(), "L\"Kernel32\"");
+}
+
+#[test]
+fn test_escape_literal_c() {
+    assert_eq!(escape_literal_c("Usage: %s\n"), "Usage: %s\\n");
+    assert_eq!(escape_literal_c("a\"b\\c\t"), "a\\\"b\\\\c\\t");
+    assert_eq!(escape_literal_c("\x1bA"), "\\x1b\"\"A", "Hex escape tidak boleh menelan karakter hex berikutnya");
+}
+
+#[test]
+fn test_pindai_vmem_hanya_segment_read_only() {
+    let mut vmem = VirtualMemory::baru(0x1000, "x86_64", "elf");
+    vmem.tambah_segment(0x1000, b"kode_bukan_string\0".to_vec(), IzinAkses::ReadExecute, ".text".to_string());
+    vmem.tambah_segment(0x2000, b"hello world\0".to_vec(), IzinAkses::Read, ".rodata".to_string());
+    let mut tabel = TabelString::new();
+    tabel.pindai_vmem(&vmem);
+    assert!(tabel.cari_string(0x1000).is_none(), "Segment executable tidak boleh dipindai");
+    assert!(tabel.cari_string(0x2000).is_some());
+}
+
+#[test]
+fn test_referensi_string_dari_ir() {
+    let mut tabel = TabelString::new();
+    tabel.pindai_segment(0x2000, b"hello world\0");
+    let mut map_ir = HashMap::new();
+    map_ir.insert(0x1000, vec![
+        StatementIr::new(0x1000, OperasiIr::Lea, TipeOperand::Register("rdi".to_string()), TipeOperand::Memory(0x2000)),
+        StatementIr::new(0x1010, OperasiIr::Mov, TipeOperand::Register("esi".to_string()), TipeOperand::Immediate(0x2000)),
+        StatementIr::new(0x1018, OperasiIr::Mov, TipeOperand::Register("esi".to_string()), TipeOperand::Immediate(0x2001)),
+    ]);
+    map_ir.insert(0x1100, vec![
+        StatementIr::new(0x1100, OperasiIr::Lea, TipeOperand::Register("rsi".to_string()), TipeOperand::Memory(0x2000)),
+    ]);
+    tabel.catat_referensi_dari_ir(&map_ir);
+    let entri = tabel.cari_string(0x2000).unwrap();
+    assert_eq!(entri.referensi, vec![0x1000, 0x1010, 0x1100]);
+    assert_eq!(tabel.format_literal_c(0x2000).unwrap(), "\"hello world\"");
+}
+
+#[test]
+fn test_substring_ekor_yang_digabung() {
+    let mut tabel = TabelString::new();
+    tabel.pindai_segment(0x2000, b"halo dunia\0");
+    let mut data_utf16 = buat_utf16le("Kernel32");
+    data_utf16.insert(0, 0xFF);
+    data_utf16.insert(0, 0xFF);
+    tabel.pindai_segment(0x3000, &data_utf16);
+    assert_eq!(tabel.cari_string(0x2005).unwrap().alamat, 0x2000, "alamat di tengah string harus ditemukan lewat rentang");
+    assert!(tabel.cari_string(0x200B).is_none());
+    assert_eq!(tabel.format_literal_c(0x2005).unwrap(), "\"dunia\"");
+    assert_eq!(tabel.format_literal_c(0x300A).unwrap(), "L\"el32\"");
+    assert!(tabel.format_literal_c(0x3003).is_none(), "offset ganjil bukan awal unit UTF-16");
+    let mut map_ir = HashMap::new();
+    map_ir.insert(0x1000, vec![
+        StatementIr::new(0x1000, OperasiIr::Lea, TipeOperand::Register("rdi".to_string()), TipeOperand::Memory(0x2005)),
+    ]);
+    tabel.catat_referensi_dari_ir(&map_ir);
+    let dunia = tabel.entri.get(&0x2005).expect("substring yang direferensikan harus dicatat");
+    assert_eq!(dunia.isi, "dunia");
+    assert_eq!(dunia.panjang_byte, 6);
+    assert_eq!(dunia.referensi, vec![0x1000]);
+    assert!(tabel.cari_string(0x2000).unwrap().referensi.is_empty());
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use rcdecomp_core::analysis::recovery::ast::NodeAst;
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::analysis::recovery::stack::StackFrame;
+use rcdecomp_core::analysis::recovery::types::{TypeSystem, KonvensiPanggilan};
+use rcdecomp_core::arch::x86::X86Arsitektur64;
+use rcdecomp_core::codegen::c_gen::CEmitter;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, StatementIr, TipeDataIr, TipeOperand};
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+
+const REP_MOVSB: [u8; 2] = [0xF3, 0xA4];
+const REP_MOVSQ: [u8; 3] = [0xF3, 0x48, 0xA5];
+const REP_STOSB: [u8; 2] = [0xF3, 0xAA];
+const REP_STOSD: [u8; 2] = [0xF3, 0xAB];
+const REPNE_SCASB: [u8; 2] = [0xF2, 0xAE];
+const REPE_CMPSB: [u8; 2] = [0xF3, 0xA6];
+const MOVSB: [u8; 1] = [0xA4];
+const STOSB: [u8; 1] = [0xAA];
+const STD: [u8; 1] = [0xFD];
+const CLD: [u8; 1] = [0xFC];
+const RET: [u8; 1] = [0xC3];
+
+fn lift_dengan_arah(bytes: &[u8], mundur: bool) -> Vec<StatementIr> {
+    let engine = DisasmEngine::buat_engine_baru("x86_64");
+    let mut instr = engine.ambil_satu_instruksi(bytes, 0x1000).expect("instruksi harus terdekode");
+    instr.arah_mundur = mundur;
+    IrLifter::untuk_arsitektur("x86_64").konversi_instruksi_ke_microcode(&instr)
+}
+
+fn lift(bytes: &[u8]) -> Vec<StatementIr> {
+    lift_dengan_arah(bytes, false)
+}
+
+fn reg(nama: &str) -> TipeOperand {
+    TipeOperand::Register(nama.to_string())
+}
+
+fn imm(nilai: i64) -> TipeOperand {
+    TipeOperand::Immediate(nilai)
+}
+
+fn expr(operasi: OperasiIr, kiri: TipeOperand, kanan: TipeOperand) -> TipeOperand {
+    TipeOperand::Expression { operasi, operand_kiri: Box::new(kiri), operand_kanan: Box::new(kanan) }
+}
+
+fn intrinsic(ir: &[StatementIr]) -> (String, Vec<TipeOperand>) {
+    ir.iter().find_map(|s| match &s.operation_code {
+        OperasiIr::Intrinsic(nama) => Some((nama.clone(), s.operand_tambahan.clone())),
+        _ => None,
+    }).unwrap_or_else(|| panic!("harus ada intrinsic memori: {:?}", ir))
+}
+
+fn nilai_akhir(ir: &[StatementIr], nama: &str) -> TipeOperand {
+    ir.iter().rev().find(|s| s.operand_satu == reg(nama)).map(|s| s.operand_dua.clone()).unwrap_or_else(|| panic!("{} harus diperbarui: {:?}", nama, ir))
+}
+
+#[test]
+fn test_rep_movs_menjadi_memcpy() {
+    let ir = lift(&REP_MOVSB);
+    assert_eq!(intrinsic(&ir), ("memcpy".to_string(), vec![reg("rdi"), reg("rsi"), reg("rcx")]));
+    assert_eq!(nilai_akhir(&ir, "rdi"), expr(OperasiIr::Add, reg("rdi"), reg("rcx")));
+    assert_eq!(nilai_akhir(&ir, "rsi"), expr(OperasiIr::Add, reg("rsi"), reg("rcx")));
+    assert_eq!(nilai_akhir(&ir, "rcx"), imm(0));
+    let ir = lift(&REP_MOVSQ);
+    let panjang = expr(OperasiIr::Imul, reg("rcx"), imm(8));
+    assert_eq!(intrinsic(&ir), ("memcpy".to_string(), vec![reg("rdi"), reg("rsi"), panjang.clone()]));
+    assert_eq!(nilai_akhir(&ir, "rdi"), expr(OperasiIr::Add, reg("rdi"), panjang));
+}
+
+#[test]
+fn test_rep_movs_mundur_menjadi_memmove() {
+    let ir = lift_dengan_arah(&REP_MOVSB, true);
+    let awal = |p: &str| expr(OperasiIr::Sub, reg(p), expr(OperasiIr::Sub, reg("rcx"), imm(1)));
+    assert_eq!(intrinsic(&ir), ("memmove".to_string(), vec![awal("rdi"), awal("rsi"), reg("rcx")]));
+    assert_eq!(nilai_akhir(&ir, "rdi"), expr(OperasiIr::Sub, reg("rdi"), reg("rcx")));
+}
+
+#[test]
+fn test_rep_stos_menjadi_memset() {
+    let ir = lift(&REP_STOSB);
+    let (nama, args) = intrinsic(&ir);
+    assert_eq!(nama, "memset");
+    assert_eq!(args[0], reg("rdi"));
+    assert_eq!(args[2], reg("rcx"));
+    assert_eq!(nilai_akhir(&ir, "rdi"), expr(OperasiIr::Add, reg("rdi"), reg("rcx")));
+    let ir = lift(&REP_STOSD);
+    assert_eq!(intrinsic(&ir).0, "__stosd", "stos dword diisi per elemen 32-bit");
+    assert_eq!(nilai_akhir(&ir, "rdi"), expr(OperasiIr::Add, reg("rdi"), expr(OperasiIr::Imul, reg("rcx"), imm(4))));
+}
+
+#[test]
+fn test_repne_scas_menjadi_memchr() {
+    let ir = lift(&REPNE_SCASB);
+    let (nama, args) = intrinsic(&ir);
+    assert_eq!(nama, "memchr");
+    assert_eq!((args[0].clone(), args[2].clone()), (reg("rdi"), reg("rcx")));
+    let zf = nilai_akhir(&ir, "eflags_zf");
+    assert!(matches!(zf, TipeOperand::Conditional { .. }), "zf bergantung pada hasil pencarian: {:?}", zf);
+    assert_eq!(nilai_akhir(&ir, "rdi"), expr(OperasiIr::Add, reg("rdi"), reg("temp_string_jumlah")));
+    assert_eq!(nilai_akhir(&ir, "rcx"), expr(OperasiIr::Sub, reg("rcx"), reg("temp_string_jumlah")));
+    assert_eq!(intrinsic(&lift_dengan_arah(&REPNE_SCASB, true)).0, "memrchr");
+}
+
+#[test]
+fn test_repe_cmps_menjadi_memcmp() {
+    let ir = lift(&REPE_CMPSB);
+    assert_eq!(intrinsic(&ir), ("memcmp".to_string(), vec![reg("rsi"), reg("rdi"), reg("rcx")]));
+    assert_eq!(nilai_akhir(&ir, "eflags_zf"), expr(OperasiIr::Je, reg("temp_string"), imm(0)));
+    assert_eq!(nilai_akhir(&ir, "eflags_cf"), expr(OperasiIr::Jl, reg("temp_string"), imm(0)));
+}
+
+#[test]
+fn test_string_tanpa_rep_satu_elemen() {
+    let ir = lift(&MOVSB);
+    assert!(ir.iter().all(|s| !matches!(s.operation_code, OperasiIr::Intrinsic(_))), "movsb tunggal tidak boleh menjadi intrinsic: {:?}", ir);
+    assert_eq!(ir[0].operation_code, OperasiIr::Mov);
+    assert_eq!(ir[0].tipe_hasil, TipeDataIr::I8);
+    assert_eq!(nilai_akhir(&ir, "rdi"), expr(OperasiIr::Add, reg("rdi"), imm(1)));
+    assert_eq!(nilai_akhir(&ir, "rsi"), expr(OperasiIr::Add, reg("rsi"), imm(1)));
+    let ir = lift_dengan_arah(&STOSB, true);
+    assert_eq!(nilai_akhir(&ir, "rdi"), expr(OperasiIr::Sub, reg("rdi"), imm(1)));
+    assert_eq!(lift(&STD)[0].operand_dua, imm(1));
+    assert_eq!(lift(&CLD)[0].operand_satu, reg("eflags_df"));
+}
+
+#[test]
+fn test_flag_arah_dilacak_explorer() {
+    let kode = [&STD[..], &REP_MOVSB, &CLD, &REP_MOVSB, &RET].concat();
+    let mut vmem = VirtualMemory::baru(0x401000, "x86_64", "elf");
+    vmem.tambah_segment(0x401000, kode, IzinAkses::ReadExecute, ".text".to_string());
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(&vmem);
+    let ctx = analyzer.ambil_hasil_fungsi().get(&0x401000).expect("fungsi harus terdeteksi");
+    let nama: Vec<String> = ctx.ir_code.iter().filter_map(|s| match &s.operation_code {
+        OperasiIr::Intrinsic(n) => Some(n.clone()),
+        _ => None,
+    }).collect();
+    assert_eq!(nama, vec!["memmove".to_string(), "memcpy".to_string()]);
+}
+
+#[test]
+fn test_cemitter_mencetak_panggilan_libc() {
+    let mut stmts = Vec::new();
+    for kode in [&REP_MOVSB[..], &REP_STOSB, &REPNE_SCASB, &REPE_CMPSB] {
+        stmts.extend(lift(kode));
+    }
+    let mut emitter = CEmitter::new();
+    let kode = emitter.hasilkan_fungsi_tunggal(
+        "salin",
+        &NodeAst::Block(stmts),
+        &TypeSystem::new(),
+        &StackFrame::new(),
+        &BTreeMap::new(),
+        &[],
+        KonvensiPanggilan::Bawaan,
+        &X86Arsitektur64,
+    );
+    for baris in ["memcpy(rdi, rsi, rcx);", "rdi = rdi + rcx;", "memset(rdi, ", "= memchr(rdi, ", "= memcmp(rsi, rdi, rcx);"] {
+        assert!(kode.contains(baris), "output harus memuat '{}':\n{}", baris, kode);
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, StatementIr, TipeOperand};
+use rcdecomp_core::analysis::graph::cfg::{ControlFlowGraph, BasicBlock};
+use rcdecomp_core::analysis::graph::dom::DominatorTree;
+use rcdecomp_core::analysis::passes::ssa::SsaTransformer;
+use rcdecomp_core::analysis::passes::opt_expr::ExpressionOptimizer;
+
+const MOV_EAX_EBX: [u8; 2] = [0x89, 0xD8];
+const MOV_AL_BL: [u8; 2] = [0x88, 0xD8];
+const MOV_AH_BL: [u8; 2] = [0x88, 0xDC];
+const MOV_AX_BX: [u8; 3] = [0x66, 0x89, 0xD8];
+const ADD_EAX_1: [u8; 3] = [0x83, 0xC0, 0x01];
+const MOVZX_EAX_BL: [u8; 3] = [0x0F, 0xB6, 0xC3];
+const MOVSX_EAX_BL: [u8; 3] = [0x0F, 0xBE, 0xC3];
+const MOVSXD_RAX_EBX: [u8; 3] = [0x48, 0x63, 0xC3];
+const CBW: [u8; 2] = [0x66, 0x98];
+const CWDE: [u8; 1] = [0x98];
+const CDQE: [u8; 2] = [0x48, 0x98];
+const MOV_EAX_1: [u8; 5] = [0xB8, 0x01, 0x00, 0x00, 0x00];
+const MOV_RBX_RAX: [u8; 3] = [0x48, 0x89, 0xC3];
+const MOV_W0_W1: [u8; 4] = [0xE0, 0x03, 0x01, 0x2A];
+const SXTW_X0_W1: [u8; 4] = [0x20, 0x7C, 0x40, 0x93];
+const UXTB_W0_W1: [u8; 4] = [0x20, 0x1C, 0x00, 0x53];
+
+fn lift_arch(arch: &str, bytes: &[u8]) -> Vec<StatementIr> {
+    let engine = DisasmEngine::buat_engine_baru(arch);
+    let instr = engine.ambil_satu_instruksi(bytes, 0x1000).expect("instruksi harus terdekode");
+    IrLifter::untuk_arsitektur(arch).konversi_instruksi_ke_microcode(&instr)
+}
+
+fn evaluasi(op: &TipeOperand, reg: &HashMap<String, i64>) -> i64 {
+    match op {
+        TipeOperand::Immediate(v) => *v,
+        TipeOperand::Register(r) => reg.get(r).copied().unwrap_or(0),
+        TipeOperand::Expression { operasi, operand_kiri, operand_kanan } => {
+            let a = evaluasi(operand_kiri, reg);
+            let b = evaluasi(operand_kanan, reg);
+            match operasi {
+                OperasiIr::Add => a.wrapping_add(b),
+                OperasiIr::Shr => (a as u64).wrapping_shr(b as u32) as i64,
+                lain => lain.evaluasi_lebar(a, b).unwrap_or_else(|| panic!("operasi {:?} tidak didukung evaluator test", lain)),
+            }
+        },
+        lain => panic!("operand {:?} tidak didukung evaluator test", lain),
+    }
+}
+
+fn jalankan(arch: &str, bytes: &[u8], awal: &[(&str, i64)]) -> HashMap<String, i64> {
+    let mut reg: HashMap<String, i64> = awal.iter().map(|(n, v)| (n.to_string(), *v)).collect();
+    for stmt in lift_arch(arch, bytes) {
+        if let TipeOperand::Register(dest) = &stmt.operand_satu
+            && !dest.starts_with("eflags_") && !dest.starts_with("temp_")
+        {
+            let nilai = evaluasi(&stmt.operand_dua, &reg);
+            reg.insert(dest.clone(), nilai);
+        }
+    }
+    reg
+}
+
+fn tulis_ke(ir: &[StatementIr], nama: &str) -> bool {
+    ir.iter().any(|s| s.operand_satu == TipeOperand::Register(nama.to_string()))
+}
+
+fn expr(operasi: OperasiIr, kiri: TipeOperand, kanan: TipeOperand) -> TipeOperand {
+    TipeOperand::Expression { operasi, operand_kiri: Box::new(kiri), operand_kanan: Box::new(kanan) }
+}
+
+fn reg(nama: &str) -> TipeOperand {
+    TipeOperand::Register(nama.to_string())
+}
+
+#[test]
+fn test_tulis_register_32_bit_mengosongkan_bit_atas() {
+    let ir = lift_arch("x86_64", &MOV_EAX_EBX);
+    assert!(tulis_ke(&ir, "rax") && !tulis_ke(&ir, "eax"), "penulisan eax harus menjadi penulisan rax: {:?}", ir);
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::ZeroExtend, expr(OperasiIr::Truncate, reg("rbx"), TipeOperand::Immediate(32)), TipeOperand::Immediate(32)));
+    let hasil = jalankan("x86_64", &MOV_EAX_EBX, &[("rax", 0x7FFF_FFFF_0000_0001), ("rbx", 0x1234_5678_9ABC_DEF0)]);
+    assert_eq!(hasil["rax"], 0x9ABC_DEF0);
+    let hasil = jalankan("x86_64", &ADD_EAX_1, &[("rax", 0x1111_1111_FFFF_FFFF)]);
+    assert_eq!(hasil["rax"], 0, "add eax juga membuang 32 bit atas");
+}
+
+#[test]
+fn test_tulis_register_8_dan_16_bit_mempertahankan_sisa() {
+    let awal = [("rax", 0x1122_3344_5566_7788), ("rbx", 0x0000_0000_0000_AABB)];
+    assert_eq!(jalankan("x86_64", &MOV_AL_BL, &awal)["rax"], 0x1122_3344_5566_77BB);
+    assert_eq!(jalankan("x86_64", &MOV_AH_BL, &awal)["rax"], 0x1122_3344_5566_BB88);
+    assert_eq!(jalankan("x86_64", &MOV_AX_BX, &awal)["rax"], 0x1122_3344_5566_AABB);
+    let ir = lift_arch("x86_64", &MOV_AH_BL);
+    assert!(matches!(&ir[0].operand_dua, TipeOperand::Expression { operasi: OperasiIr::BitInsert { offset: 8, lebar: 8 }, .. }));
+    let hasil = jalankan("x86", &MOV_AL_BL, &[("eax", 0x1234_5678), ("ebx", 0x9A)]);
+    assert_eq!(hasil["eax"], 0x1234_569A, "di mode 32 bit akarnya eax");
+}
+
+#[test]
+fn test_ekstensi_movzx_movsx_dan_cdqe() {
+    let awal = [("rax", -1), ("rbx", 0x0000_0000_8000_0080)];
+    assert_eq!(jalankan("x86_64", &MOVZX_EAX_BL, &awal)["rax"], 0x80);
+    assert_eq!(jalankan("x86_64", &MOVSX_EAX_BL, &awal)["rax"], 0xFFFF_FF80);
+    assert_eq!(jalankan("x86_64", &MOVSXD_RAX_EBX, &awal)["rax"], 0xFFFF_FFFF_8000_0080u64 as i64);
+    let ir = lift_arch("x86_64", &MOVSXD_RAX_EBX);
+    assert!(ir.iter().any(|s| matches!(&s.operand_dua, TipeOperand::Expression { operasi: OperasiIr::SignExtend, .. })));
+    assert_eq!(jalankan("x86_64", &CDQE, &[("rax", 0x1234_5678_FFFF_FFFE)])["rax"], -2);
+    assert_eq!(jalankan("x86_64", &CWDE, &[("rax", 0x1234_5678_0000_8000)])["rax"], 0xFFFF_8000);
+    assert_eq!(jalankan("x86_64", &CBW, &[("rax", 0x1234_5678_0000_00FF)])["rax"], 0x1234_5678_0000_FFFF);
+}
+
+#[test]
+fn test_register_w_arm64() {
+    let ir = lift_arch("aarch64", &MOV_W0_W1);
+    assert!(tulis_ke(&ir, "x0") && !tulis_ke(&ir, "w0"), "penulisan w0 harus menjadi penulisan x0: {:?}", ir);
+    assert_eq!(jalankan("aarch64", &MOV_W0_W1, &[("x0", -1), ("x1", 0x5_8000_0001)])["x0"], 0x8000_0001);
+    assert_eq!(jalankan("aarch64", &SXTW_X0_W1, &[("x1", 0x1_8000_0000)])["x0"], 0xFFFF_FFFF_8000_0000u64 as i64);
+    assert_eq!(jalankan("aarch64", &UXTB_W0_W1, &[("x0", -1), ("x1", 0x1FF)])["x0"], 0xFF);
+}
+
+fn cfg_dari_ir(ir: Vec<StatementIr>) -> ControlFlowGraph {
+    let mut cfg = ControlFlowGraph::inisialisasi_graph_kosong();
+    cfg.entry_point = 0;
+    let mut entry = BasicBlock::new(0);
+    entry.instruksi_list = ir;
+    entry.instruksi_list.push(StatementIr::new(0x2000, OperasiIr::Ret, TipeOperand::None, TipeOperand::None));
+    cfg.blocks.insert(0, entry);
+    cfg
+}
+
+fn propagasi_konstanta_rbx(ir: Vec<StatementIr>) -> TipeOperand {
+    let mut cfg = cfg_dari_ir(ir);
+    let mut dom_tree = DominatorTree::new();
+    dom_tree.hitung_dominators(&cfg);
+    let mut transformer = SsaTransformer::new();
+    transformer.lakukan_transformasi_ssa(&mut cfg, &dom_tree);
+    let posisi = cfg.blocks[&0].instruksi_list.iter()
+        .rposition(|s| matches!(&s.operand_satu, TipeOperand::SsaVariable(n, _) if n == "rbx"))
+        .expect("rbx harus ditulis");
+    transformer.optimasi_propagasi_konstanta(&mut cfg);
+    cfg.blocks[&0].instruksi_list[posisi].operand_dua.clone()
+}
+
+#[test]
+fn test_sccp_melihat_lebar_register() {
+    let mut ir = vec![StatementIr::new(0x0, OperasiIr::Mov, reg("rax"), TipeOperand::Immediate(-1))];
+    ir.extend(lift_arch("x86_64", &MOV_AL_BL));
+    ir.extend(lift_arch("x86_64", &ADD_EAX_1));
+    ir.extend(lift_arch("x86_64", &MOV_RBX_RAX));
+    ir.insert(1, StatementIr::new(0x0, OperasiIr::Mov, reg("rbx"), TipeOperand::Immediate(0x7F)));
+    let akhir = propagasi_konstanta_rbx(ir);
+    assert_eq!(akhir, TipeOperand::Immediate(0xFFFF_FF80), "al=0x7f lalu eax+1 pada rax=-1");
+}
+
+#[test]
+fn test_sccp_ekstensi_eax_ke_rax() {
+    let mut ir = lift_arch("x86_64", &MOV_EAX_1);
+    ir.insert(0, StatementIr::new(0x0, OperasiIr::Mov, reg("rax"), TipeOperand::Immediate(-1)));
+    ir.extend(lift_arch("x86_64", &MOV_RBX_RAX));
+    let akhir = propagasi_konstanta_rbx(ir);
+    assert_eq!(akhir, TipeOperand::Immediate(1), "mov eax, 1 membuang nilai lama rax");
+}
+
+#[test]
+fn test_optimizer_ekspresi_menghormati_lebar() {
+    let x = reg("rcx");
+    let mut cfg = cfg_dari_ir(vec![
+        StatementIr::new(0x0, OperasiIr::Mov, reg("r1"), expr(OperasiIr::ZeroExtend,
+            expr(OperasiIr::ZeroExtend, x.clone(), TipeOperand::Immediate(8)), TipeOperand::Immediate(32))),
+        StatementIr::new(0x4, OperasiIr::Mov, reg("r2"), expr(OperasiIr::Truncate,
+            expr(OperasiIr::SignExtend, x.clone(), TipeOperand::Immediate(32)), TipeOperand::Immediate(16))),
+        StatementIr::new(0x8, OperasiIr::Mov, reg("r3"), expr(OperasiIr::SignExtend, TipeOperand::Immediate(0x80), TipeOperand::Immediate(8))),
+        StatementIr::new(0xc, OperasiIr::Mov, reg("r4"), expr(OperasiIr::BitInsert { offset: 8, lebar: 8 },
+            TipeOperand::Immediate(0x1234), TipeOperand::Immediate(0xAB))),
+        StatementIr::new(0x10, OperasiIr::Mov, reg("r5"), expr(OperasiIr::Shl, TipeOperand::Immediate(1), TipeOperand::Immediate(64))),
+    ]);
+    ExpressionOptimizer::new().jalankan_optimasi(&mut cfg);
+    let ir = &cfg.blocks[&0].instruksi_list;
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::ZeroExtend, x.clone(), TipeOperand::Immediate(8)));
+    assert_eq!(ir[1].operand_dua, expr(OperasiIr::Truncate, x, TipeOperand::Immediate(16)));
+    assert_eq!(ir[2].operand_dua, TipeOperand::Immediate(-128));
+    assert_eq!(ir[3].operand_dua, TipeOperand::Immediate(0xAB34));
+    assert_eq!(ir[4].operand_dua, TipeOperand::Immediate(1), "shift dilipat wrapping tanpa panic");
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
-use rcdecomp_core::ir::types::{StatementIr, OperasiIr, TipeOperand};
+use rcdecomp_core::ir::types::{StatementIr, OperasiIr, TipeOperand, TipeDataIr};
 use rcdecomp_core::analysis::recovery::types::{
-    TypeSystem, TipePrimitif, SignatureFungsi
+    TypeSystem, TipePrimitif, SignatureFungsi, KonvensiPanggilan
 };
 
 fn create_stmt(addr: u64, op: OperasiIr, op1: TipeOperand, op2: TipeOperand) -> StatementIr {
@@ -35,10 +35,12 @@ fn test_type_unification_conflict_resolution() {
     sys.global_signatures.insert(addr_func_int, SignatureFungsi {
         return_type: TipePrimitif::Void,
         arg_types: vec![TipePrimitif::Integer(4)],
+        konvensi: KonvensiPanggilan::Bawaan,
     });
     sys.global_signatures.insert(addr_func_float, SignatureFungsi {
         return_type: TipePrimitif::Void,
         arg_types: vec![TipePrimitif::Float(4)],
+        konvensi: KonvensiPanggilan::Bawaan,
     });
     let mut stmts = Vec::new();
     stmts.push(stmt_call(0x100, addr_func_int, vec![reg("var_a")]));
@@ -72,6 +74,7 @@ fn test_struct_reconstruction_from_fields() {
     sys.global_signatures.insert(0x999, SignatureFungsi {
         return_type: TipePrimitif::Void,
         arg_types: vec![TipePrimitif::Integer(4)],
+        konvensi: KonvensiPanggilan::Bawaan,
     });
     functions.get_mut(&0x100).unwrap().push(stmt_call(0x108, 0x999, vec![reg("temp1")]));
     functions.get_mut(&0x100).unwrap().push(stmt_call(0x10C, 0x999, vec![reg("temp2")]));
@@ -120,4 +123,23 @@ fn test_recursive_type_handling() {
     assert!(layout.is_recursive, "Struct harus terdeteksi sebagai rekursif");
     let field_type = layout.fields.get(&0).expect("Field 0 missing");
     assert_eq!(field_type, type_ptr, "Field 0 harus bertipe pointer ke struct itu sendiri");
-}
\ No newline at end of file
+}
+#[test]
+fn test_tipe_float_dari_operasi_skalar() {
+    let mut sys = TypeSystem::new();
+    let tambah = TipeOperand::Expression {
+        operasi: OperasiIr::FAdd,
+        operand_kiri: Box::new(reg("xmm0_1")),
+        operand_kanan: Box::new(reg("xmm1_1")),
+    };
+    let stmts = vec![
+        create_stmt(0x100, OperasiIr::FAdd, reg("xmm0_2"), tambah).with_type(TipeDataIr::F64),
+        create_stmt(0x104, OperasiIr::FConv, reg("xmm2_1"), reg("eax_1")).with_type(TipeDataIr::F32),
+    ];
+    let mut functions = HashMap::new();
+    functions.insert(0x100, stmts);
+    sys.analisis_interprosedural(&functions);
+    assert_eq!(sys.variable_types.get("xmm0_2"), Some(&TipePrimitif::Float(8)));
+    assert_eq!(sys.variable_types.get("xmm2_1"), Some(&TipePrimitif::Float(4)));
+    assert_eq!(sys.dapatkan_tipe_c_string("xmm0_2"), "double");
+}
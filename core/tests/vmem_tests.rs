@@ -1,4 +1,4 @@
-use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses, EntriRelokasi};
 
 fn create_dummy_vmem() -> VirtualMemory {
     VirtualMemory::baru(0x1000, "x86_64", "raw")
@@ -87,4 +87,41 @@ fn test_boundary_search_logic() {
     assert!(vmem.baca_byte(0x1010).is_none());
     assert!(vmem.baca_byte(0x1500).is_none());
     assert!(vmem.baca_byte(0x2000).is_some());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_rebase_image_dengan_relokasi() {
+    let mut vmem = create_dummy_vmem();
+    vmem.image_base = 0x1000;
+    vmem.tambah_segment(0x1000, vec![0x90; 16], IzinAkses::ReadExecute, ".text".to_string());
+    let mut data = vec![0u8; 16];
+    data[..8].copy_from_slice(&0x1004u64.to_le_bytes());
+    data[8..].copy_from_slice(&0x1008u64.to_le_bytes());
+    vmem.tambah_segment(0x2000, data, IzinAkses::ReadWrite, ".data".to_string());
+    vmem.relokasi.push(EntriRelokasi { alamat: 0x2000, lebar: 8 });
+    vmem.simbol_global.insert(0x1004, "fungsi_a".to_string());
+    let delta = vmem.rebase_ke(0x5555_0000);
+    assert_eq!(delta, 0x5555_0000 - 0x1000);
+    assert_eq!(vmem.image_base, 0x5555_0000);
+    assert_eq!(vmem.entry_point, 0x5555_0000);
+    assert_eq!(vmem.segments[0].start_addr, 0x5555_0000);
+    assert_eq!(vmem.segments[1].start_addr, 0x5555_1000);
+    assert_eq!(vmem.simbol_global.get(&0x5555_0004).map(|s| s.as_str()), Some("fungsi_a"));
+    assert_eq!(vmem.baca_pointer(0x5555_1000, 8), Some(0x5555_0004), "Pointer terelokasi harus ikut bergeser");
+    assert_eq!(vmem.baca_pointer(0x5555_1008, 8), Some(0x1008), "Pointer tanpa relokasi tidak boleh diubah");
+    assert_eq!(vmem.relokasi[0].alamat, 0x5555_1000);
+}
+
+#[test]
+fn test_rebase_ke_base_lebih_rendah() {
+    let mut vmem = create_dummy_vmem();
+    vmem.image_base = 0x10000000;
+    vmem.entry_point = 0x10001000;
+    let mut data = vec![0u8; 4];
+    data.copy_from_slice(&0x10001000u32.to_le_bytes());
+    vmem.tambah_segment(0x10002000, data, IzinAkses::Read, ".rdata".to_string());
+    vmem.relokasi.push(EntriRelokasi { alamat: 0x10002000, lebar: 4 });
+    vmem.rebase_ke(0x400000);
+    assert_eq!(vmem.entry_point, 0x401000);
+    assert_eq!(vmem.baca_pointer(0x402000, 4), Some(0x401000), "Relokasi 32-bit harus tetap valid saat delta negatif");
+}
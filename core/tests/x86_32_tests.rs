@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::analysis::recovery::abi::{CallingConventionAnalyzer, InfoKonvensiX86, TipeAbi};
+use rcdecomp_core::analysis::recovery::ast::NodeAst;
+use rcdecomp_core::analysis::recovery::stack::StackFrame;
+use rcdecomp_core::analysis::recovery::types::{TypeSystem, KonvensiPanggilan};
+use rcdecomp_core::arch::x86::X86Arsitektur32;
+use rcdecomp_core::codegen::c_gen::CEmitter;
+use rcdecomp_core::disasm::instruction::{InstructionNormalized, JenisOperandDisasm};
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, TipeOperand, TipeDataIr};
+
+const KODE_STDCALL: [u8; 13] = [0x55, 0x89, 0xE5, 0x8B, 0x45, 0x08, 0x03, 0x45, 0x0C, 0x5D, 0xC2, 0x08, 0x00];
+const KODE_CDECL: [u8; 11] = [0x55, 0x89, 0xE5, 0x8B, 0x45, 0x08, 0x03, 0x45, 0x0C, 0x5D, 0xC3];
+const KODE_FASTCALL: [u8; 5] = [0x89, 0xC8, 0x01, 0xD0, 0xC3];
+const KODE_THISCALL: [u8; 6] = [0x8B, 0x41, 0x04, 0xC2, 0x04, 0x00];
+const KODE_ECX_DINOLKAN: [u8; 7] = [0x31, 0xC9, 0x8D, 0x41, 0x01, 0xC3, 0x90];
+
+fn deteksi_konvensi(kode: &[u8], is_method: bool) -> InfoKonvensiX86 {
+    let mut vmem = VirtualMemory::baru(0x401000, "x86", "pe");
+    vmem.tambah_segment(0x401000, kode.to_vec(), IzinAkses::ReadExecute, ".text".to_string());
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(&vmem);
+    let ctx = analyzer.ambil_hasil_fungsi().get(&0x401000).expect("fungsi harus terdeteksi");
+    let konvensi = CallingConventionAnalyzer::new(&X86Arsitektur32, "pe");
+    assert_eq!(konvensi.profil.tipe, TipeAbi::X86Cdecl);
+    konvensi.deteksi_konvensi_x86(&ctx.ir_code, is_method)
+}
+
+fn instr(addr: u64, mnemonic: &str, operands: Vec<JenisOperandDisasm>) -> InstructionNormalized {
+    let mut i = InstructionNormalized::new(addr, mnemonic, "");
+    i.operands_detail = operands;
+    i
+}
+
+#[test]
+fn test_lifter_memakai_esp() {
+    let lifter = IrLifter::untuk_arsitektur("x86");
+    assert_eq!(lifter.pointer_size, 4);
+    let push = lifter.konversi_instruksi_ke_microcode(&instr(0x1000, "push", vec![JenisOperandDisasm::Register("ebp".to_string())]));
+    assert_eq!(push[0].operand_satu, TipeOperand::Register("esp".to_string()));
+    assert_eq!(push[0].operand_dua, TipeOperand::Immediate(4));
+    assert_eq!(push[0].tipe_hasil, TipeDataIr::I32);
+    assert_eq!(push[1].operand_satu, TipeOperand::MemoryRef { base: "esp".to_string(), offset: 0 });
+    let call = lifter.konversi_instruksi_ke_microcode(&instr(0x1001, "call", vec![JenisOperandDisasm::Immediate(0x2000)]));
+    assert_eq!(call[0].operand_satu, TipeOperand::Register("esp".to_string()));
+    let ret = lifter.konversi_instruksi_ke_microcode(&instr(0x1006, "ret", vec![JenisOperandDisasm::Immediate(8)]));
+    assert_eq!(ret[0].operation_code, OperasiIr::Add);
+    assert_eq!(ret[0].operand_dua, TipeOperand::Immediate(12), "ret imm16 harus ikut membersihkan argumen");
+    assert_eq!(ret[1].operation_code, OperasiIr::Ret);
+    let ret64 = IrLifter::untuk_arsitektur("x86_64").konversi_instruksi_ke_microcode(&instr(0x1006, "ret", vec![]));
+    assert_eq!(ret64[0].operand_satu, TipeOperand::Register("rsp".to_string()));
+}
+
+#[test]
+fn test_stdcall_dari_ret_imm16() {
+    let info = deteksi_konvensi(&KODE_STDCALL, false);
+    assert_eq!(info.konvensi, KonvensiPanggilan::Stdcall);
+    assert_eq!(info.pembersihan_stack, 8);
+    assert_eq!(info.daftar_parameter(), vec!["arg_8", "arg_12"]);
+}
+
+#[test]
+fn test_cdecl_argumen_dari_frame_ebp() {
+    let info = deteksi_konvensi(&KODE_CDECL, false);
+    assert_eq!(info.konvensi, KonvensiPanggilan::Cdecl);
+    assert_eq!(info.pembersihan_stack, 0);
+    assert_eq!(info.argumen_stack, vec!["arg_8", "arg_12"]);
+    assert!(info.register_argumen.is_empty());
+}
+
+#[test]
+fn test_fastcall_dan_thiscall() {
+    let fastcall = deteksi_konvensi(&KODE_FASTCALL, false);
+    assert_eq!(fastcall.konvensi, KonvensiPanggilan::Fastcall);
+    assert_eq!(fastcall.register_argumen, vec!["ecx_0", "edx_0"]);
+    let thiscall = deteksi_konvensi(&KODE_THISCALL, false);
+    assert_eq!(thiscall.konvensi, KonvensiPanggilan::Thiscall, "ecx dipakai sebagai basis pointer harus dianggap this");
+    assert_eq!(thiscall.daftar_parameter(), vec!["ecx_0", "arg_8"]);
+    let dinolkan = deteksi_konvensi(&KODE_ECX_DINOLKAN, false);
+    assert_eq!(dinolkan.konvensi, KonvensiPanggilan::Cdecl, "xor ecx, ecx bukan pembacaan argumen");
+}
+
+#[test]
+fn test_prototipe_memuat_konvensi() {
+    let mut emitter = CEmitter::new();
+    let params = vec!["ecx_0".to_string(), "arg_8".to_string()];
+    let kode = emitter.hasilkan_fungsi_tunggal(
+        "sub_401000",
+        &NodeAst::Sequence(Vec::new()),
+        &TypeSystem::new(),
+        &StackFrame::new(),
+        &BTreeMap::new(),
+        &params,
+        KonvensiPanggilan::Thiscall,
+        &X86Arsitektur32,
+    );
+    let prototipe = kode.lines().next().unwrap();
+    assert!(prototipe.starts_with("long __thiscall sub_401000("), "prototipe: {}", prototipe);
+    assert!(prototipe.contains("ecx_0") && prototipe.contains("arg_8"));
+}
@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+use rcdecomp_core::analysis::recovery::ast::NodeAst;
+use rcdecomp_core::analysis::recovery::stack::StackFrame;
+use rcdecomp_core::analysis::recovery::types::{TypeSystem, KonvensiPanggilan};
+use rcdecomp_core::arch::x86::X86Arsitektur64;
+use rcdecomp_core::codegen::c_gen::CEmitter;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::types::{OperasiIr, StatementIr, TipeDataIr, TipeOperand};
+
+const SETE_AL: [u8; 3] = [0x0F, 0x94, 0xC0];
+const CMOVNE_RAX_RDX: [u8; 4] = [0x48, 0x0F, 0x45, 0xC2];
+const CMOVL_EAX_ECX: [u8; 3] = [0x0F, 0x4C, 0xC1];
+const BT_EAX_5: [u8; 4] = [0x0F, 0xBA, 0xE0, 0x05];
+const BTS_RCX_RDX: [u8; 4] = [0x48, 0x0F, 0xAB, 0xD1];
+const BTR_MEM_ESI: [u8; 3] = [0x0F, 0xB3, 0x37];
+const BSWAP_EAX: [u8; 2] = [0x0F, 0xC8];
+const BSWAP_RCX: [u8; 3] = [0x48, 0x0F, 0xC9];
+const CQO: [u8; 2] = [0x48, 0x99];
+const CDQ: [u8; 1] = [0x99];
+const SHLD_RAX_RDX_4: [u8; 5] = [0x48, 0x0F, 0xA4, 0xD0, 0x04];
+const SHRD_EAX_EDX_CL: [u8; 3] = [0x0F, 0xAD, 0xD0];
+const NEG_RAX: [u8; 3] = [0x48, 0xF7, 0xD8];
+const NOT_ECX: [u8; 2] = [0xF7, 0xD1];
+const ADC_RAX_RCX: [u8; 3] = [0x48, 0x11, 0xC8];
+const SBB_EAX_EDX: [u8; 2] = [0x19, 0xD0];
+const MUL_RCX: [u8; 3] = [0x48, 0xF7, 0xE1];
+const IMUL_RCX: [u8; 3] = [0x48, 0xF7, 0xE9];
+const DIV_RCX: [u8; 3] = [0x48, 0xF7, 0xF1];
+const IDIV_RCX: [u8; 3] = [0x48, 0xF7, 0xF9];
+const DIV_CL: [u8; 2] = [0xF6, 0xF1];
+const POPCNT_EAX_ECX: [u8; 4] = [0xF3, 0x0F, 0xB8, 0xC1];
+const TZCNT_RAX_RCX: [u8; 5] = [0xF3, 0x48, 0x0F, 0xBC, 0xC1];
+
+fn lift(bytes: &[u8]) -> Vec<StatementIr> {
+    let engine = DisasmEngine::buat_engine_baru("x86_64");
+    let instr = engine.ambil_satu_instruksi(bytes, 0x1000).expect("instruksi harus terdekode");
+    IrLifter::untuk_arsitektur("x86_64").konversi_instruksi_ke_microcode(&instr)
+}
+
+fn reg(nama: &str) -> TipeOperand {
+    TipeOperand::Register(nama.to_string())
+}
+
+fn imm(nilai: i64) -> TipeOperand {
+    TipeOperand::Immediate(nilai)
+}
+
+fn expr(operasi: OperasiIr, kiri: TipeOperand, kanan: TipeOperand) -> TipeOperand {
+    TipeOperand::Expression { operasi, operand_kiri: Box::new(kiri), operand_kanan: Box::new(kanan) }
+}
+
+fn pilih(kondisi: TipeOperand, benar: TipeOperand, salah: TipeOperand) -> TipeOperand {
+    TipeOperand::Conditional { condition: Box::new(kondisi), true_val: Box::new(benar), false_val: Box::new(salah) }
+}
+
+fn potong(nama: &str, lebar: i64) -> TipeOperand {
+    expr(OperasiIr::Truncate, reg(nama), imm(lebar))
+}
+
+fn tulis_ke<'a>(ir: &'a [StatementIr], nama: &str) -> &'a StatementIr {
+    ir.iter().rev().find(|s| s.operand_satu == reg(nama)).unwrap_or_else(|| panic!("{} harus ditulis: {:?}", nama, ir))
+}
+
+#[test]
+fn test_setcc_menjadi_boolean() {
+    let ir = lift(&SETE_AL);
+    assert_eq!(ir.len(), 1);
+    let nilai = pilih(expr(OperasiIr::Je, reg("eflags_zf"), imm(1)), imm(1), imm(0));
+    assert_eq!(ir[0].operand_satu, reg("rax"));
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::BitInsert { offset: 0, lebar: 8 }, reg("rax"), nilai), "sete hanya menulis byte rendah");
+    assert_eq!(ir[0].tipe_hasil, TipeDataIr::I8);
+}
+
+#[test]
+fn test_cmov_menjadi_ternary() {
+    let ir = lift(&CMOVNE_RAX_RDX);
+    assert_eq!(ir[0].operation_code, OperasiIr::Cmov);
+    assert_eq!(ir[0].operand_dua, pilih(expr(OperasiIr::Je, reg("eflags_zf"), imm(0)), reg("rdx"), reg("rax")));
+    let ir = lift(&CMOVL_EAX_ECX);
+    let kondisi = expr(OperasiIr::Jne, reg("eflags_sf"), reg("eflags_of"));
+    let nilai = pilih(kondisi, potong("rcx", 32), potong("rax", 32));
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::ZeroExtend, nilai, imm(32)), "cmov 32-bit selalu meng-nol-kan bagian atas");
+}
+
+#[test]
+fn test_keluarga_bt() {
+    let ir = lift(&BT_EAX_5);
+    let cf = tulis_ke(&ir, "eflags_cf");
+    assert_eq!(cf.operand_dua, expr(OperasiIr::And, expr(OperasiIr::Shr, potong("rax", 32), imm(5)), imm(1)));
+    assert!(ir.iter().all(|s| s.operand_satu != reg("rax")), "bt tidak mengubah operand");
+    let ir = lift(&BTS_RCX_RDX);
+    let posisi = expr(OperasiIr::And, reg("rdx"), imm(63));
+    assert_eq!(tulis_ke(&ir, "rcx").operand_dua, expr(OperasiIr::Or, reg("rcx"), expr(OperasiIr::Shl, imm(1), posisi)));
+    let urutan_cf = ir.iter().position(|s| s.operand_satu == reg("eflags_cf")).unwrap();
+    let urutan_rcx = ir.iter().position(|s| s.operand_satu == reg("rcx")).unwrap();
+    assert!(urutan_cf < urutan_rcx, "cf harus membaca bit sebelum diubah");
+    let ir = lift(&BTR_MEM_ESI);
+    let uji = tulis_ke(&ir, "eflags_cf");
+    assert_eq!(uji.operation_code, OperasiIr::Intrinsic("_bittestandreset".to_string()), "bit string di memori memakai intrinsic");
+    assert_eq!(uji.operand_tambahan, vec![reg("temp_alamat_bit"), potong("rsi", 32)]);
+}
+
+#[test]
+fn test_bswap_dan_ekstensi_tanda() {
+    let ir = lift(&BSWAP_RCX);
+    assert_eq!(ir[0].operation_code, OperasiIr::Intrinsic("__builtin_bswap64".to_string()));
+    assert_eq!((ir[0].operand_satu.clone(), ir[0].operand_dua.clone()), (reg("rcx"), reg("rcx")));
+    let ir = lift(&BSWAP_EAX);
+    assert_eq!(ir[0].operation_code, OperasiIr::Intrinsic("__builtin_bswap32".to_string()));
+    assert_eq!(tulis_ke(&ir, "rax").operand_dua, expr(OperasiIr::ZeroExtend, reg("temp_sub_register"), imm(32)));
+    let ir = lift(&CQO);
+    assert_eq!(tulis_ke(&ir, "rdx").operand_dua, expr(OperasiIr::Sar, reg("rax"), imm(63)));
+    let ir = lift(&CDQ);
+    let tanda = expr(OperasiIr::Sar, potong("rax", 32), imm(31));
+    assert_eq!(tulis_ke(&ir, "rdx").operand_dua, expr(OperasiIr::ZeroExtend, tanda, imm(32)));
+}
+
+#[test]
+fn test_shld_shrd() {
+    let ir = lift(&SHLD_RAX_RDX_4);
+    let hasil = tulis_ke(&ir, "rax");
+    assert_eq!(hasil.operand_dua, expr(OperasiIr::Or, expr(OperasiIr::Shl, reg("rax"), imm(4)), expr(OperasiIr::Shr, reg("rdx"), imm(60))));
+    let cf = tulis_ke(&ir, "eflags_cf");
+    assert!(matches!(&cf.operand_dua, TipeOperand::Conditional { false_val, .. }
+        if **false_val == expr(OperasiIr::And, expr(OperasiIr::Shr, reg("temp_flag_kiri"), expr(OperasiIr::Sub, imm(64), imm(4))), imm(1))),
+        "cf adalah bit terakhir yang keluar: {:?}", cf.operand_dua);
+    let ir = lift(&SHRD_EAX_EDX_CL);
+    let TipeOperand::Expression { operasi: OperasiIr::ZeroExtend, operand_kiri, .. } = &tulis_ke(&ir, "rax").operand_dua else {
+        panic!("shrd 32-bit harus di-zero-extend: {:?}", ir);
+    };
+    let jumlah = expr(OperasiIr::And, potong("rcx", 8), imm(31));
+    let geser = expr(OperasiIr::Or,
+        expr(OperasiIr::Shr, potong("rax", 32), jumlah.clone()),
+        expr(OperasiIr::Shl, potong("rdx", 32), expr(OperasiIr::Sub, imm(32), jumlah.clone())));
+    assert_eq!(**operand_kiri, pilih(expr(OperasiIr::Je, jumlah, imm(0)), potong("rax", 32), geser), "hitungan nol tidak mengubah tujuan");
+}
+
+#[test]
+fn test_neg_not_adc_sbb_terjangkau() {
+    for (kode, operasi) in [(&NEG_RAX[..], OperasiIr::Neg), (&NOT_ECX, OperasiIr::Not), (&ADC_RAX_RCX, OperasiIr::Adc), (&SBB_EAX_EDX, OperasiIr::Sbb)] {
+        let ir = lift(kode);
+        assert!(ir.iter().any(|s| s.operation_code == operasi), "{:?} harus dilift: {:?}", operasi, ir);
+        assert!(ir.iter().all(|s| s.operation_code != OperasiIr::Unknown));
+    }
+    let ir = lift(&NOT_ECX);
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::ZeroExtend, expr(OperasiIr::Xor, potong("rcx", 32), imm(-1)), imm(32)));
+    let ir = lift(&ADC_RAX_RCX);
+    assert_eq!(tulis_ke(&ir, "rax").operand_dua, expr(OperasiIr::Add, expr(OperasiIr::Add, reg("rax"), reg("rcx")), reg("eflags_cf")));
+}
+
+#[test]
+fn test_mul_div_register_implisit() {
+    let ir = lift(&MUL_RCX);
+    assert_eq!(ir[0].operand_satu, reg("temp_kali_tinggi"), "bagian tinggi dihitung sebelum rax ditimpa");
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::MulHiU, reg("rax"), reg("rcx")));
+    assert_eq!(ir[1].operand_dua, expr(OperasiIr::Mul, reg("rax"), reg("rcx")));
+    assert_eq!(tulis_ke(&ir, "rdx").operand_dua, reg("temp_kali_tinggi"));
+    let ir = lift(&DIV_RCX);
+    assert_eq!(ir[0].operand_dua, expr(OperasiIr::Intrinsic("__concat128".to_string()), reg("rdx"), reg("rax")));
+    assert_eq!(ir[0].tipe_hasil, TipeDataIr::I128);
+    assert_eq!(ir[1].operand_dua, expr(OperasiIr::Div, reg("temp_dividen"), reg("rcx")));
+    let sisa = expr(OperasiIr::Sub, reg("temp_dividen"), expr(OperasiIr::Imul, reg("temp_hasil_bagi"), reg("rcx")));
+    assert_eq!(tulis_ke(&ir, "rdx").operand_dua, sisa);
+    assert_eq!(tulis_ke(&ir, "rax").operand_dua, reg("temp_hasil_bagi"));
+    assert!(ir.iter().all(|s| s.operation_code != OperasiIr::Unknown), "rdx tidak boleh lagi Unknown");
+    assert_eq!(lift(&IDIV_RCX)[1].operation_code, OperasiIr::Idiv);
+    let ir = lift(&DIV_CL);
+    assert_eq!(ir[0].operand_dua, potong("rax", 16), "div 8-bit membagi ax");
+    let sisa = ir.iter().find(|s| matches!(&s.operand_dua, TipeOperand::Expression { operasi: OperasiIr::BitInsert { offset: 8, lebar: 8 }, .. }));
+    assert!(sisa.is_some(), "sisa bagi 8-bit ditulis ke ah: {:?}", ir);
+}
+
+#[test]
+fn test_mul_imul_satu_operand_beda_tanda() {
+    let ir_mul = lift(&MUL_RCX);
+    let ir_imul = lift(&IMUL_RCX);
+    assert_eq!(ir_imul[0].operand_dua, expr(OperasiIr::MulHi, reg("rax"), reg("rcx")));
+    assert_eq!(ir_imul[1].operand_dua, expr(OperasiIr::Imul, reg("rax"), reg("rcx")));
+    assert_ne!(ir_mul[0].operation_code, ir_imul[0].operation_code, "bagian tinggi mul dan imul harus berbeda");
+    assert_ne!(ir_mul[0].operand_dua, ir_imul[0].operand_dua);
+}
+
+#[test]
+fn test_cemitter_aritmatika_128_bit() {
+    let mut stmts = Vec::new();
+    for kode in [&CQO[..], &IDIV_RCX, &MUL_RCX, &IMUL_RCX, &ADC_RAX_RCX, &POPCNT_EAX_ECX, &TZCNT_RAX_RCX] {
+        stmts.extend(lift(kode));
+    }
+    let mut emitter = CEmitter::new();
+    let kode = emitter.hasilkan_fungsi_tunggal(
+        "bagi",
+        &NodeAst::Block(stmts),
+        &TypeSystem::new(),
+        &StackFrame::new(),
+        &BTreeMap::new(),
+        &[],
+        KonvensiPanggilan::Bawaan,
+        &X86Arsitektur64,
+    );
+    for baris in [
+        "rdx = (long)rax >> 0x3f;",
+        "temp_dividen = __concat128(rdx, rax);",
+        "temp_hasil_bagi = temp_dividen / rcx;",
+        "rdx = temp_dividen - temp_hasil_bagi * rcx;",
+        "temp_kali_tinggi = __umulh(rax, rcx);",
+        "temp_kali_tinggi = __mulh(rax, rcx);",
+        "rax *= rcx;",
+        "rax = rax + rcx + eflags_cf;",
+        "temp_sub_register = __builtin_popcount((uint32_t)rcx);",
+        "rax = _tzcnt_u64(rcx);",
+    ] {
+        assert!(kode.contains(baris), "output harus memuat '{}':\n{}", baris, kode);
+    }
+    for operasi in ["Mul", "MulHi", "MulHiU", "Sar", "Idiv", "Adc", "Popcnt", "Tzcnt"] {
+        assert!(!kode.contains(&format!("Unhandled Op: {}\n", operasi)), "{} harus tercetak:\n{}", operasi, kode);
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use rcdecomp_core::analysis::graph::cfg::{ControlFlowGraph, BasicBlock};
+use rcdecomp_core::analysis::recovery::ast::{ControlFlowStructurer, NodeAst};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::disasm::engine::DisasmEngine;
+use rcdecomp_core::ir::lift::IrLifter;
+use rcdecomp_core::ir::lift::x87::{self, HasilBandingFloat};
+use rcdecomp_core::ir::types::{OperasiIr, OrderedFloat, StatementIr, TipeDataIr, TipeOperand};
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+
+const FLD_DWORD_ESP4: [u8; 4] = [0xD9, 0x44, 0x24, 0x04];
+const FLD_QWORD_EBX: [u8; 2] = [0xDD, 0x03];
+const FADDP_ST1: [u8; 2] = [0xDE, 0xC1];
+const FSTP_QWORD_ECX: [u8; 2] = [0xDD, 0x19];
+const FISTP_DWORD_ECX: [u8; 2] = [0xDB, 0x19];
+const FXCH_ST1: [u8; 2] = [0xD9, 0xC9];
+const FSUBRP_ST1: [u8; 2] = [0xDE, 0xE1];
+const FDIVRP_ST1: [u8; 2] = [0xDE, 0xF1];
+const FSUB_ST0_ST2: [u8; 2] = [0xD8, 0xE2];
+const FLDZ: [u8; 2] = [0xD9, 0xEE];
+const FCHS: [u8; 2] = [0xD9, 0xE0];
+const FCOMP_DWORD_EAX: [u8; 2] = [0xD8, 0x18];
+const FCOMIP_ST1: [u8; 2] = [0xDF, 0xF1];
+const FNSTSW_AX: [u8; 2] = [0xDF, 0xE0];
+const TEST_AH_41: [u8; 3] = [0xF6, 0xC4, 0x41];
+const JNE_7: [u8; 2] = [0x75, 0x07];
+const JA_7: [u8; 2] = [0x77, 0x07];
+const RET: [u8; 1] = [0xC3];
+
+fn gabung(bagian: &[&[u8]]) -> Vec<u8> {
+    bagian.concat()
+}
+
+fn lift_urutan(kode: &[u8]) -> Vec<Vec<StatementIr>> {
+    let engine = DisasmEngine::buat_engine_baru("x86");
+    let lifter = IrLifter::untuk_arsitektur("x86");
+    let mut peta = HashMap::new();
+    let mut offset = 0;
+    let mut hasil = Vec::new();
+    while offset < kode.len() {
+        let mut instr = engine.ambil_satu_instruksi(&kode[offset..], 0x1000 + offset as u64).expect("instruksi harus terdekode");
+        offset += instr.hitung_panjang_byte();
+        x87::lacak_top_fpu(&mut instr, &mut peta);
+        hasil.push(lifter.konversi_instruksi_ke_microcode(&instr));
+    }
+    hasil
+}
+
+fn reg(nama: &str) -> TipeOperand {
+    TipeOperand::Register(nama.to_string())
+}
+
+fn expr(operasi: OperasiIr, kiri: TipeOperand, kanan: TipeOperand) -> TipeOperand {
+    TipeOperand::Expression { operasi, operand_kiri: Box::new(kiri), operand_kanan: Box::new(kanan) }
+}
+
+fn kondisi_branch(kode: &[u8]) -> String {
+    let ir: Vec<StatementIr> = lift_urutan(kode).into_iter().flatten().collect();
+    let target = match ir.last().map(|s| &s.operand_satu) {
+        Some(TipeOperand::Immediate(t)) => *t as u64,
+        lain => panic!("instruksi terakhir harus jump kondisional: {:?}", lain),
+    };
+    let fallthrough = 0x1000 + kode.len() as u64;
+    let gabungan = 0x1100;
+    let mut cfg = ControlFlowGraph::inisialisasi_graph_kosong();
+    cfg.entry_point = 0x1000;
+    let mut header = BasicBlock::new(0x1000);
+    header.instruksi_list = ir;
+    header.successors = vec![target, fallthrough];
+    cfg.blocks.insert(0x1000, header);
+    for (id, succ, tujuan) in [(target, Some(gabungan), "ecx"), (fallthrough, Some(gabungan), "edx"), (gabungan, None, "eax")] {
+        let mut block = BasicBlock::new(id);
+        block.instruksi_list.push(StatementIr::new(id, OperasiIr::Mov, reg(tujuan), TipeOperand::Immediate(id as i64)));
+        if let Some(t) = succ {
+            block.instruksi_list.push(StatementIr::new(id + 4, OperasiIr::Jmp, TipeOperand::Immediate(t as i64), TipeOperand::None));
+            block.successors.push(t);
+        }
+        cfg.blocks.insert(id, block);
+    }
+    cfg.blocks.get_mut(&target).unwrap().predecessors = vec![0x1000];
+    cfg.blocks.get_mut(&fallthrough).unwrap().predecessors = vec![0x1000];
+    cfg.blocks.get_mut(&gabungan).unwrap().predecessors = vec![target, fallthrough];
+    let ast = ControlFlowStructurer::new().bangun_tree_struktur(&mut cfg);
+    let NodeAst::Sequence(nodes) = &ast else { panic!("AST harus berupa sequence: {:#?}", ast) };
+    nodes.iter().find_map(|n| match n {
+        NodeAst::IfElse { kondisi, .. } => Some(kondisi.clone()),
+        _ => None,
+    }).unwrap_or_else(|| panic!("if-else harus terdeteksi: {:#?}", ast))
+}
+
+#[test]
+fn test_slot_stack_mengikuti_top() {
+    let kode = gabung(&[&FLD_DWORD_ESP4, &FLD_QWORD_EBX, &FADDP_ST1, &FSTP_QWORD_ECX]);
+    let ir = lift_urutan(&kode);
+    assert_eq!(ir[0][0].operation_code, OperasiIr::FConv);
+    assert_eq!(ir[0][0].operand_satu, reg("fpr7"), "push pertama harus menulis fpr7");
+    assert_eq!(ir[0][0].tipe_hasil, TipeDataIr::F80);
+    assert_eq!(ir[1][0].operand_satu, reg("fpr6"), "push kedua harus menulis fpr6");
+    assert_eq!(ir[2][0].operation_code, OperasiIr::FAdd);
+    assert_eq!(ir[2][0].operand_satu, reg("fpr7"), "faddp st(1) menulis st(1) sebelum pop");
+    assert_eq!(ir[2][0].operand_dua, expr(OperasiIr::FAdd, reg("fpr7"), reg("fpr6")));
+    assert_eq!(ir[3][0].operand_dua, reg("fpr7"), "fstp setelah pop membaca fpr7");
+    assert_eq!(ir[3][0].tipe_hasil, TipeDataIr::F64);
+    assert!(matches!(ir[3][0].operand_satu, TipeOperand::MemoryRef { ref base, offset: 0 } if base == "ecx"));
+}
+
+#[test]
+fn test_operand_terbalik_dan_simpan_integer() {
+    let kode = gabung(&[&FLDZ, &FLD_QWORD_EBX, &FLD_QWORD_EBX, &FSUBRP_ST1, &FSUB_ST0_ST2, &FDIVRP_ST1, &FISTP_DWORD_ECX]);
+    let ir = lift_urutan(&kode);
+    assert_eq!(ir[0][0].operand_dua, TipeOperand::FloatImmediate(OrderedFloat(0.0)), "fldz harus memuat konstanta 0.0");
+    assert_eq!(ir[3][0].operand_satu, reg("fpr6"));
+    assert_eq!(ir[3][0].operand_dua, expr(OperasiIr::FSub, reg("fpr5"), reg("fpr6")), "fsubrp st(1) = st0 - st(1)");
+    assert_eq!(ir[4][0].operand_dua, expr(OperasiIr::FSub, reg("fpr6"), reg("fpr0")), "fsub st(0), st(2)");
+    assert_eq!(ir[5][0].operand_satu, reg("fpr7"));
+    assert_eq!(ir[5][0].operand_dua, expr(OperasiIr::FDiv, reg("fpr6"), reg("fpr7")), "fdivrp st(1) = st0 / st(1)");
+    assert_eq!(ir[6][0].operation_code, OperasiIr::FConv);
+    assert_eq!(ir[6][0].operand_dua, reg("fpr7"));
+    assert_eq!(ir[6][0].tipe_hasil, TipeDataIr::I32, "fistp dword harus bertipe integer 32-bit");
+}
+
+#[test]
+fn test_fxch_dan_fchs() {
+    let kode = gabung(&[&FLDZ, &FLDZ, &FXCH_ST1, &FCHS]);
+    let ir = lift_urutan(&kode);
+    let tukar: Vec<(TipeOperand, TipeOperand)> = ir[2].iter().map(|s| (s.operand_satu.clone(), s.operand_dua.clone())).collect();
+    assert_eq!(tukar, vec![
+        (reg("temp_fpu"), reg("fpr6")),
+        (reg("fpr6"), reg("fpr7")),
+        (reg("fpr7"), reg("temp_fpu")),
+    ]);
+    assert_eq!(ir[3][0].operand_satu, reg("fpr6"));
+    assert_eq!(ir[3][0].operand_dua, expr(OperasiIr::FSub, TipeOperand::FloatImmediate(OrderedFloat(0.0)), reg("fpr6")));
+}
+
+#[test]
+fn test_top_dilacak_explorer() {
+    let kode = gabung(&[&FLD_DWORD_ESP4, &FLD_QWORD_EBX, &FADDP_ST1, &FSTP_QWORD_ECX, &RET]);
+    let mut vmem = VirtualMemory::baru(0x401000, "x86", "elf");
+    vmem.tambah_segment(0x401000, kode, IzinAkses::ReadExecute, ".text".to_string());
+    let mut analyzer = RecursiveDescent::new(&vmem.arsitektur);
+    analyzer.lakukan_analisis_full(&vmem);
+    let ctx = analyzer.ambil_hasil_fungsi().get(&0x401000).expect("fungsi harus terdeteksi");
+    let tambah = ctx.ir_code.iter().find(|s| s.operation_code == OperasiIr::FAdd).expect("faddp harus terangkat");
+    assert_eq!(tambah.operand_satu, reg("fpr7"));
+    assert_eq!(tambah.operand_dua, expr(OperasiIr::FAdd, reg("fpr7"), reg("fpr6")));
+}
+
+#[test]
+fn test_evaluasi_branch_float() {
+    let hasil = |lebih_besar, lebih_kecil, sama, tak_berurut| HasilBandingFloat { lebih_besar, lebih_kecil, sama, tak_berurut };
+    assert_eq!(x87::evaluasi_branch_float(&OperasiIr::Ja, None), Some(hasil(true, false, false, false)));
+    assert_eq!(x87::evaluasi_branch_float(&OperasiIr::Jb, None), Some(hasil(false, true, false, true)));
+    assert_eq!(x87::evaluasi_branch_float(&OperasiIr::Jp, None), Some(hasil(false, false, false, true)));
+    assert_eq!(x87::evaluasi_branch_float(&OperasiIr::Jne, Some(0x41)), Some(hasil(false, true, true, true)));
+    assert_eq!(x87::evaluasi_branch_float(&OperasiIr::Je, Some(0x45)), Some(hasil(true, false, false, false)));
+    assert_eq!(x87::evaluasi_branch_float(&OperasiIr::Jp, Some(0x44)), Some(hasil(true, true, false, true)));
+    assert_eq!(x87::evaluasi_branch_float(&OperasiIr::Jmp, None), None);
+}
+
+#[test]
+fn test_kondisi_float_fnstsw_test_ah() {
+    let kode = gabung(&[&FCOMP_DWORD_EAX, &FNSTSW_AX, &TEST_AH_41, &JNE_7]);
+    let kondisi = kondisi_branch(&kode);
+    assert!(kondisi.starts_with("!(fpr0 > "), "test ah,0x41; jne berarti tidak lebih besar: {}", kondisi);
+}
+
+#[test]
+fn test_kondisi_float_fcomi() {
+    let kode = gabung(&[&FLDZ, &FLDZ, &FCOMIP_ST1, &JA_7]);
+    assert_eq!(kondisi_branch(&kode), "fpr6 > fpr7");
+}
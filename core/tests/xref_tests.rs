@@ -0,0 +1,123 @@
+use rcdecomp_core::loader::vmem::{VirtualMemory, IzinAkses};
+use rcdecomp_core::analysis::recovery::explorer::RecursiveDescent;
+use rcdecomp_core::analysis::recovery::xref::{kumpulkan_xref_instruksi, DatabaseXref, EntriXref, JenisXref};
+use rcdecomp_core::disasm::engine::DisasmEngine;
+
+fn buat_vmem_program() -> VirtualMemory {
+    let mut vmem = VirtualMemory::baru(0x1000, "x86_64", "raw");
+    let mut kode = vec![0xCC; 0x40];
+    let fungsi_utama = [
+        0xE8, 0x0B, 0x00, 0x00, 0x00,
+        0x8B, 0x05, 0xF5, 0x1F, 0x00, 0x00,
+        0xC3,
+    ];
+    let fungsi_kedua = [
+        0x48, 0x89, 0x05, 0xF1, 0x1F, 0x00, 0x00,
+        0xE9, 0x04, 0x00, 0x00, 0x00,
+    ];
+    kode[0x00..0x0C].copy_from_slice(&fungsi_utama);
+    kode[0x10..0x1C].copy_from_slice(&fungsi_kedua);
+    kode[0x20] = 0xC3;
+    vmem.tambah_segment(0x1000, kode, IzinAkses::ReadExecute, ".text".to_string());
+    let mut data = vec![0u8; 0x10];
+    data[0..8].copy_from_slice(&0x1020u64.to_le_bytes());
+    vmem.tambah_segment(0x3000, data, IzinAkses::ReadWrite, ".data".to_string());
+    vmem.simbol_global.insert(0x1000, "main".to_string());
+    vmem.simbol_global.insert(0x1010, "helper".to_string());
+    vmem.simbol_global.insert(0x1020, "tail_target".to_string());
+    vmem
+}
+
+fn cari_xref(daftar: &[EntriXref], dari: u64, jenis: JenisXref) -> Option<&EntriXref> {
+    daftar.iter().find(|e| e.dari == dari && e.jenis == jenis)
+}
+
+#[test]
+fn test_xref_code_ke_code() {
+    let vmem = buat_vmem_program();
+    let mut analyzer = RecursiveDescent::new("x86_64");
+    analyzer.lakukan_analisis_full(&vmem);
+    let db = &analyzer.xref_db;
+    let call = cari_xref(db.ambil_xref_ke(0x1010), 0x1000, JenisXref::Call).expect("Xref call harus tercatat");
+    assert_eq!(call.fungsi_asal, Some(0x1000));
+    assert_eq!(db.ambil_caller(0x1010), vec![0x1000]);
+    let tail = cari_xref(db.ambil_xref_ke(0x1020), 0x1017, JenisXref::TailCall);
+    assert!(tail.is_some(), "Jump ke entry fungsi lain harus jadi tail call");
+    assert_eq!(db.ambil_caller(0x1020), vec![0x1010]);
+}
+
+#[test]
+fn test_xref_code_ke_data_dengan_ukuran_akses() {
+    let vmem = buat_vmem_program();
+    let mut analyzer = RecursiveDescent::new("x86_64");
+    analyzer.lakukan_analisis_full(&vmem);
+    let db = &analyzer.xref_db;
+    let baca = cari_xref(db.ambil_xref_ke(0x3000), 0x1005, JenisXref::Baca).expect("Baca global harus tercatat");
+    assert_eq!(baca.ukuran_akses, 4);
+    let tulis = cari_xref(db.ambil_xref_ke(0x3008), 0x1010, JenisXref::Tulis).expect("Tulis global harus tercatat");
+    assert_eq!(tulis.ukuran_akses, 8);
+    assert!(cari_xref(db.ambil_xref_ke(0x3008), 0x1010, JenisXref::Baca).is_none(), "Mov ke memori bukan baca");
+}
+
+#[test]
+fn test_xref_pointer_data() {
+    let vmem = buat_vmem_program();
+    let mut analyzer = RecursiveDescent::new("x86_64");
+    analyzer.lakukan_analisis_full(&vmem);
+    let pointer = analyzer.xref_db.ambil_xref_dari(0x3000);
+    assert_eq!(pointer.len(), 1);
+    assert_eq!(pointer[0].ke, 0x1020);
+    assert_eq!(pointer[0].jenis, JenisXref::PointerData);
+    assert!(analyzer.xref_db.ambil_xref_dari(0x3008).is_empty(), "Slot nol bukan pointer");
+}
+
+#[test]
+fn test_database_xref_dedup_dan_geser() {
+    let mut db = DatabaseXref::new();
+    let entri = EntriXref { dari: 0x1000, ke: 0x2000, jenis: JenisXref::Call, ukuran_akses: 0, fungsi_asal: Some(0x1000) };
+    db.tambah_xref(entri.clone());
+    db.tambah_xref(entri);
+    assert_eq!(db.jumlah_xref(), 1, "Xref duplikat tidak boleh dicatat dua kali");
+    db.geser_alamat(|a| a + 0x10000);
+    assert!(db.ambil_xref_ke(0x2000).is_empty());
+    assert_eq!(db.ambil_caller(0x12000), vec![0x11000]);
+    for kode in 0..7 {
+        assert_eq!(JenisXref::dari_kode(kode).unwrap().kode(), kode);
+    }
+    assert!(JenisXref::dari_kode(7).is_none());
+}
+
+fn xref_satu_instruksi(arch: &str, bytes: &[u8], alamat: u64) -> Vec<EntriXref> {
+    let mut vmem = VirtualMemory::baru(0x1000, arch, "raw");
+    vmem.tambah_segment(0x1000, vec![0; 0x1000], IzinAkses::ReadExecute, ".text".to_string());
+    let engine = DisasmEngine::buat_engine_baru(arch);
+    let instr = engine.ambil_satu_instruksi(bytes, alamat).expect("Instruksi harus bisa didekode");
+    kumpulkan_xref_instruksi(&instr, alamat, &vmem)
+}
+
+#[test]
+fn test_xref_call_mips_jal() {
+    let xref = xref_satu_instruksi("mips", &[0x0C, 0x00, 0x04, 0x40], 0x1000);
+    assert_eq!(xref.len(), 1);
+    assert_eq!(xref[0].ke, 0x1100);
+    assert_eq!(xref[0].jenis, JenisXref::Call, "jal MIPS adalah call");
+}
+
+#[test]
+fn test_xref_call_riscv_jal_ra() {
+    let call = xref_satu_instruksi("riscv64", &[0xEF, 0x00, 0x00, 0x10], 0x1000);
+    assert_eq!(call.len(), 1);
+    assert_eq!(call[0].ke, 0x1100);
+    assert_eq!(call[0].jenis, JenisXref::Call, "jal ra adalah call");
+    let jump = xref_satu_instruksi("riscv64", &[0x6F, 0x00, 0x00, 0x10], 0x1000);
+    assert_eq!(jump.len(), 1);
+    assert_eq!(jump[0].jenis, JenisXref::Jump, "jal zero adalah jump");
+}
+
+#[test]
+fn test_xref_call_arm_blx() {
+    let xref = xref_satu_instruksi("arm", &[0x40, 0x00, 0x00, 0xFA], 0x1000);
+    assert_eq!(xref.len(), 1);
+    assert_eq!(xref[0].ke, 0x1108);
+    assert_eq!(xref[0].jenis, JenisXref::Call, "blx ARM adalah call");
+}
@@ -30,8 +30,89 @@ struct ContextDecompiler;
 
 struct ContextDecompiler *buat_konteks_decompiler(void);
 
+/**
+ * # Safety
+ * `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+ */
+int atur_base_rebase(struct ContextDecompiler *ctx_ptr, uint64_t base_baru);
+
+/**
+ * # Safety
+ * `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+ */
+int atur_sembunyikan_crt(struct ContextDecompiler *ctx_ptr, int aktif);
+
+/**
+ * # Safety
+ * `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+ */
+int atur_sintaks_listing(struct ContextDecompiler *ctx_ptr, int sintaks);
+
+/**
+ * # Safety
+ * `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+ * `path_ptr` harus null atau string C yang diakhiri nul.
+ */
+int tambah_spec_semantik(struct ContextDecompiler *ctx_ptr, const char *path_ptr);
+
 void hapus_konteks_decompiler(struct ContextDecompiler *ctx_ptr);
 
 int muat_file_biner(struct ContextDecompiler *ctx_ptr, const char *path_ptr);
 
+/**
+ * # Safety
+ * `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+ * `buffer` boleh null atau harus dapat ditulis sebanyak `panjang_buffer` byte.
+ */
+int ambil_listing_asm(struct ContextDecompiler *ctx_ptr, char *buffer, uintptr_t panjang_buffer);
+
+/**
+ * # Safety
+ * `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+ */
+int jumlah_string_terdeteksi(const struct ContextDecompiler *ctx_ptr);
+
+/**
+ * # Safety
+ * `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+ * `out_alamat` boleh null; `buffer` boleh null atau harus dapat ditulis sebanyak `panjang_buffer` byte.
+ */
+int ambil_string_terdeteksi(const struct ContextDecompiler *ctx_ptr,
+                            uintptr_t index,
+                            uint64_t *out_alamat,
+                            char *buffer,
+                            uintptr_t panjang_buffer);
+
+/**
+ * # Safety
+ * `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+ * `out_referensi` harus dapat ditulis sebanyak `kapasitas` elemen.
+ */
+int ambil_referensi_string(const struct ContextDecompiler *ctx_ptr,
+                           uintptr_t index,
+                           uint64_t *out_referensi,
+                           uintptr_t kapasitas);
+
+/**
+ * # Safety
+ * `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+ * `out_dari` dan `out_jenis` boleh null atau harus dapat ditulis sebanyak `kapasitas` elemen.
+ */
+int ambil_xref_ke(const struct ContextDecompiler *ctx_ptr,
+                  uint64_t alamat,
+                  uint64_t *out_dari,
+                  int *out_jenis,
+                  uintptr_t kapasitas);
+
+/**
+ * # Safety
+ * `ctx_ptr` harus null atau pointer dari `buat_konteks_decompiler` yang belum dihapus.
+ * `out_ke` dan `out_jenis` boleh null atau harus dapat ditulis sebanyak `kapasitas` elemen.
+ */
+int ambil_xref_dari(const struct ContextDecompiler *ctx_ptr,
+                    uint64_t alamat,
+                    uint64_t *out_ke,
+                    int *out_jenis,
+                    uintptr_t kapasitas);
+
 #endif  /* RCDECOMP_CORE_H */